@@ -0,0 +1,223 @@
+//! The guest program for the cycle benchmark.
+//!
+//! It loads an input from the host, runs one verification entry point on it, and reports the
+//! boundaries of the expensive steps to the host.
+//!
+//! The input is a list of items, each item is prefixed with its length in a little-endian
+//! `u32`, and the first item is the id of the entry point.
+//!
+//! The witnesses are decoded as the on-chain scripts do, so both the witness envelopes and the
+//! bare witnesses are accepted.
+
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+use core::arch::asm;
+
+use eth_light_client_in_ckb_verification::{
+    types::{core, decode_client, decode_legacy_witness, decode_witness, packed, prelude::*},
+    utilities::bls::PackedClientSyncCommittee,
+};
+
+ckb_std::entry!(program_entry);
+ckb_std::default_alloc!();
+
+// Custom syscalls which are provided by the host.
+const SYSCALL_INPUT_LENGTH: u64 = 9000;
+const SYSCALL_LOAD_INPUT: u64 = 9001;
+const SYSCALL_PROFILE_STEP: u64 = 9002;
+
+// The ids of entry points, they should be same as the ids in the host.
+const CLIENT_BOOTSTRAP: u8 = 1;
+const CLIENT_UPDATE: u8 = 2;
+const CLIENT_UPDATE_UNCOMPRESSED: u8 = 3;
+const SYNC_COMMITTEE_UPDATE: u8 = 4;
+const SYNC_COMMITTEE_UPDATE_UNCOMPRESSED: u8 = 5;
+const TRANSACTION_PROOF: u8 = 6;
+const TRANSACTION_PAYLOAD: u8 = 7;
+
+// The exit codes for errors which are not returned from the verification.
+const ERROR_BAD_INPUT: i8 = -1;
+const ERROR_UNKNOWN_ENTRY: i8 = -2;
+
+#[no_mangle]
+pub fn eth_light_client_in_ckb_profile_step(step: u8, is_begin: bool) {
+    unsafe {
+        syscall(SYSCALL_PROFILE_STEP, u64::from(step), u64::from(is_begin));
+    }
+}
+
+unsafe fn syscall(number: u64, arg0: u64, arg1: u64) -> u64 {
+    let mut ret = arg0;
+    asm!("ecall", inout("a0") ret, in("a1") arg1, in("a7") number);
+    ret
+}
+
+fn load_input() -> Vec<u8> {
+    let length = unsafe { syscall(SYSCALL_INPUT_LENGTH, 0, 0) } as usize;
+    let mut input = vec![0u8; length];
+    unsafe {
+        syscall(SYSCALL_LOAD_INPUT, input.as_mut_ptr() as u64, length as u64);
+    }
+    input
+}
+
+fn split_items(mut input: &[u8]) -> Option<Vec<&[u8]>> {
+    let mut items = Vec::new();
+    while !input.is_empty() {
+        if input.len() < 4 {
+            return None;
+        }
+        let mut length_bytes = [0u8; 4];
+        length_bytes.copy_from_slice(&input[..4]);
+        let length = u32::from_le_bytes(length_bytes) as usize;
+        if input.len() < 4 + length {
+            return None;
+        }
+        items.push(&input[4..4 + length]);
+        input = &input[4 + length..];
+    }
+    Some(items)
+}
+
+fn program_entry() -> i8 {
+    let input = load_input();
+    let items = match split_items(&input) {
+        Some(items) if !items.is_empty() => items,
+        _ => return ERROR_BAD_INPUT,
+    };
+    match (items[0].first(), &items[1..]) {
+        (Some(&CLIENT_BOOTSTRAP), [bootstrap, sync_committee]) => {
+            verify_client_bootstrap(bootstrap, sync_committee)
+        }
+        (Some(&CLIENT_UPDATE), [update, old_client, new_client, root, sync_committee]) => {
+            verify_client_update::<packed::ClientSyncCommitteeReader>(
+                update,
+                old_client,
+                new_client,
+                root,
+                sync_committee,
+            )
+        }
+        (
+            Some(&CLIENT_UPDATE_UNCOMPRESSED),
+            [update, old_client, new_client, root, sync_committee],
+        ) => verify_client_update::<packed::ClientUncompressedSyncCommitteeReader>(
+            update,
+            old_client,
+            new_client,
+            root,
+            sync_committee,
+        ),
+        (Some(&SYNC_COMMITTEE_UPDATE), [update, slot, root, current, next]) => {
+            verify_sync_committee_update::<packed::ClientSyncCommitteeReader>(
+                update, slot, root, current, next,
+            )
+        }
+        (Some(&SYNC_COMMITTEE_UPDATE_UNCOMPRESSED), [update, slot, root, current, next]) => {
+            verify_sync_committee_update::<packed::ClientUncompressedSyncCommitteeReader>(
+                update, slot, root, current, next,
+            )
+        }
+        (Some(&TRANSACTION_PROOF), [client, proof]) => verify_transaction_proof(client, proof),
+        (Some(&TRANSACTION_PAYLOAD), [proof, payload]) => {
+            verify_transaction_payload(proof, payload)
+        }
+        _ => ERROR_UNKNOWN_ENTRY,
+    }
+}
+
+fn verify_client_bootstrap(bootstrap: &[u8], sync_committee: &[u8]) -> i8 {
+    let bootstrap: core::ClientBootstrap =
+        match decode_witness::<packed::ClientBootstrapReader>(bootstrap) {
+            Ok((_, bootstrap)) => bootstrap.unpack(),
+            Err(_) => return ERROR_BAD_INPUT,
+        };
+    let sync_committee = packed::ClientSyncCommitteeReader::new_unchecked(sync_committee);
+    match bootstrap.verify_packed_client_sync_committee(sync_committee) {
+        Ok(()) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+fn verify_client_update<'r, P>(
+    update: &'r [u8],
+    old_client: &'r [u8],
+    new_client: &'r [u8],
+    root: &'r [u8],
+    sync_committee: &'r [u8],
+) -> i8
+where
+    P: PackedClientSyncCommittee<'r>,
+{
+    let update = match decode_legacy_witness::<packed::ClientUpdateReader>(update) {
+        Ok((_, update)) => update,
+        Err(_) => return ERROR_BAD_INPUT,
+    };
+    let (old_client, new_client) = match (decode_client(old_client), decode_client(new_client)) {
+        (Ok(old_client), Ok(new_client)) => (old_client, new_client),
+        _ => return ERROR_BAD_INPUT,
+    };
+    let root: core::Hash = packed::HashReader::new_unchecked(root).unpack();
+    let sync_committee = P::new_unchecked(sync_committee);
+    match update.verify_client_update(old_client, root, sync_committee, new_client) {
+        Ok(()) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+fn verify_sync_committee_update<'r, P>(
+    update: &'r [u8],
+    slot: &'r [u8],
+    root: &'r [u8],
+    current: &'r [u8],
+    next: &'r [u8],
+) -> i8
+where
+    P: PackedClientSyncCommittee<'r>,
+{
+    let update: core::SyncCommitteeUpdate =
+        match decode_witness::<packed::SyncCommitteeUpdateReader>(update) {
+            Ok((_, update)) => update.unpack(),
+            Err(_) => return ERROR_BAD_INPUT,
+        };
+    let slot: u64 = packed::Uint64Reader::new_unchecked(slot).unpack();
+    let root: core::Hash = packed::HashReader::new_unchecked(root).unpack();
+    let current = P::new_unchecked(current);
+    let next = P::new_unchecked(next);
+    match update.verify_packed_client_sync_committee(slot, root, current, next) {
+        Ok(()) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+fn verify_transaction_proof(client: &[u8], proof: &[u8]) -> i8 {
+    let (client, proof) = match (
+        decode_client(client),
+        decode_witness::<packed::TransactionProofReader>(proof),
+    ) {
+        (Ok(client), Ok((_, proof))) => (client, proof),
+        _ => return ERROR_BAD_INPUT,
+    };
+    match client.verify_packed_transaction_proof(proof) {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+fn verify_transaction_payload(proof: &[u8], payload: &[u8]) -> i8 {
+    let (proof, payload): (core::TransactionProof, _) = match (
+        decode_witness::<packed::TransactionProofReader>(proof),
+        decode_witness::<packed::TransactionPayloadReader>(payload),
+    ) {
+        (Ok((_, proof)), Ok((_, payload))) => (proof.unpack(), payload),
+        _ => return ERROR_BAD_INPUT,
+    };
+    match proof.verify_packed_payload(payload) {
+        Ok(()) => 0,
+        Err(err) => err as i8,
+    }
+}
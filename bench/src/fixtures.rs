@@ -0,0 +1,297 @@
+//! Builds the inputs of the guest program from the mainnet test data.
+
+use std::fs;
+
+use eth2_types::{
+    light_client_bootstrap::PatchedLightClientBootstrap,
+    light_client_finality_update::{LightClientFinalityUpdate, PatchedLightClientFinalityUpdate},
+    light_client_update::PatchedLightClientUpdate,
+    BeaconBlock, BeaconBlockHeader, MainnetEthSpec,
+};
+use eth_light_client_in_ckb_prover::{
+    CachedBeaconBlock, DummyLightClient, LightClientBootstrap, LightClientUpdate, Receipts,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::forks,
+    types::{core, packed, prelude::*, WITNESS_ENVELOPE_VERSION},
+};
+use ethers_core::types::TransactionReceipt;
+use tree_hash::Hash256;
+
+const DATA_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../tests/data/mainnet");
+
+// The ids of entry points, they should be same as the ids in the guest program.
+const CLIENT_BOOTSTRAP: u8 = 1;
+const CLIENT_UPDATE: u8 = 2;
+const CLIENT_UPDATE_UNCOMPRESSED: u8 = 3;
+const SYNC_COMMITTEE_UPDATE: u8 = 4;
+const SYNC_COMMITTEE_UPDATE_UNCOMPRESSED: u8 = 5;
+const TRANSACTION_PROOF: u8 = 6;
+const TRANSACTION_PAYLOAD: u8 = 7;
+
+const BOOTSTRAP_SLOT: u64 = 6632736;
+const FINALIZED_SLOTS: [u64; 4] = [6632768, 6632800, 6632832, 6632864];
+const SYNC_COMMITTEE_BOOTSTRAP_SLOT: u64 = 4612096;
+const SYNC_COMMITTEE_PERIOD: u64 = 563;
+const TRANSACTION_BLOCK_SLOT: u64 = 6632854;
+const TRANSACTION_INDEX: usize = 0;
+
+/// A benchmark case: a name and the input of the guest program.
+pub struct Case {
+    pub name: &'static str,
+    pub input: Vec<u8>,
+}
+
+/// Builds all benchmark cases.
+pub fn build_cases() -> Vec<Case> {
+    let mut cases = Vec::new();
+    cases.push(client_bootstrap());
+    cases.extend(client_update());
+    cases.extend(sync_committee_update());
+    cases.extend(transaction());
+    cases
+}
+
+fn client_bootstrap() -> Case {
+    let bootstrap = load_bootstrap(BOOTSTRAP_SLOT);
+    let client_bootstrap = bootstrap.build_client_bootstrap();
+    let sync_committee = bootstrap.build_client_sync_committee();
+    Case {
+        name: "client-bootstrap",
+        input: encode_input(
+            CLIENT_BOOTSTRAP,
+            &[
+                wrap_witness(core::Witness::ClientBootstrap(client_bootstrap)).as_slice(),
+                sync_committee.pack().as_slice(),
+            ],
+        ),
+    }
+}
+
+fn client_update() -> Vec<Case> {
+    let genesis_validators_root = load_genesis_validators_root();
+    let mut light_client = DummyLightClient::new(load_bootstrap(BOOTSTRAP_SLOT));
+    let sync_committee = light_client.client_sync_committee().clone();
+    let uncompressed_sync_committee = sync_committee.uncompress().unwrap();
+
+    let old_client = light_client.client().clone();
+    let finalized_slot = FINALIZED_SLOTS[0];
+    let headers = ((old_client.maximal_slot + 1)..=finalized_slot)
+        .map(load_beacon_block_header)
+        .collect::<Vec<_>>();
+    let client_update =
+        light_client.apply_finality_update(load_finality_update(finalized_slot), headers);
+    let mut new_client = light_client.client().clone();
+    new_client.id = old_client.id;
+
+    let update = wrap_witness(core::Witness::ClientUpdate(client_update));
+    let old_client = old_client.pack();
+    let new_client = new_client.pack();
+    let root = packed::Hash::from_slice(genesis_validators_root.as_bytes()).unwrap();
+    vec![
+        Case {
+            name: "client-update",
+            input: encode_input(
+                CLIENT_UPDATE,
+                &[
+                    update.as_slice(),
+                    old_client.as_slice(),
+                    new_client.as_slice(),
+                    root.as_slice(),
+                    sync_committee.pack().as_slice(),
+                ],
+            ),
+        },
+        Case {
+            name: "client-update-uncompressed",
+            input: encode_input(
+                CLIENT_UPDATE_UNCOMPRESSED,
+                &[
+                    update.as_slice(),
+                    old_client.as_slice(),
+                    new_client.as_slice(),
+                    root.as_slice(),
+                    uncompressed_sync_committee.pack().as_slice(),
+                ],
+            ),
+        },
+    ]
+}
+
+fn sync_committee_update() -> Vec<Case> {
+    let genesis_validators_root = load_genesis_validators_root();
+    let current_sync_committee =
+        load_bootstrap(SYNC_COMMITTEE_BOOTSTRAP_SLOT).build_client_sync_committee();
+    let update = load_light_client_update(SYNC_COMMITTEE_PERIOD);
+    let sync_committee_update = wrap_witness(core::Witness::SyncCommitteeUpdate(
+        update.build_sync_committee_update(),
+    ));
+    let next_sync_committee = update.build_next_client_sync_committee();
+
+    let slots_in_one_period =
+        forks::phase0::SLOTS_PER_EPOCH * forks::altair::EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+    let slot = (slots_in_one_period * SYNC_COMMITTEE_PERIOD).pack();
+    let root = packed::Hash::from_slice(genesis_validators_root.as_bytes()).unwrap();
+
+    let current_uncompressed_sync_committee = current_sync_committee.uncompress().unwrap();
+    let next_uncompressed_sync_committee = next_sync_committee.uncompress().unwrap();
+    vec![
+        Case {
+            name: "sync-committee-update",
+            input: encode_input(
+                SYNC_COMMITTEE_UPDATE,
+                &[
+                    sync_committee_update.as_slice(),
+                    slot.as_slice(),
+                    root.as_slice(),
+                    current_sync_committee.pack().as_slice(),
+                    next_sync_committee.pack().as_slice(),
+                ],
+            ),
+        },
+        Case {
+            name: "sync-committee-update-uncompressed",
+            input: encode_input(
+                SYNC_COMMITTEE_UPDATE_UNCOMPRESSED,
+                &[
+                    sync_committee_update.as_slice(),
+                    slot.as_slice(),
+                    root.as_slice(),
+                    current_uncompressed_sync_committee.pack().as_slice(),
+                    next_uncompressed_sync_committee.pack().as_slice(),
+                ],
+            ),
+        },
+    ]
+}
+
+fn transaction() -> Vec<Case> {
+    let mut light_client = DummyLightClient::new(load_bootstrap(BOOTSTRAP_SLOT));
+    for finalized_slot in FINALIZED_SLOTS {
+        let headers = ((light_client.client().maximal_slot + 1)..=finalized_slot)
+            .map(load_beacon_block_header)
+            .collect::<Vec<_>>();
+        let _client_update =
+            light_client.apply_finality_update(load_finality_update(finalized_slot), headers);
+    }
+    let client = light_client.client().clone();
+
+    let block: CachedBeaconBlock = {
+        let json_value = load_json("beacon/block", TRANSACTION_BLOCK_SLOT);
+        let block: BeaconBlock<MainnetEthSpec> =
+            serde_json::from_value(json_value["data"]["message"].clone()).unwrap();
+        block.into()
+    };
+    let receipts: Receipts = {
+        let json_value = load_json_file(&format!(
+            "{DATA_ROOT}/execution/block_receipts/number-{:09}.json",
+            block.number()
+        ));
+        let receipts: Vec<TransactionReceipt> =
+            serde_json::from_value(json_value["result"].clone()).unwrap();
+        receipts.into()
+    };
+
+    let header = light_client
+        .beacon_header_at_slot(TRANSACTION_BLOCK_SLOT)
+        .unwrap();
+    let proof = wrap_witness(core::Witness::TransactionProof(core::TransactionProof {
+        header: header.inner.clone(),
+        transaction_index: TRANSACTION_INDEX as u64,
+        receipts_root: receipts.root(),
+        header_mmr_proof: light_client.build_header_mmr_proof(TRANSACTION_BLOCK_SLOT),
+        transaction_ssz_proof: block.generate_transaction_proof_for_block_body(TRANSACTION_INDEX),
+        receipt_mpt_proof: receipts.generate_proof(TRANSACTION_INDEX),
+        receipts_root_ssz_proof: block.generate_receipts_root_proof_for_block_body(),
+    }));
+    let payload = wrap_witness(core::Witness::TransactionPayload(
+        core::TransactionPayload {
+            transaction: block.transaction(TRANSACTION_INDEX).unwrap().to_vec(),
+            receipt: receipts.encode_data(TRANSACTION_INDEX),
+        },
+    ));
+
+    vec![
+        Case {
+            name: "transaction-proof",
+            input: encode_input(
+                TRANSACTION_PROOF,
+                &[client.pack().as_slice(), proof.as_slice()],
+            ),
+        },
+        Case {
+            name: "transaction-payload",
+            input: encode_input(TRANSACTION_PAYLOAD, &[proof.as_slice(), payload.as_slice()]),
+        },
+    ]
+}
+
+fn wrap_witness(witness: core::Witness) -> packed::WitnessEnvelope {
+    core::WitnessEnvelope {
+        version: WITNESS_ENVELOPE_VERSION,
+        witness,
+    }
+    .pack()
+}
+
+fn encode_input(entry: u8, items: &[&[u8]]) -> Vec<u8> {
+    let mut input = Vec::new();
+    for item in [&[entry][..]].iter().chain(items.iter()) {
+        input.extend_from_slice(&(item.len() as u32).to_le_bytes());
+        input.extend_from_slice(item);
+    }
+    input
+}
+
+fn load_json_file(path: &str) -> serde_json::Value {
+    let json_str =
+        fs::read_to_string(path).unwrap_or_else(|err| panic!("failed to read {path}: {err}"));
+    serde_json::from_str(&json_str).unwrap()
+}
+
+fn load_json(case_dir: &str, slot: u64) -> serde_json::Value {
+    load_json_file(&format!("{DATA_ROOT}/{case_dir}/slot-{slot:09}.json"))
+}
+
+fn load_bootstrap(slot: u64) -> LightClientBootstrap {
+    let json_value = load_json("light_client/bootstrap", slot);
+    let bootstrap: PatchedLightClientBootstrap<MainnetEthSpec> =
+        serde_json::from_value(json_value["data"].clone()).unwrap();
+    bootstrap.into()
+}
+
+fn load_finality_update(slot: u64) -> LightClientFinalityUpdate<MainnetEthSpec> {
+    let json_value = load_json("light_client/finality_update", slot);
+    let finality_update: PatchedLightClientFinalityUpdate<MainnetEthSpec> =
+        serde_json::from_value(json_value["data"].clone()).unwrap();
+    finality_update.into()
+}
+
+fn load_light_client_update(period: u64) -> LightClientUpdate {
+    let json_value = load_json_file(&format!(
+        "{DATA_ROOT}/light_client/update/period-{period:06}.json"
+    ));
+    let update: PatchedLightClientUpdate<MainnetEthSpec> =
+        serde_json::from_value(json_value[0]["data"].clone()).unwrap();
+    update.into()
+}
+
+fn load_beacon_block_header(slot: u64) -> BeaconBlockHeader {
+    let json_value = load_json("beacon/header", slot);
+    if json_value.get("code").is_some() {
+        BeaconBlockHeader {
+            slot: slot.into(),
+            proposer_index: 0,
+            parent_root: Default::default(),
+            state_root: Default::default(),
+            body_root: Default::default(),
+        }
+    } else {
+        serde_json::from_value(json_value["data"]["header"]["message"].clone()).unwrap()
+    }
+}
+
+fn load_genesis_validators_root() -> Hash256 {
+    let json_value = load_json_file(&format!("{DATA_ROOT}/beacon_genesis.json"));
+    serde_json::from_value(json_value["genesis_validators_root"].clone()).unwrap()
+}
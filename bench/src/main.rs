@@ -0,0 +1,124 @@
+//! Measures the CKB-VM cycles of the verification entry points.
+//!
+//! The guest program should be built before running this benchmark, see `make bench-guest`.
+
+use std::{fs, path::PathBuf, process};
+
+use ckb_vm::Bytes;
+use clap::Parser;
+
+mod fixtures;
+mod report;
+mod vm;
+
+use report::{CaseReport, Report};
+
+const DEFAULT_GUEST: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/guest/target/riscv64imac-unknown-none-elf/release/eth_light_client_in_ckb-bench-guest"
+);
+const DEFAULT_BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/baseline.json");
+
+#[derive(Parser)]
+#[command(about = "Measure the CKB-VM cycles of the verification entry points.")]
+struct Args {
+    /// The path of the guest program.
+    #[arg(long, default_value = DEFAULT_GUEST)]
+    guest: PathBuf,
+    /// The path of the baseline report.
+    #[arg(long, default_value = DEFAULT_BASELINE)]
+    baseline: PathBuf,
+    /// Save the results as the new baseline instead of comparing with it.
+    #[arg(long)]
+    save_baseline: bool,
+    /// Exit with a non-zero code if any case costs more cycles than the baseline by more than
+    /// this threshold (in percent).
+    #[arg(long, default_value_t = 1.0)]
+    threshold: f64,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let program: Bytes = fs::read(&args.guest)
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "failed to read the guest program {}: {err}",
+                args.guest.display()
+            );
+            process::exit(2);
+        })
+        .into();
+
+    let mut report = Report::default();
+    let mut has_failures = false;
+    for case in fixtures::build_cases() {
+        let outcome = vm::run(&program, case.input).unwrap_or_else(|err| {
+            eprintln!("failed to run case {}: {err:?}", case.name);
+            process::exit(2);
+        });
+        if outcome.exit_code != 0 {
+            eprintln!(
+                "case {} failed with exit code {}",
+                case.name, outcome.exit_code
+            );
+            has_failures = true;
+        }
+        report
+            .cases
+            .insert(case.name.to_owned(), CaseReport::from(outcome));
+    }
+
+    if args.save_baseline {
+        report.print(None, args.threshold);
+        report.print_encoding_comparison();
+        if has_failures {
+            eprintln!("refuse to save a baseline with failed cases");
+            process::exit(1);
+        }
+        report.save(&args.baseline).unwrap_or_else(|err| {
+            eprintln!(
+                "failed to save the baseline {}: {err}",
+                args.baseline.display()
+            );
+            process::exit(2);
+        });
+        return;
+    }
+
+    if !args.baseline.exists() {
+        eprintln!(
+            "no baseline at {}, run with `--save-baseline` to create one",
+            args.baseline.display()
+        );
+        process::exit(2);
+    }
+    let baseline = Report::load(&args.baseline).unwrap_or_else(|err| {
+        eprintln!(
+            "failed to load the baseline {}: {err}",
+            args.baseline.display()
+        );
+        process::exit(2);
+    });
+    let regressions = report.print(Some(&baseline), args.threshold);
+    report.print_encoding_comparison();
+    let missing_cases = report.missing_cases(&baseline);
+    if !missing_cases.is_empty() {
+        eprintln!(
+            "no baseline for cases: {}, run with `--save-baseline` to update the baseline",
+            missing_cases.join(", ")
+        );
+        process::exit(2);
+    }
+    if !regressions.is_empty() {
+        eprintln!(
+            "cycles regressed by more than {}%: {}",
+            args.threshold,
+            regressions.join(", ")
+        );
+        process::exit(1);
+    }
+    if has_failures {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,137 @@
+//! The report of a benchmark run, and the comparison against a baseline.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::vm::Outcome;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub cases: BTreeMap<String, CaseReport>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CaseReport {
+    pub exit_code: i8,
+    pub cycles: u64,
+    pub steps: BTreeMap<String, u64>,
+}
+
+impl From<Outcome> for CaseReport {
+    fn from(outcome: Outcome) -> Self {
+        let steps = outcome
+            .steps
+            .into_iter()
+            .map(|(step, cycles)| (step.name().to_owned(), cycles))
+            .collect();
+        Self {
+            exit_code: outcome.exit_code,
+            cycles: outcome.cycles,
+            steps,
+        }
+    }
+}
+
+impl Report {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let json_str = fs::read_to_string(path)?;
+        serde_json::from_str(&json_str).map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json_str = serde_json::to_string_pretty(self)
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        fs::write(path, json_str + "\n")
+    }
+
+    /// Returns the names of cases which are not in the baseline.
+    pub fn missing_cases(&self, baseline: &Self) -> Vec<String> {
+        self.cases
+            .keys()
+            .filter(|name| !baseline.cases.contains_key(*name))
+            .cloned()
+            .collect()
+    }
+
+    /// Prints the report, compared with the baseline if any.
+    ///
+    /// Returns the names of cases which cost more cycles than the baseline by more than the
+    /// threshold (in percent).
+    pub fn print(&self, baseline_opt: Option<&Self>, threshold: f64) -> Vec<String> {
+        let mut regressions = Vec::new();
+        println!(
+            "{:<40} {:>6} {:>16} {:>16} {:>9}",
+            "case / step", "exit", "cycles", "baseline", "change"
+        );
+        for (name, case) in &self.cases {
+            let base_case_opt = baseline_opt.and_then(|baseline| baseline.cases.get(name));
+            let base_cycles_opt = base_case_opt.map(|base_case| base_case.cycles);
+            print_row(name, Some(case.exit_code), case.cycles, base_cycles_opt);
+            for (step, cycles) in &case.steps {
+                let base_cycles_opt =
+                    base_case_opt.and_then(|base_case| base_case.steps.get(step).copied());
+                print_row(&format!("  {step}"), None, *cycles, base_cycles_opt);
+            }
+            if let Some(base_cycles) = base_cycles_opt {
+                if change_in_percent(case.cycles, base_cycles) > threshold {
+                    regressions.push(name.to_owned());
+                }
+            }
+        }
+        regressions
+    }
+}
+
+/// The suffix of the cases which use the uncompressed sync committee.
+const UNCOMPRESSED_SUFFIX: &str = "-uncompressed";
+
+impl Report {
+    /// Prints the cycles of the cases with the uncompressed sync committee, compared with the
+    /// same cases with the compressed sync committee.
+    pub fn print_encoding_comparison(&self) {
+        println!(
+            "{:<40} {:>16} {:>16} {:>9}",
+            "sync committee encoding", "compressed", "uncompressed", "change"
+        );
+        for (name, case) in &self.cases {
+            let compressed_opt =
+                name.strip_suffix(UNCOMPRESSED_SUFFIX)
+                    .and_then(|compressed_name| {
+                        self.cases
+                            .get(compressed_name)
+                            .map(|compressed_case| (compressed_name, compressed_case))
+                    });
+            let (compressed_name, compressed_case) = if let Some(compressed) = compressed_opt {
+                compressed
+            } else {
+                continue;
+            };
+            let change = change_in_percent(case.cycles, compressed_case.cycles);
+            println!(
+                "{compressed_name:<40} {:>16} {:>16} {change:>+8.2}%",
+                compressed_case.cycles, case.cycles
+            );
+        }
+    }
+}
+
+fn print_row(name: &str, exit_code_opt: Option<i8>, cycles: u64, base_cycles_opt: Option<u64>) {
+    let exit_code = exit_code_opt
+        .map(|code| code.to_string())
+        .unwrap_or_default();
+    let (base_cycles, change) = base_cycles_opt
+        .map(|base_cycles| {
+            let change = change_in_percent(cycles, base_cycles);
+            (base_cycles.to_string(), format!("{change:+.2}%"))
+        })
+        .unwrap_or_else(|| ("-".to_owned(), "-".to_owned()));
+    println!("{name:<40} {exit_code:>6} {cycles:>16} {base_cycles:>16} {change:>9}");
+}
+
+fn change_in_percent(cycles: u64, base_cycles: u64) -> f64 {
+    if base_cycles == 0 {
+        return 0.0;
+    }
+    (cycles as f64 - base_cycles as f64) * 100.0 / base_cycles as f64
+}
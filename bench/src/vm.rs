@@ -0,0 +1,135 @@
+//! Runs the guest program in CKB-VM and collects the cycles.
+
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+
+use ckb_vm::{
+    cost_model::estimate_cycles,
+    machine::{DefaultCoreMachine, DefaultMachineBuilder, VERSION1},
+    memory::{sparse::SparseMemory, wxorx::WXorXMemory},
+    registers::{A0, A1, A7},
+    Bytes, CoreMachine, Error, Memory, Register, SupportMachine, Syscalls, TraceMachine, ISA_B,
+    ISA_IMC, ISA_MOP,
+};
+use eth_light_client_in_ckb_verification::profile::Step;
+
+// Custom syscalls which are provided for the guest program.
+const SYSCALL_INPUT_LENGTH: u64 = 9000;
+const SYSCALL_LOAD_INPUT: u64 = 9001;
+const SYSCALL_PROFILE_STEP: u64 = 9002;
+// The debug syscall of CKB.
+const SYSCALL_DEBUG: u64 = 2177;
+
+/// The result of running the guest program once.
+#[derive(Debug, Default)]
+pub struct Outcome {
+    pub exit_code: i8,
+    pub cycles: u64,
+    pub steps: BTreeMap<Step, u64>,
+}
+
+#[derive(Default)]
+struct StepCounter {
+    // The depth and the cycles when the outermost one begins.
+    running: BTreeMap<Step, (usize, u64)>,
+    totals: BTreeMap<Step, u64>,
+}
+
+struct BenchSyscalls {
+    input: Rc<Vec<u8>>,
+    counter: Rc<RefCell<StepCounter>>,
+}
+
+impl StepCounter {
+    fn mark(&mut self, step: Step, is_begin: bool, cycles: u64) {
+        if is_begin {
+            let entry = self.running.entry(step).or_insert((0, cycles));
+            entry.0 += 1;
+        } else if let Some(entry) = self.running.get_mut(&step) {
+            entry.0 -= 1;
+            if entry.0 == 0 {
+                let begin = entry.1;
+                self.running.remove(&step);
+                *self.totals.entry(step).or_default() += cycles - begin;
+            }
+        }
+    }
+}
+
+impl<Mac: SupportMachine> Syscalls<Mac> for BenchSyscalls {
+    fn initialize(&mut self, _machine: &mut Mac) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn ecall(&mut self, machine: &mut Mac) -> Result<bool, Error> {
+        let number = machine.registers()[A7].to_u64();
+        match number {
+            SYSCALL_INPUT_LENGTH => {
+                machine.set_register(A0, Mac::REG::from_u64(self.input.len() as u64));
+            }
+            SYSCALL_LOAD_INPUT => {
+                let addr = machine.registers()[A0].to_u64();
+                let length = machine.registers()[A1].to_u64() as usize;
+                let length = length.min(self.input.len());
+                machine
+                    .memory_mut()
+                    .store_bytes(addr, &self.input[..length])?;
+                machine.set_register(A0, Mac::REG::from_u64(0));
+            }
+            SYSCALL_PROFILE_STEP => {
+                let id = machine.registers()[A0].to_u64() as u8;
+                let is_begin = machine.registers()[A1].to_u64() != 0;
+                if let Some(step) = Step::from_id(id) {
+                    let cycles = machine.cycles();
+                    self.counter.borrow_mut().mark(step, is_begin, cycles);
+                }
+                machine.set_register(A0, Mac::REG::from_u64(0));
+            }
+            SYSCALL_DEBUG => {
+                let mut addr = machine.registers()[A0].to_u64();
+                let mut buffer = Vec::new();
+                loop {
+                    let byte = machine
+                        .memory_mut()
+                        .load8(&Mac::REG::from_u64(addr))?
+                        .to_u8();
+                    if byte == 0 {
+                        break;
+                    }
+                    buffer.push(byte);
+                    addr += 1;
+                }
+                eprintln!("[guest] {}", String::from_utf8_lossy(&buffer));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// Runs the guest program with the input.
+pub fn run(program: &Bytes, input: Vec<u8>) -> Result<Outcome, Error> {
+    let counter = Rc::new(RefCell::new(StepCounter::default()));
+    let syscalls = BenchSyscalls {
+        input: Rc::new(input),
+        counter: Rc::clone(&counter),
+    };
+    let core_machine = DefaultCoreMachine::<u64, WXorXMemory<SparseMemory<u64>>>::new(
+        ISA_IMC | ISA_B | ISA_MOP,
+        VERSION1,
+        u64::MAX,
+    );
+    let machine = DefaultMachineBuilder::new(core_machine)
+        .instruction_cycle_func(Box::new(estimate_cycles))
+        .syscall(Box::new(syscalls))
+        .build();
+    let mut machine = TraceMachine::new(machine);
+    machine.load_program(program, &[])?;
+    let exit_code = machine.run()?;
+    let cycles = machine.machine.cycles();
+    let steps = counter.borrow().totals.clone();
+    Ok(Outcome {
+        exit_code,
+        cycles,
+        steps,
+    })
+}
@@ -0,0 +1,73 @@
+use std::collections::BTreeMap;
+
+use eth_light_client_in_ckb_verification::consensus_specs::ChainConfig;
+
+use crate::LightClientUpdate;
+
+/// Stores the best light client update for each sync committee period.
+///
+/// The period of an update is the period of its attested header, and the next sync committee
+/// in it is for the next period.
+///
+/// The updates are ranked with the preset and the fork schedule of the chain, the default
+/// store is for the mainnet.
+#[derive(Clone)]
+pub struct BestUpdateStore {
+    config: ChainConfig,
+    updates: BTreeMap<u64, LightClientUpdate>,
+}
+
+impl Default for BestUpdateStore {
+    fn default() -> Self {
+        Self::new(ChainConfig::MAINNET)
+    }
+}
+
+impl BestUpdateStore {
+    pub fn new(config: ChainConfig) -> Self {
+        Self {
+            config,
+            updates: BTreeMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &ChainConfig {
+        &self.config
+    }
+
+    /// Stores the update if it's better than the stored one in the same period.
+    ///
+    /// Returns `true` if the update is stored; the worse one, if any, is dropped.
+    pub fn insert(&mut self, update: LightClientUpdate) -> bool {
+        let attested_slot: u64 = update.original().attested_header.slot.into();
+        let period = self
+            .config
+            .compute_sync_committee_period_at_slot(attested_slot);
+        let is_better = self
+            .updates
+            .get(&period)
+            .map(|stored| update.is_better_than(stored, &self.config))
+            .unwrap_or(true);
+        if is_better {
+            self.updates.insert(period, update);
+        }
+        is_better
+    }
+
+    pub fn get(&self, period: u64) -> Option<&LightClientUpdate> {
+        self.updates.get(&period)
+    }
+
+    pub fn remove(&mut self, period: u64) -> Option<LightClientUpdate> {
+        self.updates.remove(&period)
+    }
+
+    pub fn periods(&self) -> impl Iterator<Item = u64> + '_ {
+        self.updates.keys().copied()
+    }
+
+    /// Removes all updates before the period.
+    pub fn prune(&mut self, period: u64) {
+        self.updates = self.updates.split_off(&period);
+    }
+}
@@ -0,0 +1,316 @@
+use std::{fs, io, path::Path};
+
+use eth2_types::{BeaconState, BeaconStateError, EthSpec, MainnetEthSpec, Slot, Unsigned as _};
+use ssz::{DecodeError, Encode};
+use tree_hash::{Hash256, TreeHash};
+
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{
+        self,
+        forks::phase0::{BALANCES_PER_CHUNK, SLOTS_PER_HISTORICAL_ROOT},
+        ChainConfig,
+    },
+    types::core,
+};
+
+use crate::{
+    light_client_ssz::{decode_error_to_io_error, decompress_snappy},
+    ssz_proof::{SszNode, SszPathError, SszProof},
+};
+
+/// A beacon state with its SSZ merkle tree, to generate proofs for the fields in the state.
+///
+/// Only the states since Altair are supported, the states before Altair are rejected when they are
+/// converted.
+#[derive(Clone)]
+pub struct CachedBeaconState {
+    state: SszNode,
+    original: BeaconState<MainnetEthSpec>,
+}
+
+impl TryFrom<BeaconState<MainnetEthSpec>> for CachedBeaconState {
+    type Error = BeaconStateError;
+
+    fn try_from(state: BeaconState<MainnetEthSpec>) -> Result<Self, Self::Error> {
+        let validators = state
+            .validators()
+            .iter()
+            .map(SszNode::leaf)
+            .collect::<Vec<_>>();
+        let validator_registry_limit =
+            <MainnetEthSpec as EthSpec>::ValidatorRegistryLimit::to_usize();
+
+        let mut fields = vec![
+            // Versioning
+            ("genesis_time", SszNode::leaf(&state.genesis_time())),
+            (
+                "genesis_validators_root",
+                SszNode::leaf(&state.genesis_validators_root()),
+            ),
+            ("slot", SszNode::leaf(&state.slot())),
+            ("fork", SszNode::leaf(&state.fork())),
+            // History
+            (
+                "latest_block_header",
+                SszNode::leaf(state.latest_block_header()),
+            ),
+            (
+                "block_roots",
+                SszNode::vector(state.block_roots().iter().map(SszNode::leaf).collect()),
+            ),
+            (
+                "state_roots",
+                SszNode::vector(state.state_roots().iter().map(SszNode::leaf).collect()),
+            ),
+            ("historical_roots", SszNode::leaf(state.historical_roots())),
+            // Eth1
+            ("eth1_data", SszNode::leaf(state.eth1_data())),
+            ("eth1_data_votes", SszNode::leaf(state.eth1_data_votes())),
+            (
+                "eth1_deposit_index",
+                SszNode::leaf(&state.eth1_deposit_index()),
+            ),
+            // Registry
+            (
+                "validators",
+                SszNode::list(validators, validator_registry_limit),
+            ),
+            (
+                "balances",
+                SszNode::basic_list(&state.balances()[..], validator_registry_limit),
+            ),
+            // Randomness
+            ("randao_mixes", SszNode::leaf(state.randao_mixes())),
+            // Slashings
+            ("slashings", SszNode::leaf(state.slashings())),
+            // Participation
+            (
+                "previous_epoch_participation",
+                SszNode::leaf(state.previous_epoch_participation()?),
+            ),
+            (
+                "current_epoch_participation",
+                SszNode::leaf(state.current_epoch_participation()?),
+            ),
+            // Finality
+            (
+                "justification_bits",
+                SszNode::leaf(state.justification_bits()),
+            ),
+            (
+                "previous_justified_checkpoint",
+                SszNode::leaf(&state.previous_justified_checkpoint()),
+            ),
+            (
+                "current_justified_checkpoint",
+                SszNode::leaf(&state.current_justified_checkpoint()),
+            ),
+            (
+                "finalized_checkpoint",
+                SszNode::leaf(&state.finalized_checkpoint()),
+            ),
+            // Inactivity
+            (
+                "inactivity_scores",
+                SszNode::leaf(state.inactivity_scores()?),
+            ),
+            // Sync
+            (
+                "current_sync_committee",
+                SszNode::leaf(state.current_sync_committee()?.as_ref()),
+            ),
+            (
+                "next_sync_committee",
+                SszNode::leaf(state.next_sync_committee()?.as_ref()),
+            ),
+        ];
+        // Bellatrix
+        if let Ok(header) = state.latest_execution_payload_header() {
+            fields.push((
+                "latest_execution_payload_header",
+                SszNode::leaf_with_root(header.tree_hash_root()),
+            ));
+        }
+        // Capella
+        if let Ok(historical_summaries) = state.historical_summaries() {
+            let summaries = historical_summaries
+                .iter()
+                .map(|summary| {
+                    let bytes = summary.as_ssz_bytes();
+                    SszNode::container(vec![
+                        (
+                            "block_summary_root",
+                            SszNode::leaf_with_root(Hash256::from_slice(&bytes[..32])),
+                        ),
+                        (
+                            "state_summary_root",
+                            SszNode::leaf_with_root(Hash256::from_slice(&bytes[32..])),
+                        ),
+                    ])
+                })
+                .collect();
+            let historical_roots_limit =
+                <MainnetEthSpec as EthSpec>::HistoricalRootsLimit::to_usize();
+            fields.push((
+                "next_withdrawal_index",
+                SszNode::leaf(&state.next_withdrawal_index()?),
+            ));
+            fields.push((
+                "next_withdrawal_validator_index",
+                SszNode::leaf(&state.next_withdrawal_validator_index()?),
+            ));
+            fields.push((
+                "historical_summaries",
+                SszNode::list(summaries, historical_roots_limit),
+            ));
+        }
+
+        let state_node = SszNode::container(fields);
+        Ok(Self {
+            state: state_node,
+            original: state,
+        })
+    }
+}
+
+impl CachedBeaconState {
+    /// Decodes a beacon state from SSZ bytes, the fork is chosen by the slot.
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let spec = MainnetEthSpec::default_spec();
+        let state = BeaconState::from_ssz_bytes(bytes, &spec)?;
+        Self::try_from(state)
+            .map_err(|err| DecodeError::BytesInvalid(format!("unsupported beacon state: {err:?}")))
+    }
+
+    /// Loads a beacon state from an SSZ file, such as the response of the Beacon API
+    /// `/eth/v2/debug/beacon/states/{state_id}` in `application/octet-stream`.
+    pub fn from_ssz_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        Self::from_ssz_bytes(&bytes).map_err(decode_error_to_io_error)
+    }
+
+    /// Decodes a beacon state from SSZ bytes which are compressed in the snappy frame format,
+    /// such as the entries in era files.
+    pub fn from_ssz_snappy_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let bytes = decompress_snappy(bytes)?;
+        Self::from_ssz_bytes(&bytes).map_err(decode_error_to_io_error)
+    }
+
+    pub fn original(&self) -> &BeaconState<MainnetEthSpec> {
+        &self.original
+    }
+
+    pub fn slot(&self) -> Slot {
+        self.original.slot()
+    }
+
+    pub fn state(&self) -> &SszNode {
+        &self.state
+    }
+
+    pub fn state_root(&self) -> Hash256 {
+        self.state.root()
+    }
+
+    /// Generates the proof for a path in the beacon state, such as `validators[0]`.
+    pub fn generate_proof_for_beacon_state(&self, path: &str) -> Result<SszProof, SszPathError> {
+        self.state.generate_proof(path)
+    }
+
+    /// Builds the proof for the value of a field in the beacon state, which is committed by
+    /// the header.
+    pub fn build_beacon_state_proof<T: Encode + TreeHash>(
+        &self,
+        path: &str,
+        value: &T,
+        header: &core::Header,
+        header_mmr_proof: core::MmrProof,
+    ) -> Result<core::BeaconStateProof, SszPathError> {
+        assert_eq!(header.state_root, self.state_root());
+        let proof = self.generate_proof_for_beacon_state(path)?;
+        assert_eq!(proof.leaf, value.tree_hash_root());
+        let state_proof = core::BeaconStateProof {
+            header: header.clone(),
+            header_mmr_proof,
+            generalized_index: proof.generalized_index as u64,
+            value: value.as_ssz_bytes(),
+            state_ssz_proof: proof.branch,
+        };
+        Ok(state_proof)
+    }
+
+    /// Builds the proof for a validator and its balance in the beacon state, which is committed
+    /// by the header.
+    pub fn build_validator_proof(
+        &self,
+        validator_index: usize,
+        header: &core::Header,
+        header_mmr_proof: core::MmrProof,
+    ) -> Result<core::ValidatorProof, SszPathError> {
+        assert_eq!(header.state_root, self.state_root());
+        let validators = self.original.validators();
+        let validator =
+            validators
+                .get(validator_index)
+                .cloned()
+                .ok_or(SszPathError::IndexOutOfBounds {
+                    index: validator_index,
+                    length: validators.len(),
+                })?;
+        let validator_proof =
+            self.generate_proof_for_beacon_state(&format!("validators[{validator_index}]"))?;
+        let balances_chunk_index = validator_index / BALANCES_PER_CHUNK;
+        let balances_chunk_proof =
+            self.generate_proof_for_beacon_state(&format!("balances[{balances_chunk_index}]"))?;
+        let validator_proof = core::ValidatorProof {
+            header: header.clone(),
+            header_mmr_proof,
+            validator_index: validator_index as u64,
+            validator: validator.into(),
+            validator_ssz_proof: validator_proof.branch,
+            balances_chunk: balances_chunk_proof.leaf,
+            balances_chunk_ssz_proof: balances_chunk_proof.branch,
+        };
+        Ok(validator_proof)
+    }
+
+    /// Builds the proof for a historical header, through the `historical_summaries` in this
+    /// beacon state, which is committed by the anchor header.
+    ///
+    /// The `era_state` is the beacon state at the end of the era of the historical header, which
+    /// `block_roots` are summarized, such as the state in an era file.
+    pub fn build_historical_header_proof(
+        &self,
+        anchor_header: &core::Header,
+        anchor_header_mmr_proof: core::MmrProof,
+        era_state: &CachedBeaconState,
+        header_slot: u64,
+    ) -> core::HistoricalHeaderProof {
+        assert_eq!(anchor_header.state_root, self.state_root());
+        let summary_index =
+            consensus_specs::get_historical_summary_index(&ChainConfig::MAINNET, header_slot)
+                .unwrap();
+        let block_root_index = header_slot % SLOTS_PER_HISTORICAL_ROOT;
+        let block_roots_proof = era_state
+            .state
+            .node("block_roots")
+            .and_then(|block_roots| block_roots.generate_proof(&format!("[{block_root_index}]")))
+            .unwrap();
+        let block_summary_root_proof = self
+            .generate_proof_for_beacon_state(&format!(
+                "historical_summaries[{summary_index}].block_summary_root"
+            ))
+            .unwrap();
+        assert_eq!(
+            block_summary_root_proof.leaf,
+            era_state.state.node("block_roots").unwrap().root()
+        );
+        let mut header_ssz_proof = block_roots_proof.branch;
+        header_ssz_proof.extend(block_summary_root_proof.branch);
+        core::HistoricalHeaderProof {
+            anchor_header: anchor_header.clone(),
+            anchor_header_mmr_proof,
+            header_ssz_proof,
+        }
+    }
+}
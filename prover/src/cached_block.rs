@@ -1,49 +1,23 @@
-use eth2_types::{BeaconBlock, EthSpec, ExecPayload as _, MainnetEthSpec, Slot, Transaction};
-use merkle_proof::MerkleTree;
-use tree_hash::{Hash256, TreeHash};
+use std::io;
 
-use eth_light_client_in_ckb_verification::{
-    consensus_specs::{forks, helpers},
-    utilities::ssz,
+use eth2_types::{
+    BeaconBlock, EthSpec, ExecPayload as _, MainnetEthSpec, SignedBeaconBlock, Slot, Transaction,
 };
+use ssz::DecodeError;
+use tree_hash::{Hash256, TreeHash as _};
 
-#[derive(Clone)]
-pub struct CachedBeaconBlock {
-    body_root: Hash256,
-    randao_reveal_root: Hash256,
-    eth1_data_root: Hash256,
-    graffiti_root: Hash256,
-    proposer_slashings_root: Hash256,
-    attester_slashings_root: Hash256,
-    attestations_root: Hash256,
-    deposits_root: Hash256,
-    voluntary_exits_root: Hash256,
-    sync_aggregate_root: Hash256,
-
-    execution_payload_root: Hash256,
-    parent_hash_root: Hash256,
-    fee_recipient_root: Hash256,
-    state_root: Hash256,
-    receipts_root: Hash256,
-    logs_bloom_root: Hash256,
-    prev_randao_root: Hash256,
-    block_number_root: Hash256,
-    gas_limit_root: Hash256,
-    gas_used_root: Hash256,
-    timestamp_root: Hash256,
-    extra_data_root: Hash256,
-    base_fee_per_gas_root: Hash256,
-    block_hash_root: Hash256,
+use eth_light_client_in_ckb_verification::{consensus_specs::helpers, utilities::bls};
 
-    transactions_root: Hash256,
-    transactions_depth: usize,
-    transactions_data_root: Hash256,
-    transaction_hashes: Vec<Hash256>,
-
-    // Capella
-    withdrawals_root: Option<Hash256>,
-    bls_to_execution_changes_root: Option<Hash256>,
+use crate::{
+    light_client_ssz::{decode_error_to_io_error, decompress_snappy, decompress_snappy_block},
+    ssz_proof::{SszNode, SszPathError, SszProof},
+};
 
+/// A beacon block with the SSZ merkle tree of its body, to generate proofs for the fields in
+/// the body.
+#[derive(Clone)]
+pub struct CachedBeaconBlock {
+    body: SszNode,
     original: BeaconBlock<MainnetEthSpec>,
 }
 
@@ -52,64 +26,172 @@ impl From<BeaconBlock<MainnetEthSpec>> for CachedBeaconBlock {
         let body = block.body();
         let payload = body.execution_payload().unwrap();
         let payload_header = payload.to_execution_payload_header();
-        let transactions = payload.transactions().unwrap();
 
-        let transaction_hashes = transactions
+        let transactions = payload
+            .transactions()
+            .unwrap()
             .iter()
-            .map(|tx| tx.tree_hash_root())
+            .map(SszNode::leaf)
             .collect::<Vec<_>>();
-        let transactions_depth =
-            ssz::ceil_depth(MainnetEthSpec::max_transactions_per_payload()) as usize;
-        let transactions_data_root =
-            MerkleTree::create(&transaction_hashes, transactions_depth).hash();
+        let mut payload_fields = vec![
+            (
+                "parent_hash",
+                SszNode::leaf_with_root(payload.parent_hash().tree_hash_root()),
+            ),
+            (
+                "fee_recipient",
+                SszNode::leaf_with_root(payload.fee_recipient().tree_hash_root()),
+            ),
+            (
+                "state_root",
+                SszNode::leaf_with_root(payload_header.state_root().tree_hash_root()),
+            ),
+            (
+                "receipts_root",
+                SszNode::leaf_with_root(payload_header.receipts_root().tree_hash_root()),
+            ),
+            (
+                "logs_bloom",
+                SszNode::leaf_with_root(payload_header.logs_bloom().tree_hash_root()),
+            ),
+            (
+                "prev_randao",
+                SszNode::leaf_with_root(payload.prev_randao().tree_hash_root()),
+            ),
+            (
+                "block_number",
+                SszNode::leaf_with_root(payload.block_number().tree_hash_root()),
+            ),
+            (
+                "gas_limit",
+                SszNode::leaf_with_root(payload.gas_limit().tree_hash_root()),
+            ),
+            (
+                "gas_used",
+                SszNode::leaf_with_root(payload_header.gas_used().tree_hash_root()),
+            ),
+            (
+                "timestamp",
+                SszNode::leaf_with_root(payload.timestamp().tree_hash_root()),
+            ),
+            (
+                "extra_data",
+                SszNode::leaf_with_root(payload_header.extra_data().tree_hash_root()),
+            ),
+            (
+                "base_fee_per_gas",
+                SszNode::leaf_with_root(payload_header.base_fee_per_gas().tree_hash_root()),
+            ),
+            (
+                "block_hash",
+                SszNode::leaf_with_root(payload.block_hash().tree_hash_root()),
+            ),
+            (
+                "transactions",
+                SszNode::list(transactions, MainnetEthSpec::max_transactions_per_payload()),
+            ),
+        ];
+        // Capella
+        if let Ok(withdrawals_root) = payload.withdrawals_root() {
+            payload_fields.push(("withdrawals", SszNode::leaf_with_root(withdrawals_root)));
+        }
 
-        let withdrawals_root = payload.withdrawals_root().ok();
-        let bls_to_execution_changes_root = body
-            .bls_to_execution_changes()
-            .ok()
-            .map(TreeHash::tree_hash_root);
+        let mut body_fields = vec![
+            (
+                "randao_reveal",
+                SszNode::leaf_with_root(body.randao_reveal().tree_hash_root()),
+            ),
+            (
+                "eth1_data",
+                SszNode::leaf_with_root(body.eth1_data().tree_hash_root()),
+            ),
+            (
+                "graffiti",
+                SszNode::leaf_with_root(body.graffiti().tree_hash_root()),
+            ),
+            (
+                "proposer_slashings",
+                SszNode::leaf_with_root(body.proposer_slashings().tree_hash_root()),
+            ),
+            (
+                "attester_slashings",
+                SszNode::leaf_with_root(body.attester_slashings().tree_hash_root()),
+            ),
+            (
+                "attestations",
+                SszNode::leaf_with_root(body.attestations().tree_hash_root()),
+            ),
+            (
+                "deposits",
+                SszNode::leaf_with_root(body.deposits().tree_hash_root()),
+            ),
+            (
+                "voluntary_exits",
+                SszNode::leaf_with_root(body.voluntary_exits().tree_hash_root()),
+            ),
+            (
+                "sync_aggregate",
+                SszNode::leaf_with_root(body.sync_aggregate().unwrap().tree_hash_root()),
+            ),
+            ("execution_payload", SszNode::container(payload_fields)),
+        ];
+        // Capella
+        if let Ok(bls_to_execution_changes) = body.bls_to_execution_changes() {
+            body_fields.push((
+                "bls_to_execution_changes",
+                SszNode::leaf_with_root(bls_to_execution_changes.tree_hash_root()),
+            ));
+        }
+        let body = SszNode::container(body_fields);
+        assert_eq!(body.root(), block.body_root());
 
         Self {
-            body_root: block.body_root(),
-            randao_reveal_root: body.randao_reveal().tree_hash_root(),
-            eth1_data_root: body.eth1_data().tree_hash_root(),
-            graffiti_root: body.graffiti().tree_hash_root(),
-            proposer_slashings_root: body.proposer_slashings().tree_hash_root(),
-            attester_slashings_root: body.attester_slashings().tree_hash_root(),
-            attestations_root: body.attestations().tree_hash_root(),
-            deposits_root: body.deposits().tree_hash_root(),
-            voluntary_exits_root: body.voluntary_exits().tree_hash_root(),
-            sync_aggregate_root: body.sync_aggregate().unwrap().tree_hash_root(),
-
-            execution_payload_root: payload.tree_hash_root(),
-            parent_hash_root: payload.parent_hash().tree_hash_root(),
-            fee_recipient_root: payload.fee_recipient().tree_hash_root(),
-            state_root: payload_header.state_root().tree_hash_root(),
-            receipts_root: payload_header.receipts_root().tree_hash_root(),
-            logs_bloom_root: payload_header.logs_bloom().tree_hash_root(),
-            prev_randao_root: payload.prev_randao().tree_hash_root(),
-            block_number_root: payload.block_number().tree_hash_root(),
-            gas_limit_root: payload.gas_limit().tree_hash_root(),
-            gas_used_root: payload_header.gas_used().tree_hash_root(),
-            timestamp_root: payload.timestamp().tree_hash_root(),
-            extra_data_root: payload_header.extra_data().tree_hash_root(),
-            base_fee_per_gas_root: payload_header.base_fee_per_gas().tree_hash_root(),
-            block_hash_root: payload.block_hash().tree_hash_root(),
-
-            transactions_root: transactions.tree_hash_root(),
-            transactions_depth,
-            transactions_data_root,
-            transaction_hashes,
-
-            withdrawals_root,
-            bls_to_execution_changes_root,
-
+            body,
             original: block,
         }
     }
 }
 
 impl CachedBeaconBlock {
+    /// Decodes a beacon block from the SSZ bytes of a signed beacon block, such as the response
+    /// of the Beacon API `/eth/v2/beacon/blocks/{block_id}` in `application/octet-stream`.
+    ///
+    /// The fork is chosen by the slot of the block.
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let spec = MainnetEthSpec::default_spec();
+        SignedBeaconBlock::<MainnetEthSpec>::from_ssz_bytes(bytes, &spec)
+            .map(|block| block.deconstruct().0.into())
+    }
+
+    /// Decodes a beacon block from the SSZ bytes of a signed beacon block which are compressed
+    /// in the snappy frame format, such as the entries in era files.
+    pub fn from_ssz_snappy_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let bytes = decompress_snappy(bytes)?;
+        Self::from_ssz_bytes(&bytes).map_err(decode_error_to_io_error)
+    }
+
+    /// Decodes a beacon block from a message of the gossip topic `beacon_block`, which is the
+    /// SSZ bytes of a signed beacon block compressed in the snappy block format.
+    ///
+    /// The fork digest is from the topic, the block should be in the fork of its slot.
+    pub fn from_gossip_message(
+        bytes: &[u8],
+        fork_digest: &[u8; 4],
+        genesis_validators_root: Hash256,
+    ) -> io::Result<Self> {
+        let bytes = decompress_snappy_block(bytes)?;
+        let block = Self::from_ssz_bytes(&bytes).map_err(decode_error_to_io_error)?;
+        let fork_version = helpers::compute_fork_version_at_slot(block.slot().into());
+        if bls::compute_fork_digest(&fork_version, genesis_validators_root) != *fork_digest {
+            let msg = format!(
+                "the block at slot {} is not in the fork of the digest {fork_digest:02x?}",
+                block.slot()
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+        }
+        Ok(block)
+    }
+
     pub fn original(&self) -> &BeaconBlock<MainnetEthSpec> {
         &self.original
     }
@@ -150,180 +232,80 @@ impl CachedBeaconBlock {
             .cloned()
     }
 
+    pub fn body(&self) -> &SszNode {
+        &self.body
+    }
+
     pub fn body_root(&self) -> Hash256 {
-        self.body_root
+        self.body.root()
     }
 
     pub fn execution_payload_root(&self) -> Hash256 {
-        self.execution_payload_root
+        self.body.node("execution_payload").unwrap().root()
     }
 
     pub fn transactions_root(&self) -> Hash256 {
-        self.transactions_root
+        self.body
+            .node("execution_payload.transactions")
+            .unwrap()
+            .root()
     }
 
     pub fn transactions_data_root(&self) -> Hash256 {
-        self.transactions_data_root
+        self.body
+            .node("execution_payload.transactions")
+            .unwrap()
+            .data_root()
+            .unwrap()
+    }
+
+    /// Generates the proof for a path in the block body, such as
+    /// `execution_payload.transactions[0]`.
+    pub fn generate_proof_for_block_body(&self, path: &str) -> Result<SszProof, SszPathError> {
+        self.body.generate_proof(path)
     }
 
     pub fn generate_transaction_proof_for_transactions_data(&self, index: usize) -> Vec<Hash256> {
-        let depth = self.transactions_depth;
-        let tree = MerkleTree::create(&self.transaction_hashes, depth);
-        let (_, proof) = tree.generate_proof(index, depth).unwrap();
+        let mut proof = self.generate_transaction_proof_for_transactions(index);
+        // Remove the length, which is mixed in.
+        proof.pop();
         proof
     }
 
     pub fn generate_transaction_proof_for_transactions(&self, index: usize) -> Vec<Hash256> {
-        let mut proof = self.generate_transaction_proof_for_transactions_data(index);
-        let item = ssz::length_hash(self.transaction_hashes.len());
-        proof.push(item);
-        proof
+        let path = format!("[{index}]");
+        self.body
+            .node("execution_payload.transactions")
+            .and_then(|transactions| transactions.generate_proof(&path))
+            .unwrap()
+            .branch
     }
 
     pub fn generate_transaction_proof_for_execution_payload(&self, index: usize) -> Vec<Hash256> {
-        let mut proof = self.generate_transaction_proof_for_transactions(index);
-        let mut leaves = vec![
-            self.parent_hash_root,
-            self.fee_recipient_root,
-            self.state_root,
-            self.receipts_root,
-            self.logs_bloom_root,
-            self.prev_randao_root,
-            self.block_number_root,
-            self.gas_limit_root,
-            self.gas_used_root,
-            self.timestamp_root,
-            self.extra_data_root,
-            self.base_fee_per_gas_root,
-            self.block_hash_root,
-            self.transactions_root,
-        ];
-        let (depth, field_index) =
-            if self.slot() < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
-                use forks::bellatrix::containers;
-                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-                let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-                let field_index = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-                (depth, field_index)
-            } else {
-                use forks::capella::containers;
-                leaves.push(self.withdrawals_root.unwrap());
-                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-                let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-                let field_index = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-                (depth, field_index)
-            };
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof.extend(fields_proof);
-        proof
+        let path = format!("transactions[{index}]");
+        self.body
+            .node("execution_payload")
+            .and_then(|payload| payload.generate_proof(&path))
+            .unwrap()
+            .branch
     }
 
     pub fn generate_transaction_proof_for_block_body(&self, index: usize) -> Vec<Hash256> {
-        let mut proof = self.generate_transaction_proof_for_execution_payload(index);
-        let mut leaves = vec![
-            self.randao_reveal_root,
-            self.eth1_data_root,
-            self.graffiti_root,
-            self.proposer_slashings_root,
-            self.attester_slashings_root,
-            self.attestations_root,
-            self.deposits_root,
-            self.voluntary_exits_root,
-            self.sync_aggregate_root,
-            self.execution_payload_root,
-        ];
-        let (depth, field_index) =
-            if self.slot() < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
-                use forks::bellatrix::containers;
-                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-                let depth = containers::BLOCK_BODY_DEPTH as usize;
-                let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-                (depth, field_index)
-            } else {
-                use forks::capella::containers;
-                leaves.push(self.bls_to_execution_changes_root.unwrap());
-                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-                let depth = containers::BLOCK_BODY_DEPTH as usize;
-                let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-                (depth, field_index)
-            };
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof.extend(fields_proof);
-        proof
+        let path = format!("execution_payload.transactions[{index}]");
+        self.generate_proof_for_block_body(&path).unwrap().branch
     }
 
     pub fn generate_receipts_root_proof_for_execution_payload(&self) -> Vec<Hash256> {
-        let mut leaves = vec![
-            self.parent_hash_root,
-            self.fee_recipient_root,
-            self.state_root,
-            self.receipts_root,
-            self.logs_bloom_root,
-            self.prev_randao_root,
-            self.block_number_root,
-            self.gas_limit_root,
-            self.gas_used_root,
-            self.timestamp_root,
-            self.extra_data_root,
-            self.base_fee_per_gas_root,
-            self.block_hash_root,
-            self.transactions_root,
-        ];
-        let (depth, field_index) =
-            if self.slot() < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
-                use forks::bellatrix::containers;
-                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-                let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-                let field_index = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-                (depth, field_index)
-            } else {
-                use forks::capella::containers;
-                leaves.push(self.withdrawals_root.unwrap());
-                assert_eq!(leaves.len(), containers::EXECUTION_PAYLOAD_FIELDS_COUNT);
-                let depth = containers::EXECUTION_PAYLOAD_DEPTH as usize;
-                let field_index = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-                (depth, field_index)
-            };
-
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof
+        self.body
+            .node("execution_payload")
+            .and_then(|payload| payload.generate_proof("receipts_root"))
+            .unwrap()
+            .branch
     }
 
     pub fn generate_receipts_root_proof_for_block_body(&self) -> Vec<Hash256> {
-        let mut proof = self.generate_receipts_root_proof_for_execution_payload();
-        let mut leaves = vec![
-            self.randao_reveal_root,
-            self.eth1_data_root,
-            self.graffiti_root,
-            self.proposer_slashings_root,
-            self.attester_slashings_root,
-            self.attestations_root,
-            self.deposits_root,
-            self.voluntary_exits_root,
-            self.sync_aggregate_root,
-            self.execution_payload_root,
-        ];
-        let (depth, field_index) =
-            if self.slot() < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
-                use forks::bellatrix::containers;
-                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-                let depth = containers::BLOCK_BODY_DEPTH as usize;
-                let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-                (depth, field_index)
-            } else {
-                use forks::capella::containers;
-                leaves.push(self.bls_to_execution_changes_root.unwrap());
-                assert_eq!(leaves.len(), containers::BLOCK_BODY_FIELDS_COUNT);
-                let depth = containers::BLOCK_BODY_DEPTH as usize;
-                let field_index = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-                (depth, field_index)
-            };
-        let tree = MerkleTree::create(&leaves, depth);
-        let (_, fields_proof) = tree.generate_proof(field_index, depth).unwrap();
-        proof.extend(fields_proof);
-        proof
+        self.generate_proof_for_block_body("execution_payload.receipts_root")
+            .unwrap()
+            .branch
     }
 }
@@ -1,7 +1,11 @@
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeSet, HashMap},
+    io,
+};
 
 use eth2_types::{
-    light_client_finality_update::LightClientFinalityUpdate, BeaconBlockHeader, MainnetEthSpec,
+    light_client_finality_update::LightClientFinalityUpdate,
+    light_client_optimistic_update::LightClientOptimisticUpdate, BeaconBlockHeader, MainnetEthSpec,
 };
 
 use eth_light_client_in_ckb_verification::{
@@ -9,13 +13,14 @@ use eth_light_client_in_ckb_verification::{
     utilities::mmr,
 };
 
-use crate::LightClientBootstrap;
+use crate::{EraFile, LightClientBootstrap};
 
 pub struct DummyLightClient {
     client: core::Client,
     client_sync_committee: core::ClientSyncCommittee,
     store: mmr::lib::util::MemStore<packed::HeaderDigest>,
     headers: HashMap<u64, mmr::HeaderWithCache>,
+    optimistic_header: Option<mmr::HeaderWithCache>,
 }
 
 impl DummyLightClient {
@@ -38,6 +43,9 @@ impl DummyLightClient {
                 maximal_slot: bootstrap_slot,
                 tip_header_root,
                 headers_mmr_root,
+                optimistic_slot: bootstrap_slot,
+                optimistic_header_root: tip_header_root,
+                finalized_slot: bootstrap_slot,
             }
         };
         let client_sync_committee = bootstrap.build_client_sync_committee();
@@ -46,6 +54,7 @@ impl DummyLightClient {
             client_sync_committee,
             store,
             headers,
+            optimistic_header: None,
         }
     }
 
@@ -75,67 +84,63 @@ impl DummyLightClient {
             .collect::<Vec<_>>()
     }
 
-    pub fn apply_finality_update(
-        &mut self,
-        finality_update: LightClientFinalityUpdate<MainnetEthSpec>,
-        headers: Vec<BeaconBlockHeader>,
-    ) -> core::ClientUpdate {
-        let (client_update_headers, new_headers_mmr_proof) = {
-            let mut client_update_headers = Vec::with_capacity(headers.len());
-            let mut positions = Vec::with_capacity(headers.len());
+    /// Builds one MMR proof for the headers at several slots, the slots are sorted and
+    /// deduplicated.
+    ///
+    /// The optimistic header could be included, it's not in the MMR, so it's not proven by the
+    /// MMR proof.
+    pub fn build_multi_header_proof(
+        &self,
+        slots: &[u64],
+    ) -> mmr::lib::Result<core::MultiHeaderProof> {
+        let slots = slots.iter().copied().collect::<BTreeSet<_>>();
+        let mut headers = Vec::with_capacity(slots.len());
+        let mut positions = Vec::with_capacity(slots.len());
+        for slot in slots {
+            if let Some(header) = self.headers.get(&slot) {
+                headers.push(header.inner.clone());
+                positions.push(mmr::lib::leaf_index_to_pos(slot - self.client.minimal_slot));
+            } else if let Some(header) = self
+                .optimistic_header()
+                .filter(|header| header.inner.slot == slot)
+            {
+                headers.push(header.inner.clone());
+            } else {
+                return Err(mmr::lib::Error::GenProofForInvalidLeaves);
+            }
+        }
+        let headers_mmr_proof = if positions.is_empty() {
+            Vec::new()
+        } else {
             let last_index = self.client.maximal_slot - self.client.minimal_slot;
             let mmr_size = mmr::lib::leaf_index_to_mmr_size(last_index);
-            let mut mmr = mmr::ClientRootMMR::new(mmr_size, &self.store);
-            for header in headers {
-                let header: core::Header = header.into();
-                self.client.maximal_slot = header.slot;
-
-                let index = header.slot - self.client.minimal_slot;
-                let position = mmr::lib::leaf_index_to_pos(index);
-
-                let header_with_cache = header.calc_cache();
-                self.client.tip_header_root = header_with_cache.root;
-
-                self.headers
-                    .insert(header_with_cache.inner.slot, header_with_cache.clone());
-
-                mmr.push(header_with_cache.packed_digest()).unwrap();
-                positions.push(position);
-                client_update_headers.push(header_with_cache.inner);
-            }
-            self.client.headers_mmr_root = mmr.get_root().unwrap().unpack();
-            let headers_mmr_proof_items = mmr
-                .gen_proof(positions)
-                .unwrap()
+            let mmr = mmr::ClientRootMMR::new(mmr_size, &self.store);
+            mmr.gen_proof(positions)?
                 .proof_items()
                 .iter()
-                .map(Clone::clone)
-                .collect::<Vec<_>>();
-            mmr.commit().unwrap();
-            let headers_mmr_proof = packed::MmrProof::new_builder()
-                .set(headers_mmr_proof_items)
-                .build();
-            (client_update_headers, headers_mmr_proof.unpack())
+                .map(|item| item.unpack())
+                .collect::<Vec<_>>()
         };
+        Ok(core::MultiHeaderProof {
+            headers,
+            headers_mmr_proof,
+        })
+    }
+
+    pub fn apply_finality_update(
+        &mut self,
+        finality_update: LightClientFinalityUpdate<MainnetEthSpec>,
+        headers: Vec<BeaconBlockHeader>,
+    ) -> core::ClientUpdate {
+        let (client_update_headers, new_headers_mmr_proof) = self
+            .append_headers(headers)
+            .expect("failed to append headers into the MMR");
+        self.client.finalized_slot = self.client.maximal_slot;
 
         {
             let attested_header = finality_update.attested_header.into();
             let finality_branch = finality_update.finality_branch.to_vec();
-            let sync_committee_bits = core::SyncCommitteeBits::from_slice(
-                finality_update
-                    .sync_aggregate
-                    .sync_committee_bits
-                    .as_slice(),
-            );
-            let sync_committee_signature = finality_update
-                .sync_aggregate
-                .sync_committee_signature
-                .serialize()
-                .into();
-            let sync_aggregate = core::SyncAggregate {
-                sync_committee_bits,
-                sync_committee_signature,
-            };
+            let sync_aggregate = finality_update.sync_aggregate.into();
             let signature_slot = finality_update.signature_slot.into();
             core::ClientUpdate {
                 attested_header,
@@ -147,4 +152,155 @@ impl DummyLightClient {
             }
         }
     }
+
+    /// Applies the attested header as the last header without finality.
+    ///
+    /// The last header in `headers` should be the attested header.
+    pub fn apply_force_update(
+        &mut self,
+        optimistic_update: LightClientOptimisticUpdate<MainnetEthSpec>,
+        headers: Vec<BeaconBlockHeader>,
+    ) -> core::ClientUpdate {
+        let (client_update_headers, new_headers_mmr_proof) = self
+            .append_headers(headers)
+            .expect("failed to append headers into the MMR");
+
+        {
+            let attested_header = optimistic_update.attested_header.into();
+            let sync_aggregate = optimistic_update.sync_aggregate.into();
+            let signature_slot = optimistic_update.signature_slot.into();
+            core::ClientUpdate {
+                attested_header,
+                finality_branch: Vec::new(),
+                sync_aggregate,
+                signature_slot,
+                new_headers_mmr_proof,
+                headers: client_update_headers,
+            }
+        }
+    }
+
+    /// Appends the headers in the era file, which are after the tip, without any light client
+    /// updates, and treats them as finalized.
+    ///
+    /// The headers in an era are always finalized, so they could be used to backfill the client
+    /// offline.
+    ///
+    /// The headers should continue from the tip header of the client.
+    pub fn apply_era_file(&mut self, era_file: &EraFile) -> io::Result<()> {
+        let next_slot = self.client.maximal_slot + 1;
+        let slots = era_file.slots();
+        if slots.start > next_slot {
+            let msg = format!(
+                "the era {} starts at slot {} but the next slot is {next_slot}",
+                era_file.era(),
+                slots.start,
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        let headers = era_file
+            .beacon_block_headers()?
+            .into_iter()
+            .filter(|header| header.slot.as_u64() >= next_slot)
+            .collect::<Vec<_>>();
+        if headers.is_empty() {
+            return Ok(());
+        }
+        let mut parent_root = self.client.tip_header_root;
+        for header in &headers {
+            let header: core::Header = header.clone().into();
+            if header.is_empty() {
+                continue;
+            }
+            if header.parent_root != parent_root {
+                let msg = format!(
+                    "the header at slot {} in the era {} is not the child of {parent_root:#x}",
+                    header.slot,
+                    era_file.era(),
+                );
+                return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+            }
+            parent_root = header.calc_cache().root;
+        }
+        self.append_headers(headers).map_err(|err| {
+            let msg = format!("failed to append headers into the MMR: {err:?}");
+            io::Error::new(io::ErrorKind::Other, msg)
+        })?;
+        self.client.finalized_slot = self.client.maximal_slot;
+        Ok(())
+    }
+
+    // Appends the headers into the MMR, the tip header is the last non-empty header.
+    fn append_headers(
+        &mut self,
+        headers: Vec<BeaconBlockHeader>,
+    ) -> mmr::lib::Result<(core::HeaderRange, core::MmrProof)> {
+        let parent_root = self.client.tip_header_root;
+        let mut client_update_headers = Vec::with_capacity(headers.len());
+        let mut positions = Vec::with_capacity(headers.len());
+        let last_index = self.client.maximal_slot - self.client.minimal_slot;
+        let mmr_size = mmr::lib::leaf_index_to_mmr_size(last_index);
+        let mut mmr = mmr::ClientRootMMR::new(mmr_size, &self.store);
+        for header in headers {
+            let header: core::Header = header.into();
+            self.client.maximal_slot = header.slot;
+
+            let index = header.slot - self.client.minimal_slot;
+            let position = mmr::lib::leaf_index_to_pos(index);
+
+            let header_with_cache = header.calc_cache();
+            if !header_with_cache.is_empty() {
+                self.client.tip_header_root = header_with_cache.root;
+            }
+
+            self.headers
+                .insert(header_with_cache.inner.slot, header_with_cache.clone());
+
+            mmr.push(header_with_cache.packed_digest())?;
+            positions.push(position);
+            client_update_headers.push(header_with_cache);
+        }
+        self.client.headers_mmr_root = mmr.get_root()?.unpack();
+        if self.client.optimistic_slot <= self.client.maximal_slot {
+            self.client.optimistic_slot = self.client.maximal_slot;
+            self.client.optimistic_header_root = self.client.tip_header_root;
+        }
+        let headers_mmr_proof_items = mmr
+            .gen_proof(positions)?
+            .proof_items()
+            .iter()
+            .map(Clone::clone)
+            .collect::<Vec<_>>();
+        mmr.commit()?;
+        let headers_mmr_proof = packed::MmrProof::new_builder()
+            .set(headers_mmr_proof_items)
+            .build();
+        let client_update_headers = core::HeaderRange::compact(parent_root, &client_update_headers);
+        Ok((client_update_headers, headers_mmr_proof.unpack()))
+    }
+
+    pub fn apply_optimistic_update(
+        &mut self,
+        optimistic_update: LightClientOptimisticUpdate<MainnetEthSpec>,
+    ) -> core::ClientOptimisticUpdate {
+        let attested_header: core::Header = optimistic_update.attested_header.into();
+        let header_with_cache = attested_header.calc_cache();
+        self.client.optimistic_slot = header_with_cache.inner.slot;
+        self.client.optimistic_header_root = header_with_cache.root;
+        self.optimistic_header = Some(header_with_cache.clone());
+
+        let sync_aggregate = optimistic_update.sync_aggregate.into();
+        let signature_slot = optimistic_update.signature_slot.into();
+        core::ClientOptimisticUpdate {
+            attested_header: header_with_cache.inner,
+            sync_aggregate,
+            signature_slot,
+        }
+    }
+
+    pub fn optimistic_header(&self) -> Option<&mmr::HeaderWithCache> {
+        self.optimistic_header
+            .as_ref()
+            .filter(|header| header.inner.slot > self.client.maximal_slot)
+    }
 }
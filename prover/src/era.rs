@@ -0,0 +1,261 @@
+use std::{fmt, fs, io, ops::Range, path::Path};
+
+use eth2_types::{BeaconBlockHeader, EthSpec, MainnetEthSpec, SignedBeaconBlock};
+
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::forks::phase0::SLOTS_PER_HISTORICAL_ROOT, types::core,
+};
+
+use crate::{
+    light_client_ssz::{decode_error_to_io_error, decompress_snappy},
+    CachedBeaconBlock, CachedBeaconState,
+};
+
+const HEADER_SIZE: usize = 8;
+
+/// The types of the entries in e2store files.
+///
+/// Ref: [The e2store format](https://github.com/status-im/nimbus-eth2/blob/stable/docs/e2store.md)
+mod entry_type {
+    pub(super) const VERSION: [u8; 2] = [0x65, 0x32];
+    pub(super) const COMPRESSED_SIGNED_BEACON_BLOCK: [u8; 2] = [0x01, 0x00];
+    pub(super) const COMPRESSED_BEACON_STATE: [u8; 2] = [0x02, 0x00];
+    pub(super) const SLOT_INDEX: [u8; 2] = [0x69, 0x32];
+}
+
+/// An era file, which contains the blocks of an era and the beacon state at the end of the era.
+///
+/// The layout is:
+///
+/// ```text
+/// era := Version | block* | era-state | other-entries* | slot-index(block)? | slot-index(state)
+/// ```
+///
+/// The block slot index is absent in the genesis era.
+///
+/// Ref: [The era format](https://github.com/status-im/nimbus-eth2/blob/stable/docs/e2store.md#era-files)
+pub struct EraFile {
+    bytes: Vec<u8>,
+    blocks_index: Option<SlotIndex>,
+    state_index: SlotIndex,
+}
+
+struct SlotIndex {
+    start_slot: u64,
+    // The absolute positions of the entries, `None` for the empty slots.
+    positions: Vec<Option<usize>>,
+}
+
+impl EraFile {
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        let version = read_entry(&bytes, 0, entry_type::VERSION)?;
+        if !version.is_empty() {
+            return Err(invalid_data("the version entry should be empty"));
+        }
+        let state_index = read_slot_index(&bytes, bytes.len())?;
+        if state_index.positions.len() != 1 {
+            return Err(invalid_data(
+                "the state slot index should have only one entry",
+            ));
+        }
+        let state_index_position = bytes.len() - slot_index_size(1);
+        let blocks_index = if state_index.start_slot == 0 {
+            None
+        } else {
+            let blocks_index = read_slot_index(&bytes, state_index_position)?;
+            if blocks_index.positions.len() as u64 != SLOTS_PER_HISTORICAL_ROOT
+                || blocks_index
+                    .start_slot
+                    .checked_add(SLOTS_PER_HISTORICAL_ROOT)
+                    != Some(state_index.start_slot)
+            {
+                return Err(invalid_data("the block slot index mismatches the state"));
+            }
+            Some(blocks_index)
+        };
+        Ok(Self {
+            bytes,
+            blocks_index,
+            state_index,
+        })
+    }
+
+    /// Loads an era file, which is named as `<config-name>-<era-number>-<short-historical-root>.era`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        Self::from_bytes(bytes)
+    }
+
+    pub fn era(&self) -> u64 {
+        self.state_index.start_slot / SLOTS_PER_HISTORICAL_ROOT
+    }
+
+    /// Returns the slots of the blocks in this era.
+    pub fn slots(&self) -> Range<u64> {
+        self.blocks_index
+            .as_ref()
+            .map(|index| index.start_slot..self.state_index.start_slot)
+            .unwrap_or_default()
+    }
+
+    /// Returns the signed block at the slot, or `None` if the slot is empty.
+    pub fn signed_block_at_slot(
+        &self,
+        slot: u64,
+    ) -> io::Result<Option<SignedBeaconBlock<MainnetEthSpec>>> {
+        let position = if let Some(position) = self
+            .blocks_index
+            .as_ref()
+            .and_then(|index| index.position(slot))
+        {
+            position
+        } else {
+            return Ok(None);
+        };
+        let data = read_entry(
+            &self.bytes,
+            position,
+            entry_type::COMPRESSED_SIGNED_BEACON_BLOCK,
+        )?;
+        let bytes = decompress_snappy(data)?;
+        let spec = MainnetEthSpec::default_spec();
+        let block =
+            SignedBeaconBlock::from_ssz_bytes(&bytes, &spec).map_err(decode_error_to_io_error)?;
+        if block.slot().as_u64() != slot {
+            return Err(invalid_data(format!(
+                "the block at slot {slot} has a different slot {}",
+                block.slot()
+            )));
+        }
+        Ok(Some(block))
+    }
+
+    /// Returns the block at the slot, or `None` if the slot is empty.
+    ///
+    /// Only the blocks since Bellatrix are supported, since the others have no execution
+    /// payloads.
+    pub fn block_at_slot(&self, slot: u64) -> io::Result<Option<CachedBeaconBlock>> {
+        self.signed_block_at_slot(slot)
+            .map(|block_opt| block_opt.map(|block| block.deconstruct().0.into()))
+    }
+
+    /// Returns the header at the slot, or `None` if the slot is empty.
+    pub fn header_at_slot(&self, slot: u64) -> io::Result<Option<core::Header>> {
+        self.signed_block_at_slot(slot)
+            .map(|block_opt| block_opt.map(|block| block.message().block_header().into()))
+    }
+
+    /// Returns the headers for all slots in this era, the headers for the empty slots only have
+    /// the slots.
+    pub fn beacon_block_headers(&self) -> io::Result<Vec<BeaconBlockHeader>> {
+        self.slots()
+            .map(|slot| {
+                self.signed_block_at_slot(slot).map(|block_opt| {
+                    block_opt
+                        .map(|block| block.message().block_header())
+                        .unwrap_or_else(|| BeaconBlockHeader {
+                            slot: slot.into(),
+                            proposer_index: 0,
+                            parent_root: Default::default(),
+                            state_root: Default::default(),
+                            body_root: Default::default(),
+                        })
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the beacon state at the end of this era.
+    pub fn state(&self) -> io::Result<CachedBeaconState> {
+        let position = self
+            .state_index
+            .position(self.state_index.start_slot)
+            .ok_or_else(|| invalid_data("no beacon state in the era file"))?;
+        let data = read_entry(&self.bytes, position, entry_type::COMPRESSED_BEACON_STATE)?;
+        let bytes = decompress_snappy(data)?;
+        CachedBeaconState::from_ssz_bytes(&bytes).map_err(decode_error_to_io_error)
+    }
+}
+
+impl SlotIndex {
+    fn position(&self, slot: u64) -> Option<usize> {
+        slot.checked_sub(self.start_slot)
+            .and_then(|offset| self.positions.get(offset as usize))
+            .and_then(Clone::clone)
+    }
+}
+
+fn slot_index_size(count: usize) -> usize {
+    HEADER_SIZE + 8 + 8 * count + 8
+}
+
+fn read_i64(bytes: &[u8], position: usize) -> i64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[position..position + 8]);
+    i64::from_le_bytes(buf)
+}
+
+// Reads the slot index which ends at `end`.
+fn read_slot_index(bytes: &[u8], end: usize) -> io::Result<SlotIndex> {
+    if end < slot_index_size(0) {
+        return Err(invalid_data("no enough bytes for a slot index"));
+    }
+    let count = read_i64(bytes, end - 8);
+    if count < 0 || count as usize > end / 8 || slot_index_size(count as usize) > end {
+        return Err(invalid_data(format!(
+            "invalid count {count} of a slot index"
+        )));
+    }
+    let count = count as usize;
+    let start = end - slot_index_size(count);
+    let data = read_entry(bytes, start, entry_type::SLOT_INDEX)?;
+    if data.len() != slot_index_size(count) - HEADER_SIZE {
+        return Err(invalid_data(
+            "the size of the slot index mismatches its count",
+        ));
+    }
+    let start_slot = read_i64(data, 0);
+    let start_slot = u64::try_from(start_slot)
+        .map_err(|_| invalid_data(format!("invalid start slot {start_slot} of a slot index")))?;
+    let positions = (0..count)
+        .map(|i| match read_i64(data, 8 + 8 * i) {
+            0 => Ok(None),
+            offset => usize::try_from(start as i64 + offset)
+                .map(Some)
+                .map_err(|_| invalid_data(format!("invalid offset {offset} in a slot index"))),
+        })
+        .collect::<io::Result<_>>()?;
+    Ok(SlotIndex {
+        start_slot,
+        positions,
+    })
+}
+
+// Reads the data of the entry at `position`, and checks its type.
+fn read_entry(bytes: &[u8], position: usize, expected_type: [u8; 2]) -> io::Result<&[u8]> {
+    let header = bytes
+        .get(position..position + HEADER_SIZE)
+        .ok_or_else(|| invalid_data(format!("no entry at {position}")))?;
+    if header[0..2] != expected_type {
+        return Err(invalid_data(format!(
+            "the type of the entry at {position} should be {expected_type:?} but got {:?}",
+            &header[0..2]
+        )));
+    }
+    if header[6..8] != [0, 0] {
+        return Err(invalid_data(format!(
+            "the reserved bytes of the entry at {position} should be zeros"
+        )));
+    }
+    let mut length = [0u8; 4];
+    length.copy_from_slice(&header[2..6]);
+    let length = u32::from_le_bytes(length) as usize;
+    let data_start = position + HEADER_SIZE;
+    bytes
+        .get(data_start..data_start + length)
+        .ok_or_else(|| invalid_data(format!("no enough bytes for the entry at {position}")))
+}
+
+fn invalid_data<S: fmt::Display>(msg: S) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
@@ -1,15 +1,36 @@
+mod cached_beacon_state;
 mod cached_block;
+mod era;
 mod receipts;
+mod ssz_proof;
+#[cfg(feature = "test-support")]
+mod synthetic_chain;
+mod transaction_index;
 
 mod light_client_bootstrap;
+mod light_client_ssz;
 mod light_client_update;
 
+mod best_update;
+
 mod dummy_light_client;
 
+pub use cached_beacon_state::CachedBeaconState;
 pub use cached_block::CachedBeaconBlock;
+pub use era::EraFile;
 pub use receipts::{encode_receipt, Receipts};
+pub use ssz_proof::{SszNode, SszPathError, SszProof};
+#[cfg(feature = "test-support")]
+pub use synthetic_chain::{SyntheticChain, SyntheticChainConfig};
+pub use transaction_index::TransactionIndex;
 
 pub use light_client_bootstrap::LightClientBootstrap;
+pub use light_client_ssz::{
+    LightClientBootstrapCapella, LightClientFork, LightClientHeaderCapella,
+    LightClientUpdateCapella,
+};
 pub use light_client_update::LightClientUpdate;
 
+pub use best_update::BestUpdateStore;
+
 pub use dummy_light_client::DummyLightClient;
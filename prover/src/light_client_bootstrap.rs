@@ -1,11 +1,13 @@
-use std::sync::Arc;
+use std::{io, sync::Arc};
 
 use eth2_types::{
     light_client_bootstrap::{
         LightClientBootstrap as OriginalLightClientBootstrap, PatchedLightClientBootstrap,
     },
-    MainnetEthSpec, Slot,
+    BeaconBlockHeader, FixedVector, MainnetEthSpec, Slot,
 };
+use ssz::DecodeError;
+use tree_hash::Hash256;
 
 use eth_light_client_in_ckb_verification::{
     consensus_specs::helpers,
@@ -13,6 +15,13 @@ use eth_light_client_in_ckb_verification::{
     utilities::mmr,
 };
 
+use crate::{
+    light_client_ssz::{
+        decode_error_to_io_error, decode_light_client_bootstrap, decode_response_chunks,
+    },
+    CachedBeaconState, LightClientFork,
+};
+
 #[derive(Clone)]
 pub struct LightClientBootstrap {
     original: OriginalLightClientBootstrap<MainnetEthSpec>,
@@ -26,7 +35,86 @@ impl From<PatchedLightClientBootstrap<MainnetEthSpec>> for LightClientBootstrap
     }
 }
 
+impl From<OriginalLightClientBootstrap<MainnetEthSpec>> for LightClientBootstrap {
+    fn from(original: OriginalLightClientBootstrap<MainnetEthSpec>) -> Self {
+        Self { original }
+    }
+}
+
 impl LightClientBootstrap {
+    /// Decodes a light client bootstrap from SSZ bytes, such as the response of the Beacon API
+    /// `/eth/v1/beacon/light_client/bootstrap/{block_root}` in `application/octet-stream`.
+    ///
+    /// The layout is chosen by the fork, such as the `Eth-Consensus-Version` header of the
+    /// response.
+    pub fn from_ssz_bytes(bytes: &[u8], fork: LightClientFork) -> Result<Self, DecodeError> {
+        decode_light_client_bootstrap(bytes, fork).map(|original| Self { original })
+    }
+
+    /// Decodes a light client bootstrap from the response of the p2p request
+    /// `LightClientBootstrap`, which is a single response chunk in the `ssz_snappy` encoding.
+    ///
+    /// The layout is chosen by the context bytes of the chunk.
+    pub fn from_p2p_response(bytes: &[u8], genesis_validators_root: Hash256) -> io::Result<Self> {
+        let mut chunks = decode_response_chunks(bytes, genesis_validators_root)?;
+        if chunks.len() != 1 {
+            let msg = format!("expect 1 response chunk but got {}", chunks.len());
+            return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+        }
+        let (fork, payload) = chunks.remove(0);
+        Self::from_ssz_bytes(&payload, fork).map_err(decode_error_to_io_error)
+    }
+
+    /// Builds a light client bootstrap from a beacon state and the header of the block at the
+    /// same slot.
+    ///
+    /// The `current_sync_committee_branch` is computed from the beacon state locally, so the
+    /// beacon state should be finalized and trusted.
+    pub fn from_beacon_state(
+        state: &CachedBeaconState,
+        header: BeaconBlockHeader,
+    ) -> io::Result<Self> {
+        if header.slot != state.slot() {
+            let msg = format!(
+                "the header at slot {} is not at the slot {} of the beacon state",
+                header.slot,
+                state.slot()
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        if header.state_root != state.state_root() {
+            let msg = format!(
+                "the state root {:#x} in the header is not the root {:#x} of the beacon state",
+                header.state_root,
+                state.state_root()
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+        }
+        let current_sync_committee = state
+            .original()
+            .current_sync_committee()
+            .map(Arc::clone)
+            .map_err(|err| {
+                let msg = format!("no current sync committee in the beacon state: {err:?}");
+                io::Error::new(io::ErrorKind::InvalidInput, msg)
+            })?;
+        let current_sync_committee_branch = state
+            .generate_proof_for_beacon_state("current_sync_committee")
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))
+            .and_then(|proof| {
+                FixedVector::new(proof.branch).map_err(|err| {
+                    let msg = format!("invalid current sync committee branch: {err:?}");
+                    io::Error::new(io::ErrorKind::InvalidData, msg)
+                })
+            })?;
+        let original = OriginalLightClientBootstrap {
+            header,
+            current_sync_committee,
+            current_sync_committee_branch,
+        };
+        Ok(Self { original })
+    }
+
     pub fn original(&self) -> &OriginalLightClientBootstrap<MainnetEthSpec> {
         &self.original
     }
@@ -75,6 +163,9 @@ impl LightClientBootstrap {
             maximal_slot: slot,
             tip_header_root: header_with_cache.root,
             headers_mmr_root,
+            optimistic_slot: slot,
+            optimistic_header_root: header_with_cache.root,
+            finalized_slot: slot,
         }
     }
 }
@@ -0,0 +1,277 @@
+//! The SSZ layouts of the light client data since Capella.
+//!
+//! Since Capella, the headers in the light client data contain the execution payload headers,
+//! so the layouts are different from the original types, which only have the beacon block
+//! headers, as same as the layouts before Capella.
+//!
+//! Ref: [Light client data since Capella](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/light-client/sync-protocol.md)
+
+use std::{io, sync::Arc};
+
+use eth2_types::{
+    light_client_bootstrap::LightClientBootstrap as OriginalLightClientBootstrap,
+    light_client_update::LightClientUpdate as OriginalLightClientUpdate,
+    typenum::{U4, U5, U6},
+    BeaconBlockHeader, EthSpec, ExecutionPayloadHeaderCapella, FixedVector, MainnetEthSpec, Slot,
+    SyncAggregate, SyncCommittee,
+};
+use snap::read::FrameDecoder;
+use ssz::{Decode, DecodeError};
+use ssz_derive::{Decode, Encode};
+use tree_hash::Hash256;
+
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, helpers},
+    utilities::bls,
+};
+
+/// The maximum allowed size of uncompressed payloads in the req/resp domain and of uncompressed
+/// gossip messages.
+///
+/// Ref: [`MAX_CHUNK_SIZE` and `GOSSIP_MAX_SIZE`](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/bellatrix/p2p-interface.md#configuration)
+const MAX_PAYLOAD_SIZE: u64 = 10 * (1 << 20);
+
+/// The response code of a successful response chunk in the req/resp domain.
+const RESPONSE_CODE_SUCCESS: u8 = 0;
+
+/// The forks which light client data are in different layouts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightClientFork {
+    /// Altair and Bellatrix, the headers are the beacon block headers.
+    Altair,
+    /// Since Capella, the headers contain the execution payload headers.
+    Capella,
+}
+
+impl LightClientFork {
+    pub fn from_fork_version(fork_version: &[u8; 4]) -> Option<Self> {
+        if *fork_version == forks::capella::FORK_VERSION {
+            Some(Self::Capella)
+        } else if *fork_version == forks::bellatrix::FORK_VERSION
+            || *fork_version == forks::altair::FORK_VERSION
+        {
+            Some(Self::Altair)
+        } else {
+            None
+        }
+    }
+
+    /// Chooses the fork by the context bytes of a response chunk, which are the fork digest.
+    pub fn from_fork_digest(
+        fork_digest: &[u8; 4],
+        genesis_validators_root: Hash256,
+    ) -> Option<Self> {
+        [
+            forks::altair::FORK_VERSION,
+            forks::bellatrix::FORK_VERSION,
+            forks::capella::FORK_VERSION,
+        ]
+        .iter()
+        .find(|fork_version| {
+            bls::compute_fork_digest(fork_version, genesis_validators_root) == *fork_digest
+        })
+        .and_then(Self::from_fork_version)
+    }
+
+    /// Chooses the fork by its name, such as the `Eth-Consensus-Version` header of the Beacon
+    /// API responses.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "altair" | "bellatrix" => Some(Self::Altair),
+            "capella" => Some(Self::Capella),
+            _ => None,
+        }
+    }
+
+    fn check_slot(self, slot: Slot) -> Result<(), DecodeError> {
+        let fork_version = helpers::compute_fork_version_at_slot(slot.into());
+        if Self::from_fork_version(&fork_version) == Some(self) {
+            Ok(())
+        } else {
+            let msg = format!("the data at slot {slot} should not be in the layout of {self:?}");
+            Err(DecodeError::BytesInvalid(msg))
+        }
+    }
+}
+
+// The layouts are generic over the preset, so the test vectors in the `minimal` preset could be
+// decoded.
+
+#[derive(Clone, Encode, Decode)]
+pub struct LightClientHeaderCapella<E: EthSpec = MainnetEthSpec> {
+    pub beacon: BeaconBlockHeader,
+    pub execution: ExecutionPayloadHeaderCapella<E>,
+    pub execution_branch: FixedVector<Hash256, U4>,
+}
+
+#[derive(Clone, Encode, Decode)]
+pub struct LightClientBootstrapCapella<E: EthSpec = MainnetEthSpec> {
+    pub header: LightClientHeaderCapella<E>,
+    pub current_sync_committee: SyncCommittee<E>,
+    pub current_sync_committee_branch: FixedVector<Hash256, U5>,
+}
+
+#[derive(Clone, Encode, Decode)]
+pub struct LightClientUpdateCapella<E: EthSpec = MainnetEthSpec> {
+    pub attested_header: LightClientHeaderCapella<E>,
+    pub next_sync_committee: SyncCommittee<E>,
+    pub next_sync_committee_branch: FixedVector<Hash256, U5>,
+    pub finalized_header: LightClientHeaderCapella<E>,
+    pub finality_branch: FixedVector<Hash256, U6>,
+    pub sync_aggregate: SyncAggregate<E>,
+    pub signature_slot: Slot,
+}
+
+impl<E: EthSpec> From<LightClientBootstrapCapella<E>> for OriginalLightClientBootstrap<E> {
+    fn from(bootstrap: LightClientBootstrapCapella<E>) -> Self {
+        Self {
+            header: bootstrap.header.beacon,
+            current_sync_committee: Arc::new(bootstrap.current_sync_committee),
+            current_sync_committee_branch: bootstrap.current_sync_committee_branch,
+        }
+    }
+}
+
+impl<E: EthSpec> From<LightClientUpdateCapella<E>> for OriginalLightClientUpdate<E> {
+    fn from(update: LightClientUpdateCapella<E>) -> Self {
+        Self {
+            attested_header: update.attested_header.beacon,
+            next_sync_committee: Arc::new(update.next_sync_committee),
+            next_sync_committee_branch: update.next_sync_committee_branch,
+            finalized_header: update.finalized_header.beacon,
+            finality_branch: update.finality_branch,
+            sync_aggregate: update.sync_aggregate,
+            signature_slot: update.signature_slot,
+        }
+    }
+}
+
+/// Decodes the original light client bootstrap in the layout of the fork.
+pub(crate) fn decode_light_client_bootstrap(
+    bytes: &[u8],
+    fork: LightClientFork,
+) -> Result<OriginalLightClientBootstrap<MainnetEthSpec>, DecodeError> {
+    let bootstrap = match fork {
+        LightClientFork::Altair => OriginalLightClientBootstrap::from_ssz_bytes(bytes)?,
+        LightClientFork::Capella => {
+            LightClientBootstrapCapella::<MainnetEthSpec>::from_ssz_bytes(bytes)?.into()
+        }
+    };
+    fork.check_slot(bootstrap.header.slot)?;
+    Ok(bootstrap)
+}
+
+/// Decodes the original light client update in the layout of the fork.
+pub(crate) fn decode_light_client_update(
+    bytes: &[u8],
+    fork: LightClientFork,
+) -> Result<OriginalLightClientUpdate<MainnetEthSpec>, DecodeError> {
+    let update = match fork {
+        LightClientFork::Altair => OriginalLightClientUpdate::from_ssz_bytes(bytes)?,
+        LightClientFork::Capella => {
+            LightClientUpdateCapella::<MainnetEthSpec>::from_ssz_bytes(bytes)?.into()
+        }
+    };
+    fork.check_slot(update.attested_header.slot)?;
+    Ok(update)
+}
+
+/// Splits the successful response chunks of light client data in the req/resp domain with the
+/// `ssz_snappy` encoding, into the forks and the uncompressed payloads.
+///
+/// Each chunk is `<result> | <context-bytes> | <varint length> | <snappy frames>`, the context
+/// bytes are the fork digest, and the length is the size of the uncompressed payload.
+///
+/// Ref: [Messages / Encoding strategies](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/phase0/p2p-interface.md#encoding-strategies)
+pub(crate) fn decode_response_chunks(
+    mut bytes: &[u8],
+    genesis_validators_root: Hash256,
+) -> io::Result<Vec<(LightClientFork, Vec<u8>)>> {
+    let mut chunks = Vec::new();
+    while let Some((code, rest)) = bytes.split_first() {
+        if *code != RESPONSE_CODE_SUCCESS {
+            let msg = format!("the response code of chunk#{} is {code}", chunks.len());
+            return Err(invalid_data(msg));
+        }
+        if rest.len() < 4 {
+            let msg = format!("no context bytes in chunk#{}", chunks.len());
+            return Err(invalid_data(msg));
+        }
+        let (context_bytes, rest) = rest.split_at(4);
+        let mut fork_digest = [0u8; 4];
+        fork_digest.copy_from_slice(context_bytes);
+        let fork = LightClientFork::from_fork_digest(&fork_digest, genesis_validators_root)
+            .ok_or_else(|| {
+                let msg = format!(
+                    "unsupported fork digest {fork_digest:02x?} in chunk#{}",
+                    chunks.len()
+                );
+                invalid_data(msg)
+            })?;
+        let (length, mut rest) = decode_varint(rest)?;
+        if length > MAX_PAYLOAD_SIZE {
+            let msg = format!("the payload size of chunk#{} is {length}", chunks.len());
+            return Err(invalid_data(msg));
+        }
+        // The frame decoder reads the whole frames, it stops at the end of the payload, and the
+        // rest bytes are the next chunks.
+        let mut payload = Vec::with_capacity(length as usize);
+        io::Read::read_to_end(
+            &mut io::Read::take(FrameDecoder::new(&mut rest), length),
+            &mut payload,
+        )?;
+        if payload.len() as u64 != length {
+            let msg = format!(
+                "the payload size of chunk#{} is {} but expect {length}",
+                chunks.len(),
+                payload.len()
+            );
+            return Err(invalid_data(msg));
+        }
+        chunks.push((fork, payload));
+        bytes = rest;
+    }
+    Ok(chunks)
+}
+
+/// Decompresses the data in the snappy frame format, such as the entries in era files.
+pub(crate) fn decompress_snappy(data: &[u8]) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    io::Read::read_to_end(&mut FrameDecoder::new(data), &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Decompresses the data in the snappy block format, such as the gossip messages.
+pub(crate) fn decompress_snappy_block(data: &[u8]) -> io::Result<Vec<u8>> {
+    let length = snap::raw::decompress_len(data)?;
+    if length as u64 > MAX_PAYLOAD_SIZE {
+        return Err(invalid_data(format!("the uncompressed size is {length}")));
+    }
+    let bytes = snap::raw::Decoder::new().decompress_vec(data)?;
+    Ok(bytes)
+}
+
+pub(crate) fn decode_error_to_io_error(err: DecodeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("{err:?}"))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// Decodes an unsigned protobuf varint, which is at most 10 bytes for an u64.
+fn decode_varint(bytes: &[u8]) -> io::Result<(u64, &[u8])> {
+    let mut value = 0u64;
+    for (index, byte) in bytes.iter().enumerate().take(10) {
+        if index == 9 && *byte > 1 {
+            break;
+        }
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Ok((value, &bytes[index + 1..]));
+        }
+    }
+    Err(invalid_data(
+        "invalid varint for the payload size".to_owned(),
+    ))
+}
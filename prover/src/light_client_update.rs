@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{io, sync::Arc};
 
 use eth2_types::{
     light_client_update::{
@@ -6,8 +6,20 @@ use eth2_types::{
     },
     MainnetEthSpec,
 };
+use ssz::DecodeError;
+use tree_hash::Hash256;
 
-use eth_light_client_in_ckb_verification::{consensus_specs::helpers, types::core};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{helpers, ChainConfig},
+    types::core,
+};
+
+use crate::{
+    light_client_ssz::{
+        decode_error_to_io_error, decode_light_client_update, decode_response_chunks,
+    },
+    LightClientFork,
+};
 
 #[derive(Clone)]
 pub struct LightClientUpdate {
@@ -22,27 +34,63 @@ impl From<PatchedLightClientUpdate<MainnetEthSpec>> for LightClientUpdate {
     }
 }
 
+impl From<OriginalLightClientUpdate<MainnetEthSpec>> for LightClientUpdate {
+    fn from(original: OriginalLightClientUpdate<MainnetEthSpec>) -> Self {
+        Self { original }
+    }
+}
+
 impl LightClientUpdate {
+    /// Decodes a light client update from SSZ bytes, such as an item in the response of the
+    /// Beacon API `/eth/v1/beacon/light_client/updates` in `application/octet-stream`.
+    ///
+    /// The layout is chosen by the fork, such as the `version` of the item.
+    pub fn from_ssz_bytes(bytes: &[u8], fork: LightClientFork) -> Result<Self, DecodeError> {
+        decode_light_client_update(bytes, fork).map(|original| Self { original })
+    }
+
+    /// Decodes light client updates from the response of the p2p request
+    /// `LightClientUpdatesByRange`, which are response chunks in the `ssz_snappy` encoding.
+    ///
+    /// The layout of each update is chosen by the context bytes of its chunk.
+    pub fn from_p2p_response(
+        bytes: &[u8],
+        genesis_validators_root: Hash256,
+    ) -> io::Result<Vec<Self>> {
+        decode_response_chunks(bytes, genesis_validators_root)?
+            .into_iter()
+            .map(|(fork, payload)| {
+                Self::from_ssz_bytes(&payload, fork).map_err(decode_error_to_io_error)
+            })
+            .collect()
+    }
+
     pub fn original(&self) -> &OriginalLightClientUpdate<MainnetEthSpec> {
         &self.original
     }
 
+    /// The sync committee period of the attested header.
+    pub fn sync_committee_period(&self) -> u64 {
+        helpers::compute_sync_committee_period_at_slot(self.original.attested_header.slot.into())
+    }
+
+    pub fn summary(&self) -> core::UpdateSummary {
+        (&self.original).into()
+    }
+
+    /// Checks if the update is better than the other one, with the preset and the fork
+    /// schedule of the chain.
+    ///
+    /// A pending update should be replaced if the new update is better than it.
+    pub fn is_better_than(&self, other: &Self, config: &ChainConfig) -> bool {
+        self.summary().is_better_than(&other.summary(), config)
+    }
+
     pub fn build_sync_committee_update(&self) -> core::SyncCommitteeUpdate {
         let original = self.original();
         let attested_header = original.attested_header.clone().into();
         let next_sync_committee_branch = original.next_sync_committee_branch.to_vec();
-        let sync_committee_bits = core::SyncCommitteeBits::from_slice(
-            original.sync_aggregate.sync_committee_bits.as_slice(),
-        );
-        let sync_committee_signature = original
-            .sync_aggregate
-            .sync_committee_signature
-            .serialize()
-            .into();
-        let sync_aggregate = core::SyncAggregate {
-            sync_committee_bits,
-            sync_committee_signature,
-        };
+        let sync_aggregate = original.sync_aggregate.clone().into();
         let signature_slot = original.signature_slot.into();
         core::SyncCommitteeUpdate {
             attested_header,
@@ -53,9 +101,7 @@ impl LightClientUpdate {
     }
 
     pub fn build_next_client_sync_committee(&self) -> core::ClientSyncCommittee {
-        let attested_slot = self.original.attested_header.slot;
-        let attested_period = helpers::compute_sync_committee_period_at_slot(attested_slot.into());
-        let period = attested_period + 1;
+        let period = self.sync_committee_period() + 1;
         let next_sync_committee = Arc::clone(&self.original().next_sync_committee);
         let pubkeys = next_sync_committee.pubkeys.to_vec();
         let aggregate_pubkey = next_sync_committee.aggregate_pubkey;
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use merkle_proof::MerkleTree;
+use tree_hash::{mix_in_length, Hash256, TreeHash, BYTES_PER_CHUNK};
+
+use eth_light_client_in_ckb_verification::utilities::ssz;
+
+/// A node of the SSZ merkle tree of a value, which is described by its fields or items.
+///
+/// Only containers, vectors and lists could be traversed by paths; other values are described
+/// as leaves. For lists of basic types, the items are packed into chunks, so the indexes in
+/// paths are the indexes of chunks.
+#[derive(Clone)]
+pub struct SszNode {
+    root: Hash256,
+    kind: SszNodeKind,
+}
+
+#[derive(Clone)]
+enum SszNodeKind {
+    Leaf,
+    Container {
+        fields: Vec<(&'static str, SszNode)>,
+        depth: usize,
+    },
+    Vector {
+        items: Vec<SszNode>,
+        depth: usize,
+    },
+    List {
+        items: Vec<SszNode>,
+        depth: usize,
+        data_root: Hash256,
+        length: usize,
+    },
+}
+
+/// An SSZ proof for a leaf in a merkle tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SszProof {
+    pub leaf: Hash256,
+    /// The branch from the bottom to the top.
+    pub branch: Vec<Hash256>,
+    pub generalized_index: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SszPathError {
+    InvalidPath(String),
+    UnknownField(String),
+    NotContainer(String),
+    NotList(usize),
+    IndexOutOfBounds { index: usize, length: usize },
+}
+
+#[derive(Clone, Debug)]
+enum PathElement<'a> {
+    Field(&'a str),
+    Index(usize),
+}
+
+impl fmt::Display for SszPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPath(path) => write!(f, "invalid path \"{path}\""),
+            Self::UnknownField(field) => write!(f, "unknown field \"{field}\""),
+            Self::NotContainer(field) => write!(f, "field \"{field}\" is not in a container"),
+            Self::NotList(index) => write!(f, "index {index} is not in a list"),
+            Self::IndexOutOfBounds { index, length } => {
+                write!(f, "index {index} is out of bounds (length: {length})")
+            }
+        }
+    }
+}
+
+impl SszNode {
+    /// Creates a leaf from a value.
+    pub fn leaf<T: TreeHash + ?Sized>(value: &T) -> Self {
+        Self::leaf_with_root(value.tree_hash_root())
+    }
+
+    /// Creates a leaf from a tree hash root.
+    pub fn leaf_with_root(root: Hash256) -> Self {
+        Self {
+            root,
+            kind: SszNodeKind::Leaf,
+        }
+    }
+
+    /// Creates a container from its fields, in order.
+    pub fn container(fields: Vec<(&'static str, SszNode)>) -> Self {
+        let depth = ssz::ceil_depth(fields.len()) as usize;
+        let leaves = fields.iter().map(|(_, node)| node.root).collect::<Vec<_>>();
+        let root = MerkleTree::create(&leaves, depth).hash();
+        Self {
+            root,
+            kind: SszNodeKind::Container { fields, depth },
+        }
+    }
+
+    /// Creates a vector of composite types, or a vector of 32-bytes basic types, from its
+    /// items.
+    pub fn vector(items: Vec<SszNode>) -> Self {
+        let depth = ssz::ceil_depth(items.len()) as usize;
+        let leaves = items.iter().map(|node| node.root).collect::<Vec<_>>();
+        let root = MerkleTree::create(&leaves, depth).hash();
+        Self {
+            root,
+            kind: SszNodeKind::Vector { items, depth },
+        }
+    }
+
+    /// Creates a list of composite types from its items and its maximum length.
+    pub fn list(items: Vec<SszNode>, limit: usize) -> Self {
+        let length = items.len();
+        Self::list_of_chunks(items, limit, length)
+    }
+
+    /// Creates a list of basic types from its items and its maximum length.
+    ///
+    /// The items are packed into chunks, each chunk is a leaf.
+    pub fn basic_list<T: TreeHash>(items: &[T], limit: usize) -> Self {
+        let packing_factor = T::tree_hash_packing_factor();
+        let item_size = BYTES_PER_CHUNK / packing_factor;
+        let chunks = items
+            .chunks(packing_factor)
+            .map(|chunk_items| {
+                let mut chunk = [0u8; BYTES_PER_CHUNK];
+                for (index, item) in chunk_items.iter().enumerate() {
+                    let start = index * item_size;
+                    chunk[start..start + item_size]
+                        .copy_from_slice(&item.tree_hash_packed_encoding());
+                }
+                Self::leaf_with_root(Hash256::from(chunk))
+            })
+            .collect();
+        let chunks_limit = (limit + packing_factor - 1) / packing_factor;
+        Self::list_of_chunks(chunks, chunks_limit, items.len())
+    }
+
+    fn list_of_chunks(items: Vec<SszNode>, limit: usize, length: usize) -> Self {
+        let depth = ssz::ceil_depth(limit) as usize;
+        let leaves = items.iter().map(|node| node.root).collect::<Vec<_>>();
+        let data_root = MerkleTree::create(&leaves, depth).hash();
+        let root = mix_in_length(&data_root, length);
+        Self {
+            root,
+            kind: SszNodeKind::List {
+                items,
+                depth,
+                data_root,
+                length,
+            },
+        }
+    }
+
+    pub fn root(&self) -> Hash256 {
+        self.root
+    }
+
+    /// The root of the items in a list, without the length mixed in.
+    pub fn data_root(&self) -> Option<Hash256> {
+        if let SszNodeKind::List { data_root, .. } = self.kind {
+            Some(data_root)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the count of the items in a list.
+    pub fn items_count(&self) -> Option<usize> {
+        if let SszNodeKind::List { length, .. } = self.kind {
+            Some(length)
+        } else {
+            None
+        }
+    }
+
+    /// Finds the node by a path, such as `execution_payload.transactions[0]`.
+    pub fn node(&self, path: &str) -> Result<&Self, SszPathError> {
+        let mut node = self;
+        for element in parse_path(path)? {
+            node = node.child(&element)?.1;
+        }
+        Ok(node)
+    }
+
+    /// Generates the proof for the node at a path, such as `execution_payload.transactions[0]`.
+    pub fn generate_proof(&self, path: &str) -> Result<SszProof, SszPathError> {
+        let elements = parse_path(path)?;
+        self.generate_proof_for_elements(&elements)
+    }
+
+    fn generate_proof_for_elements(
+        &self,
+        elements: &[PathElement],
+    ) -> Result<SszProof, SszPathError> {
+        let (element, rest) = if let Some(split) = elements.split_first() {
+            split
+        } else {
+            let proof = SszProof {
+                leaf: self.root,
+                branch: Vec::new(),
+                generalized_index: 1,
+            };
+            return Ok(proof);
+        };
+        let (index, child) = self.child(element)?;
+        let mut proof = child.generate_proof_for_elements(rest)?;
+        let local_generalized_index = match self.kind {
+            SszNodeKind::Container { ref fields, depth } => {
+                let leaves = fields.iter().map(|(_, node)| node.root).collect::<Vec<_>>();
+                let tree = MerkleTree::create(&leaves, depth);
+                let (_, fields_proof) = tree.generate_proof(index, depth).unwrap();
+                proof.branch.extend(fields_proof);
+                (1 << depth) + index
+            }
+            SszNodeKind::Vector { ref items, depth } => {
+                let leaves = items.iter().map(|node| node.root).collect::<Vec<_>>();
+                let tree = MerkleTree::create(&leaves, depth);
+                let (_, items_proof) = tree.generate_proof(index, depth).unwrap();
+                proof.branch.extend(items_proof);
+                (1 << depth) + index
+            }
+            SszNodeKind::List {
+                ref items,
+                depth,
+                length,
+                ..
+            } => {
+                let leaves = items.iter().map(|node| node.root).collect::<Vec<_>>();
+                let tree = MerkleTree::create(&leaves, depth);
+                let (_, items_proof) = tree.generate_proof(index, depth).unwrap();
+                proof.branch.extend(items_proof);
+                proof.branch.push(ssz::length_hash(length));
+                (1 << (depth + 1)) + index
+            }
+            SszNodeKind::Leaf => unreachable!(),
+        };
+        proof.generalized_index =
+            concat_generalized_indices(local_generalized_index, proof.generalized_index);
+        Ok(proof)
+    }
+
+    fn child(&self, element: &PathElement) -> Result<(usize, &Self), SszPathError> {
+        match (element, &self.kind) {
+            (PathElement::Field(name), SszNodeKind::Container { fields, .. }) => fields
+                .iter()
+                .enumerate()
+                .find(|(_, (field_name, _))| field_name == name)
+                .map(|(index, (_, node))| (index, node))
+                .ok_or_else(|| SszPathError::UnknownField(name.to_string())),
+            (PathElement::Field(name), _) => Err(SszPathError::NotContainer(name.to_string())),
+            (PathElement::Index(index), SszNodeKind::Vector { items, .. })
+            | (PathElement::Index(index), SszNodeKind::List { items, .. }) => items
+                .get(*index)
+                .map(|node| (*index, node))
+                .ok_or(SszPathError::IndexOutOfBounds {
+                    index: *index,
+                    length: items.len(),
+                }),
+            (PathElement::Index(index), _) => Err(SszPathError::NotList(*index)),
+        }
+    }
+}
+
+// Concatenates the generalized index of a node and the generalized index in that node.
+fn concat_generalized_indices(parent: usize, child: usize) -> usize {
+    let depth = usize::BITS - 1 - child.leading_zeros();
+    (parent << depth) | (child - (1 << depth))
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathElement>, SszPathError> {
+    let invalid = || SszPathError::InvalidPath(path.to_owned());
+    let mut elements = Vec::new();
+    if path.is_empty() {
+        return Ok(elements);
+    }
+    for segment in path.split('.') {
+        let (name, mut indexes) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+        if name.is_empty() {
+            if indexes.is_empty() || !elements.is_empty() {
+                return Err(invalid());
+            }
+        } else {
+            elements.push(PathElement::Field(name));
+        }
+        while !indexes.is_empty() {
+            let end = indexes.find(']').ok_or_else(invalid)?;
+            if !indexes.starts_with('[') {
+                return Err(invalid());
+            }
+            let index = indexes[1..end].parse().map_err(|_| invalid())?;
+            elements.push(PathElement::Index(index));
+            indexes = &indexes[end + 1..];
+        }
+    }
+    Ok(elements)
+}
@@ -0,0 +1,411 @@
+//! A deterministic synthetic beacon chain, to test the rare cases which are not in the mainnet
+//! test data, such as the exact supermajority participation, long runs of empty slots and the
+//! fork transitions.
+//!
+//! The preset is chosen by the `EthSpec`, and the fork schedule is set in the configuration,
+//! the default is the mainnet.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use eth2_types::{
+    light_client_bootstrap::LightClientBootstrap as OriginalLightClientBootstrap,
+    light_client_finality_update::LightClientFinalityUpdate,
+    light_client_optimistic_update::LightClientOptimisticUpdate,
+    light_client_update::LightClientUpdate as OriginalLightClientUpdate, typenum::Unsigned as _,
+    AggregatePublicKey, AggregateSignature, BeaconBlockHeader, BitVector, Epoch, EthSpec,
+    FixedVector, MainnetEthSpec, SecretKey, Slot, SyncAggregate, SyncCommittee,
+};
+use ethers_core::utils::keccak256;
+use tree_hash::{Hash256, TreeHash as _};
+
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, ChainConfig},
+    utilities::{bls, ssz::ContainerLayout},
+};
+
+use crate::SszNode;
+
+#[derive(Clone)]
+pub struct SyntheticChainConfig {
+    /// The seed to derive the secret keys of the sync committee members.
+    pub seed: u64,
+    pub genesis_validators_root: Hash256,
+    /// The slot of the first header, which is treated as finalized, to bootstrap light clients.
+    pub start_slot: u64,
+    /// The slot of the last header, inclusive.
+    pub end_slot: u64,
+    /// The slots without blocks.
+    pub empty_slots: BTreeSet<u64>,
+    /// The count of the sync committee members which sign the attested headers by default.
+    pub participants_count: usize,
+    /// The preset and the fork schedule, the preset should be same as the `EthSpec` of the chain.
+    pub chain_config: ChainConfig,
+}
+
+/// A synthetic beacon chain, which headers have valid parent links, and which beacon states
+/// only contain the slots, the sync committees and the finalized checkpoints.
+pub struct SyntheticChain<E: EthSpec = MainnetEthSpec> {
+    config: SyntheticChainConfig,
+    sync_committees: BTreeMap<u64, SyntheticSyncCommittee<E>>,
+    // The non-empty headers and their roots.
+    headers: BTreeMap<u64, (BeaconBlockHeader, Hash256)>,
+}
+
+struct SyntheticSyncCommittee<E: EthSpec> {
+    secret_keys: Vec<SecretKey>,
+    sync_committee: Arc<SyncCommittee<E>>,
+    root: Hash256,
+}
+
+impl SyntheticChainConfig {
+    /// Creates a configuration for the mainnet.
+    pub fn new(start_slot: u64, end_slot: u64) -> Self {
+        Self::with_chain_config(start_slot, end_slot, ChainConfig::MAINNET)
+    }
+
+    /// Creates a configuration for a chain with another preset or another fork schedule, all
+    /// members of the sync committees sign the attested headers by default.
+    pub fn with_chain_config(start_slot: u64, end_slot: u64, chain_config: ChainConfig) -> Self {
+        Self {
+            seed: 0,
+            genesis_validators_root: Hash256::repeat_byte(0x42),
+            start_slot,
+            end_slot,
+            empty_slots: BTreeSet::new(),
+            participants_count: chain_config.sync_committee_size,
+            chain_config,
+        }
+    }
+}
+
+impl<E: EthSpec> SyntheticSyncCommittee<E> {
+    fn new(seed: u64, period: u64) -> Self {
+        let secret_keys = (0..E::SyncCommitteeSize::to_usize())
+            .map(|index| {
+                let mut data = Vec::with_capacity(24);
+                data.extend_from_slice(&seed.to_be_bytes());
+                data.extend_from_slice(&period.to_be_bytes());
+                data.extend_from_slice(&(index as u64).to_be_bytes());
+                let mut bytes = keccak256(data);
+                // Make sure the secret key is less than the curve order.
+                bytes[0] = 0;
+                SecretKey::deserialize(&bytes).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let pubkeys = secret_keys
+            .iter()
+            .map(SecretKey::public_key)
+            .collect::<Vec<_>>();
+        let aggregate_pubkey = AggregatePublicKey::aggregate(&pubkeys)
+            .unwrap()
+            .to_public_key()
+            .compress();
+        let pubkeys = pubkeys.iter().map(|pubkey| pubkey.compress()).collect();
+        let sync_committee = SyncCommittee {
+            pubkeys: FixedVector::new(pubkeys).unwrap(),
+            aggregate_pubkey,
+        };
+        let root = sync_committee.tree_hash_root();
+        Self {
+            secret_keys,
+            sync_committee: Arc::new(sync_committee),
+            root,
+        }
+    }
+}
+
+impl SyntheticChain<MainnetEthSpec> {
+    /// Creates a chain with the mainnet preset.
+    pub fn new(config: SyntheticChainConfig) -> Self {
+        Self::with_preset(config)
+    }
+}
+
+impl<E: EthSpec> SyntheticChain<E> {
+    /// Creates a chain with the preset of the `EthSpec`.
+    pub fn with_preset(config: SyntheticChainConfig) -> Self {
+        let chain_config = &config.chain_config;
+        assert_eq!(chain_config.slots_per_epoch, E::slots_per_epoch());
+        assert_eq!(
+            chain_config.epochs_per_sync_committee_period,
+            E::EpochsPerSyncCommitteePeriod::to_u64()
+        );
+        assert_eq!(
+            chain_config.sync_committee_size,
+            E::SyncCommitteeSize::to_usize()
+        );
+        assert!(config.start_slot < config.end_slot);
+        assert!(!config.empty_slots.contains(&config.start_slot));
+        assert!(config.participants_count <= chain_config.sync_committee_size);
+        let start_period = chain_config.compute_sync_committee_period_at_slot(config.start_slot);
+        let end_period = chain_config.compute_sync_committee_period_at_slot(config.end_slot);
+        // The next sync committee of the last period is required by the states.
+        let sync_committees = (start_period..=end_period + 1)
+            .map(|period| (period, SyntheticSyncCommittee::new(config.seed, period)))
+            .collect();
+        let mut chain = Self {
+            config,
+            sync_committees,
+            headers: BTreeMap::new(),
+        };
+        let mut parent_root = Hash256::repeat_byte(0xff);
+        for slot in chain.config.start_slot..=chain.config.end_slot {
+            if chain.config.empty_slots.contains(&slot) {
+                continue;
+            }
+            let header = BeaconBlockHeader {
+                slot: slot.into(),
+                proposer_index: slot % 1024 + 1,
+                parent_root,
+                state_root: chain.build_state(slot).root(),
+                body_root: Hash256::from_low_u64_be(slot),
+            };
+            parent_root = header.tree_hash_root();
+            chain.headers.insert(slot, (header, parent_root));
+        }
+        chain
+    }
+
+    pub fn config(&self) -> &SyntheticChainConfig {
+        &self.config
+    }
+
+    pub fn genesis_validators_root(&self) -> Hash256 {
+        self.config.genesis_validators_root
+    }
+
+    pub fn chain_config(&self) -> &ChainConfig {
+        &self.config.chain_config
+    }
+
+    /// Returns the header at the slot, or `None` if the slot is empty.
+    pub fn header_at_slot(&self, slot: u64) -> Option<&BeaconBlockHeader> {
+        self.headers.get(&slot).map(|(header, _)| header)
+    }
+
+    /// Returns the headers for all slots in the range, the headers for the empty slots only
+    /// have the slots.
+    pub fn beacon_block_headers(&self, start_slot: u64, end_slot: u64) -> Vec<BeaconBlockHeader> {
+        (start_slot..=end_slot)
+            .map(|slot| {
+                self.header_at_slot(slot)
+                    .cloned()
+                    .unwrap_or_else(|| BeaconBlockHeader {
+                        slot: slot.into(),
+                        proposer_index: 0,
+                        parent_root: Default::default(),
+                        state_root: Default::default(),
+                        body_root: Default::default(),
+                    })
+            })
+            .collect()
+    }
+
+    pub fn sync_committee(&self, period: u64) -> &SyncCommittee<E> {
+        &self.sync_committees[&period].sync_committee
+    }
+
+    /// Returns the finalized header in the beacon state at the slot.
+    pub fn finalized_header(&self, slot: u64) -> &BeaconBlockHeader {
+        let (_, finalized_slot) = self.finalized_checkpoint(slot);
+        self.header_at_slot(finalized_slot).unwrap()
+    }
+
+    pub fn bootstrap(&self) -> OriginalLightClientBootstrap<E> {
+        let slot = self.config.start_slot;
+        let header = self.header_at_slot(slot).unwrap().clone();
+        let period = self
+            .chain_config()
+            .compute_sync_committee_period_at_slot(slot);
+        let current_sync_committee = Arc::clone(&self.sync_committees[&period].sync_committee);
+        let current_sync_committee_branch = self
+            .build_state(slot)
+            .generate_proof("current_sync_committee")
+            .map(|proof| FixedVector::new(proof.branch).unwrap())
+            .unwrap();
+        OriginalLightClientBootstrap {
+            header,
+            current_sync_committee,
+            current_sync_committee_branch,
+        }
+    }
+
+    /// Signs the attested header by the first `participants_count` members of the sync
+    /// committee in the period of the signature slot.
+    pub fn sign_attested_header(
+        &self,
+        attested_slot: u64,
+        signature_slot: u64,
+        participants_count: usize,
+    ) -> SyncAggregate<E> {
+        let (_, attested_root) = &self.headers[&attested_slot];
+        let period = self
+            .chain_config()
+            .compute_sync_committee_period_at_slot(signature_slot);
+        let message = bls::compute_signing_root_at_signature_slot(
+            self.chain_config(),
+            *attested_root,
+            signature_slot,
+            &forks::altair::DOMAIN_SYNC_COMMITTEE,
+            self.config.genesis_validators_root,
+        );
+        let mut sync_committee_bits = BitVector::new();
+        let mut sync_committee_signature = AggregateSignature::infinity();
+        for (index, secret_key) in self.sync_committees[&period]
+            .secret_keys
+            .iter()
+            .enumerate()
+            .take(participants_count)
+        {
+            sync_committee_bits.set(index, true).unwrap();
+            sync_committee_signature.add_assign(&secret_key.sign(message));
+        }
+        SyncAggregate {
+            sync_committee_bits,
+            sync_committee_signature,
+        }
+    }
+
+    pub fn finality_update(
+        &self,
+        attested_slot: u64,
+        signature_slot: u64,
+    ) -> LightClientFinalityUpdate<E> {
+        let finality_branch = self
+            .build_state(attested_slot)
+            .generate_proof("finalized_checkpoint.root")
+            .map(|proof| FixedVector::new(proof.branch).unwrap())
+            .unwrap();
+        LightClientFinalityUpdate {
+            attested_header: self.header_at_slot(attested_slot).unwrap().clone(),
+            finalized_header: self.finalized_header(attested_slot).clone(),
+            finality_branch,
+            sync_aggregate: self.sign_attested_header(
+                attested_slot,
+                signature_slot,
+                self.config.participants_count,
+            ),
+            signature_slot: signature_slot.into(),
+        }
+    }
+
+    pub fn optimistic_update(
+        &self,
+        attested_slot: u64,
+        signature_slot: u64,
+    ) -> LightClientOptimisticUpdate<E> {
+        LightClientOptimisticUpdate {
+            attested_header: self.header_at_slot(attested_slot).unwrap().clone(),
+            sync_aggregate: self.sign_attested_header(
+                attested_slot,
+                signature_slot,
+                self.config.participants_count,
+            ),
+            signature_slot: signature_slot.into(),
+        }
+    }
+
+    pub fn light_client_update(
+        &self,
+        attested_slot: u64,
+        signature_slot: u64,
+    ) -> OriginalLightClientUpdate<E> {
+        let state = self.build_state(attested_slot);
+        let period = self
+            .chain_config()
+            .compute_sync_committee_period_at_slot(attested_slot);
+        let next_sync_committee = Arc::clone(&self.sync_committees[&(period + 1)].sync_committee);
+        let next_sync_committee_branch = state
+            .generate_proof("next_sync_committee")
+            .map(|proof| FixedVector::new(proof.branch).unwrap())
+            .unwrap();
+        let finality_branch = state
+            .generate_proof("finalized_checkpoint.root")
+            .map(|proof| FixedVector::new(proof.branch).unwrap())
+            .unwrap();
+        OriginalLightClientUpdate {
+            attested_header: self.header_at_slot(attested_slot).unwrap().clone(),
+            next_sync_committee,
+            next_sync_committee_branch,
+            finalized_header: self.finalized_header(attested_slot).clone(),
+            finality_branch,
+            sync_aggregate: self.sign_attested_header(
+                attested_slot,
+                signature_slot,
+                self.config.participants_count,
+            ),
+            signature_slot: signature_slot.into(),
+        }
+    }
+
+    // Returns the finalized epoch and the slot of the finalized header in the state at the slot.
+    //
+    // The epoch before the previous epoch is finalized, and the first header is finalized.
+    fn finalized_checkpoint(&self, slot: u64) -> (u64, u64) {
+        let chain_config = self.chain_config();
+        let epoch = chain_config.compute_epoch_at_slot(slot);
+        let start_epoch = chain_config.compute_epoch_at_slot(self.config.start_slot);
+        let finalized_epoch = if epoch >= start_epoch + 2 {
+            epoch - 2
+        } else {
+            start_epoch
+        };
+        let boundary_slot = chain_config.compute_start_slot_at_epoch(finalized_epoch);
+        let finalized_slot = self
+            .headers
+            .range(..=boundary_slot)
+            .next_back()
+            .map(|(slot, _)| *slot)
+            .unwrap_or(self.config.start_slot);
+        (finalized_epoch, finalized_slot)
+    }
+
+    fn build_state(&self, slot: u64) -> SszNode {
+        let period = self
+            .chain_config()
+            .compute_sync_committee_period_at_slot(slot);
+        let current_sync_committee_root = self.sync_committees[&period].root;
+        let next_sync_committee_root = self.sync_committees[&(period + 1)].root;
+        let (finalized_epoch, finalized_slot) = self.finalized_checkpoint(slot);
+        // The first header has no finalized header before it.
+        let finalized_root = if finalized_slot < slot {
+            self.headers[&finalized_slot].1
+        } else {
+            Hash256::zero()
+        };
+        let fields = beacon_state_layout(self.chain_config(), slot)
+            .fields()
+            .iter()
+            .map(|name| {
+                let node = match *name {
+                    "slot" => SszNode::leaf(&Slot::new(slot)),
+                    "current_sync_committee" => {
+                        SszNode::leaf_with_root(current_sync_committee_root)
+                    }
+                    "next_sync_committee" => SszNode::leaf_with_root(next_sync_committee_root),
+                    "finalized_checkpoint" => SszNode::container(vec![
+                        ("epoch", SszNode::leaf(&Epoch::new(finalized_epoch))),
+                        ("root", SszNode::leaf_with_root(finalized_root)),
+                    ]),
+                    _ => SszNode::leaf_with_root(Hash256::zero()),
+                };
+                (*name, node)
+            })
+            .collect();
+        SszNode::container(fields)
+    }
+}
+
+fn beacon_state_layout(chain_config: &ChainConfig, slot: u64) -> ContainerLayout {
+    let epoch = chain_config.compute_epoch_at_slot(slot);
+    if epoch >= chain_config.capella_fork.epoch {
+        forks::capella::containers::BEACON_STATE
+    } else if epoch >= chain_config.bellatrix_fork.epoch {
+        forks::bellatrix::containers::BEACON_STATE
+    } else {
+        forks::altair::containers::BEACON_STATE
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use eth_light_client_in_ckb_verification::utilities::mpt;
+use tree_hash::Hash256;
+
+use crate::CachedBeaconBlock;
+
+/// An index to look up the position of transactions by their hashes.
+///
+/// The position of a transaction is the slot of the beacon block which contains it and the
+/// index of it in the execution payload, which are required to build a transaction proof.
+#[derive(Clone, Default)]
+pub struct TransactionIndex {
+    positions: HashMap<Hash256, (u64, usize)>,
+}
+
+impl TransactionIndex {
+    /// Adds all transactions in a beacon block into the index.
+    pub fn insert_block(&mut self, block: &CachedBeaconBlock) {
+        let slot = block.slot().into();
+        for index in 0..block.transactions_count() {
+            let transaction = block.transaction(index).unwrap();
+            let hash = Hash256::from(mpt::keccak256(&transaction));
+            self.positions.insert(hash, (slot, index));
+        }
+    }
+
+    /// Returns the slot and the index of a transaction.
+    pub fn get(&self, transaction_hash: &Hash256) -> Option<(u64, usize)> {
+        self.positions.get(transaction_hash).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+}
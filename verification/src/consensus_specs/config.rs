@@ -0,0 +1,93 @@
+//! The preset and the fork schedule of a beacon chain.
+
+use crate::consensus_specs::forks;
+
+/// A scheduled fork.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fork {
+    pub epoch: u64,
+    pub version: [u8; 4],
+}
+
+/// The preset and the fork schedule of a beacon chain.
+///
+/// The constants in [`forks`] are for the mainnet, this configuration is for the other chains,
+/// such as the chains with the `minimal` preset in the consensus spec tests.
+///
+/// [`forks`]: ../forks/index.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainConfig {
+    pub slots_per_epoch: u64,
+    pub epochs_per_sync_committee_period: u64,
+    pub sync_committee_size: usize,
+    pub genesis_fork_version: [u8; 4],
+    pub altair_fork: Fork,
+    pub bellatrix_fork: Fork,
+    pub capella_fork: Fork,
+}
+
+impl ChainConfig {
+    /// The mainnet preset and the mainnet fork schedule.
+    pub const MAINNET: Self = Self {
+        slots_per_epoch: forks::phase0::SLOTS_PER_EPOCH,
+        epochs_per_sync_committee_period: forks::altair::EPOCHS_PER_SYNC_COMMITTEE_PERIOD,
+        sync_committee_size: forks::altair::SYNC_COMMITTEE_SIZE,
+        genesis_fork_version: forks::phase0::GENESIS_FORK_VERSION,
+        altair_fork: Fork {
+            epoch: forks::altair::FORK_EPOCH,
+            version: forks::altair::FORK_VERSION,
+        },
+        bellatrix_fork: Fork {
+            epoch: forks::bellatrix::FORK_EPOCH,
+            version: forks::bellatrix::FORK_VERSION,
+        },
+        capella_fork: Fork {
+            epoch: forks::capella::FORK_EPOCH,
+            version: forks::capella::FORK_VERSION,
+        },
+    };
+
+    /// Same as [`helpers::compute_epoch_at_slot`], with the preset of this chain.
+    ///
+    /// [`helpers::compute_epoch_at_slot`]: ../helpers/fn.compute_epoch_at_slot.html
+    pub const fn compute_epoch_at_slot(&self, slot: u64) -> u64 {
+        slot / self.slots_per_epoch
+    }
+
+    /// Same as [`helpers::compute_start_slot_at_epoch`], with the preset of this chain.
+    ///
+    /// [`helpers::compute_start_slot_at_epoch`]: ../helpers/fn.compute_start_slot_at_epoch.html
+    pub const fn compute_start_slot_at_epoch(&self, epoch: u64) -> u64 {
+        epoch * self.slots_per_epoch
+    }
+
+    /// Same as [`helpers::compute_sync_committee_period_at_slot`], with the preset of this
+    /// chain.
+    ///
+    /// [`helpers::compute_sync_committee_period_at_slot`]: ../helpers/fn.compute_sync_committee_period_at_slot.html
+    pub const fn compute_sync_committee_period_at_slot(&self, slot: u64) -> u64 {
+        self.compute_epoch_at_slot(slot) / self.epochs_per_sync_committee_period
+    }
+
+    /// Same as [`helpers::compute_fork_version`], with the fork schedule of this chain.
+    ///
+    /// [`helpers::compute_fork_version`]: ../helpers/fn.compute_fork_version.html
+    pub const fn compute_fork_version(&self, epoch: u64) -> [u8; 4] {
+        if epoch >= self.capella_fork.epoch {
+            self.capella_fork.version
+        } else if epoch >= self.bellatrix_fork.epoch {
+            self.bellatrix_fork.version
+        } else if epoch >= self.altair_fork.epoch {
+            self.altair_fork.version
+        } else {
+            self.genesis_fork_version
+        }
+    }
+
+    /// Same as [`helpers::compute_fork_version_at_slot`], with the fork schedule of this chain.
+    ///
+    /// [`helpers::compute_fork_version_at_slot`]: ../helpers/fn.compute_fork_version_at_slot.html
+    pub const fn compute_fork_version_at_slot(&self, slot: u64) -> [u8; 4] {
+        self.compute_fork_version(self.compute_epoch_at_slot(slot))
+    }
+}
@@ -27,25 +27,52 @@ pub const SYNC_COMMITTEE_SIZE: usize = 512;
 /// [The Beacon Chain / Preset / Sync committee]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#sync-committee
 pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: u64 = 256;
 
+/// Constants for containers.
+pub mod containers {
+    use crate::utilities::ssz::ContainerLayout;
+
+    /// The fields of [`Checkpoint`].
+    ///
+    /// [`Checkpoint`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#checkpoint
+    pub const CHECKPOINT: ContainerLayout = ContainerLayout::new(&["epoch", "root"]);
+
+    /// The fields of [`BeaconState`].
+    ///
+    /// [`BeaconState`]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#beaconstate
+    pub const BEACON_STATE: ContainerLayout = ContainerLayout::new(&[
+        "genesis_time",
+        "genesis_validators_root",
+        "slot",
+        "fork",
+        "latest_block_header",
+        "block_roots",
+        "state_roots",
+        "historical_roots",
+        "eth1_data",
+        "eth1_data_votes",
+        "eth1_deposit_index",
+        "validators",
+        "balances",
+        "randao_mixes",
+        "slashings",
+        "previous_epoch_participation",
+        "current_epoch_participation",
+        "justification_bits",
+        "previous_justified_checkpoint",
+        "current_justified_checkpoint",
+        "finalized_checkpoint",
+        "inactivity_scores",
+        "current_sync_committee",
+        "next_sync_committee",
+    ]);
+}
+
 define_generalized_index_mod!(|| {
     pub mod beacon_state {
         //! Pre-computed generalized indexes for [BeaconState].
         //!
         //! [BeaconState]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/specs/altair/beacon-chain.md#beaconstate
 
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const FINALIZED_ROOT_INDEX: usize = 105;
-
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const CURRENT_SYNC_COMMITTEE_INDEX: usize = 54;
-
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const NEXT_SYNC_COMMITTEE_INDEX: usize = 55;
+        define_beacon_state_generalized_indexes!(super::super::containers);
     }
 });
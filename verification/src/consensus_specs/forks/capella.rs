@@ -16,41 +16,127 @@ pub const FORK_VERSION: [u8; 4] = [0x03, 0x00, 0x00, 0x00];
 
 /// Constants for containers.
 pub mod containers {
-    use crate::utilities::ssz::ceil_depth;
+    use crate::utilities::ssz::{ceil_depth, ContainerLayout};
 
     use super::previous_fork::MAX_TRANSACTIONS_PER_PAYLOAD;
 
-    /// There are 15 fields in [`ExecutionPayload`].
-    ///
-    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
-    pub const EXECUTION_PAYLOAD_FIELDS_COUNT: usize = 15;
-
-    /// `receipts_root` is the 4-th field in [`ExecutionPayload`].
-    ///
-    /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
-    pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize = 3;
+    pub use super::previous_fork::containers::CHECKPOINT;
 
-    /// `transactions` is the 14-th field in [`ExecutionPayload`].
+    /// The fields of [`ExecutionPayload`].
     ///
     /// [`ExecutionPayload`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#executionpayload
-    pub const TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX: usize = 13;
-
-    /// There are 11 fields in [`BeaconBlockBody`].
+    pub const EXECUTION_PAYLOAD: ContainerLayout = ContainerLayout::new(&[
+        "parent_hash",
+        "fee_recipient",
+        "state_root",
+        "receipts_root",
+        "logs_bloom",
+        "prev_randao",
+        "block_number",
+        "gas_limit",
+        "gas_used",
+        "timestamp",
+        "extra_data",
+        "base_fee_per_gas",
+        "block_hash",
+        "transactions",
+        "withdrawals",
+    ]);
+
+    /// The fields of [`BeaconBlockBody`].
     ///
     /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconblockbody
-    pub const BLOCK_BODY_FIELDS_COUNT: usize = 11;
-
-    /// `execution_payload` is the 10-th field in [`BeaconBlockBody`].
+    pub const BEACON_BLOCK_BODY: ContainerLayout = ContainerLayout::new(&[
+        "randao_reveal",
+        "eth1_data",
+        "graffiti",
+        "proposer_slashings",
+        "attester_slashings",
+        "attestations",
+        "deposits",
+        "voluntary_exits",
+        "sync_aggregate",
+        "execution_payload",
+        "bls_to_execution_changes",
+    ]);
+
+    /// The fields of [`BeaconState`].
     ///
-    /// [`BeaconBlockBody`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconblockbody
-    pub const EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX: usize = 9;
+    /// [`BeaconState`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconstate
+    pub const BEACON_STATE: ContainerLayout = ContainerLayout::new(&[
+        // Versioning
+        "genesis_time",
+        "genesis_validators_root",
+        "slot",
+        "fork",
+        // History
+        "latest_block_header",
+        "block_roots",
+        "state_roots",
+        "historical_roots",
+        // Eth1
+        "eth1_data",
+        "eth1_data_votes",
+        "eth1_deposit_index",
+        // Registry
+        "validators",
+        "balances",
+        // Randomness
+        "randao_mixes",
+        // Slashings
+        "slashings",
+        // Participation
+        "previous_epoch_participation",
+        "current_epoch_participation",
+        // Finality
+        "justification_bits",
+        "previous_justified_checkpoint",
+        "current_justified_checkpoint",
+        "finalized_checkpoint",
+        // Inactivity
+        "inactivity_scores",
+        // Sync
+        "current_sync_committee",
+        "next_sync_committee",
+        // Execution
+        "latest_execution_payload_header",
+        // Withdrawals
+        "next_withdrawal_index",
+        "next_withdrawal_validator_index",
+        // Deep history valid from Capella onwards
+        "historical_summaries",
+    ]);
+
+    /// The fields of [`HistoricalSummary`].
+    ///
+    /// [`HistoricalSummary`]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#historicalsummary
+    pub const HISTORICAL_SUMMARY: ContainerLayout =
+        ContainerLayout::new(&["block_summary_root", "state_summary_root"]);
+
+    /// The count of the fields in [`EXECUTION_PAYLOAD`].
+    pub const EXECUTION_PAYLOAD_FIELDS_COUNT: usize = EXECUTION_PAYLOAD.fields_count();
+
+    /// The index of `receipts_root` in [`EXECUTION_PAYLOAD`].
+    pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX: usize =
+        EXECUTION_PAYLOAD.field_index("receipts_root");
+
+    /// The index of `transactions` in [`EXECUTION_PAYLOAD`].
+    pub const TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX: usize =
+        EXECUTION_PAYLOAD.field_index("transactions");
+
+    /// The count of the fields in [`BEACON_BLOCK_BODY`].
+    pub const BLOCK_BODY_FIELDS_COUNT: usize = BEACON_BLOCK_BODY.fields_count();
+
+    /// The index of `execution_payload` in [`BEACON_BLOCK_BODY`].
+    pub const EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX: usize =
+        BEACON_BLOCK_BODY.field_index("execution_payload");
 
     /// The depth of [`MAX_TRANSACTIONS_PER_PAYLOAD`].
     pub const TRANSACTIONS_DEPTH: u32 = ceil_depth(MAX_TRANSACTIONS_PER_PAYLOAD);
     /// The depth of [`EXECUTION_PAYLOAD_FIELDS_COUNT`].
-    pub const EXECUTION_PAYLOAD_DEPTH: u32 = ceil_depth(EXECUTION_PAYLOAD_FIELDS_COUNT);
+    pub const EXECUTION_PAYLOAD_DEPTH: u32 = EXECUTION_PAYLOAD.depth();
     /// The depth of [`BLOCK_BODY_FIELDS_COUNT`].
-    pub const BLOCK_BODY_DEPTH: u32 = ceil_depth(BLOCK_BODY_FIELDS_COUNT);
+    pub const BLOCK_BODY_DEPTH: u32 = BEACON_BLOCK_BODY.depth();
 }
 
 define_generalized_index_mod!(|super::containers| {
@@ -59,21 +145,10 @@ define_generalized_index_mod!(|super::containers| {
         //!
         //! [BeaconState]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/capella/beacon-chain.md#beaconstate
 
-        use super::super::previous_fork::generalized_index::beacon_state as previous_fork;
-
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const FINALIZED_ROOT_INDEX: usize = previous_fork::FINALIZED_ROOT_INDEX;
-
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const CURRENT_SYNC_COMMITTEE_INDEX: usize = previous_fork::CURRENT_SYNC_COMMITTEE_INDEX;
+        define_beacon_state_generalized_indexes!(super::super::containers);
 
-        /// From [Light Client / Sync Protocol / Constants].
-        ///
-        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#constants
-        pub const NEXT_SYNC_COMMITTEE_INDEX: usize = previous_fork::NEXT_SYNC_COMMITTEE_INDEX;
+        /// Generalized index for `historical_summaries` in `BeaconState`.
+        pub const HISTORICAL_SUMMARIES_INDEX: usize =
+            containers::BEACON_STATE.generalized_index("historical_summaries");
     }
 });
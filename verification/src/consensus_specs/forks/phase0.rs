@@ -11,3 +11,21 @@ pub const GENESIS_FORK_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
 ///
 /// [The Beacon Chain / Configuration/ Time parameters]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#time-parameters
 pub const SLOTS_PER_EPOCH: u64 = 32;
+
+/// From [The Beacon Chain / Preset / Time parameters].
+///
+/// [The Beacon Chain / Preset / Time parameters]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#time-parameters-1
+pub const SLOTS_PER_HISTORICAL_ROOT: u64 = 8192;
+
+/// From [The Beacon Chain / Preset / State list lengths].
+///
+/// [The Beacon Chain / Preset / State list lengths]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#state-list-lengths
+pub const HISTORICAL_ROOTS_LIMIT: usize = 1 << 24;
+
+/// From [The Beacon Chain / Preset / State list lengths].
+///
+/// [The Beacon Chain / Preset / State list lengths]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#state-list-lengths
+pub const VALIDATOR_REGISTRY_LIMIT: usize = 1 << 40;
+
+/// The count of balances (`Gwei`, 8 bytes) which are packed in a chunk (32 bytes).
+pub const BALANCES_PER_CHUNK: usize = 4;
@@ -1,23 +1,107 @@
-use crate::consensus_specs::{forks, helpers};
+use crate::{
+    consensus_specs::{forks, helpers, ChainConfig},
+    utilities::ssz,
+};
 
-pub const fn get_generalized_index_of_receipts_root_in_block_body(slot: u64) -> usize {
-    if slot < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
+pub const fn get_generalized_index_of_receipts_root_in_block_body(
+    config: &ChainConfig,
+    slot: u64,
+) -> usize {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
         forks::bellatrix::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY
     } else {
         forks::capella::generalized_index::RECEIPTS_ROOT_IN_BLOCK_BODY
     }
 }
 
-pub const fn get_generalized_index_of_transaction_in_block_body_offset(slot: u64) -> usize {
-    if slot < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
+pub const fn get_generalized_index_of_transaction_in_block_body_offset(
+    config: &ChainConfig,
+    slot: u64,
+) -> usize {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
         forks::bellatrix::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
     } else {
         forks::capella::generalized_index::TRANSACTION_IN_BLOCK_BODY_OFFSET
     }
 }
 
-pub const fn get_depth_and_index_from_current_sync_committee_index(slot: u64) -> (u32, usize) {
-    if slot < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
+pub const fn get_generalized_index_of_validator_in_beacon_state_offset(
+    config: &ChainConfig,
+    slot: u64,
+) -> usize {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
+        forks::bellatrix::generalized_index::beacon_state::VALIDATOR_IN_BEACON_STATE_OFFSET
+    } else {
+        forks::capella::generalized_index::beacon_state::VALIDATOR_IN_BEACON_STATE_OFFSET
+    }
+}
+
+pub const fn get_generalized_index_of_balances_chunk_in_beacon_state_offset(
+    config: &ChainConfig,
+    slot: u64,
+) -> usize {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
+        forks::bellatrix::generalized_index::beacon_state::BALANCES_CHUNK_IN_BEACON_STATE_OFFSET
+    } else {
+        forks::capella::generalized_index::beacon_state::BALANCES_CHUNK_IN_BEACON_STATE_OFFSET
+    }
+}
+
+/// Returns the index in `historical_summaries` for the era which contains the slot.
+///
+/// Returns `None` if the slot is before Capella, since the eras before Capella are summarized
+/// in `historical_roots`.
+pub const fn get_historical_summary_index(config: &ChainConfig, slot: u64) -> Option<usize> {
+    let capella_start_slot = config.compute_start_slot_at_epoch(config.capella_fork.epoch);
+    if slot < capella_start_slot {
+        None
+    } else {
+        let era = slot / forks::phase0::SLOTS_PER_HISTORICAL_ROOT;
+        let capella_era = capella_start_slot / forks::phase0::SLOTS_PER_HISTORICAL_ROOT;
+        Some((era - capella_era) as usize)
+    }
+}
+
+/// Returns the generalized index of the block root at the slot in the `BeaconState` at the
+/// anchor slot, through the `block_summary_root` in `historical_summaries`.
+///
+/// The layout of the `BeaconState` is chosen by the anchor slot.
+///
+/// Returns `None` if the `BeaconState` at the anchor slot has no `historical_summaries`, or the
+/// slot is before Capella.
+pub const fn get_generalized_index_of_historical_block_root(
+    config: &ChainConfig,
+    anchor_slot: u64,
+    slot: u64,
+) -> Option<usize> {
+    if anchor_slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
+        return None;
+    }
+    let summary_index = match get_historical_summary_index(config, slot) {
+        Some(index) => index,
+        None => return None,
+    };
+    let block_root_index = (slot % forks::phase0::SLOTS_PER_HISTORICAL_ROOT) as usize;
+    let index = ssz::concat_generalized_indices(&[
+        forks::capella::generalized_index::beacon_state::HISTORICAL_SUMMARIES_INDEX,
+        ssz::get_generalized_index_of_list_item(
+            forks::phase0::HISTORICAL_ROOTS_LIMIT,
+            summary_index,
+        ),
+        forks::capella::containers::HISTORICAL_SUMMARY.generalized_index("block_summary_root"),
+        ssz::get_generalized_index_of_vector_item(
+            forks::phase0::SLOTS_PER_HISTORICAL_ROOT as usize,
+            block_root_index,
+        ),
+    ]);
+    Some(index)
+}
+
+pub const fn get_depth_and_index_from_current_sync_committee_index(
+    config: &ChainConfig,
+    slot: u64,
+) -> (u32, usize) {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
         const INDEX: usize =
             forks::bellatrix::generalized_index::beacon_state::CURRENT_SYNC_COMMITTEE_INDEX;
         (helpers::floorlog2(INDEX), helpers::get_subtree_index(INDEX))
@@ -28,8 +112,11 @@ pub const fn get_depth_and_index_from_current_sync_committee_index(slot: u64) ->
     }
 }
 
-pub const fn get_depth_and_index_from_next_sync_committee_index(slot: u64) -> (u32, usize) {
-    if slot < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
+pub const fn get_depth_and_index_from_next_sync_committee_index(
+    config: &ChainConfig,
+    slot: u64,
+) -> (u32, usize) {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
         const INDEX: usize =
             forks::bellatrix::generalized_index::beacon_state::NEXT_SYNC_COMMITTEE_INDEX;
         (helpers::floorlog2(INDEX), helpers::get_subtree_index(INDEX))
@@ -40,8 +127,11 @@ pub const fn get_depth_and_index_from_next_sync_committee_index(slot: u64) -> (u
     }
 }
 
-pub const fn get_depth_and_index_from_finalized_root_index(slot: u64) -> (u32, usize) {
-    if slot < helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH) {
+pub const fn get_depth_and_index_from_finalized_root_index(
+    config: &ChainConfig,
+    slot: u64,
+) -> (u32, usize) {
+    if slot < config.compute_start_slot_at_epoch(config.capella_fork.epoch) {
         const INDEX: usize =
             forks::bellatrix::generalized_index::beacon_state::FINALIZED_ROOT_INDEX;
         (helpers::floorlog2(INDEX), helpers::get_subtree_index(INDEX))
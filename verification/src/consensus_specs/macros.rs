@@ -32,61 +32,77 @@ macro_rules! define_generalized_index_stmts {
             2usize.pow(containers::TRANSACTIONS_DEPTH + 1);
 
         /// Offset to calculate generalized index for a transaction in `ExecutionPayload`.
-        pub const TRANSACTION_IN_EXECUTION_PAYLOAD_OFFSET: usize = {
-            const DEPTH: u32 =
-                containers::TRANSACTIONS_DEPTH + 1 + containers::EXECUTION_PAYLOAD_DEPTH;
-            const SIZE: usize = 2usize.pow(DEPTH);
-
-            const FIELDS_COUNT_1: usize =
-                containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_1: usize = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-
-            SIZE + SIZE / FIELDS_COUNT_1 * FIELD_INDEX_1
-        };
+        pub const TRANSACTION_IN_EXECUTION_PAYLOAD_OFFSET: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::EXECUTION_PAYLOAD.generalized_index("transactions"),
+                TRANSACTION_IN_TRANSACTIONS_OFFSET,
+            ]);
 
         /// Offset to calculate generalized index for a transaction in `BlockBody`.
-        pub const TRANSACTION_IN_BLOCK_BODY_OFFSET: usize = {
-            const DEPTH: u32 = containers::TRANSACTIONS_DEPTH
-                + 1
-                + containers::EXECUTION_PAYLOAD_DEPTH
-                + containers::BLOCK_BODY_DEPTH;
-            const SIZE: usize = 2usize.pow(DEPTH);
-
-            const FIELDS_COUNT_1: usize =
-                containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_1: usize = containers::TRANSACTIONS_IN_EXECUTION_PAYLOAD_INDEX;
-            const FIELDS_COUNT_2: usize = containers::BLOCK_BODY_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_2: usize = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
-
-            SIZE + SIZE / FIELDS_COUNT_2 * FIELD_INDEX_2
-                + SIZE / FIELDS_COUNT_2 / FIELDS_COUNT_1 * FIELD_INDEX_1
-        };
+        pub const TRANSACTION_IN_BLOCK_BODY_OFFSET: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::BEACON_BLOCK_BODY.generalized_index("execution_payload"),
+                containers::EXECUTION_PAYLOAD.generalized_index("transactions"),
+                TRANSACTION_IN_TRANSACTIONS_OFFSET,
+            ]);
 
         /// Generalized index for `receipts_root` in `ExecutionPayload`.
-        pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD: usize = {
-            const DEPTH: u32 = containers::EXECUTION_PAYLOAD_DEPTH;
-            const SIZE: usize = 2usize.pow(DEPTH);
-
-            const FIELDS_COUNT_1: usize =
-                containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_1: usize = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-
-            SIZE + SIZE / FIELDS_COUNT_1 * FIELD_INDEX_1
-        };
+        pub const RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD: usize =
+            containers::EXECUTION_PAYLOAD.generalized_index("receipts_root");
 
         /// Generalized index for `receipts_root` in `BlockBody`.
-        pub const RECEIPTS_ROOT_IN_BLOCK_BODY: usize = {
-            const DEPTH: u32 = containers::EXECUTION_PAYLOAD_DEPTH + containers::BLOCK_BODY_DEPTH;
-            const SIZE: usize = 2usize.pow(DEPTH);
+        pub const RECEIPTS_ROOT_IN_BLOCK_BODY: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::BEACON_BLOCK_BODY.generalized_index("execution_payload"),
+                RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD,
+            ]);
+    };
+}
 
-            const FIELDS_COUNT_1: usize =
-                containers::EXECUTION_PAYLOAD_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_1: usize = containers::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD_INDEX;
-            const FIELDS_COUNT_2: usize = containers::BLOCK_BODY_FIELDS_COUNT.next_power_of_two();
-            const FIELD_INDEX_2: usize = containers::EXECUTION_PAYLOAD_IN_BLOCK_BODY_INDEX;
+macro_rules! define_beacon_state_generalized_indexes {
+    ($containers:path) => {
+        use $containers as containers;
 
-            SIZE + SIZE / FIELDS_COUNT_2 * FIELD_INDEX_2
-                + SIZE / FIELDS_COUNT_2 / FIELDS_COUNT_1 * FIELD_INDEX_1
-        };
+        /// From [Light Client / Sync Protocol / Constants].
+        ///
+        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
+        pub const FINALIZED_ROOT_INDEX: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::BEACON_STATE.generalized_index("finalized_checkpoint"),
+                containers::CHECKPOINT.generalized_index("root"),
+            ]);
+
+        /// From [Light Client / Sync Protocol / Constants].
+        ///
+        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
+        pub const CURRENT_SYNC_COMMITTEE_INDEX: usize =
+            containers::BEACON_STATE.generalized_index("current_sync_committee");
+
+        /// From [Light Client / Sync Protocol / Constants].
+        ///
+        /// [Light Client / Sync Protocol / Constants]: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#constants
+        pub const NEXT_SYNC_COMMITTEE_INDEX: usize =
+            containers::BEACON_STATE.generalized_index("next_sync_committee");
+
+        /// Offset to calculate generalized index for a validator in `BeaconState`.
+        pub const VALIDATOR_IN_BEACON_STATE_OFFSET: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::BEACON_STATE.generalized_index("validators"),
+                $crate::utilities::ssz::get_generalized_index_of_list_item(
+                    $crate::consensus_specs::forks::phase0::VALIDATOR_REGISTRY_LIMIT,
+                    0,
+                ),
+            ]);
+
+        /// Offset to calculate generalized index for a chunk of balances in `BeaconState`.
+        pub const BALANCES_CHUNK_IN_BEACON_STATE_OFFSET: usize =
+            $crate::utilities::ssz::concat_generalized_indices(&[
+                containers::BEACON_STATE.generalized_index("balances"),
+                $crate::utilities::ssz::get_generalized_index_of_list_item(
+                    $crate::consensus_specs::forks::phase0::VALIDATOR_REGISTRY_LIMIT
+                        / $crate::consensus_specs::forks::phase0::BALANCES_PER_CHUNK,
+                    0,
+                ),
+            ]);
     };
 }
@@ -9,5 +9,8 @@ pub mod macros;
 pub mod forks;
 pub mod helpers;
 
+mod config;
+pub use config::{ChainConfig, Fork};
+
 mod internal;
 pub use internal::*;
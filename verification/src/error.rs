@@ -6,10 +6,13 @@ pub enum ClientBootstrapError {
     IncorrectPeriod,
     UnexpectedSyncCommitteeSize,
     InvalidSyncCommitteeBranch,
+    // Validate Current Sync Committee
+    InvalidSyncCommitteePubkey,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 32,
 }
 
+#[derive(Clone, Copy)]
 #[repr(i8)]
 pub enum ClientUpdateError {
     // Verify Self
@@ -40,10 +43,44 @@ pub enum ClientUpdateError {
     MmrError,
     BlsPublicKeyBytesError,
     BlsAggregateSignatureError,
+    // Check Optimistic Clients
+    ClientOptimisticSlot,
+    ClientOptimisticHeaderRoot,
+    // Check Force Update
+    ForceUpdateIsDisabled,
+    ForceUpdateBeforeTimeout,
+    UnexpectedFinalityBranch,
+    ForceUpdatedHeaderIsNotAttested,
+    ClientFinalizedSlot,
+    // Check Compact Headers
+    InvalidCompactHeaders,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 32,
 }
 
+#[repr(i8)]
+pub enum ClientOptimisticUpdateError {
+    // Verify Self
+    AttestedHeaderIsEmpty = 1,
+    BadSignatureSlot,
+    // Check Clients
+    AttestedHeaderIsNotNewer,
+    ClientFinalizedPartChanged,
+    ClientOptimisticSlot,
+    ClientOptimisticHeaderRoot,
+    // Check Current Sync Committee
+    MismatchedSyncCommittee,
+    // Verify the Signature with Current Sync Committee
+    NotSupermajorityParticipation,
+    FailedToVerifyTheAttestedHeader,
+    // Internal Errors
+    BlsPublicKeyBytesError,
+    BlsAggregateSignatureError,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[derive(Clone, Copy)]
 #[repr(i8)]
 pub enum SyncCommitteeUpdateError {
     // Verify Self
@@ -62,6 +99,49 @@ pub enum SyncCommitteeUpdateError {
     // Internal Errors
     BlsPublicKeyBytesError,
     BlsAggregateSignatureError,
+    // Check Sync Committees Signed in Next Period
+    BadStoredNextPeriod,
+    SignatureNotInNextPeriod,
+    BadAttestedPeriod,
+    ConflictedNextSyncCommittee,
+    // Validate Next Sync Committee
+    InvalidNextSyncCommitteePubkey,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+/// The errors of the items in a batch keep their inner errors, see [`Self::error_code`].
+pub enum ClientUpdateBatchError {
+    // Verify Self
+    EmptyBatch,
+    LastItemIsNotClientUpdate,
+    // Verify Items
+    InvalidClientUpdate(ClientUpdateError),
+    InvalidSyncCommitteeRotation(SyncCommitteeUpdateError),
+}
+
+impl ClientUpdateBatchError {
+    /// Returns the error code.
+    ///
+    /// The codes of the inner errors are less than or equal to 32, so they are offset by 32
+    /// for client updates and by 64 for sync committee rotations, then they could be recovered.
+    pub fn error_code(self) -> i8 {
+        match self {
+            Self::EmptyBatch => 1,
+            Self::LastItemIsNotClientUpdate => 2,
+            Self::InvalidClientUpdate(err) => 32 + err as i8,
+            Self::InvalidSyncCommitteeRotation(err) => 64 + err as i8,
+        }
+    }
+}
+
+#[repr(i8)]
+pub enum SyncCommitteeCommitmentError {
+    // Check Sync Committee
+    MismatchedPeriod = 1,
+    InvalidSyncCommitteePubkey,
+    UnexpectedSyncCommitteeSize,
+    MismatchedSyncCommitteeRoot,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 32,
 }
@@ -79,6 +159,91 @@ pub enum TxVerificationError {
     // Internal Errors
     MmrError,
     SszError,
+    // Verify Optimistic Header
+    OptimisticHeaderRoot,
+    // Decode Receipt
+    InvalidReceipt,
+    // Verify Transaction Hash
+    MismatchedTransactionHash,
+    // Verify Header in Multi-Header Proof
+    InvalidMultiHeaderProof,
+    UnprovenHeader,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[repr(i8)]
+pub enum HistoricalHeaderProofError {
+    // Check Slots
+    UnsupportedHistoricalSlot = 1,
+    // Verify Anchor Header
+    Unsynchronized,
+    HeaderMmrProof,
+    OptimisticHeaderRoot,
+    // Verify Historical Header
+    HistoricalHeaderSszProof,
+    // Internal Errors
+    MmrError,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[repr(i8)]
+pub enum BeaconStateProofError {
+    // Verify Header
+    Unsynchronized = 1,
+    HeaderMmrProof,
+    OptimisticHeaderRoot,
+    // Verify Field
+    MismatchedGeneralizedIndex,
+    StateSszProof,
+    // Internal Errors
+    MmrError,
+    SszError,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[repr(i8)]
+pub enum ValidatorProofError {
+    // Verify Header
+    Unsynchronized = 1,
+    HeaderMmrProof,
+    OptimisticHeaderRoot,
+    // Verify Validator
+    InvalidValidatorIndex,
+    ValidatorSszProof,
+    // Verify Balance
+    BalancesChunkSszProof,
+    // Internal Errors
+    MmrError,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[repr(i8)]
+pub enum WitnessError {
+    // Decode Envelope
+    UnsupportedWitnessVersion = 1,
+    MismatchedWitnessType,
+    // Decode Bare Witness
+    InvalidWitness,
+    LegacyWitnessLayout,
+    // This is not an error, just make sure the error code is less than 32.
+    Unreachable = 32,
+}
+
+#[repr(i8)]
+pub enum MultiHeaderProofError {
+    // Check Headers
+    EmptyHeaders = 1,
+    UnsortedHeaders,
+    // Verify Headers
+    Unsynchronized,
+    HeaderMmrProof,
+    OptimisticHeaderRoot,
+    // Internal Errors
+    MmrError,
     // This is not an error, just make sure the error code is less than 32.
     Unreachable = 32,
 }
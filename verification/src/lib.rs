@@ -4,6 +4,8 @@ extern crate alloc;
 
 #[macro_use]
 mod log;
+#[macro_use]
+pub mod profile;
 
 pub mod consensus_specs;
 pub mod error;
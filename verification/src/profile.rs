@@ -0,0 +1,82 @@
+//! Marks the expensive steps during verification, to measure the cycles of them.
+//!
+//! When the feature `profile` is enabled, the binary which uses this crate should provide the
+//! following function to receive the boundaries of steps:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! pub fn eth_light_client_in_ckb_profile_step(step: u8, is_begin: bool) {
+//!     // ...
+//! }
+//! ```
+
+/// The expensive steps during verification.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    /// Decompress (or load the uncompressed) public keys of a sync committee.
+    DecompressPubkeys = 1,
+    /// Aggregate the public keys of the participants.
+    AggregatePubkeys,
+    /// Verify the aggregate signature, includes hashing to the curve and the pairing.
+    Pairing,
+    /// Calculate the tree hash roots of headers, sync committees and transactions.
+    TreeHash,
+    /// Verify MMR proofs.
+    Mmr,
+}
+
+impl Step {
+    /// All steps.
+    pub const ALL: [Self; 5] = [
+        Self::DecompressPubkeys,
+        Self::AggregatePubkeys,
+        Self::Pairing,
+        Self::TreeHash,
+        Self::Mmr,
+    ];
+
+    /// Returns the step of the id.
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|step| *step as u8 == id)
+    }
+
+    /// Returns the name of the step.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::DecompressPubkeys => "decompress-pubkeys",
+            Self::AggregatePubkeys => "aggregate-pubkeys",
+            Self::Pairing => "pairing",
+            Self::TreeHash => "tree-hash",
+            Self::Mmr => "mmr",
+        }
+    }
+}
+
+#[cfg(feature = "profile")]
+extern "Rust" {
+    fn eth_light_client_in_ckb_profile_step(step: u8, is_begin: bool);
+}
+
+#[cfg(feature = "profile")]
+#[doc(hidden)]
+pub fn mark(step: Step, is_begin: bool) {
+    unsafe { eth_light_client_in_ckb_profile_step(step as u8, is_begin) }
+}
+
+#[cfg(feature = "profile")]
+macro_rules! profile {
+    ($step:ident, $expr:expr) => {{
+        $crate::profile::mark($crate::profile::Step::$step, true);
+        let result = $expr;
+        $crate::profile::mark($crate::profile::Step::$step, false);
+        result
+    }};
+}
+
+#[cfg(not(feature = "profile"))]
+macro_rules! profile {
+    ($step:ident, $expr:expr) => {
+        $expr
+    };
+}
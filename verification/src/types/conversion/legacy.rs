@@ -0,0 +1,75 @@
+//! Unpacks the legacy layouts (version 0) into the current types.
+//!
+//! The fields which are not in the legacy layouts are filled as below:
+//! - The headers of a client update are in the full encoding.
+//! - The forced update is disabled, i.e. `force_update_timeout` is `0`.
+//! - The optimistic header and the finalized header are both the tip header.
+
+use molecule::{error::VerificationError, prelude::*};
+
+use crate::types::{core, packed, prelude::Unpack};
+
+impl<'r> Unpack<core::ClientUpdate> for packed::ClientUpdateV0Reader<'r> {
+    fn unpack(&self) -> core::ClientUpdate {
+        core::ClientUpdate {
+            attested_header: self.attested_header().unpack(),
+            finality_branch: self.finality_branch().unpack(),
+            sync_aggregate: self.sync_aggregate().unpack(),
+            signature_slot: self.signature_slot().unpack(),
+            new_headers_mmr_proof: self.new_headers_mmr_proof().unpack(),
+            headers: core::HeaderRange::Full(self.headers().unpack()),
+        }
+    }
+}
+
+impl<'r> Unpack<core::ClientInfo> for packed::ClientInfoV0Reader<'r> {
+    fn unpack(&self) -> core::ClientInfo {
+        core::ClientInfo {
+            last_client_id: self.last_client_id().into(),
+            minimal_headers_count: self.minimal_headers_count().into(),
+            genesis_validators_root: self.genesis_validators_root().unpack(),
+            force_update_timeout: 0,
+        }
+    }
+}
+
+impl<'r> Unpack<core::Client> for packed::ClientV0Reader<'r> {
+    fn unpack(&self) -> core::Client {
+        let maximal_slot: core::Uint64 = self.maximal_slot().unpack();
+        let tip_header_root: core::Hash = self.tip_header_root().unpack();
+        core::Client {
+            id: self.id().into(),
+            minimal_slot: self.minimal_slot().unpack(),
+            maximal_slot,
+            tip_header_root,
+            headers_mmr_root: self.headers_mmr_root().unpack(),
+            optimistic_slot: maximal_slot,
+            optimistic_header_root: tip_header_root,
+            finalized_slot: maximal_slot,
+        }
+    }
+}
+
+/// Decodes the data of a client info cell, in the current layout or the legacy layout.
+///
+/// Both layouts are structs, so they are told apart by their sizes.
+pub fn decode_client_info(slice: &[u8]) -> Result<core::ClientInfo, VerificationError> {
+    match packed::ClientInfoReader::from_slice(slice) {
+        Ok(reader) => Ok(reader.unpack()),
+        Err(err) => packed::ClientInfoV0Reader::from_slice(slice)
+            .map(|reader| reader.unpack())
+            .map_err(|_| err),
+    }
+}
+
+/// Decodes the data of a client cell, in the current layout or the legacy layout.
+///
+/// Both layouts are structs, so they are told apart by their sizes.
+pub fn decode_client(slice: &[u8]) -> Result<core::Client, VerificationError> {
+    match packed::ClientReader::from_slice(slice) {
+        Ok(reader) => Ok(reader.unpack()),
+        Err(err) => packed::ClientV0Reader::from_slice(slice)
+            .map(|reader| reader.unpack())
+            .map_err(|_| err),
+    }
+}
@@ -1,2 +1,10 @@
+mod legacy;
 mod pack;
 mod unpack;
+mod witness;
+
+pub use legacy::{decode_client, decode_client_info};
+pub use witness::{
+    decode_legacy_witness, decode_witness, LegacyWitnessType, WitnessType, BARE_WITNESS_VERSION,
+    WITNESS_ENVELOPE_VERSION,
+};
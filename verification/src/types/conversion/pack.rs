@@ -1,4 +1,4 @@
-use bls::{PUBLIC_KEY_BYTES_LEN, SIGNATURE_BYTES_LEN};
+use bls::{PUBLIC_KEY_BYTES_LEN, PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN, SIGNATURE_BYTES_LEN};
 use molecule::prelude::*;
 
 use crate::types::{core, packed, prelude::Pack};
@@ -69,6 +69,14 @@ impl Pack<packed::BlsPubkey> for core::BlsPubkey {
     }
 }
 
+impl Pack<packed::BlsUncompressedPubkey> for core::BlsUncompressedPubkey {
+    fn pack(&self) -> packed::BlsUncompressedPubkey {
+        assert_eq!(self.as_ref().len(), PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN);
+        let data = molecule::bytes::Bytes::from(self.as_ref().to_vec());
+        packed::BlsUncompressedPubkey::new_unchecked(data)
+    }
+}
+
 impl Pack<packed::BlsSignature> for core::BlsSignature {
     fn pack(&self) -> packed::BlsSignature {
         assert_eq!(self.as_ref().len(), SIGNATURE_BYTES_LEN);
@@ -85,6 +93,14 @@ impl Pack<packed::BlsPubkeyVec> for core::BlsPubkeyVec {
     }
 }
 
+impl Pack<packed::BlsUncompressedPubkeyVec> for core::BlsUncompressedPubkeyVec {
+    fn pack(&self) -> packed::BlsUncompressedPubkeyVec {
+        packed::BlsUncompressedPubkeyVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
 impl Pack<packed::Header> for core::Header {
     fn pack(&self) -> packed::Header {
         packed::Header::new_builder()
@@ -105,6 +121,60 @@ impl Pack<packed::HeaderVec> for core::HeaderVec {
     }
 }
 
+impl Pack<packed::CompactHeader> for core::CompactHeader {
+    fn pack(&self) -> packed::CompactHeader {
+        packed::CompactHeader::new_builder()
+            .proposer_index(self.proposer_index.pack())
+            .state_root(self.state_root.pack())
+            .body_root(self.body_root.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::CompactHeaderItem> for core::CompactHeaderItem {
+    fn pack(&self) -> packed::CompactHeaderItem {
+        let builder = packed::CompactHeaderItem::new_builder();
+        match self {
+            Self::EmptySlots(count) => {
+                let empty_slots = packed::EmptySlots::new_builder()
+                    .count(count.pack())
+                    .build();
+                builder.set(empty_slots)
+            }
+            Self::CompactHeader(header) => builder.set(header.pack()),
+        }
+        .build()
+    }
+}
+
+impl Pack<packed::CompactHeaderItemVec> for core::CompactHeaderItemVec {
+    fn pack(&self) -> packed::CompactHeaderItemVec {
+        packed::CompactHeaderItemVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::CompactHeaders> for core::CompactHeaders {
+    fn pack(&self) -> packed::CompactHeaders {
+        packed::CompactHeaders::new_builder()
+            .first_slot(self.first_slot.pack())
+            .items(self.items.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::HeaderRange> for core::HeaderRange {
+    fn pack(&self) -> packed::HeaderRange {
+        let builder = packed::HeaderRange::new_builder();
+        match self {
+            Self::Full(headers) => builder.set(headers.pack()),
+            Self::Compact(headers) => builder.set(headers.pack()),
+        }
+        .build()
+    }
+}
+
 impl Pack<packed::SyncCommitteeBits> for core::SyncCommitteeBits {
     fn pack(&self) -> packed::SyncCommitteeBits {
         let data = molecule::bytes::Bytes::from(self.as_bytes().to_vec());
@@ -130,6 +200,30 @@ impl Pack<packed::SyncCommittee> for core::SyncCommittee {
     }
 }
 
+impl Pack<packed::UncompressedSyncCommittee> for core::UncompressedSyncCommittee {
+    fn pack(&self) -> packed::UncompressedSyncCommittee {
+        packed::UncompressedSyncCommittee::new_builder()
+            .pubkeys(self.pubkeys.pack())
+            .aggregate_pubkey(self.aggregate_pubkey.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::Validator> for core::Validator {
+    fn pack(&self) -> packed::Validator {
+        packed::Validator::new_builder()
+            .pubkey(self.pubkey.pack())
+            .withdrawal_credentials(self.withdrawal_credentials.pack())
+            .effective_balance(self.effective_balance.pack())
+            .slashed(u8::from(self.slashed).into())
+            .activation_eligibility_epoch(self.activation_eligibility_epoch.pack())
+            .activation_epoch(self.activation_epoch.pack())
+            .exit_epoch(self.exit_epoch.pack())
+            .withdrawable_epoch(self.withdrawable_epoch.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::ClientBootstrap> for core::ClientBootstrap {
     fn pack(&self) -> packed::ClientBootstrap {
         packed::ClientBootstrap::new_builder()
@@ -152,6 +246,16 @@ impl Pack<packed::ClientUpdate> for core::ClientUpdate {
     }
 }
 
+impl Pack<packed::ClientOptimisticUpdate> for core::ClientOptimisticUpdate {
+    fn pack(&self) -> packed::ClientOptimisticUpdate {
+        packed::ClientOptimisticUpdate::new_builder()
+            .attested_header(self.attested_header.pack())
+            .sync_aggregate(self.sync_aggregate.pack())
+            .signature_slot(self.signature_slot.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::SyncCommitteeUpdate> for core::SyncCommitteeUpdate {
     fn pack(&self) -> packed::SyncCommitteeUpdate {
         packed::SyncCommitteeUpdate::new_builder()
@@ -163,6 +267,42 @@ impl Pack<packed::SyncCommitteeUpdate> for core::SyncCommitteeUpdate {
     }
 }
 
+impl Pack<packed::SyncCommitteeRotation> for core::SyncCommitteeRotation {
+    fn pack(&self) -> packed::SyncCommitteeRotation {
+        packed::SyncCommitteeRotation::new_builder()
+            .sync_committee_update(self.sync_committee_update.pack())
+            .next_sync_committee(self.next_sync_committee.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ClientUpdateBatchItem> for core::ClientUpdateBatchItem {
+    fn pack(&self) -> packed::ClientUpdateBatchItem {
+        let builder = packed::ClientUpdateBatchItem::new_builder();
+        match self {
+            Self::ClientUpdate(update) => builder.set(update.pack()),
+            Self::SyncCommitteeRotation(rotation) => builder.set(rotation.pack()),
+        }
+        .build()
+    }
+}
+
+impl Pack<packed::ClientUpdateBatchItemVec> for core::ClientUpdateBatchItemVec {
+    fn pack(&self) -> packed::ClientUpdateBatchItemVec {
+        packed::ClientUpdateBatchItemVec::new_builder()
+            .set(self.iter().map(|v| v.pack()).collect())
+            .build()
+    }
+}
+
+impl Pack<packed::ClientUpdateBatch> for core::ClientUpdateBatch {
+    fn pack(&self) -> packed::ClientUpdateBatch {
+        packed::ClientUpdateBatch::new_builder()
+            .items(self.items.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::TransactionProof> for core::TransactionProof {
     fn pack(&self) -> packed::TransactionProof {
         packed::TransactionProof::new_builder()
@@ -186,12 +326,96 @@ impl Pack<packed::TransactionPayload> for core::TransactionPayload {
     }
 }
 
+impl Pack<packed::HistoricalHeaderProof> for core::HistoricalHeaderProof {
+    fn pack(&self) -> packed::HistoricalHeaderProof {
+        packed::HistoricalHeaderProof::new_builder()
+            .anchor_header(self.anchor_header.pack())
+            .anchor_header_mmr_proof(self.anchor_header_mmr_proof.pack())
+            .header_ssz_proof(self.header_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::HistoricalTransactionProof> for core::HistoricalTransactionProof {
+    fn pack(&self) -> packed::HistoricalTransactionProof {
+        packed::HistoricalTransactionProof::new_builder()
+            .historical_header_proof(self.historical_header_proof.pack())
+            .transaction_proof(self.transaction_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::BeaconStateProof> for core::BeaconStateProof {
+    fn pack(&self) -> packed::BeaconStateProof {
+        packed::BeaconStateProof::new_builder()
+            .header(self.header.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .generalized_index(self.generalized_index.pack())
+            .value(self.value.pack())
+            .state_ssz_proof(self.state_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ValidatorProof> for core::ValidatorProof {
+    fn pack(&self) -> packed::ValidatorProof {
+        packed::ValidatorProof::new_builder()
+            .header(self.header.pack())
+            .header_mmr_proof(self.header_mmr_proof.pack())
+            .validator_index(self.validator_index.pack())
+            .validator(self.validator.pack())
+            .validator_ssz_proof(self.validator_ssz_proof.pack())
+            .balances_chunk(self.balances_chunk.pack())
+            .balances_chunk_ssz_proof(self.balances_chunk_ssz_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::MultiHeaderProof> for core::MultiHeaderProof {
+    fn pack(&self) -> packed::MultiHeaderProof {
+        packed::MultiHeaderProof::new_builder()
+            .headers(self.headers.pack())
+            .headers_mmr_proof(self.headers_mmr_proof.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::Witness> for core::Witness {
+    fn pack(&self) -> packed::Witness {
+        let builder = packed::Witness::new_builder();
+        match self {
+            Self::ClientBootstrap(inner) => builder.set(inner.pack()),
+            Self::ClientUpdate(inner) => builder.set(inner.pack()),
+            Self::ClientOptimisticUpdate(inner) => builder.set(inner.pack()),
+            Self::SyncCommitteeUpdate(inner) => builder.set(inner.pack()),
+            Self::ClientUpdateBatch(inner) => builder.set(inner.pack()),
+            Self::TransactionProof(inner) => builder.set(inner.pack()),
+            Self::TransactionPayload(inner) => builder.set(inner.pack()),
+            Self::HistoricalTransactionProof(inner) => builder.set(inner.pack()),
+            Self::BeaconStateProof(inner) => builder.set(inner.pack()),
+            Self::ValidatorProof(inner) => builder.set(inner.pack()),
+            Self::MultiHeaderProof(inner) => builder.set(inner.pack()),
+        }
+        .build()
+    }
+}
+
+impl Pack<packed::WitnessEnvelope> for core::WitnessEnvelope {
+    fn pack(&self) -> packed::WitnessEnvelope {
+        packed::WitnessEnvelope::new_builder()
+            .version(self.version.into())
+            .witness(self.witness.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::ClientInfo> for core::ClientInfo {
     fn pack(&self) -> packed::ClientInfo {
         packed::ClientInfo::new_builder()
             .last_client_id(self.last_client_id.into())
             .minimal_headers_count(self.minimal_headers_count.into())
             .genesis_validators_root(self.genesis_validators_root.pack())
+            .force_update_timeout(self.force_update_timeout.pack())
             .build()
     }
 }
@@ -204,6 +428,9 @@ impl Pack<packed::Client> for core::Client {
             .maximal_slot(self.maximal_slot.pack())
             .tip_header_root(self.tip_header_root.pack())
             .headers_mmr_root(self.headers_mmr_root.pack())
+            .optimistic_slot(self.optimistic_slot.pack())
+            .optimistic_header_root(self.optimistic_header_root.pack())
+            .finalized_slot(self.finalized_slot.pack())
             .build()
     }
 }
@@ -217,6 +444,24 @@ impl Pack<packed::ClientSyncCommittee> for core::ClientSyncCommittee {
     }
 }
 
+impl Pack<packed::ClientUncompressedSyncCommittee> for core::ClientUncompressedSyncCommittee {
+    fn pack(&self) -> packed::ClientUncompressedSyncCommittee {
+        packed::ClientUncompressedSyncCommittee::new_builder()
+            .period(self.period.pack())
+            .data(self.data.pack())
+            .build()
+    }
+}
+
+impl Pack<packed::ClientSyncCommitteeCommitment> for core::ClientSyncCommitteeCommitment {
+    fn pack(&self) -> packed::ClientSyncCommitteeCommitment {
+        packed::ClientSyncCommitteeCommitment::new_builder()
+            .period(self.period.pack())
+            .sync_committee_root(self.sync_committee_root.pack())
+            .build()
+    }
+}
+
 impl Pack<packed::ClientTypeArgs> for core::ClientTypeArgs {
     fn pack(&self) -> packed::ClientTypeArgs {
         packed::ClientTypeArgs::new_builder()
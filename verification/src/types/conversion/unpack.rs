@@ -1,4 +1,4 @@
-use bls::{PUBLIC_KEY_BYTES_LEN, SIGNATURE_BYTES_LEN};
+use bls::{PUBLIC_KEY_BYTES_LEN, PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN, SIGNATURE_BYTES_LEN};
 use molecule::prelude::*;
 
 use crate::types::{core, packed, prelude::Unpack};
@@ -76,6 +76,16 @@ impl<'r> Unpack<core::BlsPubkey> for packed::BlsPubkeyReader<'r> {
 }
 impl_conversion_for_entity_unpack!(BlsPubkey);
 
+impl<'r> Unpack<core::BlsUncompressedPubkey> for packed::BlsUncompressedPubkeyReader<'r> {
+    fn unpack(&self) -> core::BlsUncompressedPubkey {
+        assert_eq!(self.as_slice().len(), PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN);
+        let mut b = [0u8; PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN];
+        b.copy_from_slice(self.as_slice());
+        b.into()
+    }
+}
+impl_conversion_for_entity_unpack!(BlsUncompressedPubkey);
+
 impl<'r> Unpack<core::BlsSignature> for packed::BlsSignatureReader<'r> {
     fn unpack(&self) -> core::BlsSignature {
         assert_eq!(self.as_slice().len(), SIGNATURE_BYTES_LEN);
@@ -93,6 +103,13 @@ impl<'r> Unpack<core::BlsPubkeyVec> for packed::BlsPubkeyVecReader<'r> {
 }
 impl_conversion_for_entity_unpack!(BlsPubkeyVec);
 
+impl<'r> Unpack<core::BlsUncompressedPubkeyVec> for packed::BlsUncompressedPubkeyVecReader<'r> {
+    fn unpack(&self) -> core::BlsUncompressedPubkeyVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(BlsUncompressedPubkeyVec);
+
 impl<'r> Unpack<core::Header> for packed::HeaderReader<'r> {
     fn unpack(&self) -> core::Header {
         core::Header {
@@ -113,6 +130,62 @@ impl<'r> Unpack<core::HeaderVec> for packed::HeaderVecReader<'r> {
 }
 impl_conversion_for_entity_unpack!(HeaderVec);
 
+impl<'r> Unpack<core::CompactHeader> for packed::CompactHeaderReader<'r> {
+    fn unpack(&self) -> core::CompactHeader {
+        core::CompactHeader {
+            proposer_index: self.proposer_index().unpack(),
+            state_root: self.state_root().unpack(),
+            body_root: self.body_root().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(CompactHeader);
+
+impl<'r> Unpack<core::CompactHeaderItem> for packed::CompactHeaderItemReader<'r> {
+    fn unpack(&self) -> core::CompactHeaderItem {
+        match self.to_enum() {
+            packed::CompactHeaderItemUnionReader::EmptySlots(inner) => {
+                core::CompactHeaderItem::EmptySlots(inner.count().unpack())
+            }
+            packed::CompactHeaderItemUnionReader::CompactHeader(inner) => {
+                core::CompactHeaderItem::CompactHeader(inner.unpack())
+            }
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(CompactHeaderItem);
+
+impl<'r> Unpack<core::CompactHeaderItemVec> for packed::CompactHeaderItemVecReader<'r> {
+    fn unpack(&self) -> core::CompactHeaderItemVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(CompactHeaderItemVec);
+
+impl<'r> Unpack<core::CompactHeaders> for packed::CompactHeadersReader<'r> {
+    fn unpack(&self) -> core::CompactHeaders {
+        core::CompactHeaders {
+            first_slot: self.first_slot().unpack(),
+            items: self.items().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(CompactHeaders);
+
+impl<'r> Unpack<core::HeaderRange> for packed::HeaderRangeReader<'r> {
+    fn unpack(&self) -> core::HeaderRange {
+        match self.to_enum() {
+            packed::HeaderRangeUnionReader::HeaderVec(inner) => {
+                core::HeaderRange::Full(inner.unpack())
+            }
+            packed::HeaderRangeUnionReader::CompactHeaders(inner) => {
+                core::HeaderRange::Compact(inner.unpack())
+            }
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(HeaderRange);
+
 impl<'r> Unpack<core::SyncCommitteeBits> for packed::SyncCommitteeBitsReader<'r> {
     fn unpack(&self) -> core::SyncCommitteeBits {
         core::SyncCommitteeBits::from_slice(self.as_slice())
@@ -140,6 +213,32 @@ impl<'r> Unpack<core::SyncCommittee> for packed::SyncCommitteeReader<'r> {
 }
 impl_conversion_for_entity_unpack!(SyncCommittee);
 
+impl<'r> Unpack<core::UncompressedSyncCommittee> for packed::UncompressedSyncCommitteeReader<'r> {
+    fn unpack(&self) -> core::UncompressedSyncCommittee {
+        core::UncompressedSyncCommittee {
+            pubkeys: self.pubkeys().unpack(),
+            aggregate_pubkey: self.aggregate_pubkey().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(UncompressedSyncCommittee);
+
+impl<'r> Unpack<core::Validator> for packed::ValidatorReader<'r> {
+    fn unpack(&self) -> core::Validator {
+        core::Validator {
+            pubkey: self.pubkey().unpack(),
+            withdrawal_credentials: self.withdrawal_credentials().unpack(),
+            effective_balance: self.effective_balance().unpack(),
+            slashed: u8::from(self.slashed()) != 0,
+            activation_eligibility_epoch: self.activation_eligibility_epoch().unpack(),
+            activation_epoch: self.activation_epoch().unpack(),
+            exit_epoch: self.exit_epoch().unpack(),
+            withdrawable_epoch: self.withdrawable_epoch().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(Validator);
+
 impl<'r> Unpack<core::ClientBootstrap> for packed::ClientBootstrapReader<'r> {
     fn unpack(&self) -> core::ClientBootstrap {
         core::ClientBootstrap {
@@ -164,6 +263,17 @@ impl<'r> Unpack<core::ClientUpdate> for packed::ClientUpdateReader<'r> {
 }
 impl_conversion_for_entity_unpack!(ClientUpdate);
 
+impl<'r> Unpack<core::ClientOptimisticUpdate> for packed::ClientOptimisticUpdateReader<'r> {
+    fn unpack(&self) -> core::ClientOptimisticUpdate {
+        core::ClientOptimisticUpdate {
+            attested_header: self.attested_header().unpack(),
+            sync_aggregate: self.sync_aggregate().unpack(),
+            signature_slot: self.signature_slot().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientOptimisticUpdate);
+
 impl<'r> Unpack<core::SyncCommitteeUpdate> for packed::SyncCommitteeUpdateReader<'r> {
     fn unpack(&self) -> core::SyncCommitteeUpdate {
         core::SyncCommitteeUpdate {
@@ -176,6 +286,46 @@ impl<'r> Unpack<core::SyncCommitteeUpdate> for packed::SyncCommitteeUpdateReader
 }
 impl_conversion_for_entity_unpack!(SyncCommitteeUpdate);
 
+impl<'r> Unpack<core::SyncCommitteeRotation> for packed::SyncCommitteeRotationReader<'r> {
+    fn unpack(&self) -> core::SyncCommitteeRotation {
+        core::SyncCommitteeRotation {
+            sync_committee_update: self.sync_committee_update().unpack(),
+            next_sync_committee: self.next_sync_committee().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(SyncCommitteeRotation);
+
+impl<'r> Unpack<core::ClientUpdateBatchItem> for packed::ClientUpdateBatchItemReader<'r> {
+    fn unpack(&self) -> core::ClientUpdateBatchItem {
+        match self.to_enum() {
+            packed::ClientUpdateBatchItemUnionReader::ClientUpdate(inner) => {
+                core::ClientUpdateBatchItem::ClientUpdate(inner.unpack())
+            }
+            packed::ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(inner) => {
+                core::ClientUpdateBatchItem::SyncCommitteeRotation(inner.unpack())
+            }
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientUpdateBatchItem);
+
+impl<'r> Unpack<core::ClientUpdateBatchItemVec> for packed::ClientUpdateBatchItemVecReader<'r> {
+    fn unpack(&self) -> core::ClientUpdateBatchItemVec {
+        self.iter().map(|v| v.unpack()).collect()
+    }
+}
+impl_conversion_for_entity_unpack!(ClientUpdateBatchItemVec);
+
+impl<'r> Unpack<core::ClientUpdateBatch> for packed::ClientUpdateBatchReader<'r> {
+    fn unpack(&self) -> core::ClientUpdateBatch {
+        core::ClientUpdateBatch {
+            items: self.items().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientUpdateBatch);
+
 impl<'r> Unpack<core::TransactionProof> for packed::TransactionProofReader<'r> {
     fn unpack(&self) -> core::TransactionProof {
         core::TransactionProof {
@@ -201,12 +351,123 @@ impl<'r> Unpack<core::TransactionPayload> for packed::TransactionPayloadReader<'
 }
 impl_conversion_for_entity_unpack!(TransactionPayload);
 
+impl<'r> Unpack<core::HistoricalHeaderProof> for packed::HistoricalHeaderProofReader<'r> {
+    fn unpack(&self) -> core::HistoricalHeaderProof {
+        core::HistoricalHeaderProof {
+            anchor_header: self.anchor_header().unpack(),
+            anchor_header_mmr_proof: self.anchor_header_mmr_proof().unpack(),
+            header_ssz_proof: self.header_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(HistoricalHeaderProof);
+
+impl<'r> Unpack<core::HistoricalTransactionProof> for packed::HistoricalTransactionProofReader<'r> {
+    fn unpack(&self) -> core::HistoricalTransactionProof {
+        core::HistoricalTransactionProof {
+            historical_header_proof: self.historical_header_proof().unpack(),
+            transaction_proof: self.transaction_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(HistoricalTransactionProof);
+
+impl<'r> Unpack<core::BeaconStateProof> for packed::BeaconStateProofReader<'r> {
+    fn unpack(&self) -> core::BeaconStateProof {
+        core::BeaconStateProof {
+            header: self.header().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            generalized_index: self.generalized_index().unpack(),
+            value: self.value().unpack(),
+            state_ssz_proof: self.state_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(BeaconStateProof);
+
+impl<'r> Unpack<core::ValidatorProof> for packed::ValidatorProofReader<'r> {
+    fn unpack(&self) -> core::ValidatorProof {
+        core::ValidatorProof {
+            header: self.header().unpack(),
+            header_mmr_proof: self.header_mmr_proof().unpack(),
+            validator_index: self.validator_index().unpack(),
+            validator: self.validator().unpack(),
+            validator_ssz_proof: self.validator_ssz_proof().unpack(),
+            balances_chunk: self.balances_chunk().unpack(),
+            balances_chunk_ssz_proof: self.balances_chunk_ssz_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ValidatorProof);
+
+impl<'r> Unpack<core::MultiHeaderProof> for packed::MultiHeaderProofReader<'r> {
+    fn unpack(&self) -> core::MultiHeaderProof {
+        core::MultiHeaderProof {
+            headers: self.headers().unpack(),
+            headers_mmr_proof: self.headers_mmr_proof().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(MultiHeaderProof);
+
+impl<'r> Unpack<core::Witness> for packed::WitnessReader<'r> {
+    fn unpack(&self) -> core::Witness {
+        match self.to_enum() {
+            packed::WitnessUnionReader::ClientBootstrap(inner) => {
+                core::Witness::ClientBootstrap(inner.unpack())
+            }
+            packed::WitnessUnionReader::ClientUpdate(inner) => {
+                core::Witness::ClientUpdate(inner.unpack())
+            }
+            packed::WitnessUnionReader::ClientOptimisticUpdate(inner) => {
+                core::Witness::ClientOptimisticUpdate(inner.unpack())
+            }
+            packed::WitnessUnionReader::SyncCommitteeUpdate(inner) => {
+                core::Witness::SyncCommitteeUpdate(inner.unpack())
+            }
+            packed::WitnessUnionReader::ClientUpdateBatch(inner) => {
+                core::Witness::ClientUpdateBatch(inner.unpack())
+            }
+            packed::WitnessUnionReader::TransactionProof(inner) => {
+                core::Witness::TransactionProof(inner.unpack())
+            }
+            packed::WitnessUnionReader::TransactionPayload(inner) => {
+                core::Witness::TransactionPayload(inner.unpack())
+            }
+            packed::WitnessUnionReader::HistoricalTransactionProof(inner) => {
+                core::Witness::HistoricalTransactionProof(inner.unpack())
+            }
+            packed::WitnessUnionReader::BeaconStateProof(inner) => {
+                core::Witness::BeaconStateProof(inner.unpack())
+            }
+            packed::WitnessUnionReader::ValidatorProof(inner) => {
+                core::Witness::ValidatorProof(inner.unpack())
+            }
+            packed::WitnessUnionReader::MultiHeaderProof(inner) => {
+                core::Witness::MultiHeaderProof(inner.unpack())
+            }
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(Witness);
+
+impl<'r> Unpack<core::WitnessEnvelope> for packed::WitnessEnvelopeReader<'r> {
+    fn unpack(&self) -> core::WitnessEnvelope {
+        core::WitnessEnvelope {
+            version: self.version().into(),
+            witness: self.witness().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(WitnessEnvelope);
+
 impl<'r> Unpack<core::ClientInfo> for packed::ClientInfoReader<'r> {
     fn unpack(&self) -> core::ClientInfo {
         core::ClientInfo {
             last_client_id: self.last_client_id().into(),
             minimal_headers_count: self.minimal_headers_count().into(),
             genesis_validators_root: self.genesis_validators_root().unpack(),
+            force_update_timeout: self.force_update_timeout().unpack(),
         }
     }
 }
@@ -220,6 +481,9 @@ impl<'r> Unpack<core::Client> for packed::ClientReader<'r> {
             maximal_slot: self.maximal_slot().unpack(),
             tip_header_root: self.tip_header_root().unpack(),
             headers_mmr_root: self.headers_mmr_root().unpack(),
+            optimistic_slot: self.optimistic_slot().unpack(),
+            optimistic_header_root: self.optimistic_header_root().unpack(),
+            finalized_slot: self.finalized_slot().unpack(),
         }
     }
 }
@@ -235,6 +499,30 @@ impl<'r> Unpack<core::ClientSyncCommittee> for packed::ClientSyncCommitteeReader
 }
 impl_conversion_for_entity_unpack!(ClientSyncCommittee);
 
+impl<'r> Unpack<core::ClientUncompressedSyncCommittee>
+    for packed::ClientUncompressedSyncCommitteeReader<'r>
+{
+    fn unpack(&self) -> core::ClientUncompressedSyncCommittee {
+        core::ClientUncompressedSyncCommittee {
+            period: self.period().unpack(),
+            data: self.data().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientUncompressedSyncCommittee);
+
+impl<'r> Unpack<core::ClientSyncCommitteeCommitment>
+    for packed::ClientSyncCommitteeCommitmentReader<'r>
+{
+    fn unpack(&self) -> core::ClientSyncCommitteeCommitment {
+        core::ClientSyncCommitteeCommitment {
+            period: self.period().unpack(),
+            sync_committee_root: self.sync_committee_root().unpack(),
+        }
+    }
+}
+impl_conversion_for_entity_unpack!(ClientSyncCommitteeCommitment);
+
 impl<'r> Unpack<core::ClientTypeArgs> for packed::ClientTypeArgsReader<'r> {
     fn unpack(&self) -> core::ClientTypeArgs {
         core::ClientTypeArgs {
@@ -0,0 +1,115 @@
+//! Decodes the witnesses, which are wrapped in the witness envelopes, or the bare witnesses in
+//! the old version.
+
+use molecule::prelude::*;
+
+use crate::{
+    error::WitnessError,
+    types::{core, packed, prelude::Unpack},
+};
+
+/// The version of the bare witnesses, which are not wrapped in [`packed::WitnessEnvelope`].
+pub const BARE_WITNESS_VERSION: u8 = 0;
+/// The current version of [`packed::WitnessEnvelope`].
+pub const WITNESS_ENVELOPE_VERSION: u8 = 1;
+
+/// A witness type which could be wrapped in [`packed::WitnessEnvelope`].
+pub trait WitnessType<'r>: Reader<'r> {
+    /// The bare witnesses of this type are in a legacy layout, so they should be decoded by
+    /// [`decode_legacy_witness`].
+    const HAS_LEGACY_LAYOUT: bool = false;
+
+    /// Returns the inner witness if it's in this type.
+    fn from_witness(witness: packed::WitnessUnionReader<'r>) -> Option<Self>;
+}
+
+/// A witness type whose layout was changed after the bare witnesses.
+pub trait LegacyWitnessType<'r>: WitnessType<'r> {
+    /// The unpacked witness, both layouts are unpacked into it.
+    type Unpacked;
+    /// The reader of the bare witnesses, in the legacy layout.
+    type LegacyReader: Reader<'r> + Unpack<Self::Unpacked>;
+}
+
+macro_rules! impl_witness_type {
+    ($name:ident, $reader:ident) => {
+        impl_witness_type!($name, $reader, false);
+    };
+    ($name:ident, $reader:ident, $has_legacy_layout:literal) => {
+        impl<'r> WitnessType<'r> for packed::$reader<'r> {
+            const HAS_LEGACY_LAYOUT: bool = $has_legacy_layout;
+
+            fn from_witness(witness: packed::WitnessUnionReader<'r>) -> Option<Self> {
+                if let packed::WitnessUnionReader::$name(inner) = witness {
+                    Some(inner)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+impl_witness_type!(ClientBootstrap, ClientBootstrapReader);
+impl_witness_type!(ClientUpdate, ClientUpdateReader, true);
+impl_witness_type!(ClientOptimisticUpdate, ClientOptimisticUpdateReader);
+impl_witness_type!(SyncCommitteeUpdate, SyncCommitteeUpdateReader);
+impl_witness_type!(ClientUpdateBatch, ClientUpdateBatchReader);
+impl_witness_type!(TransactionProof, TransactionProofReader);
+impl_witness_type!(TransactionPayload, TransactionPayloadReader);
+impl_witness_type!(HistoricalTransactionProof, HistoricalTransactionProofReader);
+impl_witness_type!(BeaconStateProof, BeaconStateProofReader);
+impl_witness_type!(ValidatorProof, ValidatorProofReader);
+impl_witness_type!(MultiHeaderProof, MultiHeaderProofReader);
+
+impl<'r> LegacyWitnessType<'r> for packed::ClientUpdateReader<'r> {
+    type Unpacked = core::ClientUpdate;
+    type LegacyReader = packed::ClientUpdateV0Reader<'r>;
+}
+
+/// Decodes a witness in the expected type, returns the version and the witness.
+///
+/// Both the witness envelopes and the bare witnesses are accepted, except the bare witnesses in
+/// a legacy layout, see [`decode_legacy_witness`].
+///
+/// N.B. A bare witness could not be decoded as a witness envelope, since the first field of a
+/// witness envelope is a single byte, and no witness starts with a single byte.
+pub fn decode_witness<'r, T: WitnessType<'r>>(slice: &'r [u8]) -> Result<(u8, T), WitnessError> {
+    if let Ok(envelope) = packed::WitnessEnvelopeReader::from_slice(slice) {
+        decode_envelope(envelope)
+    } else if T::HAS_LEGACY_LAYOUT {
+        Err(WitnessError::LegacyWitnessLayout)
+    } else {
+        T::from_slice(slice)
+            .map(|witness| (BARE_WITNESS_VERSION, witness))
+            .map_err(|_| WitnessError::InvalidWitness)
+    }
+}
+
+/// Decodes a witness in the expected type, returns the version and the unpacked witness.
+///
+/// The bare witnesses are decoded in the legacy layout.
+pub fn decode_legacy_witness<'r, T>(slice: &'r [u8]) -> Result<(u8, T::Unpacked), WitnessError>
+where
+    T: LegacyWitnessType<'r> + Unpack<T::Unpacked>,
+{
+    if let Ok(envelope) = packed::WitnessEnvelopeReader::from_slice(slice) {
+        decode_envelope::<T>(envelope).map(|(version, witness)| (version, witness.unpack()))
+    } else {
+        T::LegacyReader::from_slice(slice)
+            .map(|witness| (BARE_WITNESS_VERSION, witness.unpack()))
+            .map_err(|_| WitnessError::InvalidWitness)
+    }
+}
+
+fn decode_envelope<'r, T: WitnessType<'r>>(
+    envelope: packed::WitnessEnvelopeReader<'r>,
+) -> Result<(u8, T), WitnessError> {
+    let version: u8 = envelope.version().into();
+    if version == BARE_WITNESS_VERSION || version > WITNESS_ENVELOPE_VERSION {
+        return Err(WitnessError::UnsupportedWitnessVersion);
+    }
+    T::from_witness(envelope.witness().to_enum())
+        .map(|witness| (version, witness))
+        .ok_or(WitnessError::MismatchedWitnessType)
+}
@@ -13,15 +13,19 @@
 use alloc::fmt;
 use core::convert::{AsRef, From};
 
-use bls::{PublicKeyBytes, SIGNATURE_BYTES_LEN};
-use ethereum_types::H512;
+use bls::{PublicKeyBytes, PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN, SIGNATURE_BYTES_LEN};
+use ethereum_types::{H160, H512};
 use molecule::prelude::*;
 use ssz_derive::Encode;
 use tree_hash::Hash256;
 use tree_hash_derive::TreeHash;
 
 #[cfg(feature = "std")]
-use eth2_types::{BeaconBlockHeader, Slot};
+use eth2_types::{
+    light_client_finality_update::LightClientFinalityUpdate,
+    light_client_update::LightClientUpdate, BeaconBlockHeader, EthSpec, Slot,
+    SyncAggregate as Eth2SyncAggregate, Validator as Eth2Validator,
+};
 #[cfg(feature = "std")]
 use faster_hex::hex_string;
 
@@ -74,6 +78,11 @@ pub type MmrProof = Vec<HeaderDigest>;
 ///
 /// [BLS Public Key]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/validator.md#bls-public-key
 pub type BlsPubkey = PublicKeyBytes;
+/// An uncompressed [`BlsPubkey`]: the affine coordinates of the G1 point.
+///
+/// It could be loaded without decompression, but it's twice as large as the compressed one.
+#[derive(Clone, Copy)]
+pub struct BlsUncompressedPubkey([u8; PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN]);
 /// A BLS12-381 signature.
 ///
 /// See [Custom types] for more details.
@@ -85,6 +94,10 @@ pub struct BlsSignature([u8; SIGNATURE_BYTES_LEN]);
 ///
 /// [`SYNC_COMMITTEE_SIZE`]: ../../consensus_specs/altair/constant.SYNC_COMMITTEE_SIZE.html
 pub type BlsPubkeyVec = Vec<BlsPubkey>;
+/// A vector of [`BlsUncompressedPubkey`]s; the size should be [`SYNC_COMMITTEE_SIZE`].
+///
+/// [`SYNC_COMMITTEE_SIZE`]: ../../consensus_specs/altair/constant.SYNC_COMMITTEE_SIZE.html
+pub type BlsUncompressedPubkeyVec = Vec<BlsUncompressedPubkey>;
 
 //
 // Composite Types
@@ -103,6 +116,43 @@ pub struct Header {
 /// A dynamic-size vector of [`Header`]s.
 pub type HeaderVec = Vec<Header>;
 
+/// A [`Header`] without the slot and the parent root.
+///
+/// The slot is derived from the position in [`CompactHeaders`], and the parent root is the root
+/// of the previous non-empty header.
+#[derive(Clone)]
+pub struct CompactHeader {
+    pub proposer_index: Uint64,
+    pub state_root: Hash,
+    pub body_root: Hash,
+}
+
+/// An item in [`CompactHeaders`].
+#[derive(Clone)]
+pub enum CompactHeaderItem {
+    /// The count of continuous empty slots.
+    EmptySlots(Uint64),
+    /// A header which parent root could be derived.
+    CompactHeader(CompactHeader),
+}
+
+/// A dynamic-size vector of [`CompactHeaderItem`]s.
+pub type CompactHeaderItemVec = Vec<CompactHeaderItem>;
+
+/// Continuous headers starting from `first_slot`, which empty slots are run-length encoded.
+#[derive(Clone)]
+pub struct CompactHeaders {
+    pub first_slot: Uint64,
+    pub items: CompactHeaderItemVec,
+}
+
+/// Continuous headers, in the full encoding or the compact encoding.
+#[derive(Clone)]
+pub enum HeaderRange {
+    Full(HeaderVec),
+    Compact(CompactHeaders),
+}
+
 /// [`SYNC_COMMITTEE_SIZE`] bits.
 ///
 /// [`SYNC_COMMITTEE_SIZE`]: ../../consensus_specs/altair/constant.SYNC_COMMITTEE_SIZE.html
@@ -122,6 +172,26 @@ pub struct SyncCommittee {
     pub aggregate_pubkey: BlsPubkey,
 }
 
+/// Same as [`SyncCommittee`], but all public keys are uncompressed.
+#[derive(Clone)]
+pub struct UncompressedSyncCommittee {
+    pub pubkeys: BlsUncompressedPubkeyVec,
+    pub aggregate_pubkey: BlsUncompressedPubkey,
+}
+
+/// See [`Validator`](https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#validator).
+#[derive(Clone, TreeHash)]
+pub struct Validator {
+    pub pubkey: BlsPubkey,
+    pub withdrawal_credentials: Hash,
+    pub effective_balance: Uint64,
+    pub slashed: bool,
+    pub activation_eligibility_epoch: Uint64,
+    pub activation_epoch: Uint64,
+    pub exit_epoch: Uint64,
+    pub withdrawable_epoch: Uint64,
+}
+
 //
 // Witnesses
 //
@@ -144,7 +214,21 @@ pub struct ClientUpdate {
     pub sync_aggregate: SyncAggregate,
     pub signature_slot: Uint64,
     pub new_headers_mmr_proof: MmrProof,
-    pub headers: HeaderVec,
+    pub headers: HeaderRange,
+}
+
+/// The data which is used to update the optimistic tip of the client cell.
+///
+/// Only the signature of the sync committee on the attested header is verified, the attested
+/// header is not finalized.
+///
+/// References:
+/// - [`LightClientOptimisticUpdate`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#lightclientoptimisticupdate)
+#[derive(Clone)]
+pub struct ClientOptimisticUpdate {
+    pub attested_header: Header,
+    pub sync_aggregate: SyncAggregate,
+    pub signature_slot: Uint64,
 }
 
 /// The data which is used to update the sync committee cell.
@@ -156,6 +240,32 @@ pub struct SyncCommitteeUpdate {
     pub signature_slot: Uint64,
 }
 
+/// A [`SyncCommitteeUpdate`] with the next sync committee, to rotate the sync committee inside
+/// a [`ClientUpdateBatch`].
+#[derive(Clone)]
+pub struct SyncCommitteeRotation {
+    pub sync_committee_update: SyncCommitteeUpdate,
+    pub next_sync_committee: ClientSyncCommittee,
+}
+
+/// An item in [`ClientUpdateBatch`].
+#[derive(Clone)]
+pub enum ClientUpdateBatchItem {
+    ClientUpdate(ClientUpdate),
+    SyncCommitteeRotation(SyncCommitteeRotation),
+}
+
+/// A dynamic-size vector of [`ClientUpdateBatchItem`]s.
+pub type ClientUpdateBatchItemVec = Vec<ClientUpdateBatchItem>;
+
+/// The data which is used to update the client cell by several updates at once.
+///
+/// The updates are applied in order, the intermediate clients are derived from them.
+#[derive(Clone)]
+pub struct ClientUpdateBatch {
+    pub items: ClientUpdateBatchItemVec,
+}
+
 /// A proof which proves a transaction and its receipt are existed in the Ethereum.
 #[derive(Clone)]
 pub struct TransactionProof {
@@ -184,6 +294,97 @@ pub struct TransactionPayload {
     pub receipt: Bytes,
 }
 
+/// A proof which proves a header before the client is in the chain, through the
+/// `historical_summaries` in the beacon state of an anchor header in the client.
+#[derive(Clone)]
+pub struct HistoricalHeaderProof {
+    /// The header whose `state_root` commits to the historical summaries.
+    pub anchor_header: Header,
+    /// Prove `anchor_header` in `header_mmr_root`.
+    pub anchor_header_mmr_proof: MmrProof,
+    /// Prove the root of the historical header in the `state_root` of `anchor_header`.
+    pub header_ssz_proof: SszProof,
+}
+
+/// A [`TransactionProof`] for a transaction in a header before the client.
+///
+/// The `header_mmr_proof` in `transaction_proof` is ignored.
+#[derive(Clone)]
+pub struct HistoricalTransactionProof {
+    /// Prove the header of `transaction_proof` is in the chain.
+    pub historical_header_proof: HistoricalHeaderProof,
+    /// Prove the transaction and its receipt are in the header.
+    pub transaction_proof: TransactionProof,
+}
+
+/// A proof which proves a value of a field in the beacon state of a header.
+#[derive(Clone)]
+pub struct BeaconStateProof {
+    /// The header whose `state_root` commits to the beacon state.
+    pub header: Header,
+    /// Prove `header` in `header_mmr_root`.
+    pub header_mmr_proof: MmrProof,
+    /// The generalized index of the field in the beacon state.
+    pub generalized_index: Uint64,
+    /// The SSZ-encoded value of the field.
+    pub value: Bytes,
+    /// Prove `value` in `state_root`.
+    pub state_ssz_proof: SszProof,
+}
+
+/// A proof which proves a validator and its balance in the beacon state of a header.
+#[derive(Clone)]
+pub struct ValidatorProof {
+    /// The header whose `state_root` commits to the beacon state.
+    pub header: Header,
+    /// Prove `header` in `header_mmr_root`.
+    pub header_mmr_proof: MmrProof,
+    /// The index of the validator in the registry.
+    pub validator_index: Uint64,
+    /// The validator which requires verification.
+    pub validator: Validator,
+    /// Prove `validator` in `state_root`.
+    pub validator_ssz_proof: SszProof,
+    /// The chunk of balances which contains the balance of the validator.
+    pub balances_chunk: Hash,
+    /// Prove `balances_chunk` in `state_root`.
+    pub balances_chunk_ssz_proof: SszProof,
+}
+
+/// A proof which proves several headers are in the chain, by one MMR proof.
+#[derive(Clone)]
+pub struct MultiHeaderProof {
+    /// The headers which are sorted by their slots.
+    pub headers: HeaderVec,
+    /// Prove all `headers` in `header_mmr_root`.
+    pub headers_mmr_proof: MmrProof,
+}
+
+/// All kinds of witnesses which could be wrapped in [`WitnessEnvelope`].
+#[derive(Clone)]
+pub enum Witness {
+    ClientBootstrap(ClientBootstrap),
+    ClientUpdate(ClientUpdate),
+    ClientOptimisticUpdate(ClientOptimisticUpdate),
+    SyncCommitteeUpdate(SyncCommitteeUpdate),
+    ClientUpdateBatch(ClientUpdateBatch),
+    TransactionProof(TransactionProof),
+    TransactionPayload(TransactionPayload),
+    HistoricalTransactionProof(HistoricalTransactionProof),
+    BeaconStateProof(BeaconStateProof),
+    ValidatorProof(ValidatorProof),
+    MultiHeaderProof(MultiHeaderProof),
+}
+
+/// A witness with its version, so the type of the witness is described by itself.
+///
+/// The bare witnesses, which are not wrapped, are treated as the version 0.
+#[derive(Clone)]
+pub struct WitnessEnvelope {
+    pub version: u8,
+    pub witness: Witness,
+}
+
 //
 // Cells
 //
@@ -196,6 +397,12 @@ pub struct ClientInfo {
     /// The minimal limit of the updates count.
     pub minimal_headers_count: u8,
     pub genesis_validators_root: Hash,
+    /// How many slots without finality could the clients be force updated.
+    ///
+    /// The force update mode is disabled when it's `0`.
+    ///
+    /// Ref: [`process_light_client_store_force_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#process_light_client_store_force_update)
+    pub force_update_timeout: Uint64,
 }
 
 /// The client cell.
@@ -211,6 +418,21 @@ pub struct Client {
     pub tip_header_root: Hash,
     /// The MMR root of headers between slot `minimal_slot` and slot `maximal_slot`.
     pub headers_mmr_root: HeaderDigest,
+    /// The slot of the latest optimistic header.
+    ///
+    /// If there is no optimistic header after the finalized headers, it's same as `maximal_slot`.
+    pub optimistic_slot: Uint64,
+    /// The root of the latest optimistic header.
+    ///
+    /// If there is no optimistic header after the finalized headers, it's same as
+    /// `tip_header_root`.
+    pub optimistic_header_root: Hash,
+    /// The maximal slot of the finalized headers in MMR.
+    ///
+    /// The headers after it were applied by force updates, they are not finalized.
+    /// In that case, `maximal_slot` is the slot of the last force update, and the timeout of the
+    /// next force update starts from it.
+    pub finalized_slot: Uint64,
 }
 
 /// The sync committee cell.
@@ -220,6 +442,78 @@ pub struct ClientSyncCommittee {
     pub data: SyncCommittee,
 }
 
+/// The sync committee cell, in which all public keys are uncompressed.
+///
+/// The public keys are validated when the cell is created, so they could be loaded without
+/// decompression and validation when verify signatures.
+#[derive(Clone)]
+pub struct ClientUncompressedSyncCommittee {
+    pub period: Uint64,
+    pub data: UncompressedSyncCommittee,
+}
+
+/// The sync committee cell, which only stores the commitment of the sync committee.
+///
+/// The whole sync committee is provided in the witness, and it is checked against the
+/// commitment before it's used.
+#[derive(Clone)]
+pub struct ClientSyncCommitteeCommitment {
+    pub period: Uint64,
+    /// The tree hash root of the sync committee.
+    pub sync_committee_root: Hash,
+}
+
+/// The status of a header which was proven by a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderStatus {
+    /// The header is finalized, it is in the MMR of the client.
+    Finalized,
+    /// The header is in the MMR of the client, but it was applied by a force update, it is
+    /// not finalized.
+    ForceUpdated,
+    /// The header is only signed by the sync committee, it is the optimistic tip of the client.
+    Optimistic,
+}
+
+/// The facts of a transaction which were proven by a client.
+#[derive(Clone)]
+pub struct VerifiedTransaction {
+    /// The status of the header which contains the transaction.
+    pub header_status: HeaderStatus,
+    /// The slot of the beacon block which contains the transaction.
+    pub slot: Uint64,
+    pub header_root: Hash,
+    pub transaction_index: Uint64,
+    /// The keccak hash of the raw transaction.
+    pub transaction_hash: Hash,
+    pub receipts_root: Hash,
+    /// Whether the transaction was executed successfully.
+    pub receipt_status: bool,
+    pub logs: Vec<ReceiptLog>,
+}
+
+/// The essential data to rank light client updates.
+///
+/// References:
+/// - [`is_better_update`](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#is_better_update)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpdateSummary {
+    pub active_participants: usize,
+    pub attested_slot: Uint64,
+    pub signature_slot: Uint64,
+    /// The slot of the finalized header, if the update has a finality branch.
+    pub finalized_slot: Option<Uint64>,
+    pub has_next_sync_committee: bool,
+}
+
+/// A log in a transaction receipt.
+#[derive(Clone)]
+pub struct ReceiptLog {
+    pub address: H160,
+    pub topics: Vec<Hash>,
+    pub data: Bytes,
+}
+
 /// The args for the type script of client info cell, client sync committee cell and client cells.
 #[derive(Clone)]
 pub struct ClientTypeArgs {
@@ -244,6 +538,20 @@ impl fmt::Display for BlsSignature {
     }
 }
 
+#[cfg(feature = "std")]
+impl fmt::LowerHex for BlsUncompressedPubkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x{}", hex_string(&self.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for BlsUncompressedPubkey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self, f)
+    }
+}
+
 #[cfg(feature = "std")]
 impl fmt::Display for Header {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -290,20 +598,34 @@ impl fmt::Display for SyncCommittee {
     }
 }
 
+#[cfg(feature = "std")]
+impl fmt::Display for UncompressedSyncCommittee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{ pubkeys: [")?;
+        if !self.pubkeys.is_empty() {
+            write!(f, "{}", &self.pubkeys[0])?;
+            for pubkey in &self.pubkeys[1..] {
+                write!(f, ", {}", pubkey)?;
+            }
+        }
+        write!(f, "], aggregate: {} }}", self.aggregate_pubkey)
+    }
+}
+
 #[cfg(feature = "std")]
 impl fmt::Display for ClientInfo {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if f.alternate() {
             write!(
                 f,
-                "{{ last_client_id: {}, minimal_headers_count: {}, genesis_validators_root: {:#x} }}",
-                self.last_client_id, self.minimal_headers_count,self.genesis_validators_root
+                "{{ last_client_id: {}, minimal_headers_count: {}, force_update_timeout: {}, genesis_validators_root: {:#x} }}",
+                self.last_client_id, self.minimal_headers_count, self.force_update_timeout, self.genesis_validators_root
             )
         } else {
             write!(
                 f,
-                "{{ last_client_id: {}, minimal_headers_count: {} }}",
-                self.last_client_id, self.minimal_headers_count
+                "{{ last_client_id: {}, minimal_headers_count: {}, force_update_timeout: {} }}",
+                self.last_client_id, self.minimal_headers_count, self.force_update_timeout
             )
         }
     }
@@ -316,14 +638,34 @@ impl fmt::Display for ClientSyncCommittee {
     }
 }
 
+#[cfg(feature = "std")]
+impl fmt::Display for ClientUncompressedSyncCommittee {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{ period: {}, data: {} }}", self.period, self.data)
+    }
+}
+
 #[cfg(feature = "std")]
 impl fmt::Display for Client {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{{ id: {}, slots: [{}, {}], tip: {:#x} }}",
-            self.id, self.minimal_slot, self.maximal_slot, self.tip_header_root
-        )
+        if self.optimistic_slot > self.maximal_slot {
+            write!(
+                f,
+                "{{ id: {}, slots: [{}, {}], tip: {:#x}, optimistic: {{ slot: {}, root: {:#x} }} }}",
+                self.id,
+                self.minimal_slot,
+                self.maximal_slot,
+                self.tip_header_root,
+                self.optimistic_slot,
+                self.optimistic_header_root
+            )
+        } else {
+            write!(
+                f,
+                "{{ id: {}, slots: [{}, {}], tip: {:#x} }}",
+                self.id, self.minimal_slot, self.maximal_slot, self.tip_header_root
+            )
+        }
     }
 }
 
@@ -345,6 +687,24 @@ impl AsRef<[u8]> for BlsSignature {
     }
 }
 
+impl From<[u8; PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN]> for BlsUncompressedPubkey {
+    fn from(data: [u8; PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN]) -> Self {
+        Self(data)
+    }
+}
+
+impl From<BlsUncompressedPubkey> for [u8; PUBLIC_KEY_UNCOMPRESSED_BYTES_LEN] {
+    fn from(data: BlsUncompressedPubkey) -> Self {
+        data.0
+    }
+}
+
+impl AsRef<[u8]> for BlsUncompressedPubkey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<BeaconBlockHeader> for Header {
     fn from(header: BeaconBlockHeader) -> Self {
@@ -371,6 +731,79 @@ impl From<Header> for BeaconBlockHeader {
     }
 }
 
+#[cfg(feature = "std")]
+impl<E: EthSpec> From<Eth2SyncAggregate<E>> for SyncAggregate {
+    fn from(sync_aggregate: Eth2SyncAggregate<E>) -> Self {
+        // The bits of the smaller sync committees, such as in the `minimal` preset, are padded
+        // with zeros.
+        let bits = sync_aggregate.sync_committee_bits.as_slice();
+        let mut sync_committee_bits = SyncCommitteeBits::zero();
+        sync_committee_bits.as_bytes_mut()[..bits.len()].copy_from_slice(bits);
+        Self {
+            sync_committee_bits,
+            sync_committee_signature: sync_aggregate.sync_committee_signature.serialize().into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: EthSpec> From<&LightClientUpdate<E>> for UpdateSummary {
+    fn from(update: &LightClientUpdate<E>) -> Self {
+        let finalized_slot = if is_empty_branch(&update.finality_branch) {
+            None
+        } else {
+            Some(update.finalized_header.slot.into())
+        };
+        Self {
+            active_participants: update.sync_aggregate.sync_committee_bits.num_set_bits(),
+            attested_slot: update.attested_header.slot.into(),
+            signature_slot: update.signature_slot.into(),
+            finalized_slot,
+            has_next_sync_committee: !is_empty_branch(&update.next_sync_committee_branch),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: EthSpec> From<&LightClientFinalityUpdate<E>> for UpdateSummary {
+    fn from(update: &LightClientFinalityUpdate<E>) -> Self {
+        let finalized_slot = if is_empty_branch(&update.finality_branch) {
+            None
+        } else {
+            Some(update.finalized_header.slot.into())
+        };
+        Self {
+            active_participants: update.sync_aggregate.sync_committee_bits.num_set_bits(),
+            attested_slot: update.attested_header.slot.into(),
+            signature_slot: update.signature_slot.into(),
+            finalized_slot,
+            has_next_sync_committee: false,
+        }
+    }
+}
+
+// The branches are filled with zeros when the proven data are absent.
+#[cfg(feature = "std")]
+fn is_empty_branch(branch: &[Hash]) -> bool {
+    branch.iter().all(|node| node.is_zero())
+}
+
+#[cfg(feature = "std")]
+impl From<Eth2Validator> for Validator {
+    fn from(validator: Eth2Validator) -> Self {
+        Self {
+            pubkey: validator.pubkey,
+            withdrawal_credentials: validator.withdrawal_credentials,
+            effective_balance: validator.effective_balance,
+            slashed: validator.slashed,
+            activation_eligibility_epoch: validator.activation_eligibility_epoch.into(),
+            activation_epoch: validator.activation_epoch.into(),
+            exit_epoch: validator.exit_epoch.into(),
+            withdrawable_epoch: validator.withdrawable_epoch.into(),
+        }
+    }
+}
+
 impl Header {
     /// Checks if a header is empty.
     pub fn is_empty(&self) -> bool {
@@ -4,15 +4,19 @@ use ::core::result::Result;
 use alloc::{vec, vec::Vec};
 
 use ckb_mmr::{leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError};
-use rlp::encode;
-use ssz_types::{typenum, FixedVector, VariableList};
-use tree_hash::TreeHash as _;
-use tree_hash_derive::TreeHash;
+use eth2_hashing::hash32_concat;
+use ethereum_types::H160;
+use rlp::{encode, Rlp};
+use ssz_types::{typenum, VariableList};
+use tree_hash::{merkle_root, TreeHash};
 
 use crate::{
-    consensus_specs::{self as specs, forks, helpers},
+    consensus_specs::{self as specs, forks, ChainConfig},
     error::{
-        ClientBootstrapError, ClientUpdateError, SyncCommitteeUpdateError, TxVerificationError,
+        BeaconStateProofError, ClientBootstrapError, ClientOptimisticUpdateError,
+        ClientUpdateBatchError, ClientUpdateError, HistoricalHeaderProofError,
+        MultiHeaderProofError, SyncCommitteeCommitmentError, SyncCommitteeUpdateError,
+        TxVerificationError, ValidatorProofError,
     },
     types::{core, packed, prelude::*},
     utilities::{bls, mmr, mpt, ssz},
@@ -31,6 +35,181 @@ impl core::Header {
             maximal_slot: self.slot,
             tip_header_root: root,
             headers_mmr_root: digest,
+            optimistic_slot: self.slot,
+            optimistic_header_root: root,
+            finalized_slot: self.slot,
+        }
+    }
+}
+
+impl core::HeaderRange {
+    /// Builds the compact encoding of continuous headers.
+    ///
+    /// `parent_root` is the root of the last non-empty header before `headers`.
+    ///
+    /// The full encoding is used, if any non-empty header is not linked to the previous
+    /// non-empty header, since such headers could not be rebuilt from the compact encoding.
+    pub fn compact(mut parent_root: core::Hash, headers: &[mmr::HeaderWithCache]) -> Self {
+        let first_slot = headers
+            .first()
+            .map(|header| header.inner.slot)
+            .unwrap_or_default();
+        let mut items = Vec::new();
+        for header in headers {
+            if header.is_empty() {
+                if let Some(core::CompactHeaderItem::EmptySlots(count)) = items.last_mut() {
+                    *count += 1;
+                } else {
+                    items.push(core::CompactHeaderItem::EmptySlots(1));
+                }
+                continue;
+            }
+            if header.inner.parent_root != parent_root {
+                let headers = headers.iter().map(|header| header.inner.clone()).collect();
+                return Self::Full(headers);
+            }
+            let compact_header = core::CompactHeader {
+                proposer_index: header.inner.proposer_index,
+                state_root: header.inner.state_root,
+                body_root: header.inner.body_root,
+            };
+            items.push(core::CompactHeaderItem::CompactHeader(compact_header));
+            parent_root = header.root;
+        }
+        Self::Compact(core::CompactHeaders { first_slot, items })
+    }
+
+    /// Rebuilds all headers and calculates their roots.
+    ///
+    /// `parent_root` is the root of the last non-empty header before this range; it's used as
+    /// the parent root of the first compact header.
+    ///
+    /// The compact headers should start from `first_slot`, and should not be after
+    /// `maximal_slot`, so the count of the rebuilt headers is limited.
+    pub fn calc_cache(
+        &self,
+        parent_root: core::Hash,
+        first_slot: u64,
+        maximal_slot: u64,
+    ) -> Result<Vec<mmr::HeaderWithCache>, ClientUpdateError> {
+        match self {
+            Self::Full(headers) => {
+                let cached_headers = headers
+                    .iter()
+                    .map(|header| header.clone().calc_cache())
+                    .collect();
+                Ok(cached_headers)
+            }
+            Self::Compact(compact_headers) => calc_compact_headers_cache(
+                compact_headers.first_slot,
+                compact_headers.items.iter().cloned(),
+                parent_root,
+                first_slot,
+                maximal_slot,
+            ),
+        }
+    }
+}
+
+// Rebuilds all headers from the items of compact headers, then calculates their roots.
+//
+// The compact headers should be in the range from `expected_first_slot` to `maximal_slot`,
+// otherwise a few bytes of empty slots could make the script rebuild unlimited headers.
+pub(super) fn calc_compact_headers_cache<I: IntoIterator<Item = core::CompactHeaderItem>>(
+    first_slot: u64,
+    items: I,
+    mut parent_root: core::Hash,
+    expected_first_slot: u64,
+    maximal_slot: u64,
+) -> Result<Vec<mmr::HeaderWithCache>, ClientUpdateError> {
+    if first_slot != expected_first_slot {
+        error!(
+            "failed: compact headers start from slot {first_slot}, expect {expected_first_slot}"
+        );
+        return Err(ClientUpdateError::FirstHeaderSlot);
+    }
+    let empty_header_hasher = EmptyHeaderHasher::new();
+    let mut cached_headers = Vec::new();
+    let mut slot = first_slot;
+    for item in items {
+        let slots_count = match item {
+            core::CompactHeaderItem::EmptySlots(count) => count,
+            core::CompactHeaderItem::CompactHeader(_) => 1,
+        };
+        if slots_count == 0 {
+            error!("failed: compact headers have no empty slots at slot {slot}");
+            return Err(ClientUpdateError::InvalidCompactHeaders);
+        }
+        let next_slot = slot
+            .checked_add(slots_count)
+            .filter(|next_slot| *next_slot - 1 <= maximal_slot)
+            .ok_or_else(|| {
+                error!(
+                    "failed: compact headers from slot {slot} (count: {slots_count}) \
+                     are after the maximal slot {maximal_slot}"
+                );
+                ClientUpdateError::InvalidCompactHeaders
+            })?;
+        match item {
+            core::CompactHeaderItem::EmptySlots(_) => {
+                cached_headers
+                    .extend((slot..next_slot).map(|slot| empty_header_hasher.calc_cache(slot)));
+            }
+            core::CompactHeaderItem::CompactHeader(compact_header) => {
+                let header = core::Header {
+                    slot,
+                    proposer_index: compact_header.proposer_index,
+                    parent_root,
+                    state_root: compact_header.state_root,
+                    body_root: compact_header.body_root,
+                };
+                let cached_header = header.calc_cache();
+                parent_root = cached_header.root;
+                cached_headers.push(cached_header);
+            }
+        }
+        slot = next_slot;
+    }
+    Ok(cached_headers)
+}
+
+// Calculates the roots of empty headers.
+//
+// Only the slot of an empty header is not zero, so the roots of the zero subtrees are
+// calculated once, and only 3 hashes are required for each empty header.
+struct EmptyHeaderHasher {
+    // The roots of zero subtrees with 2 leaves and 4 leaves.
+    zero_roots: [[u8; 32]; 2],
+}
+
+impl EmptyHeaderHasher {
+    fn new() -> Self {
+        let zero_root_2 = hash32_concat(&[0u8; 32], &[0u8; 32]);
+        let zero_root_4 = hash32_concat(&zero_root_2, &zero_root_2);
+        Self {
+            zero_roots: [zero_root_2, zero_root_4],
+        }
+    }
+
+    // The header has 5 fields, so its tree has 8 leaves, and only the first leaf is not zero.
+    fn calc_cache(&self, slot: u64) -> mmr::HeaderWithCache {
+        let root = profile!(TreeHash, {
+            let mut slot_leaf = [0u8; 32];
+            slot_leaf[..8].copy_from_slice(&slot.to_le_bytes());
+            let node = hash32_concat(&slot_leaf, &[0u8; 32]);
+            let node = hash32_concat(&node, &self.zero_roots[0]);
+            hash32_concat(&node, &self.zero_roots[1])
+        });
+        let inner = core::Header {
+            slot,
+            proposer_index: 0,
+            parent_root: Default::default(),
+            state_root: Default::default(),
+            body_root: Default::default(),
+        };
+        mmr::HeaderWithCache {
+            inner,
+            root: root.into(),
         }
     }
 }
@@ -43,45 +222,290 @@ impl core::SyncAggregate {
     /// - [`is_better_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#is_better_update)
     /// - [`process_light_client_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#process_light_client_update)
     pub fn has_supermajority(&self) -> bool {
+        self.has_supermajority_with_config(&ChainConfig::MAINNET)
+    }
+
+    /// Same as [`Self::has_supermajority`], with the sync committee size of the chain.
+    ///
+    /// The bits after the sync committee are padding, it's never a supermajority if any of them
+    /// is set.
+    pub fn has_supermajority_with_config(&self, config: &ChainConfig) -> bool {
+        let total = config.sync_committee_size;
+        if let Some(ones) = self.count_participants(total) {
+            debug!("check if is supermajority: {ones} / {total}");
+            ones * 3 >= total * 2
+        } else {
+            debug!("some bits after the sync committee (size: {total}) are set");
+            false
+        }
+    }
+
+    /// Counts the participants in the first `sync_committee_size` bits.
+    ///
+    /// Returns `None` if any bit after the sync committee is set.
+    pub fn count_participants(&self, sync_committee_size: usize) -> Option<usize> {
         let bits = self.sync_committee_bits.as_ref();
-        let ones: usize = bits.iter().map(|byte| byte.count_ones() as usize).sum();
-        let total = bits.len() * 8;
-        debug!("check if is supermajority: {ones} / {total}");
-        ones * 3 >= total * 2
+        let mut ones = 0;
+        for (index, byte) in bits.iter().enumerate() {
+            for offset in 0..8 {
+                if byte & (1 << offset) != 0 {
+                    if index * 8 + offset >= sync_committee_size {
+                        return None;
+                    }
+                    ones += 1;
+                }
+            }
+        }
+        Some(ones)
     }
 }
 
-// The intermediate type of `SyncCommittee` which has SSZ support.
-#[derive(TreeHash)]
-struct SszSyncCommittee {
-    pub pubkeys: FixedVector<core::BlsPubkey, typenum::U512>,
-    pub aggregate_pubkey: core::BlsPubkey,
-}
+impl core::UpdateSummary {
+    pub fn has_supermajority(&self, config: &ChainConfig) -> bool {
+        self.active_participants * 3 >= config.sync_committee_size * 2
+    }
 
-impl From<core::SyncCommittee> for SszSyncCommittee {
-    fn from(data: core::SyncCommittee) -> Self {
-        Self {
-            pubkeys: FixedVector::from(data.pubkeys),
-            aggregate_pubkey: data.aggregate_pubkey,
+    pub fn has_finality(&self) -> bool {
+        self.finalized_slot.is_some()
+    }
+
+    /// The next sync committee is relevant only when it's signed in the same period as the
+    /// attested header.
+    pub fn has_relevant_sync_committee(&self, config: &ChainConfig) -> bool {
+        self.has_next_sync_committee
+            && config.compute_sync_committee_period_at_slot(self.attested_slot)
+                == config.compute_sync_committee_period_at_slot(self.signature_slot)
+    }
+
+    pub fn has_sync_committee_finality(&self, config: &ChainConfig) -> bool {
+        self.finalized_slot
+            .map(|finalized_slot| {
+                config.compute_sync_committee_period_at_slot(finalized_slot)
+                    == config.compute_sync_committee_period_at_slot(self.attested_slot)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Checks if `self` is better than `other`, by the same rules as `is_better_update`.
+    pub fn is_better_than(&self, other: &Self, config: &ChainConfig) -> bool {
+        // Compare supermajority (> 2/3) sync committee participation
+        let has_supermajority = self.has_supermajority(config);
+        if has_supermajority != other.has_supermajority(config) {
+            return has_supermajority;
+        }
+        if !has_supermajority && self.active_participants != other.active_participants {
+            return self.active_participants > other.active_participants;
         }
+
+        // Compare presence of relevant sync committee
+        let has_relevant_sync_committee = self.has_relevant_sync_committee(config);
+        if has_relevant_sync_committee != other.has_relevant_sync_committee(config) {
+            return has_relevant_sync_committee;
+        }
+
+        // Compare indication of any finality
+        let has_finality = self.has_finality();
+        if has_finality != other.has_finality() {
+            return has_finality;
+        }
+
+        // Compare sync committee finality
+        if has_finality {
+            let has_sync_committee_finality = self.has_sync_committee_finality(config);
+            if has_sync_committee_finality != other.has_sync_committee_finality(config) {
+                return has_sync_committee_finality;
+            }
+        }
+
+        // Tiebreaker 1: Sync committee participation beyond supermajority
+        if self.active_participants != other.active_participants {
+            return self.active_participants > other.active_participants;
+        }
+
+        // Tiebreaker 2: Prefer older data (fewer changes to best)
+        if self.attested_slot != other.attested_slot {
+            return self.attested_slot < other.attested_slot;
+        }
+        self.signature_slot < other.signature_slot
     }
 }
 
 impl core::SyncCommittee {
     /// Calculates the tree hash root.
+    ///
+    /// The public keys are merkleized as a vector of their count, so the sync committees in the
+    /// presets other than the mainnet are supported.
     pub fn tree_hash_root(self) -> core::Hash {
-        SszSyncCommittee::from(self).tree_hash_root()
+        profile!(TreeHash, {
+            let pubkeys_roots = self
+                .pubkeys
+                .iter()
+                .flat_map(|pubkey| pubkey.tree_hash_root().0)
+                .collect::<Vec<_>>();
+            let pubkeys_root = merkle_root(&pubkeys_roots, self.pubkeys.len());
+            let aggregate_pubkey_root = self.aggregate_pubkey.tree_hash_root();
+            core::Hash::from(hash32_concat(
+                pubkeys_root.as_bytes(),
+                aggregate_pubkey_root.as_bytes(),
+            ))
+        })
+    }
+}
+
+// The errors when verify the signature for an attested header.
+//
+// They are shared by all kinds of updates, and will be converted into the errors of the
+// corresponding updates.
+enum AttestedHeaderSignatureError {
+    NotSupermajorityParticipation,
+    FailedToVerifyTheAttestedHeader,
+    BlsPublicKeyBytesError,
+    BlsAggregateSignatureError,
+}
+
+macro_rules! impl_from_attested_header_signature_error {
+    ($error:ident) => {
+        impl From<AttestedHeaderSignatureError> for $error {
+            fn from(error: AttestedHeaderSignatureError) -> Self {
+                match error {
+                    AttestedHeaderSignatureError::NotSupermajorityParticipation => {
+                        Self::NotSupermajorityParticipation
+                    }
+                    AttestedHeaderSignatureError::FailedToVerifyTheAttestedHeader => {
+                        Self::FailedToVerifyTheAttestedHeader
+                    }
+                    AttestedHeaderSignatureError::BlsPublicKeyBytesError => {
+                        Self::BlsPublicKeyBytesError
+                    }
+                    AttestedHeaderSignatureError::BlsAggregateSignatureError => {
+                        Self::BlsAggregateSignatureError
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_from_attested_header_signature_error!(ClientUpdateError);
+impl_from_attested_header_signature_error!(ClientOptimisticUpdateError);
+impl_from_attested_header_signature_error!(SyncCommitteeUpdateError);
+
+// Verifies the signature for the attested header with the sync committee which is in the
+// same period as the signature slot.
+fn verify_attested_header_signature<'r, P: bls::PackedClientSyncCommittee<'r>>(
+    config: &ChainConfig,
+    attested_header: &core::Header,
+    sync_aggregate: &core::SyncAggregate,
+    signature_slot: u64,
+    genesis_validators_root: core::Hash,
+    packed_client_sync_committee: &P,
+) -> Result<(), AttestedHeaderSignatureError> {
+    if !sync_aggregate.has_supermajority_with_config(config) {
+        return Err(AttestedHeaderSignatureError::NotSupermajorityParticipation);
+    }
+    let attested_root = attested_header.tree_hash_root();
+    let message = bls::compute_signing_root_at_signature_slot(
+        config,
+        attested_root,
+        signature_slot,
+        &forks::altair::DOMAIN_SYNC_COMMITTEE,
+        genesis_validators_root,
+    );
+    let pubkeys = profile!(
+        DecompressPubkeys,
+        packed_client_sync_committee.load_all_pubkeys()
+    )
+    .map_err(|_| AttestedHeaderSignatureError::BlsPublicKeyBytesError)?;
+    let strategy = sync_aggregate.aggregation_strategy_with_config(config);
+    debug!("aggregate public keys of participants by {strategy:?}");
+    let is_verified = match strategy {
+        bls::AggregationStrategy::Addition => {
+            sync_aggregate.fast_aggregate_verify(&pubkeys, message)
+        }
+        bls::AggregationStrategy::Subtraction => {
+            let aggregate_pubkey = profile!(
+                DecompressPubkeys,
+                packed_client_sync_committee.load_aggregate_pubkey()
+            )
+            .map_err(|_| AttestedHeaderSignatureError::BlsPublicKeyBytesError)?;
+            sync_aggregate.fast_aggregate_verify_by_subtraction(
+                &pubkeys,
+                &aggregate_pubkey,
+                message,
+            )
+        }
+    }
+    .map_err(|_| AttestedHeaderSignatureError::BlsAggregateSignatureError)?;
+    if !is_verified {
+        warn!(
+            "failed: verify the signature for attested header, \
+            signature slot: {signature_slot}, sync committee (period: {}), \
+            attested root: {attested_root:#x}, \
+            genesis validators root: {genesis_validators_root:#x}",
+            packed_client_sync_committee.sync_committee_period()
+        );
+        Err(AttestedHeaderSignatureError::FailedToVerifyTheAttestedHeader)
+    } else {
+        debug!(
+            "passed: verify the signature for attested header, \
+            signature slot: {signature_slot}, sync committee (period: {}), \
+            attested root: {attested_root:#x}, \
+            genesis validators root: {genesis_validators_root:#x}",
+            packed_client_sync_committee.sync_committee_period()
+        );
+        Ok(())
     }
 }
 
+// Checks if two packed client sync committees in the same period are different.
+//
+// The bytes are compared if both are stored in the same encoding, otherwise the roots are
+// compared.
+fn is_conflicted_client_sync_committee<'s, 'n, S, N>(
+    packed_stored_client_sync_committee: &S,
+    packed_client_sync_committee: &N,
+) -> Result<bool, SyncCommitteeUpdateError>
+where
+    S: bls::PackedClientSyncCommittee<'s>,
+    N: bls::PackedClientSyncCommittee<'n>,
+{
+    let stored_bytes = packed_stored_client_sync_committee.as_slice();
+    let bytes = packed_client_sync_committee.as_slice();
+    if stored_bytes.len() == bytes.len() {
+        return Ok(stored_bytes != bytes);
+    }
+    let stored_root = packed_stored_client_sync_committee
+        .sync_committee_root()
+        .map_err(|_| SyncCommitteeUpdateError::BlsPublicKeyBytesError)?;
+    let root = packed_client_sync_committee
+        .sync_committee_root()
+        .map_err(|_| SyncCommitteeUpdateError::InvalidNextSyncCommitteePubkey)?;
+    Ok(stored_root != root)
+}
+
 impl core::ClientBootstrap {
     /// Verifies the corresponding packed client sync committee.
     ///
     /// References:
     /// - [`initialize_light_client_store`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#initialize_light_client_store)
-    pub fn verify_packed_client_sync_committee(
+    pub fn verify_packed_client_sync_committee<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        packed_client_sync_committee: P,
+    ) -> Result<(), ClientBootstrapError> {
+        self.verify_packed_client_sync_committee_with_config(
+            &ChainConfig::MAINNET,
+            packed_client_sync_committee,
+        )
+    }
+
+    /// Same as [`Self::verify_packed_client_sync_committee`], with the preset of the chain.
+    pub fn verify_packed_client_sync_committee_with_config<
+        'r,
+        P: bls::PackedClientSyncCommittee<'r>,
+    >(
         &self,
-        packed_client_sync_committee: packed::ClientSyncCommitteeReader,
+        config: &ChainConfig,
+        packed_client_sync_committee: P,
     ) -> Result<(), ClientBootstrapError> {
         //
         // Verify Self
@@ -95,27 +519,33 @@ impl core::ClientBootstrap {
         // Verify Sync Committee
         //
 
-        let client_sync_committee = packed_client_sync_committee.unpack();
-        let expected_period = helpers::compute_sync_committee_period_at_slot(self.header.slot);
-        if expected_period != client_sync_committee.period {
+        let expected_period = config.compute_sync_committee_period_at_slot(self.header.slot);
+        let actual_period = packed_client_sync_committee.sync_committee_period();
+        if expected_period != actual_period {
             warn!(
                 "failed: period is expected to be {expected_period} \
-                since slot is {}, but actual is {}",
-                self.header.slot, client_sync_committee.period,
+                since slot is {}, but actual is {actual_period}",
+                self.header.slot,
             );
             return Err(ClientBootstrapError::IncorrectPeriod);
         }
-        if client_sync_committee.data.pubkeys.len() != forks::altair::SYNC_COMMITTEE_SIZE {
+        let sync_committee_root =
+            packed_client_sync_committee
+                .sync_committee_root()
+                .map_err(|_| {
+                    error!("failed: sync committee has invalid public keys");
+                    ClientBootstrapError::InvalidSyncCommitteePubkey
+                })?;
+        let pubkeys_count = packed_client_sync_committee.pubkeys_count();
+        if pubkeys_count != config.sync_committee_size {
             error!(
-                "failed: sync committee size is expected to be {}, but actual is {}.",
-                forks::altair::SYNC_COMMITTEE_SIZE,
-                client_sync_committee.data.pubkeys.len(),
+                "failed: sync committee size is expected to be {}, but actual is {pubkeys_count}.",
+                config.sync_committee_size,
             );
             return Err(ClientBootstrapError::UnexpectedSyncCommitteeSize);
         }
-        let sync_committee_root = client_sync_committee.data.tree_hash_root();
         let (depth, index) =
-            specs::get_depth_and_index_from_current_sync_committee_index(self.header.slot);
+            specs::get_depth_and_index_from_current_sync_committee_index(config, self.header.slot);
         if !ssz::is_valid_merkle_branch(
             &sync_committee_root,
             &self.current_sync_committee_branch,
@@ -148,13 +578,180 @@ impl core::ClientUpdate {
     /// Verifies the new client and the update that make it be upgrade from the old client.
     ///
     /// N.B. `client_sync_committee` should be checked.
-    pub fn verify_client_update(
+    pub fn verify_client_update<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.verify_client_update_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_update`], with the preset and the fork schedule of the
+    /// chain.
+    pub fn verify_client_update_with_config<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.parts().verify_client_update(
+            config,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    // Verifies the update, then derives the new client from the old client.
+    fn verify_and_derive_new_client<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+    ) -> Result<core::Client, ClientUpdateError> {
+        self.parts().verify_and_derive_new_client(
+            config,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+        )
+    }
+
+    /// Verifies the new client and the force update that make it be upgrade from the old
+    /// client, when the finality is stalled.
+    ///
+    /// The attested header is applied as the last header without the finality proof, so the
+    /// finality branch should be empty.
+    /// The force update is allowed only when it's enabled in the client info, and the attested
+    /// header is at least `force_update_timeout` slots after the last finalized header, or after
+    /// the last force updated header if there is one.
+    ///
+    /// N.B. `client_sync_committee` should be checked.
+    ///
+    /// References:
+    /// - [`process_light_client_store_force_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#process_light_client_store_force_update)
+    pub fn verify_client_force_update<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        old_client: core::Client,
+        client_info: core::ClientInfo,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.verify_client_force_update_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            client_info,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_force_update`], with the preset and the fork schedule of
+    /// the chain.
+    pub fn verify_client_force_update_with_config<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        client_info: core::ClientInfo,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.parts().verify_client_force_update(
+            config,
+            old_client,
+            client_info,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    fn parts(&self) -> ClientUpdateParts<'_> {
+        ClientUpdateParts {
+            attested_header: self.attested_header.clone(),
+            finality_branch: self.finality_branch.clone(),
+            sync_aggregate: self.sync_aggregate.clone(),
+            signature_slot: self.signature_slot,
+            new_headers_mmr_proof: self
+                .new_headers_mmr_proof
+                .iter()
+                .map(|item| item.pack())
+                .collect(),
+            headers: HeaderRangeRef::Owned(&self.headers),
+        }
+    }
+}
+
+// The parts of a client update which are required by the verification.
+//
+// The headers, which are the most part of a client update, are borrowed from an unpacked client
+// update or a packed one, so a packed client update could be verified without being unpacked.
+pub(super) struct ClientUpdateParts<'a> {
+    pub(super) attested_header: core::Header,
+    pub(super) finality_branch: core::SszProof,
+    pub(super) sync_aggregate: core::SyncAggregate,
+    pub(super) signature_slot: u64,
+    pub(super) new_headers_mmr_proof: Vec<packed::HeaderDigest>,
+    pub(super) headers: HeaderRangeRef<'a>,
+}
+
+pub(super) enum HeaderRangeRef<'a> {
+    Owned(&'a core::HeaderRange),
+    Packed(packed::HeaderRangeReader<'a>),
+}
+
+impl HeaderRangeRef<'_> {
+    fn calc_cache(
+        &self,
+        parent_root: core::Hash,
+        first_slot: u64,
+        maximal_slot: u64,
+    ) -> Result<Vec<mmr::HeaderWithCache>, ClientUpdateError> {
+        match self {
+            Self::Owned(headers) => headers.calc_cache(parent_root, first_slot, maximal_slot),
+            Self::Packed(headers) => headers.calc_cache(parent_root, first_slot, maximal_slot),
+        }
+    }
+}
+
+impl ClientUpdateParts<'_> {
+    // Verifies the new client and the update.
+    pub(super) fn verify_client_update<'r, P: bls::PackedClientSyncCommittee<'r>>(
         &self,
+        config: &ChainConfig,
         old_client: core::Client,
         genesis_validators_root: core::Hash,
-        packed_client_sync_committee: packed::ClientSyncCommitteeReader,
+        packed_client_sync_committee: P,
         new_client: core::Client,
     ) -> Result<(), ClientUpdateError> {
+        let expected_new_client = self.verify_and_derive_new_client(
+            config,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+        )?;
+        check_new_client(&expected_new_client, &new_client)
+    }
+
+    // Verifies the update, then derives the new client from the old client.
+    fn verify_and_derive_new_client<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+    ) -> Result<core::Client, ClientUpdateError> {
         //
         // Verify Self
         //
@@ -174,7 +771,7 @@ impl core::ClientUpdate {
         // Verify Headers
         //
 
-        let finalized_header = self.verify_headers_and_new_client(old_client, new_client)?;
+        let (new_client, finalized_header) = self.derive_new_client(&old_client, false)?;
         if finalized_header.is_empty() {
             return Err(ClientUpdateError::FinalizedHeaderIsEmpty);
         }
@@ -183,14 +780,14 @@ impl core::ClientUpdate {
         // Check Sync Committee
         //
 
-        let client_sync_committee = packed_client_sync_committee.unpack();
-        let signature_period = helpers::compute_sync_committee_period_at_slot(self.signature_slot);
-        if client_sync_committee.period != signature_period {
+        let client_sync_committee_period = packed_client_sync_committee.sync_committee_period();
+        let signature_period = config.compute_sync_committee_period_at_slot(self.signature_slot);
+        if client_sync_committee_period != signature_period {
             warn!(
                 "failed: no matched client sync comittee, \
                 signature period is {signature_period} (slot: {}), \
-                but client sync committee period is {}",
-                self.signature_slot, client_sync_committee.period,
+                but client sync committee period is {client_sync_committee_period}",
+                self.signature_slot,
             );
             return Err(ClientUpdateError::MismatchedSyncCommittee);
         }
@@ -199,42 +796,14 @@ impl core::ClientUpdate {
         // Verify the Signature with Current Sync Committee
         //
 
-        if !self.sync_aggregate.has_supermajority() {
-            return Err(ClientUpdateError::NotSupermajorityParticipation);
-        }
-        let attested_root = self.attested_header.tree_hash_root();
-        let message = bls::compute_signing_root_at_signature_slot(
-            attested_root,
+        verify_attested_header_signature(
+            config,
+            &self.attested_header,
+            &self.sync_aggregate,
             self.signature_slot,
-            &forks::altair::DOMAIN_SYNC_COMMITTEE,
             genesis_validators_root,
-        );
-        let pubkeys = client_sync_committee
-            .data
-            .decompress_all_pubkeys()
-            .map_err(|_| ClientUpdateError::BlsPublicKeyBytesError)?;
-        let is_verified = self
-            .sync_aggregate
-            .fast_aggregate_verify(&pubkeys, message)
-            .map_err(|_| ClientUpdateError::BlsAggregateSignatureError)?;
-        if !is_verified {
-            warn!(
-                "failed: verify the signature for attested header, \
-                signature slot: {}, current sync committee (period: {}), \
-                attested root: {attested_root:#x}, \
-                genesis validators root: {genesis_validators_root:#x}",
-                self.signature_slot, client_sync_committee.period
-            );
-            return Err(ClientUpdateError::FailedToVerifyTheAttestedHeader);
-        } else {
-            debug!(
-                "passed: verify the signature for attested header, \
-                signature slot: {}, current sync committee (period: {}), \
-                attested root: {attested_root:#x}, \
-                genesis validators root: {genesis_validators_root:#x}",
-                self.signature_slot, client_sync_committee.period
-            );
-        }
+            &packed_client_sync_committee,
+        )?;
 
         //
         // Verify Finality Header
@@ -248,7 +817,7 @@ impl core::ClientUpdate {
             return Err(ClientUpdateError::FinalizedShouldBeAfterAttested);
         }
         let (depth, index) =
-            specs::get_depth_and_index_from_finalized_root_index(self.attested_header.slot);
+            specs::get_depth_and_index_from_finalized_root_index(config, self.attested_header.slot);
         if !ssz::is_valid_merkle_branch(
             &finalized_header.root,
             &self.finality_branch,
@@ -273,30 +842,140 @@ impl core::ClientUpdate {
                 self.finality_branch.len(),
             );
         }
-        Ok(())
+        Ok(new_client)
     }
 
-    fn verify_headers_and_new_client(
+    // Verifies the new client and the force update.
+    pub(super) fn verify_client_force_update<'r, P: bls::PackedClientSyncCommittee<'r>>(
         &self,
-        client: core::Client,
+        config: &ChainConfig,
+        old_client: core::Client,
+        client_info: core::ClientInfo,
+        packed_client_sync_committee: P,
         new_client: core::Client,
-    ) -> Result<mmr::HeaderWithCache, ClientUpdateError> {
-        if self.headers.is_empty() {
+    ) -> Result<(), ClientUpdateError> {
+        //
+        // Verify Self
+        //
+
+        if self.attested_header.is_empty() {
+            return Err(ClientUpdateError::AttestedHeaderIsEmpty);
+        }
+        if self.attested_header.slot >= self.signature_slot {
+            error!(
+                "failed: attested slot ({}) should be less than signature slot ({})",
+                self.attested_header.slot, self.signature_slot
+            );
+            return Err(ClientUpdateError::BadSignatureSlot);
+        }
+
+        //
+        // Check Force Update
+        //
+
+        if client_info.force_update_timeout == 0 {
+            error!("failed: force update is disabled");
+            return Err(ClientUpdateError::ForceUpdateIsDisabled);
+        }
+        // The timeout is restarted by each force update, so force updates can not be chained on
+        // every slot after the first timeout.
+        let timeout_start_slot = old_client
+            .last_force_update_slot()
+            .unwrap_or(old_client.finalized_slot);
+        let timeout_slot = timeout_start_slot.saturating_add(client_info.force_update_timeout);
+        if self.attested_header.slot < timeout_slot {
+            error!(
+                "failed: attested slot ({}) is before the force update timeout \
+                (finalized slot: {}, timeout start slot: {timeout_start_slot}, timeout: {})",
+                self.attested_header.slot,
+                old_client.finalized_slot,
+                client_info.force_update_timeout
+            );
+            return Err(ClientUpdateError::ForceUpdateBeforeTimeout);
+        }
+        if !self.finality_branch.is_empty() {
+            error!(
+                "failed: finality branch should be empty for force update, but its size is {}",
+                self.finality_branch.len()
+            );
+            return Err(ClientUpdateError::UnexpectedFinalityBranch);
+        }
+
+        //
+        // Verify Headers
+        //
+
+        let (expected_new_client, last_header) = self.derive_new_client(&old_client, true)?;
+        check_new_client(&expected_new_client, &new_client)?;
+        let attested_root = self.attested_header.tree_hash_root();
+        if last_header.root != attested_root {
+            error!(
+                "failed: the last header ({last_header}) should be the attested header \
+                ({attested_root:#x})"
+            );
+            return Err(ClientUpdateError::ForceUpdatedHeaderIsNotAttested);
+        }
+
+        //
+        // Check Sync Committee
+        //
+
+        let client_sync_committee_period = packed_client_sync_committee.sync_committee_period();
+        let signature_period = config.compute_sync_committee_period_at_slot(self.signature_slot);
+        if client_sync_committee_period != signature_period {
+            warn!(
+                "failed: no matched client sync comittee, \
+                signature period is {signature_period} (slot: {}), \
+                but client sync committee period is {client_sync_committee_period}",
+                self.signature_slot,
+            );
+            return Err(ClientUpdateError::MismatchedSyncCommittee);
+        }
+
+        //
+        // Verify the Signature with Current Sync Committee
+        //
+
+        verify_attested_header_signature(
+            config,
+            &self.attested_header,
+            &self.sync_aggregate,
+            self.signature_slot,
+            client_info.genesis_validators_root,
+            &packed_client_sync_committee,
+        )?;
+
+        Ok(())
+    }
+
+    // Verifies the headers, then derives the new client from them.
+    //
+    // Returns the new client and the last header.
+    fn derive_new_client(
+        &self,
+        client: &core::Client,
+        is_force_update: bool,
+    ) -> Result<(core::Client, mmr::HeaderWithCache), ClientUpdateError> {
+        // The last header is the finalized header or the attested header.
+        let cached_headers = self.headers.calc_cache(
+            client.tip_header_root,
+            client.maximal_slot + 1,
+            self.attested_header.slot,
+        )?;
+        if cached_headers.is_empty() {
             error!("failed: client update has no headers");
             return Err(ClientUpdateError::EmptyHeaders);
         }
 
-        let headers_count = self.headers.len();
-        info!(
-            "update client with headers (len: {headers_count}), \
-            client: {client}, new client: {new_client}"
-        );
+        let headers_count = cached_headers.len();
+        info!("update client with headers (len: {headers_count}), client: {client}");
 
-        let mut headers_iter = self.headers.iter();
-        let mut curr_cached_header = headers_iter.next().cloned().unwrap().calc_cache();
+        let mut headers_iter = cached_headers.into_iter();
+        let mut curr_cached_header = headers_iter.next().unwrap();
         let mut prev_cached_header: mmr::HeaderWithCache;
         let mut curr_tip_valid_header_root: core::Hash;
         let mut header_mmr_index: u64;
+        let first_header_mmr_index: u64;
         let mut digests = Vec::with_capacity(headers_count);
 
         // Check First Header with the Old Client
@@ -326,6 +1005,7 @@ impl core::ClientUpdate {
             };
 
             header_mmr_index = curr_cached_header.inner.slot - client.minimal_slot;
+            first_header_mmr_index = header_mmr_index;
 
             trace!(
                 "first header (slot: {}) in MMR on index {header_mmr_index}",
@@ -339,8 +1019,8 @@ impl core::ClientUpdate {
         }
 
         // Check if headers are continuous
-        for header in headers_iter {
-            curr_cached_header = header.clone().calc_cache();
+        for cached_header in headers_iter {
+            curr_cached_header = cached_header;
 
             trace!(
                 "current valid header root: {curr_tip_valid_header_root:#x}, \
@@ -381,72 +1061,259 @@ impl core::ClientUpdate {
         let new_maximal_slot = prev_cached_header.inner.slot;
 
         // Check MMR Root
-        {
+        let new_headers_mmr_root = {
             let proof: mmr::MMRProof = {
                 let max_index = new_maximal_slot - client.minimal_slot;
                 let mmr_size = leaf_index_to_mmr_size(max_index);
                 debug!("check MMR root with size: {mmr_size}, max-index: {max_index}");
-                // TODO optimize
-                let proof = self
-                    .new_headers_mmr_proof
-                    .iter()
-                    .map(|item| item.pack())
-                    .collect::<Vec<_>>();
-                mmr::MMRProof::new(mmr_size, proof)
+                mmr::MMRProof::new(mmr_size, self.new_headers_mmr_proof.clone())
             };
-            let result = proof
-                .verify_incremental(
-                    new_client.headers_mmr_root.pack(),
-                    client.headers_mmr_root.pack(),
+            let result = profile!(
+                Mmr,
+                mmr::calculate_incremental_root(
+                    &proof,
+                    &client.headers_mmr_root.pack(),
+                    first_header_mmr_index,
                     digests,
                 )
-                .map_err(|_| ClientUpdateError::MmrError)?;
-            if !result {
-                warn!(
-                    "failed: verify MMR proof for headers between {} and {new_maximal_slot}",
+            )
+            .map_err(|_| ClientUpdateError::MmrError);
+            if let Some(root) = result? {
+                debug!(
+                    "passed: verify MMR proof for headers between {} and {new_maximal_slot}",
                     client.maximal_slot + 1
                 );
-                return Err(ClientUpdateError::HeadersMmrProof);
+                root
             } else {
-                debug!(
-                    "passed: verify MMR proof for headers between {} and {new_maximal_slot}",
+                warn!(
+                    "failed: verify MMR proof for headers between {} and {new_maximal_slot}",
                     client.maximal_slot + 1
                 );
+                return Err(ClientUpdateError::HeadersMmrProof);
             }
-        }
+        };
 
-        // Check New Client
-        if new_client.id != client.id {
-            error!(
-                "failed: new client id has been changed ({} -> {})",
-                client.id, new_client.id
-            );
-            return Err(ClientUpdateError::ClientIdChanged);
-        }
-        if new_client.minimal_slot != client.minimal_slot {
-            error!(
-                "failed: new client minimal slot has been changed ({} -> {})",
-                client.minimal_slot, new_client.minimal_slot
-            );
-            return Err(ClientUpdateError::ClientMinimalSlotChanged);
-        }
-        if new_client.maximal_slot != new_maximal_slot {
-            error!(
-                "failed: new client maximal slot ({}) is incorrect, expect {new_maximal_slot}",
-                new_client.maximal_slot
+        // The optimistic header is kept only when it's still newer than the finalized headers.
+        let (optimistic_slot, optimistic_header_root) = if client.optimistic_slot > new_maximal_slot
+        {
+            (client.optimistic_slot, client.optimistic_header_root)
+        } else {
+            (new_maximal_slot, curr_tip_valid_header_root)
+        };
+        // The headers which are applied by a force update are not finalized.
+        let finalized_slot = if is_force_update {
+            client.finalized_slot
+        } else {
+            new_maximal_slot
+        };
+        let new_client = core::Client {
+            id: client.id,
+            minimal_slot: client.minimal_slot,
+            maximal_slot: new_maximal_slot,
+            tip_header_root: curr_tip_valid_header_root,
+            headers_mmr_root: new_headers_mmr_root.unpack(),
+            optimistic_slot,
+            optimistic_header_root,
+            finalized_slot,
+        };
+
+        Ok((new_client, prev_cached_header))
+    }
+}
+
+// Checks the new client against the expected new client which is derived from the update.
+fn check_new_client(
+    expected: &core::Client,
+    new_client: &core::Client,
+) -> Result<(), ClientUpdateError> {
+    if new_client.id != expected.id {
+        error!(
+            "failed: new client id has been changed ({} -> {})",
+            expected.id, new_client.id
+        );
+        return Err(ClientUpdateError::ClientIdChanged);
+    }
+    if new_client.minimal_slot != expected.minimal_slot {
+        error!(
+            "failed: new client minimal slot has been changed ({} -> {})",
+            expected.minimal_slot, new_client.minimal_slot
+        );
+        return Err(ClientUpdateError::ClientMinimalSlotChanged);
+    }
+    if new_client.maximal_slot != expected.maximal_slot {
+        error!(
+            "failed: new client maximal slot ({}) is incorrect, expect {}",
+            new_client.maximal_slot, expected.maximal_slot
+        );
+        return Err(ClientUpdateError::ClientMaximalSlot);
+    }
+    if new_client.tip_header_root != expected.tip_header_root {
+        error!(
+            "failed: new client tip valid header root ({:#x}) is incorrect, expect {:#x}",
+            new_client.tip_header_root, expected.tip_header_root
+        );
+        return Err(ClientUpdateError::ClientTipHeaderRoot);
+    }
+    if new_client.headers_mmr_root.children_hash != expected.headers_mmr_root.children_hash {
+        warn!(
+            "failed: new client headers MMR root ({:#x}) is incorrect, expect {:#x}",
+            new_client.headers_mmr_root.children_hash, expected.headers_mmr_root.children_hash
+        );
+        return Err(ClientUpdateError::HeadersMmrProof);
+    }
+    if new_client.optimistic_slot != expected.optimistic_slot {
+        error!(
+            "failed: new client optimistic slot ({}) is incorrect, expect {}",
+            new_client.optimistic_slot, expected.optimistic_slot
+        );
+        return Err(ClientUpdateError::ClientOptimisticSlot);
+    }
+    if new_client.optimistic_header_root != expected.optimistic_header_root {
+        error!(
+            "failed: new client optimistic header root ({:#x}) is incorrect, expect {:#x}",
+            new_client.optimistic_header_root, expected.optimistic_header_root
+        );
+        return Err(ClientUpdateError::ClientOptimisticHeaderRoot);
+    }
+    if new_client.finalized_slot != expected.finalized_slot {
+        error!(
+            "failed: new client finalized slot ({}) is incorrect, expect {}",
+            new_client.finalized_slot, expected.finalized_slot
+        );
+        return Err(ClientUpdateError::ClientFinalizedSlot);
+    }
+    Ok(())
+}
+
+impl core::ClientOptimisticUpdate {
+    /// Verifies the new client which only has a new optimistic header and the update that make
+    /// it be upgrade from the old client.
+    ///
+    /// Only the signature for the attested header is verified, so the finalized part of the
+    /// client should not be changed.
+    ///
+    /// N.B. `client_sync_committee` should be checked.
+    ///
+    /// References:
+    /// - [`process_light_client_optimistic_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#process_light_client_optimistic_update)
+    pub fn verify_client_optimistic_update<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientOptimisticUpdateError> {
+        self.verify_client_optimistic_update_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_optimistic_update`], with the preset and the fork schedule
+    /// of the chain.
+    pub fn verify_client_optimistic_update_with_config<
+        'r,
+        P: bls::PackedClientSyncCommittee<'r>,
+    >(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientOptimisticUpdateError> {
+        //
+        // Verify Self
+        //
+
+        if self.attested_header.is_empty() {
+            return Err(ClientOptimisticUpdateError::AttestedHeaderIsEmpty);
+        }
+        if self.attested_header.slot >= self.signature_slot {
+            error!(
+                "failed: attested slot ({}) should be less than signature slot ({})",
+                self.attested_header.slot, self.signature_slot
+            );
+            return Err(ClientOptimisticUpdateError::BadSignatureSlot);
+        }
+
+        //
+        // Check Clients
+        //
+
+        if self.attested_header.slot <= old_client.maximal_slot
+            || self.attested_header.slot <= old_client.optimistic_slot
+        {
+            error!(
+                "failed: attested header ({}) should be newer than the client ({old_client})",
+                self.attested_header
             );
-            return Err(ClientUpdateError::ClientMaximalSlot);
+            return Err(ClientOptimisticUpdateError::AttestedHeaderIsNotNewer);
         }
-        if new_client.tip_header_root != curr_tip_valid_header_root {
+        if new_client.id != old_client.id
+            || new_client.minimal_slot != old_client.minimal_slot
+            || new_client.maximal_slot != old_client.maximal_slot
+            || new_client.tip_header_root != old_client.tip_header_root
+            || new_client.finalized_slot != old_client.finalized_slot
+            || new_client.headers_mmr_root.children_hash
+                != old_client.headers_mmr_root.children_hash
+        {
+            error!(
+                "failed: the finalized part of the client should not be changed \
+                ({old_client} -> {new_client})"
+            );
+            return Err(ClientOptimisticUpdateError::ClientFinalizedPartChanged);
+        }
+        let attested_root = self.attested_header.tree_hash_root();
+        if new_client.optimistic_slot != self.attested_header.slot {
+            error!(
+                "failed: new client optimistic slot ({}) is incorrect, expect {}",
+                new_client.optimistic_slot, self.attested_header.slot
+            );
+            return Err(ClientOptimisticUpdateError::ClientOptimisticSlot);
+        }
+        if new_client.optimistic_header_root != attested_root {
             error!(
-                "failed: new client tip valid header root ({:#x}) is incorrect, \
-                expect {curr_tip_valid_header_root}",
-                new_client.tip_header_root
+                "failed: new client optimistic header root ({:#x}) is incorrect, \
+                expect {attested_root:#x}",
+                new_client.optimistic_header_root
             );
-            return Err(ClientUpdateError::ClientTipHeaderRoot);
+            return Err(ClientOptimisticUpdateError::ClientOptimisticHeaderRoot);
         }
 
-        Ok(prev_cached_header)
+        //
+        // Check Sync Committee
+        //
+
+        let client_sync_committee_period = packed_client_sync_committee.sync_committee_period();
+        let signature_period = config.compute_sync_committee_period_at_slot(self.signature_slot);
+        if client_sync_committee_period != signature_period {
+            warn!(
+                "failed: no matched client sync comittee, \
+                signature period is {signature_period} (slot: {}), \
+                but client sync committee period is {client_sync_committee_period}",
+                self.signature_slot,
+            );
+            return Err(ClientOptimisticUpdateError::MismatchedSyncCommittee);
+        }
+
+        //
+        // Verify the Signature with Current Sync Committee
+        //
+
+        verify_attested_header_signature(
+            config,
+            &self.attested_header,
+            &self.sync_aggregate,
+            self.signature_slot,
+            genesis_validators_root,
+            &packed_client_sync_committee,
+        )?;
+
+        Ok(())
     }
 }
 
@@ -454,14 +1321,47 @@ impl core::SyncCommitteeUpdate {
     /// Verifies the packed next client sync committee with maximal slot in the last client and
     /// the packed current client sync committee.
     ///
+    /// The signature should be signed in the same period as the current client sync committee.
+    /// For the signature which is signed in the next period, use
+    /// [`Self::verify_packed_client_sync_committee_signed_in_next_period`] instead.
+    ///
+    /// The current and the next client sync committees could be stored in different encodings.
+    ///
     /// N.B. `current_client_sync_committee` should be checked.
-    pub fn verify_packed_client_sync_committee(
+    pub fn verify_packed_client_sync_committee<'r, 'n, P, N>(
         &self,
         maximal_slot_in_last_client: u64,
         genesis_validators_root: core::Hash,
-        packed_current_client_sync_committee: packed::ClientSyncCommitteeReader,
-        packed_next_client_sync_committee: packed::ClientSyncCommitteeReader,
-    ) -> Result<(), SyncCommitteeUpdateError> {
+        packed_current_client_sync_committee: P,
+        packed_next_client_sync_committee: N,
+    ) -> Result<(), SyncCommitteeUpdateError>
+    where
+        P: bls::PackedClientSyncCommittee<'r>,
+        N: bls::PackedClientSyncCommittee<'n>,
+    {
+        self.verify_packed_client_sync_committee_with_config(
+            &ChainConfig::MAINNET,
+            maximal_slot_in_last_client,
+            genesis_validators_root,
+            packed_current_client_sync_committee,
+            packed_next_client_sync_committee,
+        )
+    }
+
+    /// Same as [`Self::verify_packed_client_sync_committee`], with the preset and the fork
+    /// schedule of the chain.
+    pub fn verify_packed_client_sync_committee_with_config<'r, 'n, P, N>(
+        &self,
+        config: &ChainConfig,
+        maximal_slot_in_last_client: u64,
+        genesis_validators_root: core::Hash,
+        packed_current_client_sync_committee: P,
+        packed_next_client_sync_committee: N,
+    ) -> Result<(), SyncCommitteeUpdateError>
+    where
+        P: bls::PackedClientSyncCommittee<'r>,
+        N: bls::PackedClientSyncCommittee<'n>,
+    {
         //
         // Verify Self
         //
@@ -482,23 +1382,22 @@ impl core::SyncCommitteeUpdate {
         //
 
         let last_client_period =
-            helpers::compute_sync_committee_period_at_slot(maximal_slot_in_last_client);
-        let current_client_sync_committee = packed_current_client_sync_committee.unpack();
-        if current_client_sync_committee.period != last_client_period {
+            config.compute_sync_committee_period_at_slot(maximal_slot_in_last_client);
+        let current_period = packed_current_client_sync_committee.sync_committee_period();
+        if current_period != last_client_period {
             error!(
-                "failed: current client sync committee period ({}) is not same as \
-                the maximal period ({}) in the last client (slot: {maximal_slot_in_last_client})",
-                current_client_sync_committee.period, last_client_period
+                "failed: current client sync committee period ({current_period}) is not same as \
+                the maximal period ({last_client_period}) in the last client \
+                (slot: {maximal_slot_in_last_client})"
             );
             return Err(SyncCommitteeUpdateError::BadCurrentPeriod);
         }
-        let signature_period = helpers::compute_sync_committee_period_at_slot(self.signature_slot);
-        // TODO Confirmation Required: if next sync committee could be signed in next period?
-        if current_client_sync_committee.period != signature_period {
+        let signature_period = config.compute_sync_committee_period_at_slot(self.signature_slot);
+        if current_period != signature_period {
             warn!(
                 "failed: signature (slot: {}, period: {signature_period}) \
-                could NOT be verified with current sync committee (period: {})",
-                self.signature_slot, current_client_sync_committee.period
+                could NOT be verified with current sync committee (period: {current_period})",
+                self.signature_slot
             );
             return Err(SyncCommitteeUpdateError::SignatureInNextPeriod);
         }
@@ -507,66 +1406,218 @@ impl core::SyncCommitteeUpdate {
         // Verify the Signature with Current Sync Committee
         //
 
-        if !self.sync_aggregate.has_supermajority() {
-            return Err(SyncCommitteeUpdateError::NotSupermajorityParticipation);
-        }
-        let attested_root = self.attested_header.tree_hash_root();
-        let message = bls::compute_signing_root_at_signature_slot(
-            attested_root,
+        verify_attested_header_signature(
+            config,
+            &self.attested_header,
+            &self.sync_aggregate,
             self.signature_slot,
-            &forks::altair::DOMAIN_SYNC_COMMITTEE,
             genesis_validators_root,
-        );
-        let pubkeys = current_client_sync_committee
-            .data
-            .decompress_all_pubkeys()
-            .map_err(|_| SyncCommitteeUpdateError::BlsPublicKeyBytesError)?;
-        let is_verified = self
-            .sync_aggregate
-            .fast_aggregate_verify(&pubkeys, message)
-            .map_err(|_| SyncCommitteeUpdateError::BlsAggregateSignatureError)?;
-        if !is_verified {
+            &packed_current_client_sync_committee,
+        )?;
+
+        //
+        // Verify Next Sync Committee
+        //
+
+        let next_period = packed_next_client_sync_committee.sync_committee_period();
+        if current_period + 1 != next_period {
+            error!(
+                "failed: periods are not continuous (current: {current_period}, next: {next_period})"
+            );
+            return Err(SyncCommitteeUpdateError::NoncontinuousPeriods);
+        }
+        self.verify_next_sync_committee_branch(config, packed_next_client_sync_committee)
+    }
+
+    /// Verifies the packed next client sync committee with maximal slot in the last client,
+    /// when the signature is signed in the next period of the last client.
+    ///
+    /// The signature is verified with the stored next client sync committee, which is in the
+    /// next period of the current client sync committee.
+    ///
+    /// - If the attested header is in the period of the current client sync committee, the
+    ///   update proves the stored next client sync committee again, so the packed next client
+    ///   sync committee should be same as the stored one.
+    /// - If the attested header is in the period of the stored next client sync committee, the
+    ///   update proves the client sync committee after the stored next one.
+    ///
+    /// The current, the stored next and the next client sync committees could be stored in
+    /// different encodings.
+    ///
+    /// N.B. `current_client_sync_committee` and `stored_next_client_sync_committee` should be
+    /// checked.
+    ///
+    /// References:
+    /// - [`validate_light_client_update`](https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/altair/light-client/sync-protocol.md#validate_light_client_update)
+    pub fn verify_packed_client_sync_committee_signed_in_next_period<'r, 's, 'n, P, S, N>(
+        &self,
+        maximal_slot_in_last_client: u64,
+        genesis_validators_root: core::Hash,
+        packed_current_client_sync_committee: P,
+        packed_stored_next_client_sync_committee: S,
+        packed_next_client_sync_committee: N,
+    ) -> Result<(), SyncCommitteeUpdateError>
+    where
+        P: bls::PackedClientSyncCommittee<'r>,
+        S: bls::PackedClientSyncCommittee<'s>,
+        N: bls::PackedClientSyncCommittee<'n>,
+    {
+        self.verify_packed_client_sync_committee_signed_in_next_period_with_config(
+            &ChainConfig::MAINNET,
+            maximal_slot_in_last_client,
+            genesis_validators_root,
+            packed_current_client_sync_committee,
+            packed_stored_next_client_sync_committee,
+            packed_next_client_sync_committee,
+        )
+    }
+
+    /// Same as [`Self::verify_packed_client_sync_committee_signed_in_next_period`], with the
+    /// preset and the fork schedule of the chain.
+    pub fn verify_packed_client_sync_committee_signed_in_next_period_with_config<
+        'r,
+        's,
+        'n,
+        P,
+        S,
+        N,
+    >(
+        &self,
+        config: &ChainConfig,
+        maximal_slot_in_last_client: u64,
+        genesis_validators_root: core::Hash,
+        packed_current_client_sync_committee: P,
+        packed_stored_next_client_sync_committee: S,
+        packed_next_client_sync_committee: N,
+    ) -> Result<(), SyncCommitteeUpdateError>
+    where
+        P: bls::PackedClientSyncCommittee<'r>,
+        S: bls::PackedClientSyncCommittee<'s>,
+        N: bls::PackedClientSyncCommittee<'n>,
+    {
+        //
+        // Verify Self
+        //
+
+        if self.attested_header.is_empty() {
+            return Err(SyncCommitteeUpdateError::AttestedHeaderIsEmpty);
+        }
+        if self.attested_header.slot >= self.signature_slot {
+            error!(
+                "failed: attested slot ({}) should be less than signature slot ({})",
+                self.attested_header.slot, self.signature_slot
+            );
+            return Err(SyncCommitteeUpdateError::BadSignatureSlot);
+        }
+
+        //
+        // Check Current Sync Committee
+        //
+
+        let last_client_period =
+            config.compute_sync_committee_period_at_slot(maximal_slot_in_last_client);
+        let current_period = packed_current_client_sync_committee.sync_committee_period();
+        if current_period != last_client_period {
+            error!(
+                "failed: current client sync committee period ({current_period}) is not same as \
+                the maximal period ({last_client_period}) in the last client \
+                (slot: {maximal_slot_in_last_client})"
+            );
+            return Err(SyncCommitteeUpdateError::BadCurrentPeriod);
+        }
+        let stored_next_period = packed_stored_next_client_sync_committee.sync_committee_period();
+        if current_period + 1 != stored_next_period {
+            error!(
+                "failed: stored next client sync committee period ({stored_next_period}) \
+                is not the next period of current client sync committee period ({current_period})"
+            );
+            return Err(SyncCommitteeUpdateError::BadStoredNextPeriod);
+        }
+        let signature_period = config.compute_sync_committee_period_at_slot(self.signature_slot);
+        if stored_next_period != signature_period {
             warn!(
-                "failed: verify the signature for attested header, \
-                signature slot: {}, current sync committee (period: {}), \
-                attested root: {attested_root:#x}, \
-                genesis validators_root: {genesis_validators_root:#x}",
-                self.signature_slot, current_client_sync_committee.period
+                "failed: signature (slot: {}, period: {signature_period}) \
+                could NOT be verified with stored next sync committee \
+                (period: {stored_next_period})",
+                self.signature_slot
             );
-            return Err(SyncCommitteeUpdateError::FailedToVerifyTheAttestedHeader);
-        } else {
-            debug!(
-                "passed: verify the signature for attested header, \
-                signature slot: {}, current sync committee (period: {}), \
-                attested root: {attested_root:#x}, \
-                genesis validators_root: {genesis_validators_root:#x}",
-                self.signature_slot, current_client_sync_committee.period
+            return Err(SyncCommitteeUpdateError::SignatureNotInNextPeriod);
+        }
+        let attested_period =
+            config.compute_sync_committee_period_at_slot(self.attested_header.slot);
+        if attested_period != current_period && attested_period != signature_period {
+            error!(
+                "failed: attested header (slot: {}, period: {attested_period}) should be \
+                in period {current_period} or {signature_period}",
+                self.attested_header.slot
             );
+            return Err(SyncCommitteeUpdateError::BadAttestedPeriod);
         }
 
+        //
+        // Verify the Signature with Stored Next Sync Committee
+        //
+
+        verify_attested_header_signature(
+            config,
+            &self.attested_header,
+            &self.sync_aggregate,
+            self.signature_slot,
+            genesis_validators_root,
+            &packed_stored_next_client_sync_committee,
+        )?;
+
         //
         // Verify Next Sync Committee
         //
 
-        let next_client_sync_committee = packed_next_client_sync_committee.unpack();
-        if current_client_sync_committee.period + 1 != next_client_sync_committee.period {
+        let next_period = packed_next_client_sync_committee.sync_committee_period();
+        if attested_period + 1 != next_period {
             error!(
-                "failed: periods are not continuous (current: {}, next: {})",
-                current_client_sync_committee.period, next_client_sync_committee.period
+                "failed: periods are not continuous (attested: {attested_period}, \
+                next: {next_period})"
             );
             return Err(SyncCommitteeUpdateError::NoncontinuousPeriods);
         }
-        if next_client_sync_committee.data.pubkeys.len() != forks::altair::SYNC_COMMITTEE_SIZE {
+        if next_period == stored_next_period
+            && is_conflicted_client_sync_committee(
+                &packed_stored_next_client_sync_committee,
+                &packed_next_client_sync_committee,
+            )?
+        {
             error!(
-                "failed: next sync committee size is expected to be {}, but actual is {}.",
-                forks::altair::SYNC_COMMITTEE_SIZE,
-                next_client_sync_committee.data.pubkeys.len(),
+                "failed: next client sync committee (period: {next_period}) is conflicted with \
+                the stored one"
+            );
+            return Err(SyncCommitteeUpdateError::ConflictedNextSyncCommittee);
+        }
+        self.verify_next_sync_committee_branch(config, packed_next_client_sync_committee)
+    }
+
+    fn verify_next_sync_committee_branch<'r, P: bls::PackedClientSyncCommittee<'r>>(
+        &self,
+        config: &ChainConfig,
+        packed_next_client_sync_committee: P,
+    ) -> Result<(), SyncCommitteeUpdateError> {
+        let next_sync_committee_root = packed_next_client_sync_committee
+            .sync_committee_root()
+            .map_err(|_| {
+                error!("failed: next sync committee has invalid public keys");
+                SyncCommitteeUpdateError::InvalidNextSyncCommitteePubkey
+            })?;
+        let pubkeys_count = packed_next_client_sync_committee.pubkeys_count();
+        if pubkeys_count != config.sync_committee_size {
+            error!(
+                "failed: next sync committee size is expected to be {}, \
+                but actual is {pubkeys_count}.",
+                config.sync_committee_size,
             );
             return Err(SyncCommitteeUpdateError::UnexpectedNextSyncCommitteeSize);
         }
-        let next_sync_committee_root = next_client_sync_committee.data.tree_hash_root();
-        let (depth, index) =
-            specs::get_depth_and_index_from_next_sync_committee_index(self.attested_header.slot);
+        let (depth, index) = specs::get_depth_and_index_from_next_sync_committee_index(
+            config,
+            self.attested_header.slot,
+        );
         if !ssz::is_valid_merkle_branch(
             &next_sync_committee_root,
             &self.next_sync_committee_branch,
@@ -581,7 +1632,7 @@ impl core::SyncCommitteeUpdate {
                 self.attested_header,
                 self.next_sync_committee_branch.len(),
             );
-            return Err(SyncCommitteeUpdateError::InvalidNextSyncCommitteeBranch);
+            Err(SyncCommitteeUpdateError::InvalidNextSyncCommitteeBranch)
         } else {
             debug!(
                 "passed: verify merkle branch for next sync committee \
@@ -590,55 +1641,356 @@ impl core::SyncCommitteeUpdate {
                 self.attested_header,
                 self.next_sync_committee_branch.len(),
             );
+            Ok(())
         }
-        Ok(())
     }
 }
 
-impl core::Client {
-    /// Verifies the corresponding transaction that in the proof is in the chain.
-    pub fn verify_packed_transaction_proof(
+impl core::ClientSyncCommittee {
+    /// Builds the commitment of the client sync committee, which could be stored in the cell
+    /// instead of the whole sync committee.
+    pub fn commitment(&self) -> core::ClientSyncCommitteeCommitment {
+        core::ClientSyncCommitteeCommitment {
+            period: self.period,
+            sync_committee_root: self.data.clone().tree_hash_root(),
+        }
+    }
+}
+
+impl core::ClientSyncCommitteeCommitment {
+    /// Verifies the packed client sync committee, which is provided in the witness, against
+    /// the commitment.
+    ///
+    /// After that, the packed client sync committee could be used to verify updates as the
+    /// stored one.
+    pub fn verify_packed_client_sync_committee<'r, P: bls::PackedClientSyncCommittee<'r>>(
         &self,
-        tx_proof: packed::TransactionProofReader,
-    ) -> Result<(), TxVerificationError> {
-        let header_slot = tx_proof.header().slot().unpack();
-        if self.minimal_slot > header_slot || self.maximal_slot < header_slot {
-            log_if_enabled!(|Warn| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
-                warn!(
-                    "failed: verify slots for header {:#x}, for its {}-th transaction \
-                    (client: [{}, {}], header-slot: {header_slot})",
-                    header.root, tx_proof.transaction_index, self.minimal_slot, self.maximal_slot
-                );
-            });
-            return Err(TxVerificationError::Unsynchronized);
+        packed_client_sync_committee: P,
+    ) -> Result<(), SyncCommitteeCommitmentError> {
+        self.verify_packed_client_sync_committee_with_config(
+            &ChainConfig::MAINNET,
+            packed_client_sync_committee,
+        )
+    }
+
+    /// Same as [`Self::verify_packed_client_sync_committee`], with the preset of the chain.
+    pub fn verify_packed_client_sync_committee_with_config<
+        'r,
+        P: bls::PackedClientSyncCommittee<'r>,
+    >(
+        &self,
+        config: &ChainConfig,
+        packed_client_sync_committee: P,
+    ) -> Result<(), SyncCommitteeCommitmentError> {
+        let period = packed_client_sync_committee.sync_committee_period();
+        if period != self.period {
+            error!(
+                "failed: client sync committee period ({period}) is not same as the period \
+                ({}) in the commitment",
+                self.period
+            );
+            return Err(SyncCommitteeCommitmentError::MismatchedPeriod);
         }
-        let result = self
-            .verify_single_header(tx_proof.header(), tx_proof.header_mmr_proof())
-            .map_err(|_| TxVerificationError::MmrError)?;
-        if !result {
-            log_if_enabled!(|Warn| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
-                warn!(
-                    "failed: verify MMR proof for header {:#x}, for its {}-th transaction",
-                    header.root, tx_proof.transaction_index
-                );
-            });
-            Err(TxVerificationError::HeaderMmrProof)
+        let sync_committee_root =
+            packed_client_sync_committee
+                .sync_committee_root()
+                .map_err(|_| {
+                    error!("failed: sync committee has invalid public keys");
+                    SyncCommitteeCommitmentError::InvalidSyncCommitteePubkey
+                })?;
+        let pubkeys_count = packed_client_sync_committee.pubkeys_count();
+        if pubkeys_count != config.sync_committee_size {
+            error!(
+                "failed: sync committee size is expected to be {}, but actual is {pubkeys_count}.",
+                config.sync_committee_size,
+            );
+            return Err(SyncCommitteeCommitmentError::UnexpectedSyncCommitteeSize);
+        }
+        if sync_committee_root != self.sync_committee_root {
+            warn!(
+                "failed: sync committee root ({sync_committee_root:#x}) is not same as the root \
+                ({:#x}) in the commitment (period: {period})",
+                self.sync_committee_root
+            );
+            Err(SyncCommitteeCommitmentError::MismatchedSyncCommitteeRoot)
         } else {
-            log_if_enabled!(|Debug| {
-                let tx_proof = tx_proof.unpack();
-                let header = tx_proof.header.calc_cache();
-                debug!(
-                    "passed: verify MMR proof for header {:#x}, for its {}-th transaction",
-                    header.root, tx_proof.transaction_index
-                );
-            });
+            debug!(
+                "passed: verify sync committee (root: {sync_committee_root:#x}) \
+                with the commitment (period: {period})"
+            );
             Ok(())
         }
     }
+}
+
+impl core::ClientUpdateBatch {
+    /// Verifies the new client and all updates that make it be upgraded from the old client.
+    ///
+    /// The updates are applied in order, and the intermediate clients are derived from them.
+    /// A sync committee rotation replaces the current sync committee for the following updates,
+    /// so the last item should be a client update.
+    ///
+    /// Returns the sync committee after the last rotation, if any; it should be stored as the
+    /// new sync committee cell.
+    ///
+    /// N.B. `client_sync_committee` should be checked.
+    pub fn verify_client_update_batch<'r, P>(
+        &self,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<Option<core::ClientSyncCommittee>, ClientUpdateBatchError>
+    where
+        P: bls::PackedClientSyncCommittee<'r> + Copy,
+    {
+        self.verify_client_update_batch_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_update_batch`], with the preset and the fork schedule of
+    /// the chain.
+    pub fn verify_client_update_batch_with_config<'r, P>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<Option<core::ClientSyncCommittee>, ClientUpdateBatchError>
+    where
+        P: bls::PackedClientSyncCommittee<'r> + Copy,
+    {
+        //
+        // Verify Self
+        //
+
+        let (last_client_update, items) = match self.items.split_last() {
+            Some((core::ClientUpdateBatchItem::ClientUpdate(update), items)) => (update, items),
+            Some(_) => {
+                error!("failed: the last item in the batch should be a client update");
+                return Err(ClientUpdateBatchError::LastItemIsNotClientUpdate);
+            }
+            None => {
+                error!("failed: the batch has no items");
+                return Err(ClientUpdateBatchError::EmptyBatch);
+            }
+        };
+        info!(
+            "update client with a batch (len: {}), client: {old_client}, new client: {new_client}",
+            self.items.len()
+        );
+
+        //
+        // Verify Items
+        //
+
+        let mut client = old_client;
+        let mut rotated_sync_committee: Option<packed::ClientSyncCommittee> = None;
+        for (index, item) in items.iter().enumerate() {
+            match item {
+                core::ClientUpdateBatchItem::ClientUpdate(update) => {
+                    let result = if let Some(sync_committee) = rotated_sync_committee.as_ref() {
+                        update.verify_and_derive_new_client(
+                            config,
+                            client,
+                            genesis_validators_root,
+                            sync_committee.as_reader(),
+                        )
+                    } else {
+                        update.verify_and_derive_new_client(
+                            config,
+                            client,
+                            genesis_validators_root,
+                            packed_client_sync_committee,
+                        )
+                    };
+                    client = result.map_err(|err| {
+                        warn!(
+                            "failed: verify client update (index: {index}), error: {}",
+                            err as i8
+                        );
+                        ClientUpdateBatchError::InvalidClientUpdate(err)
+                    })?;
+                    debug!("passed: verify client update (index: {index}), client: {client}");
+                }
+                core::ClientUpdateBatchItem::SyncCommitteeRotation(rotation) => {
+                    let next_sync_committee = rotation.next_sync_committee.pack();
+                    let update = &rotation.sync_committee_update;
+                    let result = if let Some(sync_committee) = rotated_sync_committee.as_ref() {
+                        update.verify_packed_client_sync_committee_with_config(
+                            config,
+                            client.maximal_slot,
+                            genesis_validators_root,
+                            sync_committee.as_reader(),
+                            next_sync_committee.as_reader(),
+                        )
+                    } else {
+                        update.verify_packed_client_sync_committee_with_config(
+                            config,
+                            client.maximal_slot,
+                            genesis_validators_root,
+                            packed_client_sync_committee,
+                            next_sync_committee.as_reader(),
+                        )
+                    };
+                    result.map_err(|err| {
+                        warn!(
+                            "failed: verify sync committee rotation (index: {index}), error: {}",
+                            err as i8
+                        );
+                        ClientUpdateBatchError::InvalidSyncCommitteeRotation(err)
+                    })?;
+                    debug!(
+                        "passed: verify sync committee rotation (index: {index}), period: {}",
+                        rotation.next_sync_committee.period
+                    );
+                    rotated_sync_committee = Some(next_sync_committee);
+                }
+            }
+        }
+
+        let result = if let Some(sync_committee) = rotated_sync_committee.as_ref() {
+            last_client_update.verify_client_update_with_config(
+                config,
+                client,
+                genesis_validators_root,
+                sync_committee.as_reader(),
+                new_client,
+            )
+        } else {
+            last_client_update.verify_client_update_with_config(
+                config,
+                client,
+                genesis_validators_root,
+                packed_client_sync_committee,
+                new_client,
+            )
+        };
+        result.map_err(|err| {
+            warn!(
+                "failed: verify the last client update, error: {}",
+                err as i8
+            );
+            ClientUpdateBatchError::InvalidClientUpdate(err)
+        })?;
+
+        Ok(rotated_sync_committee.map(|sync_committee| sync_committee.unpack()))
+    }
+}
+
+impl core::Client {
+    /// Returns the slot of the last force update, if there are headers which were applied by
+    /// force updates and not finalized yet.
+    ///
+    /// The last header of a force update is always its attested header, so it's the maximal slot
+    /// of the headers in MMR.
+    pub fn last_force_update_slot(&self) -> Option<u64> {
+        if self.maximal_slot > self.finalized_slot {
+            Some(self.maximal_slot)
+        } else {
+            None
+        }
+    }
+
+    /// Verifies the corresponding transaction that in the proof is in the chain.
+    ///
+    /// Returns whether the header, which contains the transaction, is finalized, force updated
+    /// or only optimistic.
+    ///
+    /// For the optimistic header, the MMR proof in the transaction proof is ignored, since the
+    /// optimistic header is not in the MMR.
+    pub fn verify_packed_transaction_proof(
+        &self,
+        tx_proof: packed::TransactionProofReader,
+    ) -> Result<core::HeaderStatus, TxVerificationError> {
+        self.verify_header_in_client(tx_proof.header(), tx_proof.header_mmr_proof())
+            .map_err(|err| {
+                log_if_enabled!(|Warn| {
+                    let tx_index: u64 = tx_proof.transaction_index().unpack();
+                    warn!("failed: verify the header for its {tx_index}-th transaction");
+                });
+                err.into()
+            })
+    }
+
+    /// Verifies the transaction proof and the corresponding payload in one call.
+    ///
+    /// Returns the facts of the transaction, include the decoded receipt.
+    ///
+    /// N.B. The receipts before Byzantium are not supported, they have no status code.
+    pub fn verify_packed_transaction(
+        &self,
+        tx_proof: packed::TransactionProofReader,
+        payload: packed::TransactionPayloadReader,
+    ) -> Result<core::VerifiedTransaction, TxVerificationError> {
+        let header_status = self.verify_packed_transaction_proof(tx_proof)?;
+        tx_proof.verify_packed_payload(payload)?;
+        let transaction_index = tx_proof.transaction_index().unpack();
+        let (receipt_status, logs) =
+            decode_receipt(payload.receipt().raw_data()).ok_or_else(|| {
+                warn!("failed: decode the {transaction_index}-th receipt");
+                TxVerificationError::InvalidReceipt
+            })?;
+        let header = tx_proof.header().unpack().calc_cache();
+        let transaction_hash = mpt::keccak256(payload.transaction().raw_data()).into();
+        Ok(core::VerifiedTransaction {
+            header_status,
+            slot: header.inner.slot,
+            header_root: header.root,
+            transaction_index,
+            transaction_hash,
+            receipts_root: tx_proof.receipts_root().unpack(),
+            receipt_status,
+            logs,
+        })
+    }
+
+    /// Verifies the corresponding transactions that in the proofs are in the chain, the headers
+    /// which contain the transactions are proven together by the multi-header proof.
+    ///
+    /// Returns the status of the header for each transaction.
+    ///
+    /// The MMR proofs in the transaction proofs are ignored, so they could be empty.
+    pub fn verify_packed_transaction_proofs(
+        &self,
+        multi_header_proof: packed::MultiHeaderProofReader,
+        tx_proofs: &[packed::TransactionProofReader],
+    ) -> Result<Vec<core::HeaderStatus>, TxVerificationError> {
+        let headers = multi_header_proof.headers();
+        let header_statuses = self
+            .verify_headers(headers, multi_header_proof.headers_mmr_proof())
+            .map_err(|err| {
+                warn!("failed: verify the headers for transactions");
+                TxVerificationError::from(err)
+            })?;
+        tx_proofs
+            .iter()
+            .map(|tx_proof| {
+                let header = tx_proof.header();
+                headers
+                    .iter()
+                    .position(|proven| proven.as_slice() == header.as_slice())
+                    .map(|index| header_statuses[index])
+                    .ok_or_else(|| {
+                        log_if_enabled!(|Warn| {
+                            let header_slot: u64 = header.slot().unpack();
+                            let tx_index: u64 = tx_proof.transaction_index().unpack();
+                            warn!(
+                                "failed: header#{header_slot} for its {tx_index}-th transaction \
+                                is not in the multi-header proof"
+                            );
+                        });
+                        TxVerificationError::UnprovenHeader
+                    })
+            })
+            .collect()
+    }
 
     /// Verifies the MMR proof that proves the corresponding header is in the chain.
     pub fn verify_single_header(
@@ -672,88 +2024,680 @@ impl core::Client {
             let digest = header_with_cache.packed_digest();
             vec![(position, digest)]
         };
-        proof.verify(self.headers_mmr_root.pack(), digests_with_positions)
+        profile!(
+            Mmr,
+            proof.verify(self.headers_mmr_root.pack(), digests_with_positions)
+        )
+    }
+
+    /// Verifies several headers are in the chain at once, the headers in the MMR of the client
+    /// are proven by one MMR proof.
+    ///
+    /// The headers should be sorted by their slots without duplicates. The last header could be
+    /// the optimistic header, which is not in the MMR.
+    ///
+    /// Returns whether each header is finalized, force updated or only optimistic.
+    pub fn verify_headers(
+        &self,
+        headers: packed::HeaderVecReader,
+        headers_mmr_proof: packed::MmrProofReader,
+    ) -> Result<Vec<core::HeaderStatus>, MultiHeaderProofError> {
+        if headers.is_empty() {
+            warn!("failed: no headers to verify");
+            return Err(MultiHeaderProofError::EmptyHeaders);
+        }
+        let mut header_statuses = Vec::with_capacity(headers.len());
+        let mut digests_with_positions = Vec::with_capacity(headers.len());
+        let mut last_slot_opt: Option<u64> = None;
+        for header in headers.iter() {
+            let header_slot = header.slot().unpack();
+            if let Some(last_slot) = last_slot_opt {
+                if last_slot >= header_slot {
+                    warn!(
+                        "failed: headers should be sorted by slots without duplicates \
+                        (header#{last_slot} is before header#{header_slot})"
+                    );
+                    return Err(MultiHeaderProofError::UnsortedHeaders);
+                }
+            }
+            last_slot_opt = Some(header_slot);
+            // The optimistic header is always the last one, since it's after all headers in MMR.
+            if header_slot > self.maximal_slot && header_slot == self.optimistic_slot {
+                let header = header.unpack().calc_cache();
+                if header.root != self.optimistic_header_root {
+                    warn!(
+                        "failed: verify optimistic header {:#x} (expect: {:#x})",
+                        header.root, self.optimistic_header_root
+                    );
+                    return Err(MultiHeaderProofError::OptimisticHeaderRoot);
+                }
+                debug!("passed: verify optimistic header {:#x}", header.root);
+                header_statuses.push(core::HeaderStatus::Optimistic);
+                continue;
+            }
+            if self.minimal_slot > header_slot || self.maximal_slot < header_slot {
+                warn!(
+                    "failed: verify slots for header#{header_slot} \
+                    (client: [{}, {}], optimistic: {})",
+                    self.minimal_slot, self.maximal_slot, self.optimistic_slot
+                );
+                return Err(MultiHeaderProofError::Unsynchronized);
+            }
+            let index = header_slot - self.minimal_slot;
+            let position = leaf_index_to_pos(index);
+            let header_with_cache = header.unpack().calc_cache();
+            trace!(
+                "verify MMR proof for header#{header_slot} with \
+                index: {index}, position: {position}, root: {:#x}",
+                header_with_cache.root
+            );
+            digests_with_positions.push((position, header_with_cache.packed_digest()));
+            if header_slot > self.finalized_slot {
+                header_statuses.push(core::HeaderStatus::ForceUpdated);
+            } else {
+                header_statuses.push(core::HeaderStatus::Finalized);
+            }
+        }
+        if !digests_with_positions.is_empty() {
+            let headers_count = digests_with_positions.len();
+            let proof: mmr::MMRProof = {
+                let max_index = self.maximal_slot - self.minimal_slot;
+                let mmr_size = leaf_index_to_mmr_size(max_index);
+                trace!(
+                    "verify MMR proof for {headers_count} headers with \
+                    MMR {{ size: {mmr_size}, max-index: {max_index} }}"
+                );
+                let proof = headers_mmr_proof
+                    .iter()
+                    .map(|r| r.to_entity())
+                    .collect::<Vec<_>>();
+                mmr::MMRProof::new(mmr_size, proof)
+            };
+            let result = profile!(
+                Mmr,
+                proof.verify(self.headers_mmr_root.pack(), digests_with_positions)
+            )
+            .map_err(|_| MultiHeaderProofError::MmrError)?;
+            if !result {
+                warn!("failed: verify MMR proof for {headers_count} headers");
+                return Err(MultiHeaderProofError::HeaderMmrProof);
+            }
+            debug!("passed: verify MMR proof for {headers_count} headers");
+        }
+        Ok(header_statuses)
+    }
+
+    /// Verifies the header, which is before the client, is in the chain.
+    ///
+    /// The root of the header is proven through the `block_summary_root` in
+    /// `historical_summaries`, in the beacon state of an anchor header, and the anchor header is
+    /// in the chain. So only the headers since Capella, which eras are completed before the era
+    /// of the anchor header, could be proven.
+    ///
+    /// Returns whether the anchor header is finalized, force updated or only optimistic.
+    pub fn verify_packed_historical_header(
+        &self,
+        header: packed::HeaderReader,
+        historical_proof: packed::HistoricalHeaderProofReader,
+    ) -> Result<core::HeaderStatus, HistoricalHeaderProofError> {
+        let header_slot: u64 = header.slot().unpack();
+        let anchor_slot: u64 = historical_proof.anchor_header().slot().unpack();
+        // Check Slots
+        let index_opt = if header_slot / forks::phase0::SLOTS_PER_HISTORICAL_ROOT
+            < anchor_slot / forks::phase0::SLOTS_PER_HISTORICAL_ROOT
+        {
+            specs::get_generalized_index_of_historical_block_root(
+                &ChainConfig::MAINNET,
+                anchor_slot,
+                header_slot,
+            )
+        } else {
+            None
+        };
+        let index = index_opt.ok_or_else(|| {
+            warn!(
+                "failed: check slots for historical header#{header_slot} \
+                (anchor-slot: {anchor_slot})"
+            );
+            HistoricalHeaderProofError::UnsupportedHistoricalSlot
+        })?;
+        // Verify Anchor Header
+        let status = self.verify_header_in_client(
+            historical_proof.anchor_header(),
+            historical_proof.anchor_header_mmr_proof(),
+        )?;
+        // Verify Historical Header
+        let header_root = header.unpack().calc_cache().root;
+        let anchor_state_root: core::Hash = historical_proof.anchor_header().state_root().unpack();
+        let header_ssz_proof: core::SszProof = historical_proof.header_ssz_proof().unpack();
+        if header_ssz_proof.len() != ssz::floor_depth(index) as usize
+            || !ssz::verify_merkle_proof(&anchor_state_root, &header_root, &header_ssz_proof, index)
+        {
+            warn!(
+                "failed: verify SSZ proof for historical header#{header_slot} {header_root:#x} \
+                in state {anchor_state_root:#x} of anchor header#{anchor_slot}"
+            );
+            Err(HistoricalHeaderProofError::HistoricalHeaderSszProof)
+        } else {
+            debug!(
+                "passed: verify SSZ proof for historical header#{header_slot} {header_root:#x} \
+                in state {anchor_state_root:#x} of anchor header#{anchor_slot}"
+            );
+            Ok(status)
+        }
+    }
+
+    /// Verifies the header of the corresponding transaction that in the proof is in the chain,
+    /// and the header is before the client.
+    ///
+    /// Same as [`Self::verify_packed_transaction_proof`], but the header is proven by
+    /// [`Self::verify_packed_historical_header`].
+    ///
+    /// Only the header is verified, the transaction should be verified by
+    /// [`packed::TransactionProofReader::verify_packed_payload`].
+    pub fn verify_packed_historical_transaction_header(
+        &self,
+        historical_tx_proof: packed::HistoricalTransactionProofReader,
+    ) -> Result<core::HeaderStatus, HistoricalHeaderProofError> {
+        self.verify_packed_historical_header(
+            historical_tx_proof.transaction_proof().header(),
+            historical_tx_proof.historical_header_proof(),
+        )
+    }
+
+    /// Verifies the value of a field in the beacon state, which is committed by the
+    /// `state_root` of the header in the proof, and the header is in the chain.
+    ///
+    /// The field is selected by its generalized index in the beacon state, and the value is
+    /// decoded as `T` from its SSZ bytes.
+    ///
+    /// Returns whether the header is finalized, force updated or only optimistic, and the
+    /// decoded value.
+    pub fn verify_packed_beacon_state_proof<T: ::ssz::Decode + TreeHash>(
+        &self,
+        state_proof: packed::BeaconStateProofReader,
+        generalized_index: u64,
+    ) -> Result<(core::HeaderStatus, T), BeaconStateProofError> {
+        // Check Field
+        let actual_generalized_index: u64 = state_proof.generalized_index().unpack();
+        if actual_generalized_index != generalized_index {
+            warn!(
+                "failed: check generalized index of the beacon state field \
+                (expect: {generalized_index}, actual: {actual_generalized_index})"
+            );
+            return Err(BeaconStateProofError::MismatchedGeneralizedIndex);
+        }
+        // Verify Header
+        let status =
+            self.verify_header_in_client(state_proof.header(), state_proof.header_mmr_proof())?;
+        // Verify Field
+        let value = T::from_ssz_bytes(state_proof.value().raw_data()).map_err(|_| {
+            warn!("failed: decode SSZ value of the beacon state field {generalized_index}");
+            BeaconStateProofError::SszError
+        })?;
+        let leaf = profile!(TreeHash, value.tree_hash_root());
+        let state_root: core::Hash = state_proof.header().state_root().unpack();
+        let state_ssz_proof: core::SszProof = state_proof.state_ssz_proof().unpack();
+        let index = generalized_index as usize;
+        if state_ssz_proof.len() != ssz::floor_depth(index) as usize
+            || !ssz::verify_merkle_proof(&state_root, &leaf, &state_ssz_proof, index)
+        {
+            warn!(
+                "failed: verify SSZ proof for beacon state field {generalized_index} \
+                with leaf {leaf:#x} in state {state_root:#x}"
+            );
+            Err(BeaconStateProofError::StateSszProof)
+        } else {
+            debug!(
+                "passed: verify SSZ proof for beacon state field {generalized_index} \
+                with leaf {leaf:#x} in state {state_root:#x}"
+            );
+            Ok((status, value))
+        }
+    }
+
+    /// Verifies the validator and its balance in the beacon state, which is committed by the
+    /// `state_root` of the header in the proof, and the header is in the chain.
+    ///
+    /// Returns whether the header is finalized, force updated or only optimistic.
+    pub fn verify_packed_validator_proof(
+        &self,
+        validator_proof: packed::ValidatorProofReader,
+    ) -> Result<core::HeaderStatus, ValidatorProofError> {
+        let validator_index: u64 = validator_proof.validator_index().unpack();
+        if validator_index >= forks::phase0::VALIDATOR_REGISTRY_LIMIT as u64 {
+            warn!("failed: check validator index {validator_index}");
+            return Err(ValidatorProofError::InvalidValidatorIndex);
+        }
+        // Verify Header
+        let status = self.verify_header_in_client(
+            validator_proof.header(),
+            validator_proof.header_mmr_proof(),
+        )?;
+        let header_slot = validator_proof.header().slot().unpack();
+        let state_root: core::Hash = validator_proof.header().state_root().unpack();
+        // Verify Validator
+        let validator: core::Validator = validator_proof.validator().unpack();
+        let validator_root = profile!(TreeHash, validator.tree_hash_root());
+        let validator_ssz_proof: core::SszProof = validator_proof.validator_ssz_proof().unpack();
+        let validator_in_state_index = validator_index as usize
+            + specs::get_generalized_index_of_validator_in_beacon_state_offset(
+                &ChainConfig::MAINNET,
+                header_slot,
+            );
+        if validator_ssz_proof.len() != ssz::floor_depth(validator_in_state_index) as usize
+            || !ssz::verify_merkle_proof(
+                &state_root,
+                &validator_root,
+                &validator_ssz_proof,
+                validator_in_state_index,
+            )
+        {
+            warn!(
+                "failed: verify SSZ proof for validator {validator_index} \
+                with root {validator_root:#x} in state {state_root:#x}"
+            );
+            return Err(ValidatorProofError::ValidatorSszProof);
+        } else {
+            debug!(
+                "passed: verify SSZ proof for validator {validator_index} \
+                with root {validator_root:#x} in state {state_root:#x}"
+            );
+        }
+        // Verify Balance
+        let balances_chunk: core::Hash = validator_proof.balances_chunk().unpack();
+        let balances_chunk_ssz_proof: core::SszProof =
+            validator_proof.balances_chunk_ssz_proof().unpack();
+        let balances_chunk_in_state_index = validator_index as usize
+            / forks::phase0::BALANCES_PER_CHUNK
+            + specs::get_generalized_index_of_balances_chunk_in_beacon_state_offset(
+                &ChainConfig::MAINNET,
+                header_slot,
+            );
+        if balances_chunk_ssz_proof.len()
+            != ssz::floor_depth(balances_chunk_in_state_index) as usize
+            || !ssz::verify_merkle_proof(
+                &state_root,
+                &balances_chunk,
+                &balances_chunk_ssz_proof,
+                balances_chunk_in_state_index,
+            )
+        {
+            warn!(
+                "failed: verify SSZ proof for balance of validator {validator_index} \
+                with chunk {balances_chunk:#x} in state {state_root:#x}"
+            );
+            Err(ValidatorProofError::BalancesChunkSszProof)
+        } else {
+            debug!(
+                "passed: verify SSZ proof for balance of validator {validator_index} \
+                with chunk {balances_chunk:#x} in state {state_root:#x}"
+            );
+            Ok(status)
+        }
+    }
+
+    // Verifies the header is the optimistic header, or it is in the MMR of the client.
+    pub(super) fn verify_header_in_client(
+        &self,
+        header: packed::HeaderReader,
+        header_mmr_proof: packed::MmrProofReader,
+    ) -> Result<core::HeaderStatus, HeaderInClientError> {
+        let header_slot = header.slot().unpack();
+        if header_slot > self.maximal_slot && header_slot == self.optimistic_slot {
+            let header = header.unpack().calc_cache();
+            if header.root != self.optimistic_header_root {
+                warn!(
+                    "failed: verify optimistic header {:#x} (expect: {:#x})",
+                    header.root, self.optimistic_header_root
+                );
+                return Err(HeaderInClientError::OptimisticHeaderRoot);
+            } else {
+                debug!("passed: verify optimistic header {:#x}", header.root);
+                return Ok(core::HeaderStatus::Optimistic);
+            }
+        }
+        if self.minimal_slot > header_slot || self.maximal_slot < header_slot {
+            warn!(
+                "failed: verify slots for header#{header_slot} \
+                (client: [{}, {}], optimistic: {})",
+                self.minimal_slot, self.maximal_slot, self.optimistic_slot
+            );
+            return Err(HeaderInClientError::Unsynchronized);
+        }
+        let result = self
+            .verify_single_header(header, header_mmr_proof)
+            .map_err(|_| HeaderInClientError::MmrError)?;
+        if !result {
+            warn!("failed: verify MMR proof for header#{header_slot}");
+            Err(HeaderInClientError::HeaderMmrProof)
+        } else {
+            debug!("passed: verify MMR proof for header#{header_slot}");
+            if header_slot > self.finalized_slot {
+                Ok(core::HeaderStatus::ForceUpdated)
+            } else {
+                Ok(core::HeaderStatus::Finalized)
+            }
+        }
+    }
+}
+
+// The failures when verify a header is in a client.
+pub(super) enum HeaderInClientError {
+    Unsynchronized,
+    HeaderMmrProof,
+    OptimisticHeaderRoot,
+    MmrError,
+}
+
+impl From<MultiHeaderProofError> for TxVerificationError {
+    fn from(err: MultiHeaderProofError) -> Self {
+        match err {
+            MultiHeaderProofError::EmptyHeaders | MultiHeaderProofError::UnsortedHeaders => {
+                Self::InvalidMultiHeaderProof
+            }
+            MultiHeaderProofError::Unsynchronized => Self::Unsynchronized,
+            MultiHeaderProofError::HeaderMmrProof => Self::HeaderMmrProof,
+            MultiHeaderProofError::OptimisticHeaderRoot => Self::OptimisticHeaderRoot,
+            MultiHeaderProofError::MmrError => Self::MmrError,
+            MultiHeaderProofError::Unreachable => Self::Unreachable,
+        }
+    }
+}
+
+impl From<HeaderInClientError> for TxVerificationError {
+    fn from(err: HeaderInClientError) -> Self {
+        match err {
+            HeaderInClientError::Unsynchronized => Self::Unsynchronized,
+            HeaderInClientError::HeaderMmrProof => Self::HeaderMmrProof,
+            HeaderInClientError::OptimisticHeaderRoot => Self::OptimisticHeaderRoot,
+            HeaderInClientError::MmrError => Self::MmrError,
+        }
+    }
+}
+
+impl From<HeaderInClientError> for HistoricalHeaderProofError {
+    fn from(err: HeaderInClientError) -> Self {
+        match err {
+            HeaderInClientError::Unsynchronized => Self::Unsynchronized,
+            HeaderInClientError::HeaderMmrProof => Self::HeaderMmrProof,
+            HeaderInClientError::OptimisticHeaderRoot => Self::OptimisticHeaderRoot,
+            HeaderInClientError::MmrError => Self::MmrError,
+        }
+    }
+}
+
+impl From<HeaderInClientError> for BeaconStateProofError {
+    fn from(err: HeaderInClientError) -> Self {
+        match err {
+            HeaderInClientError::Unsynchronized => Self::Unsynchronized,
+            HeaderInClientError::HeaderMmrProof => Self::HeaderMmrProof,
+            HeaderInClientError::OptimisticHeaderRoot => Self::OptimisticHeaderRoot,
+            HeaderInClientError::MmrError => Self::MmrError,
+        }
     }
 }
 
+impl From<HeaderInClientError> for ValidatorProofError {
+    fn from(err: HeaderInClientError) -> Self {
+        match err {
+            HeaderInClientError::Unsynchronized => Self::Unsynchronized,
+            HeaderInClientError::HeaderMmrProof => Self::HeaderMmrProof,
+            HeaderInClientError::OptimisticHeaderRoot => Self::OptimisticHeaderRoot,
+            HeaderInClientError::MmrError => Self::MmrError,
+        }
+    }
+}
+
+impl core::ValidatorProof {
+    /// Returns the balance of the validator, which is packed in the chunk of balances.
+    pub fn balance(&self) -> u64 {
+        balance_in_chunk(&self.balances_chunk, self.validator_index)
+    }
+}
+
+// Extracts a balance from a chunk, which contains 4 balances.
+pub(super) fn balance_in_chunk(balances_chunk: &core::Hash, validator_index: u64) -> u64 {
+    let size = 32 / forks::phase0::BALANCES_PER_CHUNK;
+    let start = validator_index as usize % forks::phase0::BALANCES_PER_CHUNK * size;
+    let mut balance = [0u8; 8];
+    balance.copy_from_slice(&balances_chunk.as_bytes()[start..start + size]);
+    u64::from_le_bytes(balance)
+}
+
 impl core::TransactionProof {
     /// Verifies raw bytes of the corresponding transaction and its receipt.
     pub fn verify_packed_payload(
         &self,
         payload: packed::TransactionPayloadReader,
     ) -> Result<(), TxVerificationError> {
-        self.verify_transaction(payload.transaction().raw_data())?;
-        self.verify_receipt(payload.receipt().raw_data())
+        self.verify_packed_payload_with_config(&ChainConfig::MAINNET, payload)
+    }
+
+    /// Same as [`Self::verify_packed_payload`], with the fork schedule of the chain.
+    pub fn verify_packed_payload_with_config(
+        &self,
+        config: &ChainConfig,
+        payload: packed::TransactionPayloadReader,
+    ) -> Result<(), TxVerificationError> {
+        self.verify_transaction_with_config(config, payload.transaction().raw_data())?;
+        self.verify_receipt_with_config(config, payload.receipt().raw_data())
     }
 
     /// Verifies raw bytes of the corresponding transaction.
     pub fn verify_transaction(&self, transaction: &[u8]) -> Result<(), TxVerificationError> {
-        // Since `MAX_BYTES_PER_TRANSACTION`.
-        // Ref: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#execution
-        VariableList::<u8, typenum::U1073741824>::new(transaction.to_vec())
-            .map_err(|_| TxVerificationError::SszError)
-            .and_then(|tx| {
-                let tx_root = tx.tree_hash_root();
-                let tx_index = self.transaction_index as usize;
-                let tx_in_block_offset =
-                    specs::get_generalized_index_of_transaction_in_block_body_offset(
-                        self.header.slot,
-                    );
-                let tx_in_block_index = tx_index + tx_in_block_offset;
-                if !ssz::verify_merkle_proof(
-                    &self.header.body_root,
-                    &tx_root,
-                    &self.transaction_ssz_proof,
-                    tx_in_block_index,
-                ) {
-                    warn!(
-                        "failed: verify SSZ proof for transaction {tx_root:#x} \
-                        (index: {tx_index}, offset: {tx_in_block_index})"
-                    );
-                    Err(TxVerificationError::TransactionSszProof)
-                } else {
-                    debug!(
-                        "passed: verify SSZ proof for transaction {tx_root:#x} \
-                        (index: {tx_index}, offset: {tx_in_block_index})"
-                    );
-                    Ok(())
-                }
-            })
+        self.verify_transaction_with_config(&ChainConfig::MAINNET, transaction)
+    }
+
+    /// Same as [`Self::verify_transaction`], with the fork schedule of the chain.
+    pub fn verify_transaction_with_config(
+        &self,
+        config: &ChainConfig,
+        transaction: &[u8],
+    ) -> Result<(), TxVerificationError> {
+        verify_transaction_in_header(
+            config,
+            &self.header,
+            self.transaction_index,
+            &self.transaction_ssz_proof,
+            transaction,
+        )
+    }
+
+    /// Verifies raw bytes of the corresponding transaction, and checks that the transaction has
+    /// the expected hash.
+    pub fn verify_transaction_with_hash(
+        &self,
+        transaction: &[u8],
+        transaction_hash: &core::Hash,
+    ) -> Result<(), TxVerificationError> {
+        verify_transaction_hash(self.transaction_index, transaction, transaction_hash)?;
+        self.verify_transaction(transaction)
     }
 
     /// Verifies raw bytes of the corresponding transaction receipt.
     pub fn verify_receipt(&self, receipt: &[u8]) -> Result<(), TxVerificationError> {
-        let key = encode(&self.transaction_index);
-        let receipts_root_in_block_body =
-            specs::get_generalized_index_of_receipts_root_in_block_body(self.header.slot);
-        if !mpt::verify_proof(
-            &self.receipt_mpt_proof,
-            self.receipts_root.as_bytes(),
-            &key,
-            receipt,
-        ) {
-            warn!(
-                "failed: verify MPT proof for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
-            );
-            Err(TxVerificationError::ReceiptMptProof)
-        } else if !ssz::verify_merkle_proof(
-            &self.header.body_root,
+        self.verify_receipt_with_config(&ChainConfig::MAINNET, receipt)
+    }
+
+    /// Same as [`Self::verify_receipt`], with the fork schedule of the chain.
+    pub fn verify_receipt_with_config(
+        &self,
+        config: &ChainConfig,
+        receipt: &[u8],
+    ) -> Result<(), TxVerificationError> {
+        verify_receipt_in_header(
+            config,
+            &self.header,
+            self.transaction_index,
             &self.receipts_root,
+            &self.receipt_mpt_proof,
             &self.receipts_root_ssz_proof,
-            receipts_root_in_block_body,
-        ) {
-            warn!(
-                "failed: verify SSZ proof for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
-            );
-            Err(TxVerificationError::ReceiptsRootSszProof)
-        } else {
-            debug!(
-                "passed: verify MPT & SSZ proofs for {}-th receipt with root {:#x}",
-                self.transaction_index, self.receipts_root
-            );
-            Ok(())
-        }
+            receipt,
+        )
+    }
+}
+
+// Verifies raw bytes of a transaction with its SSZ proof in the header.
+pub(super) fn verify_transaction_in_header(
+    config: &ChainConfig,
+    header: &core::Header,
+    transaction_index: u64,
+    transaction_ssz_proof: &[core::Hash],
+    transaction: &[u8],
+) -> Result<(), TxVerificationError> {
+    // Since `MAX_BYTES_PER_TRANSACTION`.
+    // Ref: https://github.com/ethereum/consensus-specs/blob/v1.2.0/specs/bellatrix/beacon-chain.md#execution
+    VariableList::<u8, typenum::U1073741824>::new(transaction.to_vec())
+        .map_err(|_| TxVerificationError::SszError)
+        .and_then(|tx| {
+            let tx_root = profile!(TreeHash, tx.tree_hash_root());
+            let tx_index = transaction_index as usize;
+            let tx_in_block_offset =
+                specs::get_generalized_index_of_transaction_in_block_body_offset(
+                    config,
+                    header.slot,
+                );
+            let tx_in_block_index = tx_index + tx_in_block_offset;
+            if !ssz::verify_merkle_proof(
+                &header.body_root,
+                &tx_root,
+                transaction_ssz_proof,
+                tx_in_block_index,
+            ) {
+                warn!(
+                    "failed: verify SSZ proof for transaction {tx_root:#x} \
+                    (index: {tx_index}, offset: {tx_in_block_index})"
+                );
+                Err(TxVerificationError::TransactionSszProof)
+            } else {
+                debug!(
+                    "passed: verify SSZ proof for transaction {tx_root:#x} \
+                    (index: {tx_index}, offset: {tx_in_block_index})"
+                );
+                Ok(())
+            }
+        })
+}
+
+// Checks the hash of raw bytes of a transaction.
+//
+// The transaction hash is the keccak256 hash of the raw bytes of the transaction, which is the
+// typed transaction envelope for typed transactions.
+// Ref: https://eips.ethereum.org/EIPS/eip-2718#transactions
+pub(super) fn verify_transaction_hash(
+    transaction_index: u64,
+    transaction: &[u8],
+    transaction_hash: &core::Hash,
+) -> Result<(), TxVerificationError> {
+    let actual: core::Hash = mpt::keccak256(transaction).into();
+    if actual != *transaction_hash {
+        warn!(
+            "failed: check the hash of the {transaction_index}-th transaction \
+            (expect: {transaction_hash:#x}, actual: {actual:#x})"
+        );
+        Err(TxVerificationError::MismatchedTransactionHash)
+    } else {
+        debug!("passed: check the hash of the {transaction_index}-th transaction {actual:#x}");
+        Ok(())
+    }
+}
+
+// Decodes the status and the logs from raw bytes of a transaction receipt, which could be
+// a legacy receipt or a typed receipt.
+//
+// The receipts before Byzantium are rejected, since their first field is a 32-byte intermediate
+// state root rather than the status code.
+//
+// Ref:
+// - https://eips.ethereum.org/EIPS/eip-2718#receipts
+// - https://eips.ethereum.org/EIPS/eip-658
+fn decode_receipt(receipt: &[u8]) -> Option<(bool, Vec<core::ReceiptLog>)> {
+    let payload = match receipt.first() {
+        Some(receipt_type) if *receipt_type <= 0x7f => &receipt[1..],
+        Some(_) => receipt,
+        None => return None,
+    };
+    let rlp = Rlp::new(payload);
+    if rlp.item_count().ok()? != 4 {
+        return None;
+    }
+    let status = match rlp.at(0).ok()?.data().ok()? {
+        [] => false,
+        [1] => true,
+        _ => return None,
+    };
+    let logs = rlp
+        .at(3)
+        .ok()?
+        .iter()
+        .map(|log| {
+            if log.item_count().ok()? != 3 {
+                return None;
+            }
+            let address = log.at(0).ok()?.data().ok()?;
+            if address.len() != 20 {
+                return None;
+            }
+            let topics = log
+                .at(1)
+                .ok()?
+                .iter()
+                .map(|topic| {
+                    topic
+                        .data()
+                        .ok()
+                        .filter(|topic| topic.len() == 32)
+                        .map(core::Hash::from_slice)
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let data = log.at(2).ok()?.data().ok()?.to_vec();
+            Some(core::ReceiptLog {
+                address: H160::from_slice(address),
+                topics,
+                data,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((status, logs))
+}
+
+// Verifies raw bytes of a transaction receipt with its MPT proof in the receipts root, and the
+// SSZ proof of the receipts root in the header.
+pub(super) fn verify_receipt_in_header<T: AsRef<[u8]>>(
+    config: &ChainConfig,
+    header: &core::Header,
+    transaction_index: u64,
+    receipts_root: &core::Hash,
+    receipt_mpt_proof: &[T],
+    receipts_root_ssz_proof: &[core::Hash],
+    receipt: &[u8],
+) -> Result<(), TxVerificationError> {
+    let key = encode(&transaction_index);
+    let receipts_root_in_block_body =
+        specs::get_generalized_index_of_receipts_root_in_block_body(config, header.slot);
+    if !mpt::verify_proof(receipt_mpt_proof, receipts_root.as_bytes(), &key, receipt) {
+        warn!(
+            "failed: verify MPT proof for {transaction_index}-th receipt with root \
+            {receipts_root:#x}"
+        );
+        Err(TxVerificationError::ReceiptMptProof)
+    } else if !ssz::verify_merkle_proof(
+        &header.body_root,
+        receipts_root,
+        receipts_root_ssz_proof,
+        receipts_root_in_block_body,
+    ) {
+        warn!(
+            "failed: verify SSZ proof for {transaction_index}-th receipt with root \
+            {receipts_root:#x}"
+        );
+        Err(TxVerificationError::ReceiptsRootSszProof)
+    } else {
+        debug!(
+            "passed: verify MPT & SSZ proofs for {transaction_index}-th receipt with root \
+            {receipts_root:#x}"
+        );
+        Ok(())
     }
 }
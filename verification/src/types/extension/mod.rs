@@ -1,3 +1,4 @@
 //! Extensions for types -- add methods to types.
 
 mod core;
+mod packed;
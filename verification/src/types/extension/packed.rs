@@ -0,0 +1,250 @@
+//! Extensions for packed types -- verify the molecule readers without unpacking them.
+//!
+//! The results are same as the corresponding methods of the core types.
+
+use ::core::result::Result;
+use alloc::vec::Vec;
+
+use eth2_hashing::hash32_concat;
+use tree_hash::{merkle_root, Hash256};
+
+use super::core::{
+    balance_in_chunk, calc_compact_headers_cache, verify_receipt_in_header,
+    verify_transaction_hash, verify_transaction_in_header, ClientUpdateParts, HeaderRangeRef,
+};
+use crate::{
+    consensus_specs::ChainConfig,
+    error::{ClientUpdateError, TxVerificationError},
+    types::{core, packed, prelude::*},
+    utilities::{bls, mmr},
+};
+
+// The minimal count of chunks of a BLS public key, which has 48 bytes.
+const BLS_PUBKEY_CHUNKS_COUNT: usize = 2;
+
+impl packed::SyncCommitteeReader<'_> {
+    /// Calculates the tree hash root from the bytes of the public keys directly.
+    ///
+    /// Same as [`core::SyncCommittee::tree_hash_root`].
+    pub fn tree_hash_root(&self) -> core::Hash {
+        profile!(TreeHash, {
+            let pubkeys = self.pubkeys();
+            let pubkeys_roots = pubkeys
+                .iter()
+                .flat_map(|pubkey| merkle_root(pubkey.as_slice(), BLS_PUBKEY_CHUNKS_COUNT).0)
+                .collect::<Vec<_>>();
+            let pubkeys_root = merkle_root(&pubkeys_roots, pubkeys.len());
+            let aggregate_pubkey_root =
+                merkle_root(self.aggregate_pubkey().as_slice(), BLS_PUBKEY_CHUNKS_COUNT);
+            Hash256::from(hash32_concat(
+                pubkeys_root.as_bytes(),
+                aggregate_pubkey_root.as_bytes(),
+            ))
+        })
+    }
+}
+
+impl packed::HeaderRangeReader<'_> {
+    /// Rebuilds all headers and calculates their roots.
+    ///
+    /// Same as [`core::HeaderRange::calc_cache`].
+    pub fn calc_cache(
+        &self,
+        parent_root: core::Hash,
+        first_slot: u64,
+        maximal_slot: u64,
+    ) -> Result<Vec<mmr::HeaderWithCache>, ClientUpdateError> {
+        match self.to_enum() {
+            packed::HeaderRangeUnionReader::HeaderVec(headers) => {
+                let cached_headers = headers
+                    .iter()
+                    .map(|header| header.unpack().calc_cache())
+                    .collect();
+                Ok(cached_headers)
+            }
+            packed::HeaderRangeUnionReader::CompactHeaders(compact_headers) => {
+                calc_compact_headers_cache(
+                    compact_headers.first_slot().unpack(),
+                    compact_headers.items().iter().map(|item| item.unpack()),
+                    parent_root,
+                    first_slot,
+                    maximal_slot,
+                )
+            }
+        }
+    }
+}
+
+impl<'r> packed::ClientUpdateReader<'r> {
+    /// Verifies the new client and the update that make it be upgrade from the old client.
+    ///
+    /// Same as [`core::ClientUpdate::verify_client_update`], but the headers are not unpacked.
+    pub fn verify_client_update<'s, P: bls::PackedClientSyncCommittee<'s>>(
+        &self,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.verify_client_update_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_update`], with the preset and the fork schedule of the
+    /// chain.
+    pub fn verify_client_update_with_config<'s, P: bls::PackedClientSyncCommittee<'s>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        genesis_validators_root: core::Hash,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.parts().verify_client_update(
+            config,
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Verifies the new client and the force update that make it be upgrade from the old
+    /// client, when the finality is stalled.
+    ///
+    /// Same as [`core::ClientUpdate::verify_client_force_update`], but the headers are not
+    /// unpacked.
+    pub fn verify_client_force_update<'s, P: bls::PackedClientSyncCommittee<'s>>(
+        &self,
+        old_client: core::Client,
+        client_info: core::ClientInfo,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.verify_client_force_update_with_config(
+            &ChainConfig::MAINNET,
+            old_client,
+            client_info,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    /// Same as [`Self::verify_client_force_update`], with the preset and the fork schedule of
+    /// the chain.
+    pub fn verify_client_force_update_with_config<'s, P: bls::PackedClientSyncCommittee<'s>>(
+        &self,
+        config: &ChainConfig,
+        old_client: core::Client,
+        client_info: core::ClientInfo,
+        packed_client_sync_committee: P,
+        new_client: core::Client,
+    ) -> Result<(), ClientUpdateError> {
+        self.parts().verify_client_force_update(
+            config,
+            old_client,
+            client_info,
+            packed_client_sync_committee,
+            new_client,
+        )
+    }
+
+    fn parts(&self) -> ClientUpdateParts<'r> {
+        ClientUpdateParts {
+            attested_header: self.attested_header().unpack(),
+            finality_branch: self.finality_branch().unpack(),
+            sync_aggregate: self.sync_aggregate().unpack(),
+            signature_slot: self.signature_slot().unpack(),
+            new_headers_mmr_proof: self
+                .new_headers_mmr_proof()
+                .iter()
+                .map(|digest| digest.to_entity())
+                .collect(),
+            headers: HeaderRangeRef::Packed(self.headers()),
+        }
+    }
+}
+
+impl packed::TransactionProofReader<'_> {
+    /// Verifies raw bytes of the corresponding transaction and its receipt.
+    ///
+    /// Same as [`core::TransactionProof::verify_packed_payload`], but the MPT proof is not
+    /// unpacked.
+    pub fn verify_packed_payload(
+        &self,
+        payload: packed::TransactionPayloadReader,
+    ) -> Result<(), TxVerificationError> {
+        self.verify_packed_payload_with_config(&ChainConfig::MAINNET, payload)
+    }
+
+    /// Same as [`Self::verify_packed_payload`], with the fork schedule of the chain.
+    pub fn verify_packed_payload_with_config(
+        &self,
+        config: &ChainConfig,
+        payload: packed::TransactionPayloadReader,
+    ) -> Result<(), TxVerificationError> {
+        let header: core::Header = self.header().unpack();
+        let transaction_index: u64 = self.transaction_index().unpack();
+        let transaction_ssz_proof: core::SszProof = self.transaction_ssz_proof().unpack();
+        verify_transaction_in_header(
+            config,
+            &header,
+            transaction_index,
+            &transaction_ssz_proof,
+            payload.transaction().raw_data(),
+        )?;
+        let receipts_root: core::Hash = self.receipts_root().unpack();
+        let receipt_mpt_proof = self
+            .receipt_mpt_proof()
+            .iter()
+            .map(|node| node.raw_data())
+            .collect::<Vec<_>>();
+        let receipts_root_ssz_proof: core::SszProof = self.receipts_root_ssz_proof().unpack();
+        verify_receipt_in_header(
+            config,
+            &header,
+            transaction_index,
+            &receipts_root,
+            &receipt_mpt_proof,
+            &receipts_root_ssz_proof,
+            payload.receipt().raw_data(),
+        )
+    }
+
+    /// Verifies raw bytes of the corresponding transaction, and checks that the transaction has
+    /// the expected hash.
+    ///
+    /// Same as [`core::TransactionProof::verify_transaction_with_hash`].
+    pub fn verify_transaction_with_hash(
+        &self,
+        transaction: &[u8],
+        transaction_hash: &core::Hash,
+    ) -> Result<(), TxVerificationError> {
+        let transaction_index: u64 = self.transaction_index().unpack();
+        verify_transaction_hash(transaction_index, transaction, transaction_hash)?;
+        let header: core::Header = self.header().unpack();
+        let transaction_ssz_proof: core::SszProof = self.transaction_ssz_proof().unpack();
+        verify_transaction_in_header(
+            &ChainConfig::MAINNET,
+            &header,
+            transaction_index,
+            &transaction_ssz_proof,
+            transaction,
+        )
+    }
+}
+
+impl packed::ValidatorProofReader<'_> {
+    /// Returns the balance of the validator, which is packed in the chunk of balances.
+    ///
+    /// Same as [`core::ValidatorProof::balance`].
+    pub fn balance(&self) -> u64 {
+        let balances_chunk: core::Hash = self.balances_chunk().unpack();
+        balance_in_chunk(&balances_chunk, self.validator_index().unpack())
+    }
+}
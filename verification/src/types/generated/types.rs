@@ -2898,8 +2898,8 @@ impl molecule::prelude::Builder for BlsPubkeyBuilder {
     }
 }
 #[derive(Clone)]
-pub struct BlsSignature(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BlsSignature {
+pub struct BlsUncompressedPubkey(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlsUncompressedPubkey {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2908,25 +2908,25 @@ impl ::core::fmt::LowerHex for BlsSignature {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BlsSignature {
+impl ::core::fmt::Debug for BlsUncompressedPubkey {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BlsSignature {
+impl ::core::fmt::Display for BlsUncompressedPubkey {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for BlsSignature {
+impl ::core::default::Default for BlsUncompressedPubkey {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BlsSignature::new_unchecked(v)
+        BlsUncompressedPubkey::new_unchecked(v)
     }
 }
-impl BlsSignature {
+impl BlsUncompressedPubkey {
     const DEFAULT_VALUE: [u8; 96] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -3227,15 +3227,15 @@ impl BlsSignature {
     pub fn raw_data(&self) -> molecule::bytes::Bytes {
         self.as_bytes()
     }
-    pub fn as_reader<'r>(&'r self) -> BlsSignatureReader<'r> {
-        BlsSignatureReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> BlsUncompressedPubkeyReader<'r> {
+        BlsUncompressedPubkeyReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for BlsSignature {
-    type Builder = BlsSignatureBuilder;
-    const NAME: &'static str = "BlsSignature";
+impl molecule::prelude::Entity for BlsUncompressedPubkey {
+    type Builder = BlsUncompressedPubkeyBuilder;
+    const NAME: &'static str = "BlsUncompressedPubkey";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BlsSignature(data)
+        BlsUncompressedPubkey(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3244,10 +3244,10 @@ impl molecule::prelude::Entity for BlsSignature {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BlsSignatureReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlsUncompressedPubkeyReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BlsSignatureReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlsUncompressedPubkeyReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -3354,8 +3354,8 @@ impl molecule::prelude::Entity for BlsSignature {
     }
 }
 #[derive(Clone, Copy)]
-pub struct BlsSignatureReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BlsSignatureReader<'r> {
+pub struct BlsUncompressedPubkeyReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlsUncompressedPubkeyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3364,19 +3364,19 @@ impl<'r> ::core::fmt::LowerHex for BlsSignatureReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for BlsSignatureReader<'r> {
+impl<'r> ::core::fmt::Debug for BlsUncompressedPubkeyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for BlsSignatureReader<'r> {
+impl<'r> ::core::fmt::Display for BlsUncompressedPubkeyReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         let raw_data = hex_string(&self.raw_data());
         write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl<'r> BlsSignatureReader<'r> {
+impl<'r> BlsUncompressedPubkeyReader<'r> {
     pub const TOTAL_SIZE: usize = 96;
     pub const ITEM_SIZE: usize = 1;
     pub const ITEM_COUNT: usize = 96;
@@ -3672,14 +3672,14 @@ impl<'r> BlsSignatureReader<'r> {
         self.as_slice()
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for BlsSignatureReader<'r> {
-    type Entity = BlsSignature;
-    const NAME: &'static str = "BlsSignatureReader";
+impl<'r> molecule::prelude::Reader<'r> for BlsUncompressedPubkeyReader<'r> {
+    type Entity = BlsUncompressedPubkey;
+    const NAME: &'static str = "BlsUncompressedPubkeyReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        BlsSignatureReader(slice)
+        BlsUncompressedPubkeyReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3693,15 +3693,15 @@ impl<'r> molecule::prelude::Reader<'r> for BlsSignatureReader<'r> {
         Ok(())
     }
 }
-pub struct BlsSignatureBuilder(pub(crate) [Byte; 96]);
-impl ::core::fmt::Debug for BlsSignatureBuilder {
+pub struct BlsUncompressedPubkeyBuilder(pub(crate) [Byte; 96]);
+impl ::core::fmt::Debug for BlsUncompressedPubkeyBuilder {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:?})", Self::NAME, &self.0[..])
     }
 }
-impl ::core::default::Default for BlsSignatureBuilder {
+impl ::core::default::Default for BlsUncompressedPubkeyBuilder {
     fn default() -> Self {
-        BlsSignatureBuilder([
+        BlsUncompressedPubkeyBuilder([
             Byte::default(),
             Byte::default(),
             Byte::default(),
@@ -3801,7 +3801,7 @@ impl ::core::default::Default for BlsSignatureBuilder {
         ])
     }
 }
-impl BlsSignatureBuilder {
+impl BlsUncompressedPubkeyBuilder {
     pub const TOTAL_SIZE: usize = 96;
     pub const ITEM_SIZE: usize = 1;
     pub const ITEM_COUNT: usize = 96;
@@ -4194,9 +4194,9 @@ impl BlsSignatureBuilder {
         self
     }
 }
-impl molecule::prelude::Builder for BlsSignatureBuilder {
-    type Entity = BlsSignature;
-    const NAME: &'static str = "BlsSignatureBuilder";
+impl molecule::prelude::Builder for BlsUncompressedPubkeyBuilder {
+    type Entity = BlsUncompressedPubkey;
+    const NAME: &'static str = "BlsUncompressedPubkeyBuilder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
@@ -4303,12 +4303,12 @@ impl molecule::prelude::Builder for BlsSignatureBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BlsSignature::new_unchecked(inner.into())
+        BlsUncompressedPubkey::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct BlsPubkeyVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for BlsPubkeyVec {
+pub struct BlsSignature(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlsSignature {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4317,552 +4317,334 @@ impl ::core::fmt::LowerHex for BlsPubkeyVec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for BlsPubkeyVec {
+impl ::core::fmt::Debug for BlsSignature {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for BlsPubkeyVec {
+impl ::core::fmt::Display for BlsSignature {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
     }
 }
-impl ::core::default::Default for BlsPubkeyVec {
+impl ::core::default::Default for BlsSignature {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        BlsPubkeyVec::new_unchecked(v)
+        BlsSignature::new_unchecked(v)
     }
 }
-impl BlsPubkeyVec {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 48;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+impl BlsSignature {
+    const DEFAULT_VALUE: [u8; 96] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
     }
-    pub fn get(&self, idx: usize) -> Option<BlsPubkey> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
     }
-    pub fn get_unchecked(&self, idx: usize) -> BlsPubkey {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        BlsPubkey::new_unchecked(self.0.slice(start..end))
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
     }
-    pub fn as_reader<'r>(&'r self) -> BlsPubkeyVecReader<'r> {
-        BlsPubkeyVecReader::new_unchecked(self.as_slice())
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
     }
-}
-impl molecule::prelude::Entity for BlsPubkeyVec {
-    type Builder = BlsPubkeyVecBuilder;
-    const NAME: &'static str = "BlsPubkeyVec";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        BlsPubkeyVec(data)
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BlsPubkeyVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        BlsPubkeyVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
     }
-}
-#[derive(Clone, Copy)]
-pub struct BlsPubkeyVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for BlsPubkeyVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
     }
-}
-impl<'r> ::core::fmt::Debug for BlsPubkeyVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
     }
-}
-impl<'r> ::core::fmt::Display for BlsPubkeyVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
     }
-}
-impl<'r> BlsPubkeyVecReader<'r> {
-    pub const ITEM_SIZE: usize = 48;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
     }
-    pub fn get(&self, idx: usize) -> Option<BlsPubkeyReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
     }
-    pub fn get_unchecked(&self, idx: usize) -> BlsPubkeyReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        BlsPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for BlsPubkeyVecReader<'r> {
-    type Entity = BlsPubkeyVec;
-    const NAME: &'static str = "BlsPubkeyVecReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        BlsPubkeyVecReader(slice)
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        Ok(())
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
     }
-}
-#[derive(Debug, Default)]
-pub struct BlsPubkeyVecBuilder(pub(crate) Vec<BlsPubkey>);
-impl BlsPubkeyVecBuilder {
-    pub const ITEM_SIZE: usize = 48;
-    pub fn set(mut self, v: Vec<BlsPubkey>) -> Self {
-        self.0 = v;
-        self
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
     }
-    pub fn push(mut self, v: BlsPubkey) -> Self {
-        self.0.push(v);
-        self
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = BlsPubkey>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
-        self
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
     }
-    pub fn replace(&mut self, index: usize, v: BlsPubkey) -> Option<BlsPubkey> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
     }
-}
-impl molecule::prelude::Builder for BlsPubkeyVecBuilder {
-    type Entity = BlsPubkeyVec;
-    const NAME: &'static str = "BlsPubkeyVecBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
-        Ok(())
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        BlsPubkeyVec::new_unchecked(inner.into())
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33..34))
     }
-}
-pub struct BlsPubkeyVecIterator(BlsPubkeyVec, usize, usize);
-impl ::core::iter::Iterator for BlsPubkeyVecIterator {
-    type Item = BlsPubkey;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34..35))
     }
-}
-impl ::core::iter::ExactSizeIterator for BlsPubkeyVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35..36))
     }
-}
-impl ::core::iter::IntoIterator for BlsPubkeyVec {
-    type Item = BlsPubkey;
-    type IntoIter = BlsPubkeyVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        BlsPubkeyVecIterator(self, 0, len)
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36..37))
     }
-}
-impl<'r> BlsPubkeyVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> BlsPubkeyVecReaderIterator<'t, 'r> {
-        BlsPubkeyVecReaderIterator(&self, 0, self.len())
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37..38))
     }
-}
-pub struct BlsPubkeyVecReaderIterator<'t, 'r>(&'t BlsPubkeyVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for BlsPubkeyVecReaderIterator<'t, 'r> {
-    type Item = BlsPubkeyReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38..39))
     }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BlsPubkeyVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39..40))
     }
-}
-#[derive(Clone)]
-pub struct Header(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Header {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40..41))
     }
-}
-impl ::core::fmt::Debug for Header {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41..42))
     }
-}
-impl ::core::fmt::Display for Header {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "slot", self.slot())?;
-        write!(f, ", {}: {}", "proposer_index", self.proposer_index())?;
-        write!(f, ", {}: {}", "parent_root", self.parent_root())?;
-        write!(f, ", {}: {}", "state_root", self.state_root())?;
-        write!(f, ", {}: {}", "body_root", self.body_root())?;
-        write!(f, " }}")
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42..43))
     }
-}
-impl ::core::default::Default for Header {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Header::new_unchecked(v)
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43..44))
     }
-}
-impl Header {
-    const DEFAULT_VALUE: [u8; 112] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 112;
-    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn slot(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(0..8))
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44..45))
     }
-    pub fn proposer_index(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(8..16))
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45..46))
     }
-    pub fn parent_root(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(16..48))
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46..47))
     }
-    pub fn state_root(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(48..80))
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47..48))
     }
-    pub fn body_root(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(80..112))
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48..49))
     }
-    pub fn as_reader<'r>(&'r self) -> HeaderReader<'r> {
-        HeaderReader::new_unchecked(self.as_slice())
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49..50))
     }
-}
-impl molecule::prelude::Entity for Header {
-    type Builder = HeaderBuilder;
-    const NAME: &'static str = "Header";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Header(data)
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50..51))
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51..52))
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52..53))
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderReader::from_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53..54))
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54..55))
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55..56))
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .slot(self.slot())
-            .proposer_index(self.proposer_index())
-            .parent_root(self.parent_root())
-            .state_root(self.state_root())
-            .body_root(self.body_root())
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56..57))
     }
-}
-#[derive(Clone, Copy)]
-pub struct HeaderReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HeaderReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57..58))
     }
-}
-impl<'r> ::core::fmt::Debug for HeaderReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58..59))
     }
-}
-impl<'r> ::core::fmt::Display for HeaderReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "slot", self.slot())?;
-        write!(f, ", {}: {}", "proposer_index", self.proposer_index())?;
-        write!(f, ", {}: {}", "parent_root", self.parent_root())?;
-        write!(f, ", {}: {}", "state_root", self.state_root())?;
-        write!(f, ", {}: {}", "body_root", self.body_root())?;
-        write!(f, " }}")
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59..60))
     }
-}
-impl<'r> HeaderReader<'r> {
-    pub const TOTAL_SIZE: usize = 112;
-    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn slot(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60..61))
     }
-    pub fn proposer_index(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[8..16])
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61..62))
     }
-    pub fn parent_root(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[16..48])
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62..63))
     }
-    pub fn state_root(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[48..80])
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63..64))
     }
-    pub fn body_root(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[80..112])
+    pub fn nth64(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(64..65))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for HeaderReader<'r> {
-    type Entity = Header;
-    const NAME: &'static str = "HeaderReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    pub fn nth65(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(65..66))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        HeaderReader(slice)
+    pub fn nth66(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(66..67))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn nth67(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(67..68))
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
-        }
-        Ok(())
+    pub fn nth68(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(68..69))
     }
-}
-#[derive(Debug, Default)]
-pub struct HeaderBuilder {
-    pub(crate) slot: Uint64,
-    pub(crate) proposer_index: Uint64,
-    pub(crate) parent_root: Hash,
-    pub(crate) state_root: Hash,
-    pub(crate) body_root: Hash,
-}
-impl HeaderBuilder {
-    pub const TOTAL_SIZE: usize = 112;
-    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn slot(mut self, v: Uint64) -> Self {
-        self.slot = v;
-        self
+    pub fn nth69(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(69..70))
     }
-    pub fn proposer_index(mut self, v: Uint64) -> Self {
-        self.proposer_index = v;
-        self
+    pub fn nth70(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(70..71))
     }
-    pub fn parent_root(mut self, v: Hash) -> Self {
-        self.parent_root = v;
-        self
+    pub fn nth71(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(71..72))
     }
-    pub fn state_root(mut self, v: Hash) -> Self {
-        self.state_root = v;
-        self
+    pub fn nth72(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(72..73))
     }
-    pub fn body_root(mut self, v: Hash) -> Self {
-        self.body_root = v;
-        self
+    pub fn nth73(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(73..74))
     }
-}
-impl molecule::prelude::Builder for HeaderBuilder {
-    type Entity = Header;
-    const NAME: &'static str = "HeaderBuilder";
-    fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+    pub fn nth74(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(74..75))
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.slot.as_slice())?;
-        writer.write_all(self.proposer_index.as_slice())?;
-        writer.write_all(self.parent_root.as_slice())?;
-        writer.write_all(self.state_root.as_slice())?;
-        writer.write_all(self.body_root.as_slice())?;
-        Ok(())
+    pub fn nth75(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(75..76))
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Header::new_unchecked(inner.into())
+    pub fn nth76(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(76..77))
     }
-}
-#[derive(Clone)]
-pub struct HeaderVec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for HeaderVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+    pub fn nth77(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(77..78))
     }
-}
-impl ::core::fmt::Debug for HeaderVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+    pub fn nth78(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(78..79))
     }
-}
-impl ::core::fmt::Display for HeaderVec {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+    pub fn nth79(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(79..80))
     }
-}
-impl ::core::default::Default for HeaderVec {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        HeaderVec::new_unchecked(v)
+    pub fn nth80(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(80..81))
     }
-}
-impl HeaderVec {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 112;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    pub fn nth81(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(81..82))
     }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub fn nth82(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(82..83))
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn nth83(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(83..84))
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn nth84(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(84..85))
     }
-    pub fn get(&self, idx: usize) -> Option<Header> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn nth85(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(85..86))
     }
-    pub fn get_unchecked(&self, idx: usize) -> Header {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Header::new_unchecked(self.0.slice(start..end))
+    pub fn nth86(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(86..87))
     }
-    pub fn as_reader<'r>(&'r self) -> HeaderVecReader<'r> {
-        HeaderVecReader::new_unchecked(self.as_slice())
+    pub fn nth87(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(87..88))
+    }
+    pub fn nth88(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(88..89))
+    }
+    pub fn nth89(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(89..90))
+    }
+    pub fn nth90(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(90..91))
+    }
+    pub fn nth91(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(91..92))
+    }
+    pub fn nth92(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(92..93))
+    }
+    pub fn nth93(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(93..94))
+    }
+    pub fn nth94(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(94..95))
+    }
+    pub fn nth95(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(95..96))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> BlsSignatureReader<'r> {
+        BlsSignatureReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for HeaderVec {
-    type Builder = HeaderVecBuilder;
-    const NAME: &'static str = "HeaderVec";
+impl molecule::prelude::Entity for BlsSignature {
+    type Builder = BlsSignatureBuilder;
+    const NAME: &'static str = "BlsSignature";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        HeaderVec(data)
+        BlsSignature(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4871,202 +4653,8817 @@ impl molecule::prelude::Entity for HeaderVec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderVecReader::from_slice(slice).map(|reader| reader.to_entity())
+        BlsSignatureReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        HeaderVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        BlsSignatureReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
-    }
-}
-#[derive(Clone, Copy)]
-pub struct HeaderVecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for HeaderVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl<'r> ::core::fmt::Debug for HeaderVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
-    }
-}
-impl<'r> ::core::fmt::Display for HeaderVecReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
-    }
-}
-impl<'r> HeaderVecReader<'r> {
-    pub const ITEM_SIZE: usize = 112;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<HeaderReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
-    }
-    pub fn get_unchecked(&self, idx: usize) -> HeaderReader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+            self.nth64(),
+            self.nth65(),
+            self.nth66(),
+            self.nth67(),
+            self.nth68(),
+            self.nth69(),
+            self.nth70(),
+            self.nth71(),
+            self.nth72(),
+            self.nth73(),
+            self.nth74(),
+            self.nth75(),
+            self.nth76(),
+            self.nth77(),
+            self.nth78(),
+            self.nth79(),
+            self.nth80(),
+            self.nth81(),
+            self.nth82(),
+            self.nth83(),
+            self.nth84(),
+            self.nth85(),
+            self.nth86(),
+            self.nth87(),
+            self.nth88(),
+            self.nth89(),
+            self.nth90(),
+            self.nth91(),
+            self.nth92(),
+            self.nth93(),
+            self.nth94(),
+            self.nth95(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BlsSignatureReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlsSignatureReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BlsSignatureReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BlsSignatureReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> BlsSignatureReader<'r> {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    }
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
+    }
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    }
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
+    }
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
+    }
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
+    }
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
+    }
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
+    }
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
+    }
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
+    }
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
+    }
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
+    }
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
+    }
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
+    }
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
+    }
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
+    }
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
+    }
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
+    }
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
+    }
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
+    }
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
+    }
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
+    }
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
+    }
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
+    }
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
+    }
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
+    }
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
+    }
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
+    }
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
+    }
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    }
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    }
+    pub fn nth64(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[64..65])
+    }
+    pub fn nth65(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[65..66])
+    }
+    pub fn nth66(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[66..67])
+    }
+    pub fn nth67(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[67..68])
+    }
+    pub fn nth68(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[68..69])
+    }
+    pub fn nth69(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[69..70])
+    }
+    pub fn nth70(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[70..71])
+    }
+    pub fn nth71(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[71..72])
+    }
+    pub fn nth72(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[72..73])
+    }
+    pub fn nth73(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[73..74])
+    }
+    pub fn nth74(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[74..75])
+    }
+    pub fn nth75(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[75..76])
+    }
+    pub fn nth76(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[76..77])
+    }
+    pub fn nth77(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[77..78])
+    }
+    pub fn nth78(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[78..79])
+    }
+    pub fn nth79(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[79..80])
+    }
+    pub fn nth80(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[80..81])
+    }
+    pub fn nth81(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[81..82])
+    }
+    pub fn nth82(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[82..83])
+    }
+    pub fn nth83(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[83..84])
+    }
+    pub fn nth84(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[84..85])
+    }
+    pub fn nth85(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[85..86])
+    }
+    pub fn nth86(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[86..87])
+    }
+    pub fn nth87(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[87..88])
+    }
+    pub fn nth88(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[88..89])
+    }
+    pub fn nth89(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[89..90])
+    }
+    pub fn nth90(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[90..91])
+    }
+    pub fn nth91(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[91..92])
+    }
+    pub fn nth92(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[92..93])
+    }
+    pub fn nth93(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[93..94])
+    }
+    pub fn nth94(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[94..95])
+    }
+    pub fn nth95(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[95..96])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BlsSignatureReader<'r> {
+    type Entity = BlsSignature;
+    const NAME: &'static str = "BlsSignatureReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BlsSignatureReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct BlsSignatureBuilder(pub(crate) [Byte; 96]);
+impl ::core::fmt::Debug for BlsSignatureBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for BlsSignatureBuilder {
+    fn default() -> Self {
+        BlsSignatureBuilder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl BlsSignatureBuilder {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 96;
+    pub fn set(mut self, v: [Byte; 96]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
+    }
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
+    }
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
+    }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
+    }
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
+    }
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
+    }
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
+    }
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
+    }
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
+    }
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
+    }
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
+    }
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
+    }
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
+    }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
+    }
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
+    }
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
+    }
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
+    }
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
+    }
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
+    }
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
+    }
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
+    }
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
+    }
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
+    }
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
+    }
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
+    }
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
+    }
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
+    }
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
+    }
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
+    }
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
+    }
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
+        self
+    }
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
+        self
+    }
+    pub fn nth64(mut self, v: Byte) -> Self {
+        self.0[64] = v;
+        self
+    }
+    pub fn nth65(mut self, v: Byte) -> Self {
+        self.0[65] = v;
+        self
+    }
+    pub fn nth66(mut self, v: Byte) -> Self {
+        self.0[66] = v;
+        self
+    }
+    pub fn nth67(mut self, v: Byte) -> Self {
+        self.0[67] = v;
+        self
+    }
+    pub fn nth68(mut self, v: Byte) -> Self {
+        self.0[68] = v;
+        self
+    }
+    pub fn nth69(mut self, v: Byte) -> Self {
+        self.0[69] = v;
+        self
+    }
+    pub fn nth70(mut self, v: Byte) -> Self {
+        self.0[70] = v;
+        self
+    }
+    pub fn nth71(mut self, v: Byte) -> Self {
+        self.0[71] = v;
+        self
+    }
+    pub fn nth72(mut self, v: Byte) -> Self {
+        self.0[72] = v;
+        self
+    }
+    pub fn nth73(mut self, v: Byte) -> Self {
+        self.0[73] = v;
+        self
+    }
+    pub fn nth74(mut self, v: Byte) -> Self {
+        self.0[74] = v;
+        self
+    }
+    pub fn nth75(mut self, v: Byte) -> Self {
+        self.0[75] = v;
+        self
+    }
+    pub fn nth76(mut self, v: Byte) -> Self {
+        self.0[76] = v;
+        self
+    }
+    pub fn nth77(mut self, v: Byte) -> Self {
+        self.0[77] = v;
+        self
+    }
+    pub fn nth78(mut self, v: Byte) -> Self {
+        self.0[78] = v;
+        self
+    }
+    pub fn nth79(mut self, v: Byte) -> Self {
+        self.0[79] = v;
+        self
+    }
+    pub fn nth80(mut self, v: Byte) -> Self {
+        self.0[80] = v;
+        self
+    }
+    pub fn nth81(mut self, v: Byte) -> Self {
+        self.0[81] = v;
+        self
+    }
+    pub fn nth82(mut self, v: Byte) -> Self {
+        self.0[82] = v;
+        self
+    }
+    pub fn nth83(mut self, v: Byte) -> Self {
+        self.0[83] = v;
+        self
+    }
+    pub fn nth84(mut self, v: Byte) -> Self {
+        self.0[84] = v;
+        self
+    }
+    pub fn nth85(mut self, v: Byte) -> Self {
+        self.0[85] = v;
+        self
+    }
+    pub fn nth86(mut self, v: Byte) -> Self {
+        self.0[86] = v;
+        self
+    }
+    pub fn nth87(mut self, v: Byte) -> Self {
+        self.0[87] = v;
+        self
+    }
+    pub fn nth88(mut self, v: Byte) -> Self {
+        self.0[88] = v;
+        self
+    }
+    pub fn nth89(mut self, v: Byte) -> Self {
+        self.0[89] = v;
+        self
+    }
+    pub fn nth90(mut self, v: Byte) -> Self {
+        self.0[90] = v;
+        self
+    }
+    pub fn nth91(mut self, v: Byte) -> Self {
+        self.0[91] = v;
+        self
+    }
+    pub fn nth92(mut self, v: Byte) -> Self {
+        self.0[92] = v;
+        self
+    }
+    pub fn nth93(mut self, v: Byte) -> Self {
+        self.0[93] = v;
+        self
+    }
+    pub fn nth94(mut self, v: Byte) -> Self {
+        self.0[94] = v;
+        self
+    }
+    pub fn nth95(mut self, v: Byte) -> Self {
+        self.0[95] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BlsSignatureBuilder {
+    type Entity = BlsSignature;
+    const NAME: &'static str = "BlsSignatureBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        writer.write_all(self.0[64].as_slice())?;
+        writer.write_all(self.0[65].as_slice())?;
+        writer.write_all(self.0[66].as_slice())?;
+        writer.write_all(self.0[67].as_slice())?;
+        writer.write_all(self.0[68].as_slice())?;
+        writer.write_all(self.0[69].as_slice())?;
+        writer.write_all(self.0[70].as_slice())?;
+        writer.write_all(self.0[71].as_slice())?;
+        writer.write_all(self.0[72].as_slice())?;
+        writer.write_all(self.0[73].as_slice())?;
+        writer.write_all(self.0[74].as_slice())?;
+        writer.write_all(self.0[75].as_slice())?;
+        writer.write_all(self.0[76].as_slice())?;
+        writer.write_all(self.0[77].as_slice())?;
+        writer.write_all(self.0[78].as_slice())?;
+        writer.write_all(self.0[79].as_slice())?;
+        writer.write_all(self.0[80].as_slice())?;
+        writer.write_all(self.0[81].as_slice())?;
+        writer.write_all(self.0[82].as_slice())?;
+        writer.write_all(self.0[83].as_slice())?;
+        writer.write_all(self.0[84].as_slice())?;
+        writer.write_all(self.0[85].as_slice())?;
+        writer.write_all(self.0[86].as_slice())?;
+        writer.write_all(self.0[87].as_slice())?;
+        writer.write_all(self.0[88].as_slice())?;
+        writer.write_all(self.0[89].as_slice())?;
+        writer.write_all(self.0[90].as_slice())?;
+        writer.write_all(self.0[91].as_slice())?;
+        writer.write_all(self.0[92].as_slice())?;
+        writer.write_all(self.0[93].as_slice())?;
+        writer.write_all(self.0[94].as_slice())?;
+        writer.write_all(self.0[95].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BlsSignature::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BlsPubkeyVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlsPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BlsPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BlsPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BlsPubkeyVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BlsPubkeyVec::new_unchecked(v)
+    }
+}
+impl BlsPubkeyVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 48;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BlsPubkey> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BlsPubkey {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        BlsPubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> BlsPubkeyVecReader<'r> {
+        BlsPubkeyVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BlsPubkeyVec {
+    type Builder = BlsPubkeyVecBuilder;
+    const NAME: &'static str = "BlsPubkeyVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BlsPubkeyVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BlsPubkeyVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BlsPubkeyVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BlsPubkeyVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlsPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BlsPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BlsPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BlsPubkeyVecReader<'r> {
+    pub const ITEM_SIZE: usize = 48;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BlsPubkeyReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BlsPubkeyReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        BlsPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BlsPubkeyVecReader<'r> {
+    type Entity = BlsPubkeyVec;
+    const NAME: &'static str = "BlsPubkeyVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BlsPubkeyVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BlsPubkeyVecBuilder(pub(crate) Vec<BlsPubkey>);
+impl BlsPubkeyVecBuilder {
+    pub const ITEM_SIZE: usize = 48;
+    pub fn set(mut self, v: Vec<BlsPubkey>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BlsPubkey) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BlsPubkey>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: BlsPubkey) -> Option<BlsPubkey> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BlsPubkeyVecBuilder {
+    type Entity = BlsPubkeyVec;
+    const NAME: &'static str = "BlsPubkeyVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BlsPubkeyVec::new_unchecked(inner.into())
+    }
+}
+pub struct BlsPubkeyVecIterator(BlsPubkeyVec, usize, usize);
+impl ::core::iter::Iterator for BlsPubkeyVecIterator {
+    type Item = BlsPubkey;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BlsPubkeyVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BlsPubkeyVec {
+    type Item = BlsPubkey;
+    type IntoIter = BlsPubkeyVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BlsPubkeyVecIterator(self, 0, len)
+    }
+}
+impl<'r> BlsPubkeyVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> BlsPubkeyVecReaderIterator<'t, 'r> {
+        BlsPubkeyVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BlsPubkeyVecReaderIterator<'t, 'r>(&'t BlsPubkeyVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BlsPubkeyVecReaderIterator<'t, 'r> {
+    type Item = BlsPubkeyReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BlsPubkeyVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BlsUncompressedPubkeyVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BlsUncompressedPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BlsUncompressedPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BlsUncompressedPubkeyVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BlsUncompressedPubkeyVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BlsUncompressedPubkeyVec::new_unchecked(v)
+    }
+}
+impl BlsUncompressedPubkeyVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 96;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BlsUncompressedPubkey> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BlsUncompressedPubkey {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        BlsUncompressedPubkey::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> BlsUncompressedPubkeyVecReader<'r> {
+        BlsUncompressedPubkeyVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BlsUncompressedPubkeyVec {
+    type Builder = BlsUncompressedPubkeyVecBuilder;
+    const NAME: &'static str = "BlsUncompressedPubkeyVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BlsUncompressedPubkeyVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BlsUncompressedPubkeyVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BlsUncompressedPubkeyVecReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BlsUncompressedPubkeyVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BlsUncompressedPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BlsUncompressedPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BlsUncompressedPubkeyVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BlsUncompressedPubkeyVecReader<'r> {
+    pub const ITEM_SIZE: usize = 96;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BlsUncompressedPubkeyReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BlsUncompressedPubkeyReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        BlsUncompressedPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BlsUncompressedPubkeyVecReader<'r> {
+    type Entity = BlsUncompressedPubkeyVec;
+    const NAME: &'static str = "BlsUncompressedPubkeyVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BlsUncompressedPubkeyVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BlsUncompressedPubkeyVecBuilder(pub(crate) Vec<BlsUncompressedPubkey>);
+impl BlsUncompressedPubkeyVecBuilder {
+    pub const ITEM_SIZE: usize = 96;
+    pub fn set(mut self, v: Vec<BlsUncompressedPubkey>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BlsUncompressedPubkey) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BlsUncompressedPubkey>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: BlsUncompressedPubkey,
+    ) -> Option<BlsUncompressedPubkey> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BlsUncompressedPubkeyVecBuilder {
+    type Entity = BlsUncompressedPubkeyVec;
+    const NAME: &'static str = "BlsUncompressedPubkeyVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BlsUncompressedPubkeyVec::new_unchecked(inner.into())
+    }
+}
+pub struct BlsUncompressedPubkeyVecIterator(BlsUncompressedPubkeyVec, usize, usize);
+impl ::core::iter::Iterator for BlsUncompressedPubkeyVecIterator {
+    type Item = BlsUncompressedPubkey;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BlsUncompressedPubkeyVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BlsUncompressedPubkeyVec {
+    type Item = BlsUncompressedPubkey;
+    type IntoIter = BlsUncompressedPubkeyVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BlsUncompressedPubkeyVecIterator(self, 0, len)
+    }
+}
+impl<'r> BlsUncompressedPubkeyVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> BlsUncompressedPubkeyVecReaderIterator<'t, 'r> {
+        BlsUncompressedPubkeyVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BlsUncompressedPubkeyVecReaderIterator<'t, 'r>(
+    &'t BlsUncompressedPubkeyVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BlsUncompressedPubkeyVecReaderIterator<'t, 'r> {
+    type Item = BlsUncompressedPubkeyReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator
+    for BlsUncompressedPubkeyVecReaderIterator<'t, 'r>
+{
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Header(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Header {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "slot", self.slot())?;
+        write!(f, ", {}: {}", "proposer_index", self.proposer_index())?;
+        write!(f, ", {}: {}", "parent_root", self.parent_root())?;
+        write!(f, ", {}: {}", "state_root", self.state_root())?;
+        write!(f, ", {}: {}", "body_root", self.body_root())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Header {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Header::new_unchecked(v)
+    }
+}
+impl Header {
+    const DEFAULT_VALUE: [u8; 112] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 112;
+    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0..8))
+    }
+    pub fn proposer_index(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(8..16))
+    }
+    pub fn parent_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(16..48))
+    }
+    pub fn state_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(48..80))
+    }
+    pub fn body_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(80..112))
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderReader<'r> {
+        HeaderReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Header {
+    type Builder = HeaderBuilder;
+    const NAME: &'static str = "Header";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Header(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .slot(self.slot())
+            .proposer_index(self.proposer_index())
+            .parent_root(self.parent_root())
+            .state_root(self.state_root())
+            .body_root(self.body_root())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "slot", self.slot())?;
+        write!(f, ", {}: {}", "proposer_index", self.proposer_index())?;
+        write!(f, ", {}: {}", "parent_root", self.parent_root())?;
+        write!(f, ", {}: {}", "state_root", self.state_root())?;
+        write!(f, ", {}: {}", "body_root", self.body_root())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> HeaderReader<'r> {
+    pub const TOTAL_SIZE: usize = 112;
+    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+    pub fn proposer_index(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[8..16])
+    }
+    pub fn parent_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[16..48])
+    }
+    pub fn state_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[48..80])
+    }
+    pub fn body_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[80..112])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HeaderReader<'r> {
+    type Entity = Header;
+    const NAME: &'static str = "HeaderReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HeaderBuilder {
+    pub(crate) slot: Uint64,
+    pub(crate) proposer_index: Uint64,
+    pub(crate) parent_root: Hash,
+    pub(crate) state_root: Hash,
+    pub(crate) body_root: Hash,
+}
+impl HeaderBuilder {
+    pub const TOTAL_SIZE: usize = 112;
+    pub const FIELD_SIZES: [usize; 5] = [8, 8, 32, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn slot(mut self, v: Uint64) -> Self {
+        self.slot = v;
+        self
+    }
+    pub fn proposer_index(mut self, v: Uint64) -> Self {
+        self.proposer_index = v;
+        self
+    }
+    pub fn parent_root(mut self, v: Hash) -> Self {
+        self.parent_root = v;
+        self
+    }
+    pub fn state_root(mut self, v: Hash) -> Self {
+        self.state_root = v;
+        self
+    }
+    pub fn body_root(mut self, v: Hash) -> Self {
+        self.body_root = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for HeaderBuilder {
+    type Entity = Header;
+    const NAME: &'static str = "HeaderBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.slot.as_slice())?;
+        writer.write_all(self.proposer_index.as_slice())?;
+        writer.write_all(self.parent_root.as_slice())?;
+        writer.write_all(self.state_root.as_slice())?;
+        writer.write_all(self.body_root.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Header::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct HeaderVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for HeaderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for HeaderVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HeaderVec::new_unchecked(v)
+    }
+}
+impl HeaderVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 112;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Header> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Header {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderVecReader<'r> {
+        HeaderVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HeaderVec {
+    type Builder = HeaderVecBuilder;
+    const NAME: &'static str = "HeaderVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HeaderVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> HeaderVecReader<'r> {
+    pub const ITEM_SIZE: usize = 112;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<HeaderReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> HeaderReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
         let end = start + Self::ITEM_SIZE;
         HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for HeaderVecReader<'r> {
-    type Entity = HeaderVec;
-    const NAME: &'static str = "HeaderVecReader";
+impl<'r> molecule::prelude::Reader<'r> for HeaderVecReader<'r> {
+    type Entity = HeaderVec;
+    const NAME: &'static str = "HeaderVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HeaderVecBuilder(pub(crate) Vec<Header>);
+impl HeaderVecBuilder {
+    pub const ITEM_SIZE: usize = 112;
+    pub fn set(mut self, v: Vec<Header>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Header) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Header>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Header) -> Option<Header> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for HeaderVecBuilder {
+    type Entity = HeaderVec;
+    const NAME: &'static str = "HeaderVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HeaderVec::new_unchecked(inner.into())
+    }
+}
+pub struct HeaderVecIterator(HeaderVec, usize, usize);
+impl ::core::iter::Iterator for HeaderVecIterator {
+    type Item = Header;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for HeaderVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for HeaderVec {
+    type Item = Header;
+    type IntoIter = HeaderVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        HeaderVecIterator(self, 0, len)
+    }
+}
+impl<'r> HeaderVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> HeaderVecReaderIterator<'t, 'r> {
+        HeaderVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct HeaderVecReaderIterator<'t, 'r>(&'t HeaderVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for HeaderVecReaderIterator<'t, 'r> {
+    type Item = HeaderReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HeaderVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct EmptySlots(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EmptySlots {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EmptySlots {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EmptySlots {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for EmptySlots {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        EmptySlots::new_unchecked(v)
+    }
+}
+impl EmptySlots {
+    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 1] = [8];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn count(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0..8))
+    }
+    pub fn as_reader<'r>(&'r self) -> EmptySlotsReader<'r> {
+        EmptySlotsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EmptySlots {
+    type Builder = EmptySlotsBuilder;
+    const NAME: &'static str = "EmptySlots";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EmptySlots(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EmptySlotsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EmptySlotsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().count(self.count())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EmptySlotsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EmptySlotsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EmptySlotsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EmptySlotsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "count", self.count())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> EmptySlotsReader<'r> {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 1] = [8];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn count(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EmptySlotsReader<'r> {
+    type Entity = EmptySlots;
+    const NAME: &'static str = "EmptySlotsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EmptySlotsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct EmptySlotsBuilder {
+    pub(crate) count: Uint64,
+}
+impl EmptySlotsBuilder {
+    pub const TOTAL_SIZE: usize = 8;
+    pub const FIELD_SIZES: [usize; 1] = [8];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn count(mut self, v: Uint64) -> Self {
+        self.count = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for EmptySlotsBuilder {
+    type Entity = EmptySlots;
+    const NAME: &'static str = "EmptySlotsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.count.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EmptySlots::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CompactHeader(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CompactHeader {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CompactHeader {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CompactHeader {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "proposer_index", self.proposer_index())?;
+        write!(f, ", {}: {}", "state_root", self.state_root())?;
+        write!(f, ", {}: {}", "body_root", self.body_root())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CompactHeader {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CompactHeader::new_unchecked(v)
+    }
+}
+impl CompactHeader {
+    const DEFAULT_VALUE: [u8; 72] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 3] = [8, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn proposer_index(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0..8))
+    }
+    pub fn state_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(8..40))
+    }
+    pub fn body_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(40..72))
+    }
+    pub fn as_reader<'r>(&'r self) -> CompactHeaderReader<'r> {
+        CompactHeaderReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CompactHeader {
+    type Builder = CompactHeaderBuilder;
+    const NAME: &'static str = "CompactHeader";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CompactHeader(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .proposer_index(self.proposer_index())
+            .state_root(self.state_root())
+            .body_root(self.body_root())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CompactHeaderReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CompactHeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CompactHeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CompactHeaderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "proposer_index", self.proposer_index())?;
+        write!(f, ", {}: {}", "state_root", self.state_root())?;
+        write!(f, ", {}: {}", "body_root", self.body_root())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> CompactHeaderReader<'r> {
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 3] = [8, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn proposer_index(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+    pub fn state_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[8..40])
+    }
+    pub fn body_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[40..72])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CompactHeaderReader<'r> {
+    type Entity = CompactHeader;
+    const NAME: &'static str = "CompactHeaderReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CompactHeaderReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CompactHeaderBuilder {
+    pub(crate) proposer_index: Uint64,
+    pub(crate) state_root: Hash,
+    pub(crate) body_root: Hash,
+}
+impl CompactHeaderBuilder {
+    pub const TOTAL_SIZE: usize = 72;
+    pub const FIELD_SIZES: [usize; 3] = [8, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn proposer_index(mut self, v: Uint64) -> Self {
+        self.proposer_index = v;
+        self
+    }
+    pub fn state_root(mut self, v: Hash) -> Self {
+        self.state_root = v;
+        self
+    }
+    pub fn body_root(mut self, v: Hash) -> Self {
+        self.body_root = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CompactHeaderBuilder {
+    type Entity = CompactHeader;
+    const NAME: &'static str = "CompactHeaderBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.proposer_index.as_slice())?;
+        writer.write_all(self.state_root.as_slice())?;
+        writer.write_all(self.body_root.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CompactHeader::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CompactHeaderItem(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CompactHeaderItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CompactHeaderItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CompactHeaderItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for CompactHeaderItem {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CompactHeaderItem::new_unchecked(v)
+    }
+}
+impl CompactHeaderItem {
+    const DEFAULT_VALUE: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> CompactHeaderItemUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => EmptySlots::new_unchecked(inner).into(),
+            1 => CompactHeader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CompactHeaderItemReader<'r> {
+        CompactHeaderItemReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CompactHeaderItem {
+    type Builder = CompactHeaderItemBuilder;
+    const NAME: &'static str = "CompactHeaderItem";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CompactHeaderItem(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderItemReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderItemReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CompactHeaderItemReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CompactHeaderItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CompactHeaderItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CompactHeaderItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> CompactHeaderItemReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> CompactHeaderItemUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => EmptySlotsReader::new_unchecked(inner).into(),
+            1 => CompactHeaderReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CompactHeaderItemReader<'r> {
+    type Entity = CompactHeaderItem;
+    const NAME: &'static str = "CompactHeaderItemReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CompactHeaderItemReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => EmptySlotsReader::verify(inner_slice, compatible),
+            1 => CompactHeaderReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CompactHeaderItemBuilder(pub(crate) CompactHeaderItemUnion);
+impl CompactHeaderItemBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<CompactHeaderItemUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for CompactHeaderItemBuilder {
+    type Entity = CompactHeaderItem;
+    const NAME: &'static str = "CompactHeaderItemBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CompactHeaderItem::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum CompactHeaderItemUnion {
+    EmptySlots(EmptySlots),
+    CompactHeader(CompactHeader),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum CompactHeaderItemUnionReader<'r> {
+    EmptySlots(EmptySlotsReader<'r>),
+    CompactHeader(CompactHeaderReader<'r>),
+}
+impl ::core::default::Default for CompactHeaderItemUnion {
+    fn default() -> Self {
+        CompactHeaderItemUnion::EmptySlots(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for CompactHeaderItemUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, EmptySlots::NAME, item)
+            }
+            CompactHeaderItemUnion::CompactHeader(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CompactHeader::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for CompactHeaderItemUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CompactHeaderItemUnionReader::EmptySlots(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, EmptySlots::NAME, item)
+            }
+            CompactHeaderItemUnionReader::CompactHeader(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CompactHeader::NAME, item)
+            }
+        }
+    }
+}
+impl CompactHeaderItemUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(ref item) => write!(f, "{}", item),
+            CompactHeaderItemUnion::CompactHeader(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> CompactHeaderItemUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            CompactHeaderItemUnionReader::EmptySlots(ref item) => write!(f, "{}", item),
+            CompactHeaderItemUnionReader::CompactHeader(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<EmptySlots> for CompactHeaderItemUnion {
+    fn from(item: EmptySlots) -> Self {
+        CompactHeaderItemUnion::EmptySlots(item)
+    }
+}
+impl ::core::convert::From<CompactHeader> for CompactHeaderItemUnion {
+    fn from(item: CompactHeader) -> Self {
+        CompactHeaderItemUnion::CompactHeader(item)
+    }
+}
+impl<'r> ::core::convert::From<EmptySlotsReader<'r>> for CompactHeaderItemUnionReader<'r> {
+    fn from(item: EmptySlotsReader<'r>) -> Self {
+        CompactHeaderItemUnionReader::EmptySlots(item)
+    }
+}
+impl<'r> ::core::convert::From<CompactHeaderReader<'r>> for CompactHeaderItemUnionReader<'r> {
+    fn from(item: CompactHeaderReader<'r>) -> Self {
+        CompactHeaderItemUnionReader::CompactHeader(item)
+    }
+}
+impl CompactHeaderItemUnion {
+    pub const NAME: &'static str = "CompactHeaderItemUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(item) => item.as_bytes(),
+            CompactHeaderItemUnion::CompactHeader(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(item) => item.as_slice(),
+            CompactHeaderItemUnion::CompactHeader(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(_) => 0,
+            CompactHeaderItemUnion::CompactHeader(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(_) => "EmptySlots",
+            CompactHeaderItemUnion::CompactHeader(_) => "CompactHeader",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CompactHeaderItemUnionReader<'r> {
+        match self {
+            CompactHeaderItemUnion::EmptySlots(item) => item.as_reader().into(),
+            CompactHeaderItemUnion::CompactHeader(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> CompactHeaderItemUnionReader<'r> {
+    pub const NAME: &'r str = "CompactHeaderItemUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            CompactHeaderItemUnionReader::EmptySlots(item) => item.as_slice(),
+            CompactHeaderItemUnionReader::CompactHeader(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            CompactHeaderItemUnionReader::EmptySlots(_) => 0,
+            CompactHeaderItemUnionReader::CompactHeader(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            CompactHeaderItemUnionReader::EmptySlots(_) => "EmptySlots",
+            CompactHeaderItemUnionReader::CompactHeader(_) => "CompactHeader",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct CompactHeaderItemVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CompactHeaderItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CompactHeaderItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CompactHeaderItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for CompactHeaderItemVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CompactHeaderItemVec::new_unchecked(v)
+    }
+}
+impl CompactHeaderItemVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CompactHeaderItem> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CompactHeaderItem {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            CompactHeaderItem::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            CompactHeaderItem::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CompactHeaderItemVecReader<'r> {
+        CompactHeaderItemVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CompactHeaderItemVec {
+    type Builder = CompactHeaderItemVecBuilder;
+    const NAME: &'static str = "CompactHeaderItemVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CompactHeaderItemVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderItemVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeaderItemVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CompactHeaderItemVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CompactHeaderItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CompactHeaderItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CompactHeaderItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> CompactHeaderItemVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<CompactHeaderItemReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> CompactHeaderItemReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            CompactHeaderItemReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            CompactHeaderItemReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CompactHeaderItemVecReader<'r> {
+    type Entity = CompactHeaderItemVec;
+    const NAME: &'static str = "CompactHeaderItemVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CompactHeaderItemVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            CompactHeaderItemReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CompactHeaderItemVecBuilder(pub(crate) Vec<CompactHeaderItem>);
+impl CompactHeaderItemVecBuilder {
+    pub fn set(mut self, v: Vec<CompactHeaderItem>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: CompactHeaderItem) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = CompactHeaderItem>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: CompactHeaderItem) -> Option<CompactHeaderItem> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for CompactHeaderItemVecBuilder {
+    type Entity = CompactHeaderItemVec;
+    const NAME: &'static str = "CompactHeaderItemVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CompactHeaderItemVec::new_unchecked(inner.into())
+    }
+}
+pub struct CompactHeaderItemVecIterator(CompactHeaderItemVec, usize, usize);
+impl ::core::iter::Iterator for CompactHeaderItemVecIterator {
+    type Item = CompactHeaderItem;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for CompactHeaderItemVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for CompactHeaderItemVec {
+    type Item = CompactHeaderItem;
+    type IntoIter = CompactHeaderItemVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        CompactHeaderItemVecIterator(self, 0, len)
+    }
+}
+impl<'r> CompactHeaderItemVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> CompactHeaderItemVecReaderIterator<'t, 'r> {
+        CompactHeaderItemVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct CompactHeaderItemVecReaderIterator<'t, 'r>(
+    &'t CompactHeaderItemVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for CompactHeaderItemVecReaderIterator<'t, 'r> {
+    type Item = CompactHeaderItemReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for CompactHeaderItemVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct CompactHeaders(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CompactHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CompactHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CompactHeaders {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "first_slot", self.first_slot())?;
+        write!(f, ", {}: {}", "items", self.items())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CompactHeaders {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        CompactHeaders::new_unchecked(v)
+    }
+}
+impl CompactHeaders {
+    const DEFAULT_VALUE: [u8; 24] = [
+        24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn first_slot(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn items(&self) -> CompactHeaderItemVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            CompactHeaderItemVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            CompactHeaderItemVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CompactHeadersReader<'r> {
+        CompactHeadersReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CompactHeaders {
+    type Builder = CompactHeadersBuilder;
+    const NAME: &'static str = "CompactHeaders";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CompactHeaders(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeadersReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CompactHeadersReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .first_slot(self.first_slot())
+            .items(self.items())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CompactHeadersReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CompactHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CompactHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CompactHeadersReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "first_slot", self.first_slot())?;
+        write!(f, ", {}: {}", "items", self.items())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CompactHeadersReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn first_slot(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn items(&self) -> CompactHeaderItemVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            CompactHeaderItemVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            CompactHeaderItemVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CompactHeadersReader<'r> {
+    type Entity = CompactHeaders;
+    const NAME: &'static str = "CompactHeadersReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CompactHeadersReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        CompactHeaderItemVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CompactHeadersBuilder {
+    pub(crate) first_slot: Uint64,
+    pub(crate) items: CompactHeaderItemVec,
+}
+impl CompactHeadersBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn first_slot(mut self, v: Uint64) -> Self {
+        self.first_slot = v;
+        self
+    }
+    pub fn items(mut self, v: CompactHeaderItemVec) -> Self {
+        self.items = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CompactHeadersBuilder {
+    type Entity = CompactHeaders;
+    const NAME: &'static str = "CompactHeadersBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.first_slot.as_slice().len()
+            + self.items.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.first_slot.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.items.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.first_slot.as_slice())?;
+        writer.write_all(self.items.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CompactHeaders::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct HeaderRange(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HeaderRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for HeaderRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for HeaderRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for HeaderRange {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HeaderRange::new_unchecked(v)
+    }
+}
+impl HeaderRange {
+    const DEFAULT_VALUE: [u8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> HeaderRangeUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => HeaderVec::new_unchecked(inner).into(),
+            1 => CompactHeaders::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderRangeReader<'r> {
+        HeaderRangeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for HeaderRange {
+    type Builder = HeaderRangeBuilder;
+    const NAME: &'static str = "HeaderRange";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HeaderRange(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderRangeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HeaderRangeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct HeaderRangeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HeaderRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for HeaderRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderRangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> HeaderRangeReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> HeaderRangeUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => HeaderVecReader::new_unchecked(inner).into(),
+            1 => CompactHeadersReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for HeaderRangeReader<'r> {
+    type Entity = HeaderRange;
+    const NAME: &'static str = "HeaderRangeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HeaderRangeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => HeaderVecReader::verify(inner_slice, compatible),
+            1 => CompactHeadersReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct HeaderRangeBuilder(pub(crate) HeaderRangeUnion);
+impl HeaderRangeBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<HeaderRangeUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for HeaderRangeBuilder {
+    type Entity = HeaderRange;
+    const NAME: &'static str = "HeaderRangeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HeaderRange::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum HeaderRangeUnion {
+    HeaderVec(HeaderVec),
+    CompactHeaders(CompactHeaders),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum HeaderRangeUnionReader<'r> {
+    HeaderVec(HeaderVecReader<'r>),
+    CompactHeaders(CompactHeadersReader<'r>),
+}
+impl ::core::default::Default for HeaderRangeUnion {
+    fn default() -> Self {
+        HeaderRangeUnion::HeaderVec(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for HeaderRangeUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderRangeUnion::HeaderVec(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, HeaderVec::NAME, item)
+            }
+            HeaderRangeUnion::CompactHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CompactHeaders::NAME, item)
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for HeaderRangeUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderRangeUnionReader::HeaderVec(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, HeaderVec::NAME, item)
+            }
+            HeaderRangeUnionReader::CompactHeaders(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, CompactHeaders::NAME, item)
+            }
+        }
+    }
+}
+impl HeaderRangeUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderRangeUnion::HeaderVec(ref item) => write!(f, "{}", item),
+            HeaderRangeUnion::CompactHeaders(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> HeaderRangeUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            HeaderRangeUnionReader::HeaderVec(ref item) => write!(f, "{}", item),
+            HeaderRangeUnionReader::CompactHeaders(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl ::core::convert::From<HeaderVec> for HeaderRangeUnion {
+    fn from(item: HeaderVec) -> Self {
+        HeaderRangeUnion::HeaderVec(item)
+    }
+}
+impl ::core::convert::From<CompactHeaders> for HeaderRangeUnion {
+    fn from(item: CompactHeaders) -> Self {
+        HeaderRangeUnion::CompactHeaders(item)
+    }
+}
+impl<'r> ::core::convert::From<HeaderVecReader<'r>> for HeaderRangeUnionReader<'r> {
+    fn from(item: HeaderVecReader<'r>) -> Self {
+        HeaderRangeUnionReader::HeaderVec(item)
+    }
+}
+impl<'r> ::core::convert::From<CompactHeadersReader<'r>> for HeaderRangeUnionReader<'r> {
+    fn from(item: CompactHeadersReader<'r>) -> Self {
+        HeaderRangeUnionReader::CompactHeaders(item)
+    }
+}
+impl HeaderRangeUnion {
+    pub const NAME: &'static str = "HeaderRangeUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            HeaderRangeUnion::HeaderVec(item) => item.as_bytes(),
+            HeaderRangeUnion::CompactHeaders(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            HeaderRangeUnion::HeaderVec(item) => item.as_slice(),
+            HeaderRangeUnion::CompactHeaders(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            HeaderRangeUnion::HeaderVec(_) => 0,
+            HeaderRangeUnion::CompactHeaders(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            HeaderRangeUnion::HeaderVec(_) => "HeaderVec",
+            HeaderRangeUnion::CompactHeaders(_) => "CompactHeaders",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> HeaderRangeUnionReader<'r> {
+        match self {
+            HeaderRangeUnion::HeaderVec(item) => item.as_reader().into(),
+            HeaderRangeUnion::CompactHeaders(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> HeaderRangeUnionReader<'r> {
+    pub const NAME: &'r str = "HeaderRangeUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            HeaderRangeUnionReader::HeaderVec(item) => item.as_slice(),
+            HeaderRangeUnionReader::CompactHeaders(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            HeaderRangeUnionReader::HeaderVec(_) => 0,
+            HeaderRangeUnionReader::CompactHeaders(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            HeaderRangeUnionReader::HeaderVec(_) => "HeaderVec",
+            HeaderRangeUnionReader::CompactHeaders(_) => "CompactHeaders",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct SyncCommitteeBits(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncCommitteeBits {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncCommitteeBits {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncCommitteeBits {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl ::core::default::Default for SyncCommitteeBits {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SyncCommitteeBits::new_unchecked(v)
+    }
+}
+impl SyncCommitteeBits {
+    const DEFAULT_VALUE: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 64;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 64;
+    pub fn nth0(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn nth1(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn nth2(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(2..3))
+    }
+    pub fn nth3(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(3..4))
+    }
+    pub fn nth4(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(4..5))
+    }
+    pub fn nth5(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(5..6))
+    }
+    pub fn nth6(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(6..7))
+    }
+    pub fn nth7(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(7..8))
+    }
+    pub fn nth8(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(8..9))
+    }
+    pub fn nth9(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(9..10))
+    }
+    pub fn nth10(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(10..11))
+    }
+    pub fn nth11(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(11..12))
+    }
+    pub fn nth12(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(12..13))
+    }
+    pub fn nth13(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(13..14))
+    }
+    pub fn nth14(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(14..15))
+    }
+    pub fn nth15(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(15..16))
+    }
+    pub fn nth16(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(16..17))
+    }
+    pub fn nth17(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(17..18))
+    }
+    pub fn nth18(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(18..19))
+    }
+    pub fn nth19(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(19..20))
+    }
+    pub fn nth20(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(20..21))
+    }
+    pub fn nth21(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(21..22))
+    }
+    pub fn nth22(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(22..23))
+    }
+    pub fn nth23(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(23..24))
+    }
+    pub fn nth24(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(24..25))
+    }
+    pub fn nth25(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(25..26))
+    }
+    pub fn nth26(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(26..27))
+    }
+    pub fn nth27(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(27..28))
+    }
+    pub fn nth28(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(28..29))
+    }
+    pub fn nth29(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(29..30))
+    }
+    pub fn nth30(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(30..31))
+    }
+    pub fn nth31(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(31..32))
+    }
+    pub fn nth32(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn nth33(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(33..34))
+    }
+    pub fn nth34(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(34..35))
+    }
+    pub fn nth35(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(35..36))
+    }
+    pub fn nth36(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(36..37))
+    }
+    pub fn nth37(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(37..38))
+    }
+    pub fn nth38(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(38..39))
+    }
+    pub fn nth39(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(39..40))
+    }
+    pub fn nth40(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(40..41))
+    }
+    pub fn nth41(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(41..42))
+    }
+    pub fn nth42(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(42..43))
+    }
+    pub fn nth43(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(43..44))
+    }
+    pub fn nth44(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(44..45))
+    }
+    pub fn nth45(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(45..46))
+    }
+    pub fn nth46(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(46..47))
+    }
+    pub fn nth47(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(47..48))
+    }
+    pub fn nth48(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(48..49))
+    }
+    pub fn nth49(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(49..50))
+    }
+    pub fn nth50(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(50..51))
+    }
+    pub fn nth51(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(51..52))
+    }
+    pub fn nth52(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(52..53))
+    }
+    pub fn nth53(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(53..54))
+    }
+    pub fn nth54(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(54..55))
+    }
+    pub fn nth55(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(55..56))
+    }
+    pub fn nth56(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(56..57))
+    }
+    pub fn nth57(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(57..58))
+    }
+    pub fn nth58(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(58..59))
+    }
+    pub fn nth59(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(59..60))
+    }
+    pub fn nth60(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(60..61))
+    }
+    pub fn nth61(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(61..62))
+    }
+    pub fn nth62(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(62..63))
+    }
+    pub fn nth63(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(63..64))
+    }
+    pub fn raw_data(&self) -> molecule::bytes::Bytes {
+        self.as_bytes()
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncCommitteeBitsReader<'r> {
+        SyncCommitteeBitsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncCommitteeBits {
+    type Builder = SyncCommitteeBitsBuilder;
+    const NAME: &'static str = "SyncCommitteeBits";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncCommitteeBits(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeBitsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeBitsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set([
+            self.nth0(),
+            self.nth1(),
+            self.nth2(),
+            self.nth3(),
+            self.nth4(),
+            self.nth5(),
+            self.nth6(),
+            self.nth7(),
+            self.nth8(),
+            self.nth9(),
+            self.nth10(),
+            self.nth11(),
+            self.nth12(),
+            self.nth13(),
+            self.nth14(),
+            self.nth15(),
+            self.nth16(),
+            self.nth17(),
+            self.nth18(),
+            self.nth19(),
+            self.nth20(),
+            self.nth21(),
+            self.nth22(),
+            self.nth23(),
+            self.nth24(),
+            self.nth25(),
+            self.nth26(),
+            self.nth27(),
+            self.nth28(),
+            self.nth29(),
+            self.nth30(),
+            self.nth31(),
+            self.nth32(),
+            self.nth33(),
+            self.nth34(),
+            self.nth35(),
+            self.nth36(),
+            self.nth37(),
+            self.nth38(),
+            self.nth39(),
+            self.nth40(),
+            self.nth41(),
+            self.nth42(),
+            self.nth43(),
+            self.nth44(),
+            self.nth45(),
+            self.nth46(),
+            self.nth47(),
+            self.nth48(),
+            self.nth49(),
+            self.nth50(),
+            self.nth51(),
+            self.nth52(),
+            self.nth53(),
+            self.nth54(),
+            self.nth55(),
+            self.nth56(),
+            self.nth57(),
+            self.nth58(),
+            self.nth59(),
+            self.nth60(),
+            self.nth61(),
+            self.nth62(),
+            self.nth63(),
+        ])
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncCommitteeBitsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncCommitteeBitsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncCommitteeBitsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncCommitteeBitsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        let raw_data = hex_string(&self.raw_data());
+        write!(f, "{}(0x{})", Self::NAME, raw_data)
+    }
+}
+impl<'r> SyncCommitteeBitsReader<'r> {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 64;
+    pub fn nth0(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn nth1(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn nth2(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    }
+    pub fn nth3(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    }
+    pub fn nth4(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    }
+    pub fn nth5(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    }
+    pub fn nth6(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[6..7])
+    }
+    pub fn nth7(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    }
+    pub fn nth8(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    }
+    pub fn nth9(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    }
+    pub fn nth10(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[10..11])
+    }
+    pub fn nth11(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[11..12])
+    }
+    pub fn nth12(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[12..13])
+    }
+    pub fn nth13(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[13..14])
+    }
+    pub fn nth14(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[14..15])
+    }
+    pub fn nth15(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    }
+    pub fn nth16(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    }
+    pub fn nth17(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    }
+    pub fn nth18(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    }
+    pub fn nth19(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    }
+    pub fn nth20(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    }
+    pub fn nth21(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    }
+    pub fn nth22(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    }
+    pub fn nth23(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    }
+    pub fn nth24(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[24..25])
+    }
+    pub fn nth25(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    }
+    pub fn nth26(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    }
+    pub fn nth27(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    }
+    pub fn nth28(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    }
+    pub fn nth29(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    }
+    pub fn nth30(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    }
+    pub fn nth31(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[31..32])
+    }
+    pub fn nth32(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    }
+    pub fn nth33(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[33..34])
+    }
+    pub fn nth34(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[34..35])
+    }
+    pub fn nth35(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    }
+    pub fn nth36(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[36..37])
+    }
+    pub fn nth37(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[37..38])
+    }
+    pub fn nth38(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[38..39])
+    }
+    pub fn nth39(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[39..40])
+    }
+    pub fn nth40(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[40..41])
+    }
+    pub fn nth41(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[41..42])
+    }
+    pub fn nth42(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[42..43])
+    }
+    pub fn nth43(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[43..44])
+    }
+    pub fn nth44(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[44..45])
+    }
+    pub fn nth45(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[45..46])
+    }
+    pub fn nth46(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[46..47])
+    }
+    pub fn nth47(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[47..48])
+    }
+    pub fn nth48(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[48..49])
+    }
+    pub fn nth49(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[49..50])
+    }
+    pub fn nth50(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[50..51])
+    }
+    pub fn nth51(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[51..52])
+    }
+    pub fn nth52(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[52..53])
+    }
+    pub fn nth53(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[53..54])
+    }
+    pub fn nth54(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[54..55])
+    }
+    pub fn nth55(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[55..56])
+    }
+    pub fn nth56(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[56..57])
+    }
+    pub fn nth57(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[57..58])
+    }
+    pub fn nth58(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[58..59])
+    }
+    pub fn nth59(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[59..60])
+    }
+    pub fn nth60(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[60..61])
+    }
+    pub fn nth61(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[61..62])
+    }
+    pub fn nth62(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    }
+    pub fn nth63(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    }
+    pub fn raw_data(&self) -> &'r [u8] {
+        self.as_slice()
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeBitsReader<'r> {
+    type Entity = SyncCommitteeBits;
+    const NAME: &'static str = "SyncCommitteeBitsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncCommitteeBitsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+pub struct SyncCommitteeBitsBuilder(pub(crate) [Byte; 64]);
+impl ::core::fmt::Debug for SyncCommitteeBitsBuilder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    }
+}
+impl ::core::default::Default for SyncCommitteeBitsBuilder {
+    fn default() -> Self {
+        SyncCommitteeBitsBuilder([
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+            Byte::default(),
+        ])
+    }
+}
+impl SyncCommitteeBitsBuilder {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const ITEM_SIZE: usize = 1;
+    pub const ITEM_COUNT: usize = 64;
+    pub fn set(mut self, v: [Byte; 64]) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn nth0(mut self, v: Byte) -> Self {
+        self.0[0] = v;
+        self
+    }
+    pub fn nth1(mut self, v: Byte) -> Self {
+        self.0[1] = v;
+        self
+    }
+    pub fn nth2(mut self, v: Byte) -> Self {
+        self.0[2] = v;
+        self
+    }
+    pub fn nth3(mut self, v: Byte) -> Self {
+        self.0[3] = v;
+        self
+    }
+    pub fn nth4(mut self, v: Byte) -> Self {
+        self.0[4] = v;
+        self
+    }
+    pub fn nth5(mut self, v: Byte) -> Self {
+        self.0[5] = v;
+        self
+    }
+    pub fn nth6(mut self, v: Byte) -> Self {
+        self.0[6] = v;
+        self
+    }
+    pub fn nth7(mut self, v: Byte) -> Self {
+        self.0[7] = v;
+        self
+    }
+    pub fn nth8(mut self, v: Byte) -> Self {
+        self.0[8] = v;
+        self
+    }
+    pub fn nth9(mut self, v: Byte) -> Self {
+        self.0[9] = v;
+        self
+    }
+    pub fn nth10(mut self, v: Byte) -> Self {
+        self.0[10] = v;
+        self
+    }
+    pub fn nth11(mut self, v: Byte) -> Self {
+        self.0[11] = v;
+        self
+    }
+    pub fn nth12(mut self, v: Byte) -> Self {
+        self.0[12] = v;
+        self
+    }
+    pub fn nth13(mut self, v: Byte) -> Self {
+        self.0[13] = v;
+        self
+    }
+    pub fn nth14(mut self, v: Byte) -> Self {
+        self.0[14] = v;
+        self
+    }
+    pub fn nth15(mut self, v: Byte) -> Self {
+        self.0[15] = v;
+        self
+    }
+    pub fn nth16(mut self, v: Byte) -> Self {
+        self.0[16] = v;
+        self
+    }
+    pub fn nth17(mut self, v: Byte) -> Self {
+        self.0[17] = v;
+        self
+    }
+    pub fn nth18(mut self, v: Byte) -> Self {
+        self.0[18] = v;
+        self
+    }
+    pub fn nth19(mut self, v: Byte) -> Self {
+        self.0[19] = v;
+        self
+    }
+    pub fn nth20(mut self, v: Byte) -> Self {
+        self.0[20] = v;
+        self
+    }
+    pub fn nth21(mut self, v: Byte) -> Self {
+        self.0[21] = v;
+        self
+    }
+    pub fn nth22(mut self, v: Byte) -> Self {
+        self.0[22] = v;
+        self
+    }
+    pub fn nth23(mut self, v: Byte) -> Self {
+        self.0[23] = v;
+        self
+    }
+    pub fn nth24(mut self, v: Byte) -> Self {
+        self.0[24] = v;
+        self
+    }
+    pub fn nth25(mut self, v: Byte) -> Self {
+        self.0[25] = v;
+        self
+    }
+    pub fn nth26(mut self, v: Byte) -> Self {
+        self.0[26] = v;
+        self
+    }
+    pub fn nth27(mut self, v: Byte) -> Self {
+        self.0[27] = v;
+        self
+    }
+    pub fn nth28(mut self, v: Byte) -> Self {
+        self.0[28] = v;
+        self
+    }
+    pub fn nth29(mut self, v: Byte) -> Self {
+        self.0[29] = v;
+        self
+    }
+    pub fn nth30(mut self, v: Byte) -> Self {
+        self.0[30] = v;
+        self
+    }
+    pub fn nth31(mut self, v: Byte) -> Self {
+        self.0[31] = v;
+        self
+    }
+    pub fn nth32(mut self, v: Byte) -> Self {
+        self.0[32] = v;
+        self
+    }
+    pub fn nth33(mut self, v: Byte) -> Self {
+        self.0[33] = v;
+        self
+    }
+    pub fn nth34(mut self, v: Byte) -> Self {
+        self.0[34] = v;
+        self
+    }
+    pub fn nth35(mut self, v: Byte) -> Self {
+        self.0[35] = v;
+        self
+    }
+    pub fn nth36(mut self, v: Byte) -> Self {
+        self.0[36] = v;
+        self
+    }
+    pub fn nth37(mut self, v: Byte) -> Self {
+        self.0[37] = v;
+        self
+    }
+    pub fn nth38(mut self, v: Byte) -> Self {
+        self.0[38] = v;
+        self
+    }
+    pub fn nth39(mut self, v: Byte) -> Self {
+        self.0[39] = v;
+        self
+    }
+    pub fn nth40(mut self, v: Byte) -> Self {
+        self.0[40] = v;
+        self
+    }
+    pub fn nth41(mut self, v: Byte) -> Self {
+        self.0[41] = v;
+        self
+    }
+    pub fn nth42(mut self, v: Byte) -> Self {
+        self.0[42] = v;
+        self
+    }
+    pub fn nth43(mut self, v: Byte) -> Self {
+        self.0[43] = v;
+        self
+    }
+    pub fn nth44(mut self, v: Byte) -> Self {
+        self.0[44] = v;
+        self
+    }
+    pub fn nth45(mut self, v: Byte) -> Self {
+        self.0[45] = v;
+        self
+    }
+    pub fn nth46(mut self, v: Byte) -> Self {
+        self.0[46] = v;
+        self
+    }
+    pub fn nth47(mut self, v: Byte) -> Self {
+        self.0[47] = v;
+        self
+    }
+    pub fn nth48(mut self, v: Byte) -> Self {
+        self.0[48] = v;
+        self
+    }
+    pub fn nth49(mut self, v: Byte) -> Self {
+        self.0[49] = v;
+        self
+    }
+    pub fn nth50(mut self, v: Byte) -> Self {
+        self.0[50] = v;
+        self
+    }
+    pub fn nth51(mut self, v: Byte) -> Self {
+        self.0[51] = v;
+        self
+    }
+    pub fn nth52(mut self, v: Byte) -> Self {
+        self.0[52] = v;
+        self
+    }
+    pub fn nth53(mut self, v: Byte) -> Self {
+        self.0[53] = v;
+        self
+    }
+    pub fn nth54(mut self, v: Byte) -> Self {
+        self.0[54] = v;
+        self
+    }
+    pub fn nth55(mut self, v: Byte) -> Self {
+        self.0[55] = v;
+        self
+    }
+    pub fn nth56(mut self, v: Byte) -> Self {
+        self.0[56] = v;
+        self
+    }
+    pub fn nth57(mut self, v: Byte) -> Self {
+        self.0[57] = v;
+        self
+    }
+    pub fn nth58(mut self, v: Byte) -> Self {
+        self.0[58] = v;
+        self
+    }
+    pub fn nth59(mut self, v: Byte) -> Self {
+        self.0[59] = v;
+        self
+    }
+    pub fn nth60(mut self, v: Byte) -> Self {
+        self.0[60] = v;
+        self
+    }
+    pub fn nth61(mut self, v: Byte) -> Self {
+        self.0[61] = v;
+        self
+    }
+    pub fn nth62(mut self, v: Byte) -> Self {
+        self.0[62] = v;
+        self
+    }
+    pub fn nth63(mut self, v: Byte) -> Self {
+        self.0[63] = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncCommitteeBitsBuilder {
+    type Entity = SyncCommitteeBits;
+    const NAME: &'static str = "SyncCommitteeBitsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.0[0].as_slice())?;
+        writer.write_all(self.0[1].as_slice())?;
+        writer.write_all(self.0[2].as_slice())?;
+        writer.write_all(self.0[3].as_slice())?;
+        writer.write_all(self.0[4].as_slice())?;
+        writer.write_all(self.0[5].as_slice())?;
+        writer.write_all(self.0[6].as_slice())?;
+        writer.write_all(self.0[7].as_slice())?;
+        writer.write_all(self.0[8].as_slice())?;
+        writer.write_all(self.0[9].as_slice())?;
+        writer.write_all(self.0[10].as_slice())?;
+        writer.write_all(self.0[11].as_slice())?;
+        writer.write_all(self.0[12].as_slice())?;
+        writer.write_all(self.0[13].as_slice())?;
+        writer.write_all(self.0[14].as_slice())?;
+        writer.write_all(self.0[15].as_slice())?;
+        writer.write_all(self.0[16].as_slice())?;
+        writer.write_all(self.0[17].as_slice())?;
+        writer.write_all(self.0[18].as_slice())?;
+        writer.write_all(self.0[19].as_slice())?;
+        writer.write_all(self.0[20].as_slice())?;
+        writer.write_all(self.0[21].as_slice())?;
+        writer.write_all(self.0[22].as_slice())?;
+        writer.write_all(self.0[23].as_slice())?;
+        writer.write_all(self.0[24].as_slice())?;
+        writer.write_all(self.0[25].as_slice())?;
+        writer.write_all(self.0[26].as_slice())?;
+        writer.write_all(self.0[27].as_slice())?;
+        writer.write_all(self.0[28].as_slice())?;
+        writer.write_all(self.0[29].as_slice())?;
+        writer.write_all(self.0[30].as_slice())?;
+        writer.write_all(self.0[31].as_slice())?;
+        writer.write_all(self.0[32].as_slice())?;
+        writer.write_all(self.0[33].as_slice())?;
+        writer.write_all(self.0[34].as_slice())?;
+        writer.write_all(self.0[35].as_slice())?;
+        writer.write_all(self.0[36].as_slice())?;
+        writer.write_all(self.0[37].as_slice())?;
+        writer.write_all(self.0[38].as_slice())?;
+        writer.write_all(self.0[39].as_slice())?;
+        writer.write_all(self.0[40].as_slice())?;
+        writer.write_all(self.0[41].as_slice())?;
+        writer.write_all(self.0[42].as_slice())?;
+        writer.write_all(self.0[43].as_slice())?;
+        writer.write_all(self.0[44].as_slice())?;
+        writer.write_all(self.0[45].as_slice())?;
+        writer.write_all(self.0[46].as_slice())?;
+        writer.write_all(self.0[47].as_slice())?;
+        writer.write_all(self.0[48].as_slice())?;
+        writer.write_all(self.0[49].as_slice())?;
+        writer.write_all(self.0[50].as_slice())?;
+        writer.write_all(self.0[51].as_slice())?;
+        writer.write_all(self.0[52].as_slice())?;
+        writer.write_all(self.0[53].as_slice())?;
+        writer.write_all(self.0[54].as_slice())?;
+        writer.write_all(self.0[55].as_slice())?;
+        writer.write_all(self.0[56].as_slice())?;
+        writer.write_all(self.0[57].as_slice())?;
+        writer.write_all(self.0[58].as_slice())?;
+        writer.write_all(self.0[59].as_slice())?;
+        writer.write_all(self.0[60].as_slice())?;
+        writer.write_all(self.0[61].as_slice())?;
+        writer.write_all(self.0[62].as_slice())?;
+        writer.write_all(self.0[63].as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncCommitteeBits::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SyncAggregate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncAggregate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncAggregate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncAggregate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "sync_committee_bits",
+            self.sync_committee_bits()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "sync_committee_signature",
+            self.sync_committee_signature()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SyncAggregate {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SyncAggregate::new_unchecked(v)
+    }
+}
+impl SyncAggregate {
+    const DEFAULT_VALUE: [u8; 160] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 160;
+    pub const FIELD_SIZES: [usize; 2] = [64, 96];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn sync_committee_bits(&self) -> SyncCommitteeBits {
+        SyncCommitteeBits::new_unchecked(self.0.slice(0..64))
+    }
+    pub fn sync_committee_signature(&self) -> BlsSignature {
+        BlsSignature::new_unchecked(self.0.slice(64..160))
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncAggregateReader<'r> {
+        SyncAggregateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncAggregate {
+    type Builder = SyncAggregateBuilder;
+    const NAME: &'static str = "SyncAggregate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncAggregate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncAggregateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncAggregateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .sync_committee_bits(self.sync_committee_bits())
+            .sync_committee_signature(self.sync_committee_signature())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncAggregateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncAggregateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncAggregateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncAggregateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "sync_committee_bits",
+            self.sync_committee_bits()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "sync_committee_signature",
+            self.sync_committee_signature()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SyncAggregateReader<'r> {
+    pub const TOTAL_SIZE: usize = 160;
+    pub const FIELD_SIZES: [usize; 2] = [64, 96];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn sync_committee_bits(&self) -> SyncCommitteeBitsReader<'r> {
+        SyncCommitteeBitsReader::new_unchecked(&self.as_slice()[0..64])
+    }
+    pub fn sync_committee_signature(&self) -> BlsSignatureReader<'r> {
+        BlsSignatureReader::new_unchecked(&self.as_slice()[64..160])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncAggregateReader<'r> {
+    type Entity = SyncAggregate;
+    const NAME: &'static str = "SyncAggregateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncAggregateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SyncAggregateBuilder {
+    pub(crate) sync_committee_bits: SyncCommitteeBits,
+    pub(crate) sync_committee_signature: BlsSignature,
+}
+impl SyncAggregateBuilder {
+    pub const TOTAL_SIZE: usize = 160;
+    pub const FIELD_SIZES: [usize; 2] = [64, 96];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn sync_committee_bits(mut self, v: SyncCommitteeBits) -> Self {
+        self.sync_committee_bits = v;
+        self
+    }
+    pub fn sync_committee_signature(mut self, v: BlsSignature) -> Self {
+        self.sync_committee_signature = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncAggregateBuilder {
+    type Entity = SyncAggregate;
+    const NAME: &'static str = "SyncAggregateBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.sync_committee_bits.as_slice())?;
+        writer.write_all(self.sync_committee_signature.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncAggregate::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SyncCommittee(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
+        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SyncCommittee {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SyncCommittee::new_unchecked(v)
+    }
+}
+impl SyncCommittee {
+    const DEFAULT_VALUE: [u8; 64] = [
+        64, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pubkeys(&self) -> BlsPubkeyVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BlsPubkeyVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn aggregate_pubkey(&self) -> BlsPubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BlsPubkey::new_unchecked(self.0.slice(start..end))
+        } else {
+            BlsPubkey::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncCommitteeReader<'r> {
+        SyncCommitteeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncCommittee {
+    type Builder = SyncCommitteeBuilder;
+    const NAME: &'static str = "SyncCommittee";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncCommittee(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .pubkeys(self.pubkeys())
+            .aggregate_pubkey(self.aggregate_pubkey())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncCommitteeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
+        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SyncCommitteeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pubkeys(&self) -> BlsPubkeyVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BlsPubkeyVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn aggregate_pubkey(&self) -> BlsPubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BlsPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BlsPubkeyReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeReader<'r> {
+    type Entity = SyncCommittee;
+    const NAME: &'static str = "SyncCommitteeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncCommitteeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BlsPubkeyVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BlsPubkeyReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SyncCommitteeBuilder {
+    pub(crate) pubkeys: BlsPubkeyVec,
+    pub(crate) aggregate_pubkey: BlsPubkey,
+}
+impl SyncCommitteeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn pubkeys(mut self, v: BlsPubkeyVec) -> Self {
+        self.pubkeys = v;
+        self
+    }
+    pub fn aggregate_pubkey(mut self, v: BlsPubkey) -> Self {
+        self.aggregate_pubkey = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncCommitteeBuilder {
+    type Entity = SyncCommittee;
+    const NAME: &'static str = "SyncCommitteeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.pubkeys.as_slice().len()
+            + self.aggregate_pubkey.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.pubkeys.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.aggregate_pubkey.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.pubkeys.as_slice())?;
+        writer.write_all(self.aggregate_pubkey.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncCommittee::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct UncompressedSyncCommittee(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for UncompressedSyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for UncompressedSyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for UncompressedSyncCommittee {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
+        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for UncompressedSyncCommittee {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        UncompressedSyncCommittee::new_unchecked(v)
+    }
+}
+impl UncompressedSyncCommittee {
+    const DEFAULT_VALUE: [u8; 112] = [
+        112, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pubkeys(&self) -> BlsUncompressedPubkeyVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BlsUncompressedPubkeyVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn aggregate_pubkey(&self) -> BlsUncompressedPubkey {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BlsUncompressedPubkey::new_unchecked(self.0.slice(start..end))
+        } else {
+            BlsUncompressedPubkey::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> UncompressedSyncCommitteeReader<'r> {
+        UncompressedSyncCommitteeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for UncompressedSyncCommittee {
+    type Builder = UncompressedSyncCommitteeBuilder;
+    const NAME: &'static str = "UncompressedSyncCommittee";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        UncompressedSyncCommittee(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        UncompressedSyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        UncompressedSyncCommitteeReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .pubkeys(self.pubkeys())
+            .aggregate_pubkey(self.aggregate_pubkey())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct UncompressedSyncCommitteeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for UncompressedSyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for UncompressedSyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for UncompressedSyncCommitteeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
+        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> UncompressedSyncCommitteeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pubkeys(&self) -> BlsUncompressedPubkeyVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BlsUncompressedPubkeyVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn aggregate_pubkey(&self) -> BlsUncompressedPubkeyReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BlsUncompressedPubkeyReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BlsUncompressedPubkeyReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for UncompressedSyncCommitteeReader<'r> {
+    type Entity = UncompressedSyncCommittee;
+    const NAME: &'static str = "UncompressedSyncCommitteeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        UncompressedSyncCommitteeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BlsUncompressedPubkeyVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BlsUncompressedPubkeyReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct UncompressedSyncCommitteeBuilder {
+    pub(crate) pubkeys: BlsUncompressedPubkeyVec,
+    pub(crate) aggregate_pubkey: BlsUncompressedPubkey,
+}
+impl UncompressedSyncCommitteeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn pubkeys(mut self, v: BlsUncompressedPubkeyVec) -> Self {
+        self.pubkeys = v;
+        self
+    }
+    pub fn aggregate_pubkey(mut self, v: BlsUncompressedPubkey) -> Self {
+        self.aggregate_pubkey = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for UncompressedSyncCommitteeBuilder {
+    type Entity = UncompressedSyncCommittee;
+    const NAME: &'static str = "UncompressedSyncCommitteeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.pubkeys.as_slice().len()
+            + self.aggregate_pubkey.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.pubkeys.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.aggregate_pubkey.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.pubkeys.as_slice())?;
+        writer.write_all(self.aggregate_pubkey.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        UncompressedSyncCommittee::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Validator(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Validator {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Validator {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Validator {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkey", self.pubkey())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_credentials",
+            self.withdrawal_credentials()
+        )?;
+        write!(f, ", {}: {}", "effective_balance", self.effective_balance())?;
+        write!(f, ", {}: {}", "slashed", self.slashed())?;
+        write!(
+            f,
+            ", {}: {}",
+            "activation_eligibility_epoch",
+            self.activation_eligibility_epoch()
+        )?;
+        write!(f, ", {}: {}", "activation_epoch", self.activation_epoch())?;
+        write!(f, ", {}: {}", "exit_epoch", self.exit_epoch())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawable_epoch",
+            self.withdrawable_epoch()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Validator {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Validator::new_unchecked(v)
+    }
+}
+impl Validator {
+    const DEFAULT_VALUE: [u8; 121] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0,
+    ];
+    pub const TOTAL_SIZE: usize = 121;
+    pub const FIELD_SIZES: [usize; 8] = [48, 32, 8, 1, 8, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn pubkey(&self) -> BlsPubkey {
+        BlsPubkey::new_unchecked(self.0.slice(0..48))
+    }
+    pub fn withdrawal_credentials(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(48..80))
+    }
+    pub fn effective_balance(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(80..88))
+    }
+    pub fn slashed(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(88..89))
+    }
+    pub fn activation_eligibility_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(89..97))
+    }
+    pub fn activation_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(97..105))
+    }
+    pub fn exit_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(105..113))
+    }
+    pub fn withdrawable_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(113..121))
+    }
+    pub fn as_reader<'r>(&'r self) -> ValidatorReader<'r> {
+        ValidatorReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Validator {
+    type Builder = ValidatorBuilder;
+    const NAME: &'static str = "Validator";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Validator(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ValidatorReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ValidatorReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .pubkey(self.pubkey())
+            .withdrawal_credentials(self.withdrawal_credentials())
+            .effective_balance(self.effective_balance())
+            .slashed(self.slashed())
+            .activation_eligibility_epoch(self.activation_eligibility_epoch())
+            .activation_epoch(self.activation_epoch())
+            .exit_epoch(self.exit_epoch())
+            .withdrawable_epoch(self.withdrawable_epoch())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ValidatorReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ValidatorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ValidatorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ValidatorReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pubkey", self.pubkey())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawal_credentials",
+            self.withdrawal_credentials()
+        )?;
+        write!(f, ", {}: {}", "effective_balance", self.effective_balance())?;
+        write!(f, ", {}: {}", "slashed", self.slashed())?;
+        write!(
+            f,
+            ", {}: {}",
+            "activation_eligibility_epoch",
+            self.activation_eligibility_epoch()
+        )?;
+        write!(f, ", {}: {}", "activation_epoch", self.activation_epoch())?;
+        write!(f, ", {}: {}", "exit_epoch", self.exit_epoch())?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdrawable_epoch",
+            self.withdrawable_epoch()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ValidatorReader<'r> {
+    pub const TOTAL_SIZE: usize = 121;
+    pub const FIELD_SIZES: [usize; 8] = [48, 32, 8, 1, 8, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn pubkey(&self) -> BlsPubkeyReader<'r> {
+        BlsPubkeyReader::new_unchecked(&self.as_slice()[0..48])
+    }
+    pub fn withdrawal_credentials(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[48..80])
+    }
+    pub fn effective_balance(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[80..88])
+    }
+    pub fn slashed(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[88..89])
+    }
+    pub fn activation_eligibility_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[89..97])
+    }
+    pub fn activation_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[97..105])
+    }
+    pub fn exit_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[105..113])
+    }
+    pub fn withdrawable_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[113..121])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ValidatorReader<'r> {
+    type Entity = Validator;
+    const NAME: &'static str = "ValidatorReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ValidatorReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ValidatorBuilder {
+    pub(crate) pubkey: BlsPubkey,
+    pub(crate) withdrawal_credentials: Hash,
+    pub(crate) effective_balance: Uint64,
+    pub(crate) slashed: Byte,
+    pub(crate) activation_eligibility_epoch: Uint64,
+    pub(crate) activation_epoch: Uint64,
+    pub(crate) exit_epoch: Uint64,
+    pub(crate) withdrawable_epoch: Uint64,
+}
+impl ValidatorBuilder {
+    pub const TOTAL_SIZE: usize = 121;
+    pub const FIELD_SIZES: [usize; 8] = [48, 32, 8, 1, 8, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn pubkey(mut self, v: BlsPubkey) -> Self {
+        self.pubkey = v;
+        self
+    }
+    pub fn withdrawal_credentials(mut self, v: Hash) -> Self {
+        self.withdrawal_credentials = v;
+        self
+    }
+    pub fn effective_balance(mut self, v: Uint64) -> Self {
+        self.effective_balance = v;
+        self
+    }
+    pub fn slashed(mut self, v: Byte) -> Self {
+        self.slashed = v;
+        self
+    }
+    pub fn activation_eligibility_epoch(mut self, v: Uint64) -> Self {
+        self.activation_eligibility_epoch = v;
+        self
+    }
+    pub fn activation_epoch(mut self, v: Uint64) -> Self {
+        self.activation_epoch = v;
+        self
+    }
+    pub fn exit_epoch(mut self, v: Uint64) -> Self {
+        self.exit_epoch = v;
+        self
+    }
+    pub fn withdrawable_epoch(mut self, v: Uint64) -> Self {
+        self.withdrawable_epoch = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ValidatorBuilder {
+    type Entity = Validator;
+    const NAME: &'static str = "ValidatorBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.pubkey.as_slice())?;
+        writer.write_all(self.withdrawal_credentials.as_slice())?;
+        writer.write_all(self.effective_balance.as_slice())?;
+        writer.write_all(self.slashed.as_slice())?;
+        writer.write_all(self.activation_eligibility_epoch.as_slice())?;
+        writer.write_all(self.activation_epoch.as_slice())?;
+        writer.write_all(self.exit_epoch.as_slice())?;
+        writer.write_all(self.withdrawable_epoch.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Validator::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClientBootstrap(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientBootstrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "current_sync_committee_branch",
+            self.current_sync_committee_branch()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClientBootstrap {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientBootstrap::new_unchecked(v)
+    }
+}
+impl ClientBootstrap {
+    const DEFAULT_VALUE: [u8; 128] = [
+        128, 0, 0, 0, 12, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn current_sync_committee_branch(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SszProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            SszProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientBootstrapReader<'r> {
+        ClientBootstrapReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientBootstrap {
+    type Builder = ClientBootstrapBuilder;
+    const NAME: &'static str = "ClientBootstrap";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientBootstrap(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientBootstrapReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientBootstrapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .header(self.header())
+            .current_sync_committee_branch(self.current_sync_committee_branch())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientBootstrapReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientBootstrapReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "current_sync_committee_branch",
+            self.current_sync_committee_branch()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ClientBootstrapReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn current_sync_committee_branch(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SszProofReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientBootstrapReader<'r> {
+    type Entity = ClientBootstrap;
+    const NAME: &'static str = "ClientBootstrapReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientBootstrapReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientBootstrapBuilder {
+    pub(crate) header: Header,
+    pub(crate) current_sync_committee_branch: SszProof,
+}
+impl ClientBootstrapBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
+        self
+    }
+    pub fn current_sync_committee_branch(mut self, v: SszProof) -> Self {
+        self.current_sync_committee_branch = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientBootstrapBuilder {
+    type Entity = ClientBootstrap;
+    const NAME: &'static str = "ClientBootstrapBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.header.as_slice().len()
+            + self.current_sync_committee_branch.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.current_sync_committee_branch.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.current_sync_committee_branch.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientBootstrap::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClientUpdate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClientUpdate {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientUpdate::new_unchecked(v)
+    }
+}
+impl ClientUpdate {
+    const DEFAULT_VALUE: [u8; 324] = [
+        68, 1, 0, 0, 28, 0, 0, 0, 140, 0, 0, 0, 144, 0, 0, 0, 48, 1, 0, 0, 56, 1, 0, 0, 60, 1, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn finality_branch(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signature_slot(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn headers(&self) -> HeaderRange {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            HeaderRange::new_unchecked(self.0.slice(start..end))
+        } else {
+            HeaderRange::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateReader<'r> {
+        ClientUpdateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientUpdate {
+    type Builder = ClientUpdateBuilder;
+    const NAME: &'static str = "ClientUpdate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientUpdate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .attested_header(self.attested_header())
+            .finality_branch(self.finality_branch())
+            .sync_aggregate(self.sync_aggregate())
+            .signature_slot(self.signature_slot())
+            .new_headers_mmr_proof(self.new_headers_mmr_proof())
+            .headers(self.headers())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientUpdateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ClientUpdateReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn finality_branch(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signature_slot(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn headers(&self) -> HeaderRangeReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            HeaderRangeReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HeaderRangeReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientUpdateReader<'r> {
+    type Entity = ClientUpdate;
+    const NAME: &'static str = "ClientUpdateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientUpdateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SyncAggregateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        HeaderRangeReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientUpdateBuilder {
+    pub(crate) attested_header: Header,
+    pub(crate) finality_branch: SszProof,
+    pub(crate) sync_aggregate: SyncAggregate,
+    pub(crate) signature_slot: Uint64,
+    pub(crate) new_headers_mmr_proof: MmrProof,
+    pub(crate) headers: HeaderRange,
+}
+impl ClientUpdateBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn attested_header(mut self, v: Header) -> Self {
+        self.attested_header = v;
+        self
+    }
+    pub fn finality_branch(mut self, v: SszProof) -> Self {
+        self.finality_branch = v;
+        self
+    }
+    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
+        self.sync_aggregate = v;
+        self
+    }
+    pub fn signature_slot(mut self, v: Uint64) -> Self {
+        self.signature_slot = v;
+        self
+    }
+    pub fn new_headers_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.new_headers_mmr_proof = v;
+        self
+    }
+    pub fn headers(mut self, v: HeaderRange) -> Self {
+        self.headers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientUpdateBuilder {
+    type Entity = ClientUpdate;
+    const NAME: &'static str = "ClientUpdateBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.attested_header.as_slice().len()
+            + self.finality_branch.as_slice().len()
+            + self.sync_aggregate.as_slice().len()
+            + self.signature_slot.as_slice().len()
+            + self.new_headers_mmr_proof.as_slice().len()
+            + self.headers.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.attested_header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.finality_branch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sync_aggregate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signature_slot.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_headers_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.attested_header.as_slice())?;
+        writer.write_all(self.finality_branch.as_slice())?;
+        writer.write_all(self.sync_aggregate.as_slice())?;
+        writer.write_all(self.signature_slot.as_slice())?;
+        writer.write_all(self.new_headers_mmr_proof.as_slice())?;
+        writer.write_all(self.headers.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientUpdate::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClientOptimisticUpdate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientOptimisticUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientOptimisticUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientOptimisticUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClientOptimisticUpdate {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientOptimisticUpdate::new_unchecked(v)
+    }
+}
+impl ClientOptimisticUpdate {
+    const DEFAULT_VALUE: [u8; 296] = [
+        40, 1, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 32, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signature_slot(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientOptimisticUpdateReader<'r> {
+        ClientOptimisticUpdateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientOptimisticUpdate {
+    type Builder = ClientOptimisticUpdateBuilder;
+    const NAME: &'static str = "ClientOptimisticUpdate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientOptimisticUpdate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientOptimisticUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientOptimisticUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .attested_header(self.attested_header())
+            .sync_aggregate(self.sync_aggregate())
+            .signature_slot(self.signature_slot())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientOptimisticUpdateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientOptimisticUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientOptimisticUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientOptimisticUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ClientOptimisticUpdateReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signature_slot(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientOptimisticUpdateReader<'r> {
+    type Entity = ClientOptimisticUpdate;
+    const NAME: &'static str = "ClientOptimisticUpdateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientOptimisticUpdateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SyncAggregateReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientOptimisticUpdateBuilder {
+    pub(crate) attested_header: Header,
+    pub(crate) sync_aggregate: SyncAggregate,
+    pub(crate) signature_slot: Uint64,
+}
+impl ClientOptimisticUpdateBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn attested_header(mut self, v: Header) -> Self {
+        self.attested_header = v;
+        self
+    }
+    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
+        self.sync_aggregate = v;
+        self
+    }
+    pub fn signature_slot(mut self, v: Uint64) -> Self {
+        self.signature_slot = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientOptimisticUpdateBuilder {
+    type Entity = ClientOptimisticUpdate;
+    const NAME: &'static str = "ClientOptimisticUpdateBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.attested_header.as_slice().len()
+            + self.sync_aggregate.as_slice().len()
+            + self.signature_slot.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.attested_header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sync_aggregate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signature_slot.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.attested_header.as_slice())?;
+        writer.write_all(self.sync_aggregate.as_slice())?;
+        writer.write_all(self.signature_slot.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientOptimisticUpdate::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SyncCommitteeUpdate(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncCommitteeUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncCommitteeUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncCommitteeUpdate {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_sync_committee_branch",
+            self.next_sync_committee_branch()
+        )?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SyncCommitteeUpdate {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SyncCommitteeUpdate::new_unchecked(v)
+    }
+}
+impl SyncCommitteeUpdate {
+    const DEFAULT_VALUE: [u8; 304] = [
+        48, 1, 0, 0, 20, 0, 0, 0, 132, 0, 0, 0, 136, 0, 0, 0, 40, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn next_sync_committee_branch(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signature_slot(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncCommitteeUpdateReader<'r> {
+        SyncCommitteeUpdateReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncCommitteeUpdate {
+    type Builder = SyncCommitteeUpdateBuilder;
+    const NAME: &'static str = "SyncCommitteeUpdate";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncCommitteeUpdate(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .attested_header(self.attested_header())
+            .next_sync_committee_branch(self.next_sync_committee_branch())
+            .sync_aggregate(self.sync_aggregate())
+            .signature_slot(self.signature_slot())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncCommitteeUpdateReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncCommitteeUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncCommitteeUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncCommitteeUpdateReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_sync_committee_branch",
+            self.next_sync_committee_branch()
+        )?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SyncCommitteeUpdateReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn attested_header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn next_sync_committee_branch(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signature_slot(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeUpdateReader<'r> {
+    type Entity = SyncCommitteeUpdate;
+    const NAME: &'static str = "SyncCommitteeUpdateReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncCommitteeUpdateReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SyncAggregateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SyncCommitteeUpdateBuilder {
+    pub(crate) attested_header: Header,
+    pub(crate) next_sync_committee_branch: SszProof,
+    pub(crate) sync_aggregate: SyncAggregate,
+    pub(crate) signature_slot: Uint64,
+}
+impl SyncCommitteeUpdateBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn attested_header(mut self, v: Header) -> Self {
+        self.attested_header = v;
+        self
+    }
+    pub fn next_sync_committee_branch(mut self, v: SszProof) -> Self {
+        self.next_sync_committee_branch = v;
+        self
+    }
+    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
+        self.sync_aggregate = v;
+        self
+    }
+    pub fn signature_slot(mut self, v: Uint64) -> Self {
+        self.signature_slot = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncCommitteeUpdateBuilder {
+    type Entity = SyncCommitteeUpdate;
+    const NAME: &'static str = "SyncCommitteeUpdateBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.attested_header.as_slice().len()
+            + self.next_sync_committee_branch.as_slice().len()
+            + self.sync_aggregate.as_slice().len()
+            + self.signature_slot.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.attested_header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.next_sync_committee_branch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sync_aggregate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signature_slot.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.attested_header.as_slice())?;
+        writer.write_all(self.next_sync_committee_branch.as_slice())?;
+        writer.write_all(self.sync_aggregate.as_slice())?;
+        writer.write_all(self.signature_slot.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncCommitteeUpdate::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SyncCommitteeRotation(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SyncCommitteeRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SyncCommitteeRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SyncCommitteeRotation {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "sync_committee_update",
+            self.sync_committee_update()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_sync_committee",
+            self.next_sync_committee()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SyncCommitteeRotation {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        SyncCommitteeRotation::new_unchecked(v)
+    }
+}
+impl SyncCommitteeRotation {
+    const DEFAULT_VALUE: [u8; 400] = [
+        144, 1, 0, 0, 12, 0, 0, 0, 60, 1, 0, 0, 48, 1, 0, 0, 20, 0, 0, 0, 132, 0, 0, 0, 136, 0, 0,
+        0, 40, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 12, 0, 0, 0, 20, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sync_committee_update(&self) -> SyncCommitteeUpdate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        SyncCommitteeUpdate::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn next_sync_committee(&self) -> ClientSyncCommittee {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ClientSyncCommittee::new_unchecked(self.0.slice(start..end))
+        } else {
+            ClientSyncCommittee::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SyncCommitteeRotationReader<'r> {
+        SyncCommitteeRotationReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SyncCommitteeRotation {
+    type Builder = SyncCommitteeRotationBuilder;
+    const NAME: &'static str = "SyncCommitteeRotation";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SyncCommitteeRotation(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeRotationReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SyncCommitteeRotationReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .sync_committee_update(self.sync_committee_update())
+            .next_sync_committee(self.next_sync_committee())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SyncCommitteeRotationReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SyncCommitteeRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SyncCommitteeRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SyncCommitteeRotationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "sync_committee_update",
+            self.sync_committee_update()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_sync_committee",
+            self.next_sync_committee()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SyncCommitteeRotationReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn sync_committee_update(&self) -> SyncCommitteeUpdateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        SyncCommitteeUpdateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn next_sync_committee(&self) -> ClientSyncCommitteeReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ClientSyncCommitteeReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ClientSyncCommitteeReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeRotationReader<'r> {
+    type Entity = SyncCommitteeRotation;
+    const NAME: &'static str = "SyncCommitteeRotationReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SyncCommitteeRotationReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        SyncCommitteeUpdateReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ClientSyncCommitteeReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SyncCommitteeRotationBuilder {
+    pub(crate) sync_committee_update: SyncCommitteeUpdate,
+    pub(crate) next_sync_committee: ClientSyncCommittee,
+}
+impl SyncCommitteeRotationBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn sync_committee_update(mut self, v: SyncCommitteeUpdate) -> Self {
+        self.sync_committee_update = v;
+        self
+    }
+    pub fn next_sync_committee(mut self, v: ClientSyncCommittee) -> Self {
+        self.next_sync_committee = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SyncCommitteeRotationBuilder {
+    type Entity = SyncCommitteeRotation;
+    const NAME: &'static str = "SyncCommitteeRotationBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.sync_committee_update.as_slice().len()
+            + self.next_sync_committee.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.sync_committee_update.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.next_sync_committee.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.sync_committee_update.as_slice())?;
+        writer.write_all(self.next_sync_committee.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SyncCommitteeRotation::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClientUpdateBatchItem(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUpdateBatchItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientUpdateBatchItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientUpdateBatchItem {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl ::core::default::Default for ClientUpdateBatchItem {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientUpdateBatchItem::new_unchecked(v)
+    }
+}
+impl ClientUpdateBatchItem {
+    const DEFAULT_VALUE: [u8; 328] = [
+        0, 0, 0, 0, 68, 1, 0, 0, 28, 0, 0, 0, 140, 0, 0, 0, 144, 0, 0, 0, 48, 1, 0, 0, 56, 1, 0, 0,
+        60, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> ClientUpdateBatchItemUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => ClientUpdate::new_unchecked(inner).into(),
+            1 => SyncCommitteeRotation::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateBatchItemReader<'r> {
+        ClientUpdateBatchItemReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientUpdateBatchItem {
+    type Builder = ClientUpdateBatchItemBuilder;
+    const NAME: &'static str = "ClientUpdateBatchItem";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientUpdateBatchItem(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchItemReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchItemReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_enum())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientUpdateBatchItemReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUpdateBatchItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientUpdateBatchItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientUpdateBatchItemReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> ClientUpdateBatchItemReader<'r> {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> ClientUpdateBatchItemUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => ClientUpdateReader::new_unchecked(inner).into(),
+            1 => SyncCommitteeRotationReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientUpdateBatchItemReader<'r> {
+    type Entity = ClientUpdateBatchItem;
+    const NAME: &'static str = "ClientUpdateBatchItemReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientUpdateBatchItemReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => ClientUpdateReader::verify(inner_slice, compatible),
+            1 => SyncCommitteeRotationReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientUpdateBatchItemBuilder(pub(crate) ClientUpdateBatchItemUnion);
+impl ClientUpdateBatchItemBuilder {
+    pub const ITEMS_COUNT: usize = 2;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<ClientUpdateBatchItemUnion>,
+    {
+        self.0 = v.into();
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientUpdateBatchItemBuilder {
+    type Entity = ClientUpdateBatchItem;
+    const NAME: &'static str = "ClientUpdateBatchItemBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientUpdateBatchItem::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum ClientUpdateBatchItemUnion {
+    ClientUpdate(ClientUpdate),
+    SyncCommitteeRotation(SyncCommitteeRotation),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum ClientUpdateBatchItemUnionReader<'r> {
+    ClientUpdate(ClientUpdateReader<'r>),
+    SyncCommitteeRotation(SyncCommitteeRotationReader<'r>),
+}
+impl ::core::default::Default for ClientUpdateBatchItemUnion {
+    fn default() -> Self {
+        ClientUpdateBatchItemUnion::ClientUpdate(::core::default::Default::default())
+    }
+}
+impl ::core::fmt::Display for ClientUpdateBatchItemUnion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdate::NAME, item)
+            }
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SyncCommitteeRotation::NAME,
+                    item
+                )
+            }
+        }
+    }
+}
+impl<'r> ::core::fmt::Display for ClientUpdateBatchItemUnionReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ClientUpdateBatchItemUnionReader::ClientUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdate::NAME, item)
+            }
+            ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    SyncCommitteeRotation::NAME,
+                    item
+                )
+            }
+        }
+    }
+}
+impl ClientUpdateBatchItemUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(ref item) => write!(f, "{}", item),
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(ref item) => write!(f, "{}", item),
+        }
+    }
+}
+impl<'r> ClientUpdateBatchItemUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            ClientUpdateBatchItemUnionReader::ClientUpdate(ref item) => write!(f, "{}", item),
+            ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(ref item) => {
+                write!(f, "{}", item)
+            }
+        }
+    }
+}
+impl ::core::convert::From<ClientUpdate> for ClientUpdateBatchItemUnion {
+    fn from(item: ClientUpdate) -> Self {
+        ClientUpdateBatchItemUnion::ClientUpdate(item)
+    }
+}
+impl ::core::convert::From<SyncCommitteeRotation> for ClientUpdateBatchItemUnion {
+    fn from(item: SyncCommitteeRotation) -> Self {
+        ClientUpdateBatchItemUnion::SyncCommitteeRotation(item)
+    }
+}
+impl<'r> ::core::convert::From<ClientUpdateReader<'r>> for ClientUpdateBatchItemUnionReader<'r> {
+    fn from(item: ClientUpdateReader<'r>) -> Self {
+        ClientUpdateBatchItemUnionReader::ClientUpdate(item)
+    }
+}
+impl<'r> ::core::convert::From<SyncCommitteeRotationReader<'r>>
+    for ClientUpdateBatchItemUnionReader<'r>
+{
+    fn from(item: SyncCommitteeRotationReader<'r>) -> Self {
+        ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(item)
+    }
+}
+impl ClientUpdateBatchItemUnion {
+    pub const NAME: &'static str = "ClientUpdateBatchItemUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(item) => item.as_bytes(),
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(item) => item.as_bytes(),
+        }
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(item) => item.as_slice(),
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(_) => 0,
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(_) => "ClientUpdate",
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(_) => "SyncCommitteeRotation",
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateBatchItemUnionReader<'r> {
+        match self {
+            ClientUpdateBatchItemUnion::ClientUpdate(item) => item.as_reader().into(),
+            ClientUpdateBatchItemUnion::SyncCommitteeRotation(item) => item.as_reader().into(),
+        }
+    }
+}
+impl<'r> ClientUpdateBatchItemUnionReader<'r> {
+    pub const NAME: &'r str = "ClientUpdateBatchItemUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            ClientUpdateBatchItemUnionReader::ClientUpdate(item) => item.as_slice(),
+            ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(item) => item.as_slice(),
+        }
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            ClientUpdateBatchItemUnionReader::ClientUpdate(_) => 0,
+            ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(_) => 1,
+        }
+    }
+    pub fn item_name(&self) -> &str {
+        match self {
+            ClientUpdateBatchItemUnionReader::ClientUpdate(_) => "ClientUpdate",
+            ClientUpdateBatchItemUnionReader::SyncCommitteeRotation(_) => "SyncCommitteeRotation",
+        }
+    }
+}
+#[derive(Clone)]
+pub struct ClientUpdateBatchItemVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUpdateBatchItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientUpdateBatchItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientUpdateBatchItemVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for ClientUpdateBatchItemVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientUpdateBatchItemVec::new_unchecked(v)
+    }
+}
+impl ClientUpdateBatchItemVec {
+    const DEFAULT_VALUE: [u8; 4] = [4, 0, 0, 0];
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ClientUpdateBatchItem> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ClientUpdateBatchItem {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            ClientUpdateBatchItem::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            ClientUpdateBatchItem::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateBatchItemVecReader<'r> {
+        ClientUpdateBatchItemVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientUpdateBatchItemVec {
+    type Builder = ClientUpdateBatchItemVecBuilder;
+    const NAME: &'static str = "ClientUpdateBatchItemVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientUpdateBatchItemVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchItemVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchItemVecReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientUpdateBatchItemVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUpdateBatchItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientUpdateBatchItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientUpdateBatchItemVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> ClientUpdateBatchItemVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<ClientUpdateBatchItemReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> ClientUpdateBatchItemReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            ClientUpdateBatchItemReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            ClientUpdateBatchItemReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientUpdateBatchItemVecReader<'r> {
+    type Entity = ClientUpdateBatchItemVec;
+    const NAME: &'static str = "ClientUpdateBatchItemVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientUpdateBatchItemVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            ClientUpdateBatchItemReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientUpdateBatchItemVecBuilder(pub(crate) Vec<ClientUpdateBatchItem>);
+impl ClientUpdateBatchItemVecBuilder {
+    pub fn set(mut self, v: Vec<ClientUpdateBatchItem>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: ClientUpdateBatchItem) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = ClientUpdateBatchItem>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: ClientUpdateBatchItem,
+    ) -> Option<ClientUpdateBatchItem> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for ClientUpdateBatchItemVecBuilder {
+    type Entity = ClientUpdateBatchItemVec;
+    const NAME: &'static str = "ClientUpdateBatchItemVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientUpdateBatchItemVec::new_unchecked(inner.into())
+    }
+}
+pub struct ClientUpdateBatchItemVecIterator(ClientUpdateBatchItemVec, usize, usize);
+impl ::core::iter::Iterator for ClientUpdateBatchItemVecIterator {
+    type Item = ClientUpdateBatchItem;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for ClientUpdateBatchItemVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for ClientUpdateBatchItemVec {
+    type Item = ClientUpdateBatchItem;
+    type IntoIter = ClientUpdateBatchItemVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        ClientUpdateBatchItemVecIterator(self, 0, len)
+    }
+}
+impl<'r> ClientUpdateBatchItemVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> ClientUpdateBatchItemVecReaderIterator<'t, 'r> {
+        ClientUpdateBatchItemVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct ClientUpdateBatchItemVecReaderIterator<'t, 'r>(
+    &'t ClientUpdateBatchItemVecReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for ClientUpdateBatchItemVecReaderIterator<'t, 'r> {
+    type Item = ClientUpdateBatchItemReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator
+    for ClientUpdateBatchItemVecReaderIterator<'t, 'r>
+{
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct ClientUpdateBatch(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUpdateBatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientUpdateBatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientUpdateBatch {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "items", self.items())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClientUpdateBatch {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientUpdateBatch::new_unchecked(v)
+    }
+}
+impl ClientUpdateBatch {
+    const DEFAULT_VALUE: [u8; 12] = [12, 0, 0, 0, 8, 0, 0, 0, 4, 0, 0, 0];
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn items(&self) -> ClientUpdateBatchItemVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            ClientUpdateBatchItemVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            ClientUpdateBatchItemVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateBatchReader<'r> {
+        ClientUpdateBatchReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientUpdateBatch {
+    type Builder = ClientUpdateBatchBuilder;
+    const NAME: &'static str = "ClientUpdateBatch";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientUpdateBatch(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientUpdateBatchReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().items(self.items())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientUpdateBatchReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUpdateBatchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientUpdateBatchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientUpdateBatchReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "items", self.items())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ClientUpdateBatchReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn items(&self) -> ClientUpdateBatchItemVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            ClientUpdateBatchItemVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ClientUpdateBatchItemVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientUpdateBatchReader<'r> {
+    type Entity = ClientUpdateBatch;
+    const NAME: &'static str = "ClientUpdateBatchReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientUpdateBatchReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ClientUpdateBatchItemVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientUpdateBatchBuilder {
+    pub(crate) items: ClientUpdateBatchItemVec,
+}
+impl ClientUpdateBatchBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn items(mut self, v: ClientUpdateBatchItemVec) -> Self {
+        self.items = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientUpdateBatchBuilder {
+    type Entity = ClientUpdateBatch;
+    const NAME: &'static str = "ClientUpdateBatchBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.items.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.items.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.items.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientUpdateBatch::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TransactionProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "transaction_index", self.transaction_index())?;
+        write!(f, ", {}: {}", "receipts_root", self.receipts_root())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "transaction_ssz_proof",
+            self.transaction_ssz_proof()
+        )?;
+        write!(f, ", {}: {}", "receipt_mpt_proof", self.receipt_mpt_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "receipts_root_ssz_proof",
+            self.receipts_root_ssz_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TransactionProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        TransactionProof::new_unchecked(v)
+    }
+}
+impl TransactionProof {
+    const DEFAULT_VALUE: [u8; 200] = [
+        200, 0, 0, 0, 32, 0, 0, 0, 144, 0, 0, 0, 152, 0, 0, 0, 184, 0, 0, 0, 188, 0, 0, 0, 192, 0,
+        0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transaction_index(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn receipts_root(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn header_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn transaction_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        SszProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn receipt_mpt_proof(&self) -> MptProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        MptProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn receipts_root_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SszProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            SszProof::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransactionProofReader<'r> {
+        TransactionProofReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TransactionProof {
+    type Builder = TransactionProofBuilder;
+    const NAME: &'static str = "TransactionProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TransactionProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransactionProofReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransactionProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .header(self.header())
+            .transaction_index(self.transaction_index())
+            .receipts_root(self.receipts_root())
+            .header_mmr_proof(self.header_mmr_proof())
+            .transaction_ssz_proof(self.transaction_ssz_proof())
+            .receipt_mpt_proof(self.receipt_mpt_proof())
+            .receipts_root_ssz_proof(self.receipts_root_ssz_proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransactionProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransactionProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "transaction_index", self.transaction_index())?;
+        write!(f, ", {}: {}", "receipts_root", self.receipts_root())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "transaction_ssz_proof",
+            self.transaction_ssz_proof()
+        )?;
+        write!(f, ", {}: {}", "receipt_mpt_proof", self.receipt_mpt_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "receipts_root_ssz_proof",
+            self.receipts_root_ssz_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TransactionProofReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transaction_index(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn receipts_root(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn header_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn transaction_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        SszProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn receipt_mpt_proof(&self) -> MptProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        MptProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn receipts_root_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SszProofReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
+    type Entity = TransactionProof;
+    const NAME: &'static str = "TransactionProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TransactionProofReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        HashReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        SszProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        MptProofReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        SszProofReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TransactionProofBuilder {
+    pub(crate) header: Header,
+    pub(crate) transaction_index: Uint64,
+    pub(crate) receipts_root: Hash,
+    pub(crate) header_mmr_proof: MmrProof,
+    pub(crate) transaction_ssz_proof: SszProof,
+    pub(crate) receipt_mpt_proof: MptProof,
+    pub(crate) receipts_root_ssz_proof: SszProof,
+}
+impl TransactionProofBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
+        self
+    }
+    pub fn transaction_index(mut self, v: Uint64) -> Self {
+        self.transaction_index = v;
+        self
+    }
+    pub fn receipts_root(mut self, v: Hash) -> Self {
+        self.receipts_root = v;
+        self
+    }
+    pub fn header_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.header_mmr_proof = v;
+        self
+    }
+    pub fn transaction_ssz_proof(mut self, v: SszProof) -> Self {
+        self.transaction_ssz_proof = v;
+        self
+    }
+    pub fn receipt_mpt_proof(mut self, v: MptProof) -> Self {
+        self.receipt_mpt_proof = v;
+        self
+    }
+    pub fn receipts_root_ssz_proof(mut self, v: SszProof) -> Self {
+        self.receipts_root_ssz_proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TransactionProofBuilder {
+    type Entity = TransactionProof;
+    const NAME: &'static str = "TransactionProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.header.as_slice().len()
+            + self.transaction_index.as_slice().len()
+            + self.receipts_root.as_slice().len()
+            + self.header_mmr_proof.as_slice().len()
+            + self.transaction_ssz_proof.as_slice().len()
+            + self.receipt_mpt_proof.as_slice().len()
+            + self.receipts_root_ssz_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.receipts_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction_ssz_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.receipt_mpt_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.receipts_root_ssz_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.transaction_index.as_slice())?;
+        writer.write_all(self.receipts_root.as_slice())?;
+        writer.write_all(self.header_mmr_proof.as_slice())?;
+        writer.write_all(self.transaction_ssz_proof.as_slice())?;
+        writer.write_all(self.receipt_mpt_proof.as_slice())?;
+        writer.write_all(self.receipts_root_ssz_proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TransactionProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TransactionPayload(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TransactionPayload {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TransactionPayload {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TransactionPayload {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transaction", self.transaction())?;
+        write!(f, ", {}: {}", "receipt", self.receipt())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TransactionPayload {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        TransactionPayload::new_unchecked(v)
+    }
+}
+impl TransactionPayload {
+    const DEFAULT_VALUE: [u8; 20] = [
+        20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transaction(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn receipt(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TransactionPayloadReader<'r> {
+        TransactionPayloadReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TransactionPayload {
+    type Builder = TransactionPayloadBuilder;
+    const NAME: &'static str = "TransactionPayload";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TransactionPayload(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransactionPayloadReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TransactionPayloadReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .transaction(self.transaction())
+            .receipt(self.receipt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TransactionPayloadReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TransactionPayloadReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TransactionPayloadReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TransactionPayloadReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "transaction", self.transaction())?;
+        write!(f, ", {}: {}", "receipt", self.receipt())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TransactionPayloadReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn transaction(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn receipt(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TransactionPayloadReader<'r> {
+    type Entity = TransactionPayload;
+    const NAME: &'static str = "TransactionPayloadReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        HeaderVecReader(slice)
+        TransactionPayloadReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        let total_size = molecule::unpack_number(slice) as usize;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct HeaderVecBuilder(pub(crate) Vec<Header>);
-impl HeaderVecBuilder {
-    pub const ITEM_SIZE: usize = 112;
-    pub fn set(mut self, v: Vec<Header>) -> Self {
-        self.0 = v;
-        self
-    }
-    pub fn push(mut self, v: Header) -> Self {
-        self.0.push(v);
+pub struct TransactionPayloadBuilder {
+    pub(crate) transaction: Bytes,
+    pub(crate) receipt: Bytes,
+}
+impl TransactionPayloadBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn transaction(mut self, v: Bytes) -> Self {
+        self.transaction = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Header>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn receipt(mut self, v: Bytes) -> Self {
+        self.receipt = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: Header) -> Option<Header> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
-    }
 }
-impl molecule::prelude::Builder for HeaderVecBuilder {
-    type Entity = HeaderVec;
-    const NAME: &'static str = "HeaderVecBuilder";
+impl molecule::prelude::Builder for TransactionPayloadBuilder {
+    type Entity = TransactionPayload;
+    const NAME: &'static str = "TransactionPayloadBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.transaction.as_slice().len()
+            + self.receipt.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.transaction.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.receipt.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.transaction.as_slice())?;
+        writer.write_all(self.receipt.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        HeaderVec::new_unchecked(inner.into())
-    }
-}
-pub struct HeaderVecIterator(HeaderVec, usize, usize);
-impl ::core::iter::Iterator for HeaderVecIterator {
-    type Item = Header;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for HeaderVecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for HeaderVec {
-    type Item = Header;
-    type IntoIter = HeaderVecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        HeaderVecIterator(self, 0, len)
-    }
-}
-impl<'r> HeaderVecReader<'r> {
-    pub fn iter<'t>(&'t self) -> HeaderVecReaderIterator<'t, 'r> {
-        HeaderVecReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct HeaderVecReaderIterator<'t, 'r>(&'t HeaderVecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for HeaderVecReaderIterator<'t, 'r> {
-    type Item = HeaderReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for HeaderVecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+        TransactionPayload::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SyncCommitteeBits(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SyncCommitteeBits {
+pub struct HistoricalHeaderProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HistoricalHeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5075,237 +13472,392 @@ impl ::core::fmt::LowerHex for SyncCommitteeBits {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SyncCommitteeBits {
+impl ::core::fmt::Debug for HistoricalHeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SyncCommitteeBits {
+impl ::core::fmt::Display for HistoricalHeaderProof {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "anchor_header", self.anchor_header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "anchor_header_mmr_proof",
+            self.anchor_header_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "header_ssz_proof", self.header_ssz_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for SyncCommitteeBits {
+impl ::core::default::Default for HistoricalHeaderProof {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SyncCommitteeBits::new_unchecked(v)
+        HistoricalHeaderProof::new_unchecked(v)
     }
 }
-impl SyncCommitteeBits {
-    const DEFAULT_VALUE: [u8; 64] = [
+impl HistoricalHeaderProof {
+    const DEFAULT_VALUE: [u8; 136] = [
+        136, 0, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 64;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 64;
-    pub fn nth0(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn nth1(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(1..2))
-    }
-    pub fn nth2(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(2..3))
-    }
-    pub fn nth3(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(3..4))
-    }
-    pub fn nth4(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(4..5))
-    }
-    pub fn nth5(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(5..6))
-    }
-    pub fn nth6(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(6..7))
-    }
-    pub fn nth7(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(7..8))
-    }
-    pub fn nth8(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(8..9))
-    }
-    pub fn nth9(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(9..10))
-    }
-    pub fn nth10(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(10..11))
-    }
-    pub fn nth11(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(11..12))
-    }
-    pub fn nth12(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(12..13))
-    }
-    pub fn nth13(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(13..14))
-    }
-    pub fn nth14(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(14..15))
-    }
-    pub fn nth15(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(15..16))
-    }
-    pub fn nth16(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(16..17))
-    }
-    pub fn nth17(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(17..18))
-    }
-    pub fn nth18(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(18..19))
-    }
-    pub fn nth19(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(19..20))
-    }
-    pub fn nth20(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(20..21))
-    }
-    pub fn nth21(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(21..22))
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth22(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(22..23))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth23(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(23..24))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth24(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(24..25))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth25(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(25..26))
+    pub fn anchor_header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth26(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(26..27))
+    pub fn anchor_header_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth27(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(27..28))
+    pub fn header_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            SszProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            SszProof::new_unchecked(self.0.slice(start..))
+        }
     }
-    pub fn nth28(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(28..29))
+    pub fn as_reader<'r>(&'r self) -> HistoricalHeaderProofReader<'r> {
+        HistoricalHeaderProofReader::new_unchecked(self.as_slice())
     }
-    pub fn nth29(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(29..30))
+}
+impl molecule::prelude::Entity for HistoricalHeaderProof {
+    type Builder = HistoricalHeaderProofBuilder;
+    const NAME: &'static str = "HistoricalHeaderProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        HistoricalHeaderProof(data)
     }
-    pub fn nth30(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(30..31))
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn nth31(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(31..32))
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    pub fn nth32(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32..33))
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HistoricalHeaderProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth33(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(33..34))
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        HistoricalHeaderProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth34(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(34..35))
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
     }
-    pub fn nth35(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(35..36))
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .anchor_header(self.anchor_header())
+            .anchor_header_mmr_proof(self.anchor_header_mmr_proof())
+            .header_ssz_proof(self.header_ssz_proof())
     }
-    pub fn nth36(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(36..37))
+}
+#[derive(Clone, Copy)]
+pub struct HistoricalHeaderProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HistoricalHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth37(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(37..38))
+}
+impl<'r> ::core::fmt::Debug for HistoricalHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth38(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(38..39))
+}
+impl<'r> ::core::fmt::Display for HistoricalHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "anchor_header", self.anchor_header())?;
+        write!(
+            f,
+            ", {}: {}",
+            "anchor_header_mmr_proof",
+            self.anchor_header_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "header_ssz_proof", self.header_ssz_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth39(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(39..40))
+}
+impl<'r> HistoricalHeaderProofReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth40(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(40..41))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth41(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(41..42))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth42(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(42..43))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth43(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(43..44))
+    pub fn anchor_header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth44(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(44..45))
+    pub fn anchor_header_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth45(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(45..46))
+    pub fn header_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SszProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn nth46(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(46..47))
+}
+impl<'r> molecule::prelude::Reader<'r> for HistoricalHeaderProofReader<'r> {
+    type Entity = HistoricalHeaderProof;
+    const NAME: &'static str = "HistoricalHeaderProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth47(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(47..48))
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HistoricalHeaderProofReader(slice)
     }
-    pub fn nth48(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(48..49))
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth49(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(49..50))
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SszProofReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
     }
-    pub fn nth50(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(50..51))
+}
+#[derive(Debug, Default)]
+pub struct HistoricalHeaderProofBuilder {
+    pub(crate) anchor_header: Header,
+    pub(crate) anchor_header_mmr_proof: MmrProof,
+    pub(crate) header_ssz_proof: SszProof,
+}
+impl HistoricalHeaderProofBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn anchor_header(mut self, v: Header) -> Self {
+        self.anchor_header = v;
+        self
     }
-    pub fn nth51(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(51..52))
+    pub fn anchor_header_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.anchor_header_mmr_proof = v;
+        self
     }
-    pub fn nth52(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(52..53))
+    pub fn header_ssz_proof(mut self, v: SszProof) -> Self {
+        self.header_ssz_proof = v;
+        self
     }
-    pub fn nth53(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(53..54))
+}
+impl molecule::prelude::Builder for HistoricalHeaderProofBuilder {
+    type Entity = HistoricalHeaderProof;
+    const NAME: &'static str = "HistoricalHeaderProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.anchor_header.as_slice().len()
+            + self.anchor_header_mmr_proof.as_slice().len()
+            + self.header_ssz_proof.as_slice().len()
     }
-    pub fn nth54(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(54..55))
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.anchor_header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.anchor_header_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_ssz_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.anchor_header.as_slice())?;
+        writer.write_all(self.anchor_header_mmr_proof.as_slice())?;
+        writer.write_all(self.header_ssz_proof.as_slice())?;
+        Ok(())
     }
-    pub fn nth55(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(55..56))
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HistoricalHeaderProof::new_unchecked(inner.into())
     }
-    pub fn nth56(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(56..57))
+}
+#[derive(Clone)]
+pub struct HistoricalTransactionProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for HistoricalTransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth57(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(57..58))
+}
+impl ::core::fmt::Debug for HistoricalTransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth58(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(58..59))
+}
+impl ::core::fmt::Display for HistoricalTransactionProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "historical_header_proof",
+            self.historical_header_proof()
+        )?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for HistoricalTransactionProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        HistoricalTransactionProof::new_unchecked(v)
     }
-    pub fn nth59(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(59..60))
+}
+impl HistoricalTransactionProof {
+    const DEFAULT_VALUE: [u8; 348] = [
+        92, 1, 0, 0, 12, 0, 0, 0, 148, 0, 0, 0, 136, 0, 0, 0, 16, 0, 0, 0, 128, 0, 0, 0, 132, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 200, 0, 0, 0, 32, 0, 0, 0, 144, 0, 0, 0, 152, 0, 0, 0, 184, 0, 0, 0, 188, 0, 0, 0, 192,
+        0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth60(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(60..61))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth61(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(61..62))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth62(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(62..63))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth63(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(63..64))
+    pub fn historical_header_proof(&self) -> HistoricalHeaderProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HistoricalHeaderProof::new_unchecked(self.0.slice(start..end))
     }
-    pub fn raw_data(&self) -> molecule::bytes::Bytes {
-        self.as_bytes()
+    pub fn transaction_proof(&self) -> TransactionProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            TransactionProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            TransactionProof::new_unchecked(self.0.slice(start..))
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> SyncCommitteeBitsReader<'r> {
-        SyncCommitteeBitsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> HistoricalTransactionProofReader<'r> {
+        HistoricalTransactionProofReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SyncCommitteeBits {
-    type Builder = SyncCommitteeBitsBuilder;
-    const NAME: &'static str = "SyncCommitteeBits";
+impl molecule::prelude::Entity for HistoricalTransactionProof {
+    type Builder = HistoricalTransactionProofBuilder;
+    const NAME: &'static str = "HistoricalTransactionProof";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SyncCommitteeBits(data)
+        HistoricalTransactionProof(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5314,86 +13866,24 @@ impl molecule::prelude::Entity for SyncCommitteeBits {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeBitsReader::from_slice(slice).map(|reader| reader.to_entity())
+        HistoricalTransactionProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeBitsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        HistoricalTransactionProofReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().set([
-            self.nth0(),
-            self.nth1(),
-            self.nth2(),
-            self.nth3(),
-            self.nth4(),
-            self.nth5(),
-            self.nth6(),
-            self.nth7(),
-            self.nth8(),
-            self.nth9(),
-            self.nth10(),
-            self.nth11(),
-            self.nth12(),
-            self.nth13(),
-            self.nth14(),
-            self.nth15(),
-            self.nth16(),
-            self.nth17(),
-            self.nth18(),
-            self.nth19(),
-            self.nth20(),
-            self.nth21(),
-            self.nth22(),
-            self.nth23(),
-            self.nth24(),
-            self.nth25(),
-            self.nth26(),
-            self.nth27(),
-            self.nth28(),
-            self.nth29(),
-            self.nth30(),
-            self.nth31(),
-            self.nth32(),
-            self.nth33(),
-            self.nth34(),
-            self.nth35(),
-            self.nth36(),
-            self.nth37(),
-            self.nth38(),
-            self.nth39(),
-            self.nth40(),
-            self.nth41(),
-            self.nth42(),
-            self.nth43(),
-            self.nth44(),
-            self.nth45(),
-            self.nth46(),
-            self.nth47(),
-            self.nth48(),
-            self.nth49(),
-            self.nth50(),
-            self.nth51(),
-            self.nth52(),
-            self.nth53(),
-            self.nth54(),
-            self.nth55(),
-            self.nth56(),
-            self.nth57(),
-            self.nth58(),
-            self.nth59(),
-            self.nth60(),
-            self.nth61(),
-            self.nth62(),
-            self.nth63(),
-        ])
+        Self::new_builder()
+            .historical_header_proof(self.historical_header_proof())
+            .transaction_proof(self.transaction_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SyncCommitteeBitsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SyncCommitteeBitsReader<'r> {
+pub struct HistoricalTransactionProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for HistoricalTransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5402,663 +13892,1184 @@ impl<'r> ::core::fmt::LowerHex for SyncCommitteeBitsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SyncCommitteeBitsReader<'r> {
+impl<'r> ::core::fmt::Debug for HistoricalTransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SyncCommitteeBitsReader<'r> {
+impl<'r> ::core::fmt::Display for HistoricalTransactionProofReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        let raw_data = hex_string(&self.raw_data());
-        write!(f, "{}(0x{})", Self::NAME, raw_data)
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "historical_header_proof",
+            self.historical_header_proof()
+        )?;
+        write!(f, ", {}: {}", "transaction_proof", self.transaction_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
 }
-impl<'r> SyncCommitteeBitsReader<'r> {
-    pub const TOTAL_SIZE: usize = 64;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 64;
-    pub fn nth0(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> HistoricalTransactionProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth1(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth2(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[2..3])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth3(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[3..4])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth4(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[4..5])
+    pub fn historical_header_proof(&self) -> HistoricalHeaderProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HistoricalHeaderProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth5(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[5..6])
+    pub fn transaction_proof(&self) -> TransactionProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            TransactionProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            TransactionProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn nth6(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[6..7])
+}
+impl<'r> molecule::prelude::Reader<'r> for HistoricalTransactionProofReader<'r> {
+    type Entity = HistoricalTransactionProof;
+    const NAME: &'static str = "HistoricalTransactionProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth7(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[7..8])
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        HistoricalTransactionProofReader(slice)
     }
-    pub fn nth8(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[8..9])
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth9(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[9..10])
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HistoricalHeaderProofReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        TransactionProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
     }
-    pub fn nth10(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[10..11])
+}
+#[derive(Debug, Default)]
+pub struct HistoricalTransactionProofBuilder {
+    pub(crate) historical_header_proof: HistoricalHeaderProof,
+    pub(crate) transaction_proof: TransactionProof,
+}
+impl HistoricalTransactionProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn historical_header_proof(mut self, v: HistoricalHeaderProof) -> Self {
+        self.historical_header_proof = v;
+        self
+    }
+    pub fn transaction_proof(mut self, v: TransactionProof) -> Self {
+        self.transaction_proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for HistoricalTransactionProofBuilder {
+    type Entity = HistoricalTransactionProof;
+    const NAME: &'static str = "HistoricalTransactionProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.historical_header_proof.as_slice().len()
+            + self.transaction_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.historical_header_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.transaction_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.historical_header_proof.as_slice())?;
+        writer.write_all(self.transaction_proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        HistoricalTransactionProof::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BeaconStateProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BeaconStateProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth11(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[11..12])
+}
+impl ::core::fmt::Debug for BeaconStateProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth12(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[12..13])
+}
+impl ::core::fmt::Display for BeaconStateProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(f, ", {}: {}", "generalized_index", self.generalized_index())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        write!(f, ", {}: {}", "state_ssz_proof", self.state_ssz_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth13(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[13..14])
+}
+impl ::core::default::Default for BeaconStateProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        BeaconStateProof::new_unchecked(v)
     }
-    pub fn nth14(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[14..15])
+}
+impl BeaconStateProof {
+    const DEFAULT_VALUE: [u8; 156] = [
+        156, 0, 0, 0, 24, 0, 0, 0, 136, 0, 0, 0, 140, 0, 0, 0, 148, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth15(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[15..16])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth16(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[16..17])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth17(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[17..18])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth18(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[18..19])
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth19(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[19..20])
+    pub fn header_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth20(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[20..21])
+    pub fn generalized_index(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth21(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[21..22])
+    pub fn value(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth22(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[22..23])
+    pub fn state_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            SszProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            SszProof::new_unchecked(self.0.slice(start..))
+        }
     }
-    pub fn nth23(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[23..24])
+    pub fn as_reader<'r>(&'r self) -> BeaconStateProofReader<'r> {
+        BeaconStateProofReader::new_unchecked(self.as_slice())
     }
-    pub fn nth24(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[24..25])
+}
+impl molecule::prelude::Entity for BeaconStateProof {
+    type Builder = BeaconStateProofBuilder;
+    const NAME: &'static str = "BeaconStateProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BeaconStateProof(data)
     }
-    pub fn nth25(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[25..26])
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn nth26(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[26..27])
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    pub fn nth27(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[27..28])
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BeaconStateProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth28(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[28..29])
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BeaconStateProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth29(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[29..30])
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
     }
-    pub fn nth30(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[30..31])
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .header(self.header())
+            .header_mmr_proof(self.header_mmr_proof())
+            .generalized_index(self.generalized_index())
+            .value(self.value())
+            .state_ssz_proof(self.state_ssz_proof())
     }
-    pub fn nth31(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[31..32])
+}
+#[derive(Clone, Copy)]
+pub struct BeaconStateProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BeaconStateProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth32(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
+}
+impl<'r> ::core::fmt::Debug for BeaconStateProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth33(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[33..34])
+}
+impl<'r> ::core::fmt::Display for BeaconStateProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(f, ", {}: {}", "generalized_index", self.generalized_index())?;
+        write!(f, ", {}: {}", "value", self.value())?;
+        write!(f, ", {}: {}", "state_ssz_proof", self.state_ssz_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth34(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[34..35])
+}
+impl<'r> BeaconStateProofReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth35(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[35..36])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth36(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[36..37])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth37(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[37..38])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth38(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[38..39])
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth39(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[39..40])
+    pub fn header_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth40(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[40..41])
+    pub fn generalized_index(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth41(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[41..42])
+    pub fn value(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth42(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[42..43])
+    pub fn state_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SszProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn nth43(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[43..44])
+}
+impl<'r> molecule::prelude::Reader<'r> for BeaconStateProofReader<'r> {
+    type Entity = BeaconStateProof;
+    const NAME: &'static str = "BeaconStateProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth44(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[44..45])
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BeaconStateProofReader(slice)
     }
-    pub fn nth45(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[45..46])
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth46(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[46..47])
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        SszProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Ok(())
     }
-    pub fn nth47(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[47..48])
+}
+#[derive(Debug, Default)]
+pub struct BeaconStateProofBuilder {
+    pub(crate) header: Header,
+    pub(crate) header_mmr_proof: MmrProof,
+    pub(crate) generalized_index: Uint64,
+    pub(crate) value: Bytes,
+    pub(crate) state_ssz_proof: SszProof,
+}
+impl BeaconStateProofBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
+        self
     }
-    pub fn nth48(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[48..49])
+    pub fn header_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.header_mmr_proof = v;
+        self
     }
-    pub fn nth49(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[49..50])
+    pub fn generalized_index(mut self, v: Uint64) -> Self {
+        self.generalized_index = v;
+        self
     }
-    pub fn nth50(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[50..51])
+    pub fn value(mut self, v: Bytes) -> Self {
+        self.value = v;
+        self
     }
-    pub fn nth51(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[51..52])
+    pub fn state_ssz_proof(mut self, v: SszProof) -> Self {
+        self.state_ssz_proof = v;
+        self
     }
-    pub fn nth52(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[52..53])
+}
+impl molecule::prelude::Builder for BeaconStateProofBuilder {
+    type Entity = BeaconStateProof;
+    const NAME: &'static str = "BeaconStateProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.header.as_slice().len()
+            + self.header_mmr_proof.as_slice().len()
+            + self.generalized_index.as_slice().len()
+            + self.value.as_slice().len()
+            + self.state_ssz_proof.as_slice().len()
     }
-    pub fn nth53(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[53..54])
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.generalized_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.value.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.state_ssz_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.header_mmr_proof.as_slice())?;
+        writer.write_all(self.generalized_index.as_slice())?;
+        writer.write_all(self.value.as_slice())?;
+        writer.write_all(self.state_ssz_proof.as_slice())?;
+        Ok(())
     }
-    pub fn nth54(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[54..55])
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BeaconStateProof::new_unchecked(inner.into())
     }
-    pub fn nth55(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[55..56])
+}
+#[derive(Clone)]
+pub struct ValidatorProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ValidatorProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth56(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[56..57])
+}
+impl ::core::fmt::Debug for ValidatorProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth57(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[57..58])
+}
+impl ::core::fmt::Display for ValidatorProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(f, ", {}: {}", "validator_index", self.validator_index())?;
+        write!(f, ", {}: {}", "validator", self.validator())?;
+        write!(
+            f,
+            ", {}: {}",
+            "validator_ssz_proof",
+            self.validator_ssz_proof()
+        )?;
+        write!(f, ", {}: {}", "balances_chunk", self.balances_chunk())?;
+        write!(
+            f,
+            ", {}: {}",
+            "balances_chunk_ssz_proof",
+            self.balances_chunk_ssz_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth58(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[58..59])
+}
+impl ::core::default::Default for ValidatorProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ValidatorProof::new_unchecked(v)
     }
-    pub fn nth59(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[59..60])
+}
+impl ValidatorProof {
+    const DEFAULT_VALUE: [u8; 317] = [
+        61, 1, 0, 0, 32, 0, 0, 0, 144, 0, 0, 0, 148, 0, 0, 0, 156, 0, 0, 0, 21, 1, 0, 0, 25, 1, 0,
+        0, 57, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth60(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[60..61])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth61(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[61..62])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth62(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[62..63])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth63(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[63..64])
+    pub fn header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn raw_data(&self) -> &'r [u8] {
-        self.as_slice()
+    pub fn header_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeBitsReader<'r> {
-    type Entity = SyncCommitteeBits;
-    const NAME: &'static str = "SyncCommitteeBitsReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    pub fn validator_index(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        SyncCommitteeBitsReader(slice)
+    pub fn validator(&self) -> Validator {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Validator::new_unchecked(self.0.slice(start..end))
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+    pub fn validator_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        SszProof::new_unchecked(self.0.slice(start..end))
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+    pub fn balances_chunk(&self) -> Hash {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Hash::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn balances_chunk_ssz_proof(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SszProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            SszProof::new_unchecked(self.0.slice(start..))
         }
-        Ok(())
     }
-}
-pub struct SyncCommitteeBitsBuilder(pub(crate) [Byte; 64]);
-impl ::core::fmt::Debug for SyncCommitteeBitsBuilder {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:?})", Self::NAME, &self.0[..])
+    pub fn as_reader<'r>(&'r self) -> ValidatorProofReader<'r> {
+        ValidatorProofReader::new_unchecked(self.as_slice())
     }
 }
-impl ::core::default::Default for SyncCommitteeBitsBuilder {
-    fn default() -> Self {
-        SyncCommitteeBitsBuilder([
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-            Byte::default(),
-        ])
+impl molecule::prelude::Entity for ValidatorProof {
+    type Builder = ValidatorProofBuilder;
+    const NAME: &'static str = "ValidatorProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ValidatorProof(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-}
-impl SyncCommitteeBitsBuilder {
-    pub const TOTAL_SIZE: usize = 64;
-    pub const ITEM_SIZE: usize = 1;
-    pub const ITEM_COUNT: usize = 64;
-    pub fn set(mut self, v: [Byte; 64]) -> Self {
-        self.0 = v;
-        self
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    pub fn nth0(mut self, v: Byte) -> Self {
-        self.0[0] = v;
-        self
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ValidatorProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth1(mut self, v: Byte) -> Self {
-        self.0[1] = v;
-        self
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ValidatorProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth2(mut self, v: Byte) -> Self {
-        self.0[2] = v;
-        self
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
     }
-    pub fn nth3(mut self, v: Byte) -> Self {
-        self.0[3] = v;
-        self
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .header(self.header())
+            .header_mmr_proof(self.header_mmr_proof())
+            .validator_index(self.validator_index())
+            .validator(self.validator())
+            .validator_ssz_proof(self.validator_ssz_proof())
+            .balances_chunk(self.balances_chunk())
+            .balances_chunk_ssz_proof(self.balances_chunk_ssz_proof())
     }
-    pub fn nth4(mut self, v: Byte) -> Self {
-        self.0[4] = v;
-        self
+}
+#[derive(Clone, Copy)]
+pub struct ValidatorProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ValidatorProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth5(mut self, v: Byte) -> Self {
-        self.0[5] = v;
-        self
+}
+impl<'r> ::core::fmt::Debug for ValidatorProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth6(mut self, v: Byte) -> Self {
-        self.0[6] = v;
-        self
+}
+impl<'r> ::core::fmt::Display for ValidatorProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "header", self.header())?;
+        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
+        write!(f, ", {}: {}", "validator_index", self.validator_index())?;
+        write!(f, ", {}: {}", "validator", self.validator())?;
+        write!(
+            f,
+            ", {}: {}",
+            "validator_ssz_proof",
+            self.validator_ssz_proof()
+        )?;
+        write!(f, ", {}: {}", "balances_chunk", self.balances_chunk())?;
+        write!(
+            f,
+            ", {}: {}",
+            "balances_chunk_ssz_proof",
+            self.balances_chunk_ssz_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth7(mut self, v: Byte) -> Self {
-        self.0[7] = v;
-        self
+}
+impl<'r> ValidatorProofReader<'r> {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth8(mut self, v: Byte) -> Self {
-        self.0[8] = v;
-        self
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth9(mut self, v: Byte) -> Self {
-        self.0[9] = v;
-        self
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth10(mut self, v: Byte) -> Self {
-        self.0[10] = v;
-        self
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth11(mut self, v: Byte) -> Self {
-        self.0[11] = v;
-        self
+    pub fn header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth12(mut self, v: Byte) -> Self {
-        self.0[12] = v;
-        self
+    pub fn header_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth13(mut self, v: Byte) -> Self {
-        self.0[13] = v;
-        self
+    pub fn validator_index(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth14(mut self, v: Byte) -> Self {
-        self.0[14] = v;
-        self
+    pub fn validator(&self) -> ValidatorReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ValidatorReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth15(mut self, v: Byte) -> Self {
-        self.0[15] = v;
-        self
+    pub fn validator_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        SszProofReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth16(mut self, v: Byte) -> Self {
-        self.0[16] = v;
-        self
+    pub fn balances_chunk(&self) -> HashReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        HashReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth17(mut self, v: Byte) -> Self {
-        self.0[17] = v;
-        self
+    pub fn balances_chunk_ssz_proof(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SszProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn nth18(mut self, v: Byte) -> Self {
-        self.0[18] = v;
-        self
+}
+impl<'r> molecule::prelude::Reader<'r> for ValidatorProofReader<'r> {
+    type Entity = ValidatorProof;
+    const NAME: &'static str = "ValidatorProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth19(mut self, v: Byte) -> Self {
-        self.0[19] = v;
-        self
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ValidatorProofReader(slice)
     }
-    pub fn nth20(mut self, v: Byte) -> Self {
-        self.0[20] = v;
-        self
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth21(mut self, v: Byte) -> Self {
-        self.0[21] = v;
-        self
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ValidatorReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        SszProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        HashReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        SszProofReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Ok(())
     }
-    pub fn nth22(mut self, v: Byte) -> Self {
-        self.0[22] = v;
+}
+#[derive(Debug, Default)]
+pub struct ValidatorProofBuilder {
+    pub(crate) header: Header,
+    pub(crate) header_mmr_proof: MmrProof,
+    pub(crate) validator_index: Uint64,
+    pub(crate) validator: Validator,
+    pub(crate) validator_ssz_proof: SszProof,
+    pub(crate) balances_chunk: Hash,
+    pub(crate) balances_chunk_ssz_proof: SszProof,
+}
+impl ValidatorProofBuilder {
+    pub const FIELD_COUNT: usize = 7;
+    pub fn header(mut self, v: Header) -> Self {
+        self.header = v;
         self
     }
-    pub fn nth23(mut self, v: Byte) -> Self {
-        self.0[23] = v;
+    pub fn header_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.header_mmr_proof = v;
         self
     }
-    pub fn nth24(mut self, v: Byte) -> Self {
-        self.0[24] = v;
+    pub fn validator_index(mut self, v: Uint64) -> Self {
+        self.validator_index = v;
         self
     }
-    pub fn nth25(mut self, v: Byte) -> Self {
-        self.0[25] = v;
+    pub fn validator(mut self, v: Validator) -> Self {
+        self.validator = v;
         self
     }
-    pub fn nth26(mut self, v: Byte) -> Self {
-        self.0[26] = v;
+    pub fn validator_ssz_proof(mut self, v: SszProof) -> Self {
+        self.validator_ssz_proof = v;
         self
     }
-    pub fn nth27(mut self, v: Byte) -> Self {
-        self.0[27] = v;
+    pub fn balances_chunk(mut self, v: Hash) -> Self {
+        self.balances_chunk = v;
         self
     }
-    pub fn nth28(mut self, v: Byte) -> Self {
-        self.0[28] = v;
+    pub fn balances_chunk_ssz_proof(mut self, v: SszProof) -> Self {
+        self.balances_chunk_ssz_proof = v;
         self
     }
-    pub fn nth29(mut self, v: Byte) -> Self {
-        self.0[29] = v;
-        self
+}
+impl molecule::prelude::Builder for ValidatorProofBuilder {
+    type Entity = ValidatorProof;
+    const NAME: &'static str = "ValidatorProofBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.header.as_slice().len()
+            + self.header_mmr_proof.as_slice().len()
+            + self.validator_index.as_slice().len()
+            + self.validator.as_slice().len()
+            + self.validator_ssz_proof.as_slice().len()
+            + self.balances_chunk.as_slice().len()
+            + self.balances_chunk_ssz_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.header_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.validator_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.validator.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.validator_ssz_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.balances_chunk.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.balances_chunk_ssz_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.header.as_slice())?;
+        writer.write_all(self.header_mmr_proof.as_slice())?;
+        writer.write_all(self.validator_index.as_slice())?;
+        writer.write_all(self.validator.as_slice())?;
+        writer.write_all(self.validator_ssz_proof.as_slice())?;
+        writer.write_all(self.balances_chunk.as_slice())?;
+        writer.write_all(self.balances_chunk_ssz_proof.as_slice())?;
+        Ok(())
     }
-    pub fn nth30(mut self, v: Byte) -> Self {
-        self.0[30] = v;
-        self
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ValidatorProof::new_unchecked(inner.into())
     }
-    pub fn nth31(mut self, v: Byte) -> Self {
-        self.0[31] = v;
-        self
+}
+#[derive(Clone)]
+pub struct MultiHeaderProof(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MultiHeaderProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth32(mut self, v: Byte) -> Self {
-        self.0[32] = v;
-        self
+}
+impl ::core::fmt::Debug for MultiHeaderProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth33(mut self, v: Byte) -> Self {
-        self.0[33] = v;
-        self
+}
+impl ::core::fmt::Display for MultiHeaderProof {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(f, ", {}: {}", "headers_mmr_proof", self.headers_mmr_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth34(mut self, v: Byte) -> Self {
-        self.0[34] = v;
-        self
+}
+impl ::core::default::Default for MultiHeaderProof {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        MultiHeaderProof::new_unchecked(v)
     }
-    pub fn nth35(mut self, v: Byte) -> Self {
-        self.0[35] = v;
-        self
+}
+impl MultiHeaderProof {
+    const DEFAULT_VALUE: [u8; 20] = [
+        20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth36(mut self, v: Byte) -> Self {
-        self.0[36] = v;
-        self
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth37(mut self, v: Byte) -> Self {
-        self.0[37] = v;
-        self
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth38(mut self, v: Byte) -> Self {
-        self.0[38] = v;
-        self
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth39(mut self, v: Byte) -> Self {
-        self.0[39] = v;
-        self
+    pub fn headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVec::new_unchecked(self.0.slice(start..end))
     }
-    pub fn nth40(mut self, v: Byte) -> Self {
-        self.0[40] = v;
-        self
+    pub fn headers_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            MmrProof::new_unchecked(self.0.slice(start..end))
+        } else {
+            MmrProof::new_unchecked(self.0.slice(start..))
+        }
     }
-    pub fn nth41(mut self, v: Byte) -> Self {
-        self.0[41] = v;
-        self
+    pub fn as_reader<'r>(&'r self) -> MultiHeaderProofReader<'r> {
+        MultiHeaderProofReader::new_unchecked(self.as_slice())
     }
-    pub fn nth42(mut self, v: Byte) -> Self {
-        self.0[42] = v;
-        self
+}
+impl molecule::prelude::Entity for MultiHeaderProof {
+    type Builder = MultiHeaderProofBuilder;
+    const NAME: &'static str = "MultiHeaderProof";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MultiHeaderProof(data)
     }
-    pub fn nth43(mut self, v: Byte) -> Self {
-        self.0[43] = v;
-        self
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
     }
-    pub fn nth44(mut self, v: Byte) -> Self {
-        self.0[44] = v;
-        self
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
     }
-    pub fn nth45(mut self, v: Byte) -> Self {
-        self.0[45] = v;
-        self
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MultiHeaderProofReader::from_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth46(mut self, v: Byte) -> Self {
-        self.0[46] = v;
-        self
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MultiHeaderProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
-    pub fn nth47(mut self, v: Byte) -> Self {
-        self.0[47] = v;
-        self
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
     }
-    pub fn nth48(mut self, v: Byte) -> Self {
-        self.0[48] = v;
-        self
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .headers(self.headers())
+            .headers_mmr_proof(self.headers_mmr_proof())
     }
-    pub fn nth49(mut self, v: Byte) -> Self {
-        self.0[49] = v;
-        self
+}
+#[derive(Clone, Copy)]
+pub struct MultiHeaderProofReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MultiHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn nth50(mut self, v: Byte) -> Self {
-        self.0[50] = v;
-        self
+}
+impl<'r> ::core::fmt::Debug for MultiHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn nth51(mut self, v: Byte) -> Self {
-        self.0[51] = v;
-        self
+}
+impl<'r> ::core::fmt::Display for MultiHeaderProofReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "headers", self.headers())?;
+        write!(f, ", {}: {}", "headers_mmr_proof", self.headers_mmr_proof())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
     }
-    pub fn nth52(mut self, v: Byte) -> Self {
-        self.0[52] = v;
-        self
+}
+impl<'r> MultiHeaderProofReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn nth53(mut self, v: Byte) -> Self {
-        self.0[53] = v;
-        self
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn nth54(mut self, v: Byte) -> Self {
-        self.0[54] = v;
-        self
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn nth55(mut self, v: Byte) -> Self {
-        self.0[55] = v;
-        self
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn nth56(mut self, v: Byte) -> Self {
-        self.0[56] = v;
-        self
+    pub fn headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn nth57(mut self, v: Byte) -> Self {
-        self.0[57] = v;
-        self
+    pub fn headers_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            MmrProofReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn nth58(mut self, v: Byte) -> Self {
-        self.0[58] = v;
-        self
+}
+impl<'r> molecule::prelude::Reader<'r> for MultiHeaderProofReader<'r> {
+    type Entity = MultiHeaderProof;
+    const NAME: &'static str = "MultiHeaderProofReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn nth59(mut self, v: Byte) -> Self {
-        self.0[59] = v;
-        self
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MultiHeaderProofReader(slice)
     }
-    pub fn nth60(mut self, v: Byte) -> Self {
-        self.0[60] = v;
-        self
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn nth61(mut self, v: Byte) -> Self {
-        self.0[61] = v;
-        self
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        HeaderVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
     }
-    pub fn nth62(mut self, v: Byte) -> Self {
-        self.0[62] = v;
+}
+#[derive(Debug, Default)]
+pub struct MultiHeaderProofBuilder {
+    pub(crate) headers: HeaderVec,
+    pub(crate) headers_mmr_proof: MmrProof,
+}
+impl MultiHeaderProofBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn headers(mut self, v: HeaderVec) -> Self {
+        self.headers = v;
         self
     }
-    pub fn nth63(mut self, v: Byte) -> Self {
-        self.0[63] = v;
+    pub fn headers_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.headers_mmr_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for SyncCommitteeBitsBuilder {
-    type Entity = SyncCommitteeBits;
-    const NAME: &'static str = "SyncCommitteeBitsBuilder";
+impl molecule::prelude::Builder for MultiHeaderProofBuilder {
+    type Entity = MultiHeaderProof;
+    const NAME: &'static str = "MultiHeaderProofBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.headers.as_slice().len()
+            + self.headers_mmr_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.0[0].as_slice())?;
-        writer.write_all(self.0[1].as_slice())?;
-        writer.write_all(self.0[2].as_slice())?;
-        writer.write_all(self.0[3].as_slice())?;
-        writer.write_all(self.0[4].as_slice())?;
-        writer.write_all(self.0[5].as_slice())?;
-        writer.write_all(self.0[6].as_slice())?;
-        writer.write_all(self.0[7].as_slice())?;
-        writer.write_all(self.0[8].as_slice())?;
-        writer.write_all(self.0[9].as_slice())?;
-        writer.write_all(self.0[10].as_slice())?;
-        writer.write_all(self.0[11].as_slice())?;
-        writer.write_all(self.0[12].as_slice())?;
-        writer.write_all(self.0[13].as_slice())?;
-        writer.write_all(self.0[14].as_slice())?;
-        writer.write_all(self.0[15].as_slice())?;
-        writer.write_all(self.0[16].as_slice())?;
-        writer.write_all(self.0[17].as_slice())?;
-        writer.write_all(self.0[18].as_slice())?;
-        writer.write_all(self.0[19].as_slice())?;
-        writer.write_all(self.0[20].as_slice())?;
-        writer.write_all(self.0[21].as_slice())?;
-        writer.write_all(self.0[22].as_slice())?;
-        writer.write_all(self.0[23].as_slice())?;
-        writer.write_all(self.0[24].as_slice())?;
-        writer.write_all(self.0[25].as_slice())?;
-        writer.write_all(self.0[26].as_slice())?;
-        writer.write_all(self.0[27].as_slice())?;
-        writer.write_all(self.0[28].as_slice())?;
-        writer.write_all(self.0[29].as_slice())?;
-        writer.write_all(self.0[30].as_slice())?;
-        writer.write_all(self.0[31].as_slice())?;
-        writer.write_all(self.0[32].as_slice())?;
-        writer.write_all(self.0[33].as_slice())?;
-        writer.write_all(self.0[34].as_slice())?;
-        writer.write_all(self.0[35].as_slice())?;
-        writer.write_all(self.0[36].as_slice())?;
-        writer.write_all(self.0[37].as_slice())?;
-        writer.write_all(self.0[38].as_slice())?;
-        writer.write_all(self.0[39].as_slice())?;
-        writer.write_all(self.0[40].as_slice())?;
-        writer.write_all(self.0[41].as_slice())?;
-        writer.write_all(self.0[42].as_slice())?;
-        writer.write_all(self.0[43].as_slice())?;
-        writer.write_all(self.0[44].as_slice())?;
-        writer.write_all(self.0[45].as_slice())?;
-        writer.write_all(self.0[46].as_slice())?;
-        writer.write_all(self.0[47].as_slice())?;
-        writer.write_all(self.0[48].as_slice())?;
-        writer.write_all(self.0[49].as_slice())?;
-        writer.write_all(self.0[50].as_slice())?;
-        writer.write_all(self.0[51].as_slice())?;
-        writer.write_all(self.0[52].as_slice())?;
-        writer.write_all(self.0[53].as_slice())?;
-        writer.write_all(self.0[54].as_slice())?;
-        writer.write_all(self.0[55].as_slice())?;
-        writer.write_all(self.0[56].as_slice())?;
-        writer.write_all(self.0[57].as_slice())?;
-        writer.write_all(self.0[58].as_slice())?;
-        writer.write_all(self.0[59].as_slice())?;
-        writer.write_all(self.0[60].as_slice())?;
-        writer.write_all(self.0[61].as_slice())?;
-        writer.write_all(self.0[62].as_slice())?;
-        writer.write_all(self.0[63].as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.headers_mmr_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.headers.as_slice())?;
+        writer.write_all(self.headers_mmr_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SyncCommitteeBits::new_unchecked(inner.into())
+        MultiHeaderProof::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SyncAggregate(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SyncAggregate {
+pub struct Witness(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Witness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6067,62 +15078,63 @@ impl ::core::fmt::LowerHex for SyncAggregate {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SyncAggregate {
+impl ::core::fmt::Debug for Witness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SyncAggregate {
+impl ::core::fmt::Display for Witness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "sync_committee_bits",
-            self.sync_committee_bits()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "sync_committee_signature",
-            self.sync_committee_signature()
-        )?;
-        write!(f, " }}")
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
     }
 }
-impl ::core::default::Default for SyncAggregate {
+impl ::core::default::Default for Witness {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SyncAggregate::new_unchecked(v)
+        Witness::new_unchecked(v)
     }
 }
-impl SyncAggregate {
-    const DEFAULT_VALUE: [u8; 160] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl Witness {
+    const DEFAULT_VALUE: [u8; 132] = [
+        0, 0, 0, 0, 128, 0, 0, 0, 12, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 160;
-    pub const FIELD_SIZES: [usize; 2] = [64, 96];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn sync_committee_bits(&self) -> SyncCommitteeBits {
-        SyncCommitteeBits::new_unchecked(self.0.slice(0..64))
-    }
-    pub fn sync_committee_signature(&self) -> BlsSignature {
-        BlsSignature::new_unchecked(self.0.slice(64..160))
+
+    pub const ITEMS_COUNT: usize = 11;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> WitnessUnion {
+        let inner = self.0.slice(molecule::NUMBER_SIZE..);
+        match self.item_id() {
+            0 => ClientBootstrap::new_unchecked(inner).into(),
+            1 => ClientUpdate::new_unchecked(inner).into(),
+            2 => ClientOptimisticUpdate::new_unchecked(inner).into(),
+            3 => SyncCommitteeUpdate::new_unchecked(inner).into(),
+            4 => ClientUpdateBatch::new_unchecked(inner).into(),
+            5 => TransactionProof::new_unchecked(inner).into(),
+            6 => TransactionPayload::new_unchecked(inner).into(),
+            7 => HistoricalTransactionProof::new_unchecked(inner).into(),
+            8 => BeaconStateProof::new_unchecked(inner).into(),
+            9 => ValidatorProof::new_unchecked(inner).into(),
+            10 => MultiHeaderProof::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> SyncAggregateReader<'r> {
-        SyncAggregateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> WitnessReader<'r> {
+        WitnessReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SyncAggregate {
-    type Builder = SyncAggregateBuilder;
-    const NAME: &'static str = "SyncAggregate";
+impl molecule::prelude::Entity for Witness {
+    type Builder = WitnessBuilder;
+    const NAME: &'static str = "Witness";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SyncAggregate(data)
+        Witness(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6131,23 +15143,21 @@ impl molecule::prelude::Entity for SyncAggregate {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncAggregateReader::from_slice(slice).map(|reader| reader.to_entity())
+        WitnessReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncAggregateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        WitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .sync_committee_bits(self.sync_committee_bits())
-            .sync_committee_signature(self.sync_committee_signature())
+        Self::new_builder().set(self.to_enum())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SyncAggregateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SyncAggregateReader<'r> {
+pub struct WitnessReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6156,364 +15166,515 @@ impl<'r> ::core::fmt::LowerHex for SyncAggregateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SyncAggregateReader<'r> {
+impl<'r> ::core::fmt::Debug for WitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SyncAggregateReader<'r> {
+impl<'r> ::core::fmt::Display for WitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(
-            f,
-            "{}: {}",
-            "sync_committee_bits",
-            self.sync_committee_bits()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "sync_committee_signature",
-            self.sync_committee_signature()
-        )?;
-        write!(f, " }}")
-    }
-}
-impl<'r> SyncAggregateReader<'r> {
-    pub const TOTAL_SIZE: usize = 160;
-    pub const FIELD_SIZES: [usize; 2] = [64, 96];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn sync_committee_bits(&self) -> SyncCommitteeBitsReader<'r> {
-        SyncCommitteeBitsReader::new_unchecked(&self.as_slice()[0..64])
-    }
-    pub fn sync_committee_signature(&self) -> BlsSignatureReader<'r> {
-        BlsSignatureReader::new_unchecked(&self.as_slice()[64..160])
+        write!(f, "{}(", Self::NAME)?;
+        self.to_enum().display_inner(f)?;
+        write!(f, ")")
+    }
+}
+impl<'r> WitnessReader<'r> {
+    pub const ITEMS_COUNT: usize = 11;
+    pub fn item_id(&self) -> molecule::Number {
+        molecule::unpack_number(self.as_slice())
+    }
+    pub fn to_enum(&self) -> WitnessUnionReader<'r> {
+        let inner = &self.as_slice()[molecule::NUMBER_SIZE..];
+        match self.item_id() {
+            0 => ClientBootstrapReader::new_unchecked(inner).into(),
+            1 => ClientUpdateReader::new_unchecked(inner).into(),
+            2 => ClientOptimisticUpdateReader::new_unchecked(inner).into(),
+            3 => SyncCommitteeUpdateReader::new_unchecked(inner).into(),
+            4 => ClientUpdateBatchReader::new_unchecked(inner).into(),
+            5 => TransactionProofReader::new_unchecked(inner).into(),
+            6 => TransactionPayloadReader::new_unchecked(inner).into(),
+            7 => HistoricalTransactionProofReader::new_unchecked(inner).into(),
+            8 => BeaconStateProofReader::new_unchecked(inner).into(),
+            9 => ValidatorProofReader::new_unchecked(inner).into(),
+            10 => MultiHeaderProofReader::new_unchecked(inner).into(),
+            _ => panic!("{}: invalid data", Self::NAME),
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SyncAggregateReader<'r> {
-    type Entity = SyncAggregate;
-    const NAME: &'static str = "SyncAggregateReader";
+impl<'r> molecule::prelude::Reader<'r> for WitnessReader<'r> {
+    type Entity = Witness;
+    const NAME: &'static str = "WitnessReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SyncAggregateReader(slice)
+        WitnessReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
+        let item_id = molecule::unpack_number(slice);
+        let inner_slice = &slice[molecule::NUMBER_SIZE..];
+        match item_id {
+            0 => ClientBootstrapReader::verify(inner_slice, compatible),
+            1 => ClientUpdateReader::verify(inner_slice, compatible),
+            2 => ClientOptimisticUpdateReader::verify(inner_slice, compatible),
+            3 => SyncCommitteeUpdateReader::verify(inner_slice, compatible),
+            4 => ClientUpdateBatchReader::verify(inner_slice, compatible),
+            5 => TransactionProofReader::verify(inner_slice, compatible),
+            6 => TransactionPayloadReader::verify(inner_slice, compatible),
+            7 => HistoricalTransactionProofReader::verify(inner_slice, compatible),
+            8 => BeaconStateProofReader::verify(inner_slice, compatible),
+            9 => ValidatorProofReader::verify(inner_slice, compatible),
+            10 => MultiHeaderProofReader::verify(inner_slice, compatible),
+            _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+        }?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SyncAggregateBuilder {
-    pub(crate) sync_committee_bits: SyncCommitteeBits,
-    pub(crate) sync_committee_signature: BlsSignature,
-}
-impl SyncAggregateBuilder {
-    pub const TOTAL_SIZE: usize = 160;
-    pub const FIELD_SIZES: [usize; 2] = [64, 96];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn sync_committee_bits(mut self, v: SyncCommitteeBits) -> Self {
-        self.sync_committee_bits = v;
-        self
-    }
-    pub fn sync_committee_signature(mut self, v: BlsSignature) -> Self {
-        self.sync_committee_signature = v;
+pub struct WitnessBuilder(pub(crate) WitnessUnion);
+impl WitnessBuilder {
+    pub const ITEMS_COUNT: usize = 11;
+    pub fn set<I>(mut self, v: I) -> Self
+    where
+        I: ::core::convert::Into<WitnessUnion>,
+    {
+        self.0 = v.into();
         self
     }
 }
-impl molecule::prelude::Builder for SyncAggregateBuilder {
-    type Entity = SyncAggregate;
-    const NAME: &'static str = "SyncAggregateBuilder";
+impl molecule::prelude::Builder for WitnessBuilder {
+    type Entity = Witness;
+    const NAME: &'static str = "WitnessBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE + self.0.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.sync_committee_bits.as_slice())?;
-        writer.write_all(self.sync_committee_signature.as_slice())?;
-        Ok(())
+        writer.write_all(&molecule::pack_number(self.0.item_id()))?;
+        writer.write_all(self.0.as_slice())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SyncAggregate::new_unchecked(inner.into())
+        Witness::new_unchecked(inner.into())
+    }
+}
+#[derive(Debug, Clone)]
+pub enum WitnessUnion {
+    ClientBootstrap(ClientBootstrap),
+    ClientUpdate(ClientUpdate),
+    ClientOptimisticUpdate(ClientOptimisticUpdate),
+    SyncCommitteeUpdate(SyncCommitteeUpdate),
+    ClientUpdateBatch(ClientUpdateBatch),
+    TransactionProof(TransactionProof),
+    TransactionPayload(TransactionPayload),
+    HistoricalTransactionProof(HistoricalTransactionProof),
+    BeaconStateProof(BeaconStateProof),
+    ValidatorProof(ValidatorProof),
+    MultiHeaderProof(MultiHeaderProof),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum WitnessUnionReader<'r> {
+    ClientBootstrap(ClientBootstrapReader<'r>),
+    ClientUpdate(ClientUpdateReader<'r>),
+    ClientOptimisticUpdate(ClientOptimisticUpdateReader<'r>),
+    SyncCommitteeUpdate(SyncCommitteeUpdateReader<'r>),
+    ClientUpdateBatch(ClientUpdateBatchReader<'r>),
+    TransactionProof(TransactionProofReader<'r>),
+    TransactionPayload(TransactionPayloadReader<'r>),
+    HistoricalTransactionProof(HistoricalTransactionProofReader<'r>),
+    BeaconStateProof(BeaconStateProofReader<'r>),
+    ValidatorProof(ValidatorProofReader<'r>),
+    MultiHeaderProof(MultiHeaderProofReader<'r>),
+}
+impl ::core::default::Default for WitnessUnion {
+    fn default() -> Self {
+        WitnessUnion::ClientBootstrap(::core::default::Default::default())
     }
 }
-#[derive(Clone)]
-pub struct SyncCommittee(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SyncCommittee {
+impl ::core::fmt::Display for WitnessUnion {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
+        match self {
+            WitnessUnion::ClientBootstrap(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientBootstrap::NAME, item)
+            }
+            WitnessUnion::ClientUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdate::NAME, item)
+            }
+            WitnessUnion::ClientOptimisticUpdate(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ClientOptimisticUpdate::NAME,
+                    item
+                )
+            }
+            WitnessUnion::SyncCommitteeUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SyncCommitteeUpdate::NAME, item)
+            }
+            WitnessUnion::ClientUpdateBatch(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdateBatch::NAME, item)
+            }
+            WitnessUnion::TransactionProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransactionProof::NAME, item)
+            }
+            WitnessUnion::TransactionPayload(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransactionPayload::NAME, item)
+            }
+            WitnessUnion::HistoricalTransactionProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    HistoricalTransactionProof::NAME,
+                    item
+                )
+            }
+            WitnessUnion::BeaconStateProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BeaconStateProof::NAME, item)
+            }
+            WitnessUnion::ValidatorProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ValidatorProof::NAME, item)
+            }
+            WitnessUnion::MultiHeaderProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, MultiHeaderProof::NAME, item)
+            }
         }
-        write!(f, "{}", hex_string(self.as_slice()))
-    }
-}
-impl ::core::fmt::Debug for SyncCommittee {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SyncCommittee {
+impl<'r> ::core::fmt::Display for WitnessUnionReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
-        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl ::core::default::Default for SyncCommittee {
-    fn default() -> Self {
-        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SyncCommittee::new_unchecked(v)
-    }
-}
-impl SyncCommittee {
-    const DEFAULT_VALUE: [u8; 64] = [
-        64, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0,
-    ];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        match self {
+            WitnessUnionReader::ClientBootstrap(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientBootstrap::NAME, item)
+            }
+            WitnessUnionReader::ClientUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdate::NAME, item)
+            }
+            WitnessUnionReader::ClientOptimisticUpdate(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    ClientOptimisticUpdate::NAME,
+                    item
+                )
+            }
+            WitnessUnionReader::SyncCommitteeUpdate(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, SyncCommitteeUpdate::NAME, item)
+            }
+            WitnessUnionReader::ClientUpdateBatch(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ClientUpdateBatch::NAME, item)
+            }
+            WitnessUnionReader::TransactionProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransactionProof::NAME, item)
+            }
+            WitnessUnionReader::TransactionPayload(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, TransactionPayload::NAME, item)
+            }
+            WitnessUnionReader::HistoricalTransactionProof(ref item) => {
+                write!(
+                    f,
+                    "{}::{}({})",
+                    Self::NAME,
+                    HistoricalTransactionProof::NAME,
+                    item
+                )
+            }
+            WitnessUnionReader::BeaconStateProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, BeaconStateProof::NAME, item)
+            }
+            WitnessUnionReader::ValidatorProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, ValidatorProof::NAME, item)
+            }
+            WitnessUnionReader::MultiHeaderProof(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, MultiHeaderProof::NAME, item)
+            }
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+}
+impl WitnessUnion {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WitnessUnion::ClientBootstrap(ref item) => write!(f, "{}", item),
+            WitnessUnion::ClientUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnion::ClientOptimisticUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnion::SyncCommitteeUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnion::ClientUpdateBatch(ref item) => write!(f, "{}", item),
+            WitnessUnion::TransactionProof(ref item) => write!(f, "{}", item),
+            WitnessUnion::TransactionPayload(ref item) => write!(f, "{}", item),
+            WitnessUnion::HistoricalTransactionProof(ref item) => write!(f, "{}", item),
+            WitnessUnion::BeaconStateProof(ref item) => write!(f, "{}", item),
+            WitnessUnion::ValidatorProof(ref item) => write!(f, "{}", item),
+            WitnessUnion::MultiHeaderProof(ref item) => write!(f, "{}", item),
+        }
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+}
+impl<'r> WitnessUnionReader<'r> {
+    pub(crate) fn display_inner(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            WitnessUnionReader::ClientBootstrap(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::ClientUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::ClientOptimisticUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::SyncCommitteeUpdate(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::ClientUpdateBatch(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::TransactionProof(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::TransactionPayload(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::HistoricalTransactionProof(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::BeaconStateProof(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::ValidatorProof(ref item) => write!(f, "{}", item),
+            WitnessUnionReader::MultiHeaderProof(ref item) => write!(f, "{}", item),
+        }
     }
-    pub fn pubkeys(&self) -> BlsPubkeyVec {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        BlsPubkeyVec::new_unchecked(self.0.slice(start..end))
+}
+impl ::core::convert::From<ClientBootstrap> for WitnessUnion {
+    fn from(item: ClientBootstrap) -> Self {
+        WitnessUnion::ClientBootstrap(item)
     }
-    pub fn aggregate_pubkey(&self) -> BlsPubkey {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BlsPubkey::new_unchecked(self.0.slice(start..end))
-        } else {
-            BlsPubkey::new_unchecked(self.0.slice(start..))
-        }
+}
+impl ::core::convert::From<ClientUpdate> for WitnessUnion {
+    fn from(item: ClientUpdate) -> Self {
+        WitnessUnion::ClientUpdate(item)
     }
-    pub fn as_reader<'r>(&'r self) -> SyncCommitteeReader<'r> {
-        SyncCommitteeReader::new_unchecked(self.as_slice())
+}
+impl ::core::convert::From<ClientOptimisticUpdate> for WitnessUnion {
+    fn from(item: ClientOptimisticUpdate) -> Self {
+        WitnessUnion::ClientOptimisticUpdate(item)
     }
 }
-impl molecule::prelude::Entity for SyncCommittee {
-    type Builder = SyncCommitteeBuilder;
-    const NAME: &'static str = "SyncCommittee";
-    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SyncCommittee(data)
+impl ::core::convert::From<SyncCommitteeUpdate> for WitnessUnion {
+    fn from(item: SyncCommitteeUpdate) -> Self {
+        WitnessUnion::SyncCommitteeUpdate(item)
     }
-    fn as_bytes(&self) -> molecule::bytes::Bytes {
-        self.0.clone()
+}
+impl ::core::convert::From<ClientUpdateBatch> for WitnessUnion {
+    fn from(item: ClientUpdateBatch) -> Self {
+        WitnessUnion::ClientUpdateBatch(item)
     }
-    fn as_slice(&self) -> &[u8] {
-        &self.0[..]
+}
+impl ::core::convert::From<TransactionProof> for WitnessUnion {
+    fn from(item: TransactionProof) -> Self {
+        WitnessUnion::TransactionProof(item)
     }
-    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
+}
+impl ::core::convert::From<TransactionPayload> for WitnessUnion {
+    fn from(item: TransactionPayload) -> Self {
+        WitnessUnion::TransactionPayload(item)
     }
-    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+}
+impl ::core::convert::From<HistoricalTransactionProof> for WitnessUnion {
+    fn from(item: HistoricalTransactionProof) -> Self {
+        WitnessUnion::HistoricalTransactionProof(item)
     }
-    fn new_builder() -> Self::Builder {
-        ::core::default::Default::default()
+}
+impl ::core::convert::From<BeaconStateProof> for WitnessUnion {
+    fn from(item: BeaconStateProof) -> Self {
+        WitnessUnion::BeaconStateProof(item)
     }
-    fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .pubkeys(self.pubkeys())
-            .aggregate_pubkey(self.aggregate_pubkey())
+}
+impl ::core::convert::From<ValidatorProof> for WitnessUnion {
+    fn from(item: ValidatorProof) -> Self {
+        WitnessUnion::ValidatorProof(item)
     }
 }
-#[derive(Clone, Copy)]
-pub struct SyncCommitteeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SyncCommitteeReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        use molecule::hex_string;
-        if f.alternate() {
-            write!(f, "0x")?;
-        }
-        write!(f, "{}", hex_string(self.as_slice()))
+impl ::core::convert::From<MultiHeaderProof> for WitnessUnion {
+    fn from(item: MultiHeaderProof) -> Self {
+        WitnessUnion::MultiHeaderProof(item)
     }
 }
-impl<'r> ::core::fmt::Debug for SyncCommitteeReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{}({:#x})", Self::NAME, self)
+impl<'r> ::core::convert::From<ClientBootstrapReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: ClientBootstrapReader<'r>) -> Self {
+        WitnessUnionReader::ClientBootstrap(item)
     }
 }
-impl<'r> ::core::fmt::Display for SyncCommitteeReader<'r> {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "pubkeys", self.pubkeys())?;
-        write!(f, ", {}: {}", "aggregate_pubkey", self.aggregate_pubkey())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
+impl<'r> ::core::convert::From<ClientUpdateReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: ClientUpdateReader<'r>) -> Self {
+        WitnessUnionReader::ClientUpdate(item)
     }
 }
-impl<'r> SyncCommitteeReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> ::core::convert::From<ClientOptimisticUpdateReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: ClientOptimisticUpdateReader<'r>) -> Self {
+        WitnessUnionReader::ClientOptimisticUpdate(item)
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+}
+impl<'r> ::core::convert::From<SyncCommitteeUpdateReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: SyncCommitteeUpdateReader<'r>) -> Self {
+        WitnessUnionReader::SyncCommitteeUpdate(item)
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+}
+impl<'r> ::core::convert::From<ClientUpdateBatchReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: ClientUpdateBatchReader<'r>) -> Self {
+        WitnessUnionReader::ClientUpdateBatch(item)
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+}
+impl<'r> ::core::convert::From<TransactionProofReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: TransactionProofReader<'r>) -> Self {
+        WitnessUnionReader::TransactionProof(item)
     }
-    pub fn pubkeys(&self) -> BlsPubkeyVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        BlsPubkeyVecReader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> ::core::convert::From<TransactionPayloadReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: TransactionPayloadReader<'r>) -> Self {
+        WitnessUnionReader::TransactionPayload(item)
     }
-    pub fn aggregate_pubkey(&self) -> BlsPubkeyReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            BlsPubkeyReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            BlsPubkeyReader::new_unchecked(&self.as_slice()[start..])
-        }
+}
+impl<'r> ::core::convert::From<HistoricalTransactionProofReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: HistoricalTransactionProofReader<'r>) -> Self {
+        WitnessUnionReader::HistoricalTransactionProof(item)
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeReader<'r> {
-    type Entity = SyncCommittee;
-    const NAME: &'static str = "SyncCommitteeReader";
-    fn to_entity(&self) -> Self::Entity {
-        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+impl<'r> ::core::convert::From<BeaconStateProofReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: BeaconStateProofReader<'r>) -> Self {
+        WitnessUnionReader::BeaconStateProof(item)
     }
-    fn new_unchecked(slice: &'r [u8]) -> Self {
-        SyncCommitteeReader(slice)
+}
+impl<'r> ::core::convert::From<ValidatorProofReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: ValidatorProofReader<'r>) -> Self {
+        WitnessUnionReader::ValidatorProof(item)
     }
-    fn as_slice(&self) -> &'r [u8] {
-        self.0
+}
+impl<'r> ::core::convert::From<MultiHeaderProofReader<'r>> for WitnessUnionReader<'r> {
+    fn from(item: MultiHeaderProofReader<'r>) -> Self {
+        WitnessUnionReader::MultiHeaderProof(item)
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
+}
+impl WitnessUnion {
+    pub const NAME: &'static str = "WitnessUnion";
+    pub fn as_bytes(&self) -> molecule::bytes::Bytes {
+        match self {
+            WitnessUnion::ClientBootstrap(item) => item.as_bytes(),
+            WitnessUnion::ClientUpdate(item) => item.as_bytes(),
+            WitnessUnion::ClientOptimisticUpdate(item) => item.as_bytes(),
+            WitnessUnion::SyncCommitteeUpdate(item) => item.as_bytes(),
+            WitnessUnion::ClientUpdateBatch(item) => item.as_bytes(),
+            WitnessUnion::TransactionProof(item) => item.as_bytes(),
+            WitnessUnion::TransactionPayload(item) => item.as_bytes(),
+            WitnessUnion::HistoricalTransactionProof(item) => item.as_bytes(),
+            WitnessUnion::BeaconStateProof(item) => item.as_bytes(),
+            WitnessUnion::ValidatorProof(item) => item.as_bytes(),
+            WitnessUnion::MultiHeaderProof(item) => item.as_bytes(),
         }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+    }
+    pub fn as_slice(&self) -> &[u8] {
+        match self {
+            WitnessUnion::ClientBootstrap(item) => item.as_slice(),
+            WitnessUnion::ClientUpdate(item) => item.as_slice(),
+            WitnessUnion::ClientOptimisticUpdate(item) => item.as_slice(),
+            WitnessUnion::SyncCommitteeUpdate(item) => item.as_slice(),
+            WitnessUnion::ClientUpdateBatch(item) => item.as_slice(),
+            WitnessUnion::TransactionProof(item) => item.as_slice(),
+            WitnessUnion::TransactionPayload(item) => item.as_slice(),
+            WitnessUnion::HistoricalTransactionProof(item) => item.as_slice(),
+            WitnessUnion::BeaconStateProof(item) => item.as_slice(),
+            WitnessUnion::ValidatorProof(item) => item.as_slice(),
+            WitnessUnion::MultiHeaderProof(item) => item.as_slice(),
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+    }
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            WitnessUnion::ClientBootstrap(_) => 0,
+            WitnessUnion::ClientUpdate(_) => 1,
+            WitnessUnion::ClientOptimisticUpdate(_) => 2,
+            WitnessUnion::SyncCommitteeUpdate(_) => 3,
+            WitnessUnion::ClientUpdateBatch(_) => 4,
+            WitnessUnion::TransactionProof(_) => 5,
+            WitnessUnion::TransactionPayload(_) => 6,
+            WitnessUnion::HistoricalTransactionProof(_) => 7,
+            WitnessUnion::BeaconStateProof(_) => 8,
+            WitnessUnion::ValidatorProof(_) => 9,
+            WitnessUnion::MultiHeaderProof(_) => 10,
         }
-        BlsPubkeyVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BlsPubkeyReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Ok(())
     }
-}
-#[derive(Debug, Default)]
-pub struct SyncCommitteeBuilder {
-    pub(crate) pubkeys: BlsPubkeyVec,
-    pub(crate) aggregate_pubkey: BlsPubkey,
-}
-impl SyncCommitteeBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn pubkeys(mut self, v: BlsPubkeyVec) -> Self {
-        self.pubkeys = v;
-        self
+    pub fn item_name(&self) -> &str {
+        match self {
+            WitnessUnion::ClientBootstrap(_) => "ClientBootstrap",
+            WitnessUnion::ClientUpdate(_) => "ClientUpdate",
+            WitnessUnion::ClientOptimisticUpdate(_) => "ClientOptimisticUpdate",
+            WitnessUnion::SyncCommitteeUpdate(_) => "SyncCommitteeUpdate",
+            WitnessUnion::ClientUpdateBatch(_) => "ClientUpdateBatch",
+            WitnessUnion::TransactionProof(_) => "TransactionProof",
+            WitnessUnion::TransactionPayload(_) => "TransactionPayload",
+            WitnessUnion::HistoricalTransactionProof(_) => "HistoricalTransactionProof",
+            WitnessUnion::BeaconStateProof(_) => "BeaconStateProof",
+            WitnessUnion::ValidatorProof(_) => "ValidatorProof",
+            WitnessUnion::MultiHeaderProof(_) => "MultiHeaderProof",
+        }
     }
-    pub fn aggregate_pubkey(mut self, v: BlsPubkey) -> Self {
-        self.aggregate_pubkey = v;
-        self
+    pub fn as_reader<'r>(&'r self) -> WitnessUnionReader<'r> {
+        match self {
+            WitnessUnion::ClientBootstrap(item) => item.as_reader().into(),
+            WitnessUnion::ClientUpdate(item) => item.as_reader().into(),
+            WitnessUnion::ClientOptimisticUpdate(item) => item.as_reader().into(),
+            WitnessUnion::SyncCommitteeUpdate(item) => item.as_reader().into(),
+            WitnessUnion::ClientUpdateBatch(item) => item.as_reader().into(),
+            WitnessUnion::TransactionProof(item) => item.as_reader().into(),
+            WitnessUnion::TransactionPayload(item) => item.as_reader().into(),
+            WitnessUnion::HistoricalTransactionProof(item) => item.as_reader().into(),
+            WitnessUnion::BeaconStateProof(item) => item.as_reader().into(),
+            WitnessUnion::ValidatorProof(item) => item.as_reader().into(),
+            WitnessUnion::MultiHeaderProof(item) => item.as_reader().into(),
+        }
     }
 }
-impl molecule::prelude::Builder for SyncCommitteeBuilder {
-    type Entity = SyncCommittee;
-    const NAME: &'static str = "SyncCommitteeBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.pubkeys.as_slice().len()
-            + self.aggregate_pubkey.as_slice().len()
+impl<'r> WitnessUnionReader<'r> {
+    pub const NAME: &'r str = "WitnessUnionReader";
+    pub fn as_slice(&self) -> &'r [u8] {
+        match self {
+            WitnessUnionReader::ClientBootstrap(item) => item.as_slice(),
+            WitnessUnionReader::ClientUpdate(item) => item.as_slice(),
+            WitnessUnionReader::ClientOptimisticUpdate(item) => item.as_slice(),
+            WitnessUnionReader::SyncCommitteeUpdate(item) => item.as_slice(),
+            WitnessUnionReader::ClientUpdateBatch(item) => item.as_slice(),
+            WitnessUnionReader::TransactionProof(item) => item.as_slice(),
+            WitnessUnionReader::TransactionPayload(item) => item.as_slice(),
+            WitnessUnionReader::HistoricalTransactionProof(item) => item.as_slice(),
+            WitnessUnionReader::BeaconStateProof(item) => item.as_slice(),
+            WitnessUnionReader::ValidatorProof(item) => item.as_slice(),
+            WitnessUnionReader::MultiHeaderProof(item) => item.as_slice(),
+        }
     }
-    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.pubkeys.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.aggregate_pubkey.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+    pub fn item_id(&self) -> molecule::Number {
+        match self {
+            WitnessUnionReader::ClientBootstrap(_) => 0,
+            WitnessUnionReader::ClientUpdate(_) => 1,
+            WitnessUnionReader::ClientOptimisticUpdate(_) => 2,
+            WitnessUnionReader::SyncCommitteeUpdate(_) => 3,
+            WitnessUnionReader::ClientUpdateBatch(_) => 4,
+            WitnessUnionReader::TransactionProof(_) => 5,
+            WitnessUnionReader::TransactionPayload(_) => 6,
+            WitnessUnionReader::HistoricalTransactionProof(_) => 7,
+            WitnessUnionReader::BeaconStateProof(_) => 8,
+            WitnessUnionReader::ValidatorProof(_) => 9,
+            WitnessUnionReader::MultiHeaderProof(_) => 10,
         }
-        writer.write_all(self.pubkeys.as_slice())?;
-        writer.write_all(self.aggregate_pubkey.as_slice())?;
-        Ok(())
     }
-    fn build(&self) -> Self::Entity {
-        let mut inner = Vec::with_capacity(self.expected_length());
-        self.write(&mut inner)
-            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SyncCommittee::new_unchecked(inner.into())
+    pub fn item_name(&self) -> &str {
+        match self {
+            WitnessUnionReader::ClientBootstrap(_) => "ClientBootstrap",
+            WitnessUnionReader::ClientUpdate(_) => "ClientUpdate",
+            WitnessUnionReader::ClientOptimisticUpdate(_) => "ClientOptimisticUpdate",
+            WitnessUnionReader::SyncCommitteeUpdate(_) => "SyncCommitteeUpdate",
+            WitnessUnionReader::ClientUpdateBatch(_) => "ClientUpdateBatch",
+            WitnessUnionReader::TransactionProof(_) => "TransactionProof",
+            WitnessUnionReader::TransactionPayload(_) => "TransactionPayload",
+            WitnessUnionReader::HistoricalTransactionProof(_) => "HistoricalTransactionProof",
+            WitnessUnionReader::BeaconStateProof(_) => "BeaconStateProof",
+            WitnessUnionReader::ValidatorProof(_) => "ValidatorProof",
+            WitnessUnionReader::MultiHeaderProof(_) => "MultiHeaderProof",
+        }
     }
 }
 #[derive(Clone)]
-pub struct ClientBootstrap(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClientBootstrap {
+pub struct WitnessEnvelope(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WitnessEnvelope {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6522,21 +15683,16 @@ impl ::core::fmt::LowerHex for ClientBootstrap {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClientBootstrap {
+impl ::core::fmt::Debug for WitnessEnvelope {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClientBootstrap {
+impl ::core::fmt::Display for WitnessEnvelope {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(
-            f,
-            ", {}: {}",
-            "current_sync_committee_branch",
-            self.current_sync_committee_branch()
-        )?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "witness", self.witness())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6544,19 +15700,19 @@ impl ::core::fmt::Display for ClientBootstrap {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClientBootstrap {
+impl ::core::default::Default for WitnessEnvelope {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClientBootstrap::new_unchecked(v)
+        WitnessEnvelope::new_unchecked(v)
     }
 }
-impl ClientBootstrap {
-    const DEFAULT_VALUE: [u8; 128] = [
-        128, 0, 0, 0, 12, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl WitnessEnvelope {
+    const DEFAULT_VALUE: [u8; 145] = [
+        145, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 12, 0, 0, 0, 124, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
@@ -6575,31 +15731,31 @@ impl ClientBootstrap {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> Header {
+    pub fn version(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Header::new_unchecked(self.0.slice(start..end))
+        Byte::new_unchecked(self.0.slice(start..end))
     }
-    pub fn current_sync_committee_branch(&self) -> SszProof {
+    pub fn witness(&self) -> Witness {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            SszProof::new_unchecked(self.0.slice(start..end))
+            Witness::new_unchecked(self.0.slice(start..end))
         } else {
-            SszProof::new_unchecked(self.0.slice(start..))
+            Witness::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ClientBootstrapReader<'r> {
-        ClientBootstrapReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> WitnessEnvelopeReader<'r> {
+        WitnessEnvelopeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClientBootstrap {
-    type Builder = ClientBootstrapBuilder;
-    const NAME: &'static str = "ClientBootstrap";
+impl molecule::prelude::Entity for WitnessEnvelope {
+    type Builder = WitnessEnvelopeBuilder;
+    const NAME: &'static str = "WitnessEnvelope";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClientBootstrap(data)
+        WitnessEnvelope(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6608,23 +15764,23 @@ impl molecule::prelude::Entity for ClientBootstrap {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientBootstrapReader::from_slice(slice).map(|reader| reader.to_entity())
+        WitnessEnvelopeReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientBootstrapReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        WitnessEnvelopeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .header(self.header())
-            .current_sync_committee_branch(self.current_sync_committee_branch())
+            .version(self.version())
+            .witness(self.witness())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientBootstrapReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientBootstrapReader<'r> {
+pub struct WitnessEnvelopeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WitnessEnvelopeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6633,21 +15789,16 @@ impl<'r> ::core::fmt::LowerHex for ClientBootstrapReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientBootstrapReader<'r> {
+impl<'r> ::core::fmt::Debug for WitnessEnvelopeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientBootstrapReader<'r> {
+impl<'r> ::core::fmt::Display for WitnessEnvelopeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(
-            f,
-            ", {}: {}",
-            "current_sync_committee_branch",
-            self.current_sync_committee_branch()
-        )?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "witness", self.witness())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6655,7 +15806,7 @@ impl<'r> ::core::fmt::Display for ClientBootstrapReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ClientBootstrapReader<'r> {
+impl<'r> WitnessEnvelopeReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -6673,31 +15824,31 @@ impl<'r> ClientBootstrapReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> HeaderReader<'r> {
+    pub fn version(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn current_sync_committee_branch(&self) -> SszProofReader<'r> {
+    pub fn witness(&self) -> WitnessReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+            WitnessReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            SszProofReader::new_unchecked(&self.as_slice()[start..])
+            WitnessReader::new_unchecked(&self.as_slice()[start..])
         }
     }
-}
-impl<'r> molecule::prelude::Reader<'r> for ClientBootstrapReader<'r> {
-    type Entity = ClientBootstrap;
-    const NAME: &'static str = "ClientBootstrapReader";
+}
+impl<'r> molecule::prelude::Reader<'r> for WitnessEnvelopeReader<'r> {
+    type Entity = WitnessEnvelope;
+    const NAME: &'static str = "WitnessEnvelopeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientBootstrapReader(slice)
+        WitnessEnvelopeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -6736,60 +15887,60 @@ impl<'r> molecule::prelude::Reader<'r> for ClientBootstrapReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        WitnessReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientBootstrapBuilder {
-    pub(crate) header: Header,
-    pub(crate) current_sync_committee_branch: SszProof,
+pub struct WitnessEnvelopeBuilder {
+    pub(crate) version: Byte,
+    pub(crate) witness: Witness,
 }
-impl ClientBootstrapBuilder {
+impl WitnessEnvelopeBuilder {
     pub const FIELD_COUNT: usize = 2;
-    pub fn header(mut self, v: Header) -> Self {
-        self.header = v;
+    pub fn version(mut self, v: Byte) -> Self {
+        self.version = v;
         self
     }
-    pub fn current_sync_committee_branch(mut self, v: SszProof) -> Self {
-        self.current_sync_committee_branch = v;
+    pub fn witness(mut self, v: Witness) -> Self {
+        self.witness = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientBootstrapBuilder {
-    type Entity = ClientBootstrap;
-    const NAME: &'static str = "ClientBootstrapBuilder";
+impl molecule::prelude::Builder for WitnessEnvelopeBuilder {
+    type Entity = WitnessEnvelope;
+    const NAME: &'static str = "WitnessEnvelopeBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.header.as_slice().len()
-            + self.current_sync_committee_branch.as_slice().len()
+            + self.version.as_slice().len()
+            + self.witness.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.header.as_slice().len();
+        total_size += self.version.as_slice().len();
         offsets.push(total_size);
-        total_size += self.current_sync_committee_branch.as_slice().len();
+        total_size += self.witness.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.header.as_slice())?;
-        writer.write_all(self.current_sync_committee_branch.as_slice())?;
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.witness.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClientBootstrap::new_unchecked(inner.into())
+        WitnessEnvelope::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ClientUpdate(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClientUpdate {
+pub struct ClientInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6798,118 +15949,71 @@ impl ::core::fmt::LowerHex for ClientUpdate {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClientUpdate {
+impl ::core::fmt::Debug for ClientInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClientUpdate {
+impl ::core::fmt::Display for ClientInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "attested_header", self.attested_header())?;
-        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
-        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
-        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
         write!(
             f,
             ", {}: {}",
-            "new_headers_mmr_proof",
-            self.new_headers_mmr_proof()
+            "minimal_headers_count",
+            self.minimal_headers_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "genesis_validators_root",
+            self.genesis_validators_root()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "force_update_timeout",
+            self.force_update_timeout()
         )?;
-        write!(f, ", {}: {}", "headers", self.headers())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClientUpdate {
+impl ::core::default::Default for ClientInfo {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClientUpdate::new_unchecked(v)
+        ClientInfo::new_unchecked(v)
     }
 }
-impl ClientUpdate {
-    const DEFAULT_VALUE: [u8; 320] = [
-        64, 1, 0, 0, 28, 0, 0, 0, 140, 0, 0, 0, 144, 0, 0, 0, 48, 1, 0, 0, 56, 1, 0, 0, 60, 1, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ClientInfo {
+    const DEFAULT_VALUE: [u8; 42] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn attested_header(&self) -> Header {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Header::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn finality_branch(&self) -> SszProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        SszProof::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn sync_aggregate(&self) -> SyncAggregate {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    pub const TOTAL_SIZE: usize = 42;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn last_client_id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn signature_slot(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+    pub fn minimal_headers_count(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
     }
-    pub fn new_headers_mmr_proof(&self) -> MmrProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        MmrProof::new_unchecked(self.0.slice(start..end))
+    pub fn genesis_validators_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(2..34))
     }
-    pub fn headers(&self) -> HeaderVec {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[28..]) as usize;
-            HeaderVec::new_unchecked(self.0.slice(start..end))
-        } else {
-            HeaderVec::new_unchecked(self.0.slice(start..))
-        }
+    pub fn force_update_timeout(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(34..42))
     }
-    pub fn as_reader<'r>(&'r self) -> ClientUpdateReader<'r> {
-        ClientUpdateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientInfoReader<'r> {
+        ClientInfoReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClientUpdate {
-    type Builder = ClientUpdateBuilder;
-    const NAME: &'static str = "ClientUpdate";
+impl molecule::prelude::Entity for ClientInfo {
+    type Builder = ClientInfoBuilder;
+    const NAME: &'static str = "ClientInfo";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClientUpdate(data)
+        ClientInfo(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -6918,27 +16022,25 @@ impl molecule::prelude::Entity for ClientUpdate {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientInfoReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .attested_header(self.attested_header())
-            .finality_branch(self.finality_branch())
-            .sync_aggregate(self.sync_aggregate())
-            .signature_slot(self.signature_slot())
-            .new_headers_mmr_proof(self.new_headers_mmr_proof())
-            .headers(self.headers())
+            .last_client_id(self.last_client_id())
+            .minimal_headers_count(self.minimal_headers_count())
+            .genesis_validators_root(self.genesis_validators_root())
+            .force_update_timeout(self.force_update_timeout())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientUpdateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientUpdateReader<'r> {
+pub struct ClientInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -6947,231 +16049,125 @@ impl<'r> ::core::fmt::LowerHex for ClientUpdateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientUpdateReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientUpdateReader<'r> {
+impl<'r> ::core::fmt::Display for ClientInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "attested_header", self.attested_header())?;
-        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
-        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
-        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
         write!(
             f,
             ", {}: {}",
-            "new_headers_mmr_proof",
-            self.new_headers_mmr_proof()
+            "minimal_headers_count",
+            self.minimal_headers_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "genesis_validators_root",
+            self.genesis_validators_root()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "force_update_timeout",
+            self.force_update_timeout()
         )?;
-        write!(f, ", {}: {}", "headers", self.headers())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
         write!(f, " }}")
     }
 }
-impl<'r> ClientUpdateReader<'r> {
-    pub const FIELD_COUNT: usize = 6;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn attested_header(&self) -> HeaderReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn finality_branch(&self) -> SszProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        SszProofReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+impl<'r> ClientInfoReader<'r> {
+    pub const TOTAL_SIZE: usize = 42;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn last_client_id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn signature_slot(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn minimal_headers_count(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
     }
-    pub fn new_headers_mmr_proof(&self) -> MmrProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn genesis_validators_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[2..34])
     }
-    pub fn headers(&self) -> HeaderVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[28..]) as usize;
-            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn force_update_timeout(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[34..42])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClientUpdateReader<'r> {
-    type Entity = ClientUpdate;
-    const NAME: &'static str = "ClientUpdateReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientInfoReader<'r> {
+    type Entity = ClientInfo;
+    const NAME: &'static str = "ClientInfoReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientUpdateReader(slice)
+        ClientInfoReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        SyncAggregateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        MmrProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        HeaderVecReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientUpdateBuilder {
-    pub(crate) attested_header: Header,
-    pub(crate) finality_branch: SszProof,
-    pub(crate) sync_aggregate: SyncAggregate,
-    pub(crate) signature_slot: Uint64,
-    pub(crate) new_headers_mmr_proof: MmrProof,
-    pub(crate) headers: HeaderVec,
+pub struct ClientInfoBuilder {
+    pub(crate) last_client_id: Byte,
+    pub(crate) minimal_headers_count: Byte,
+    pub(crate) genesis_validators_root: Hash,
+    pub(crate) force_update_timeout: Uint64,
 }
-impl ClientUpdateBuilder {
-    pub const FIELD_COUNT: usize = 6;
-    pub fn attested_header(mut self, v: Header) -> Self {
-        self.attested_header = v;
-        self
-    }
-    pub fn finality_branch(mut self, v: SszProof) -> Self {
-        self.finality_branch = v;
-        self
-    }
-    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
-        self.sync_aggregate = v;
+impl ClientInfoBuilder {
+    pub const TOTAL_SIZE: usize = 42;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn last_client_id(mut self, v: Byte) -> Self {
+        self.last_client_id = v;
         self
     }
-    pub fn signature_slot(mut self, v: Uint64) -> Self {
-        self.signature_slot = v;
+    pub fn minimal_headers_count(mut self, v: Byte) -> Self {
+        self.minimal_headers_count = v;
         self
     }
-    pub fn new_headers_mmr_proof(mut self, v: MmrProof) -> Self {
-        self.new_headers_mmr_proof = v;
+    pub fn genesis_validators_root(mut self, v: Hash) -> Self {
+        self.genesis_validators_root = v;
         self
     }
-    pub fn headers(mut self, v: HeaderVec) -> Self {
-        self.headers = v;
+    pub fn force_update_timeout(mut self, v: Uint64) -> Self {
+        self.force_update_timeout = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientUpdateBuilder {
-    type Entity = ClientUpdate;
-    const NAME: &'static str = "ClientUpdateBuilder";
+impl molecule::prelude::Builder for ClientInfoBuilder {
+    type Entity = ClientInfo;
+    const NAME: &'static str = "ClientInfoBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.attested_header.as_slice().len()
-            + self.finality_branch.as_slice().len()
-            + self.sync_aggregate.as_slice().len()
-            + self.signature_slot.as_slice().len()
-            + self.new_headers_mmr_proof.as_slice().len()
-            + self.headers.as_slice().len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.attested_header.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.finality_branch.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.sync_aggregate.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.signature_slot.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.new_headers_mmr_proof.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.headers.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.attested_header.as_slice())?;
-        writer.write_all(self.finality_branch.as_slice())?;
-        writer.write_all(self.sync_aggregate.as_slice())?;
-        writer.write_all(self.signature_slot.as_slice())?;
-        writer.write_all(self.new_headers_mmr_proof.as_slice())?;
-        writer.write_all(self.headers.as_slice())?;
+        writer.write_all(self.last_client_id.as_slice())?;
+        writer.write_all(self.minimal_headers_count.as_slice())?;
+        writer.write_all(self.genesis_validators_root.as_slice())?;
+        writer.write_all(self.force_update_timeout.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClientUpdate::new_unchecked(inner.into())
+        ClientInfo::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct SyncCommitteeUpdate(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SyncCommitteeUpdate {
+pub struct Client(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Client {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7180,104 +16176,80 @@ impl ::core::fmt::LowerHex for SyncCommitteeUpdate {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SyncCommitteeUpdate {
+impl ::core::fmt::Debug for Client {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SyncCommitteeUpdate {
+impl ::core::fmt::Display for Client {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
+        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
+        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(f, ", {}: {}", "optimistic_slot", self.optimistic_slot())?;
         write!(
             f,
             ", {}: {}",
-            "next_sync_committee_branch",
-            self.next_sync_committee_branch()
+            "optimistic_header_root",
+            self.optimistic_header_root()
         )?;
-        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
-        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
+        write!(f, ", {}: {}", "finalized_slot", self.finalized_slot())?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SyncCommitteeUpdate {
+impl ::core::default::Default for Client {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        SyncCommitteeUpdate::new_unchecked(v)
+        Client::new_unchecked(v)
     }
 }
-impl SyncCommitteeUpdate {
-    const DEFAULT_VALUE: [u8; 304] = [
-        48, 1, 0, 0, 20, 0, 0, 0, 132, 0, 0, 0, 136, 0, 0, 0, 40, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl Client {
+    const DEFAULT_VALUE: [u8; 129] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+    pub const TOTAL_SIZE: usize = 129;
+    pub const FIELD_SIZES: [usize; 8] = [1, 8, 8, 32, 32, 8, 32, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn minimal_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(1..9))
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn maximal_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(9..17))
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn tip_header_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(17..49))
     }
-    pub fn attested_header(&self) -> Header {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Header::new_unchecked(self.0.slice(start..end))
+    pub fn headers_mmr_root(&self) -> HeaderDigest {
+        HeaderDigest::new_unchecked(self.0.slice(49..81))
     }
-    pub fn next_sync_committee_branch(&self) -> SszProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        SszProof::new_unchecked(self.0.slice(start..end))
+    pub fn optimistic_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(81..89))
     }
-    pub fn sync_aggregate(&self) -> SyncAggregate {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    pub fn optimistic_header_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(89..121))
     }
-    pub fn signature_slot(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
-        } else {
-            Uint64::new_unchecked(self.0.slice(start..))
-        }
+    pub fn finalized_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(121..129))
     }
-    pub fn as_reader<'r>(&'r self) -> SyncCommitteeUpdateReader<'r> {
-        SyncCommitteeUpdateReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientReader<'r> {
+        ClientReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SyncCommitteeUpdate {
-    type Builder = SyncCommitteeUpdateBuilder;
-    const NAME: &'static str = "SyncCommitteeUpdate";
+impl molecule::prelude::Entity for Client {
+    type Builder = ClientBuilder;
+    const NAME: &'static str = "Client";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SyncCommitteeUpdate(data)
+        Client(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7286,25 +16258,29 @@ impl molecule::prelude::Entity for SyncCommitteeUpdate {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeUpdateReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SyncCommitteeUpdateReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .attested_header(self.attested_header())
-            .next_sync_committee_branch(self.next_sync_committee_branch())
-            .sync_aggregate(self.sync_aggregate())
-            .signature_slot(self.signature_slot())
+            .id(self.id())
+            .minimal_slot(self.minimal_slot())
+            .maximal_slot(self.maximal_slot())
+            .tip_header_root(self.tip_header_root())
+            .headers_mmr_root(self.headers_mmr_root())
+            .optimistic_slot(self.optimistic_slot())
+            .optimistic_header_root(self.optimistic_header_root())
+            .finalized_slot(self.finalized_slot())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SyncCommitteeUpdateReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SyncCommitteeUpdateReader<'r> {
+pub struct ClientReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7313,197 +16289,155 @@ impl<'r> ::core::fmt::LowerHex for SyncCommitteeUpdateReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SyncCommitteeUpdateReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SyncCommitteeUpdateReader<'r> {
+impl<'r> ::core::fmt::Display for ClientReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
+        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
+        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(f, ", {}: {}", "optimistic_slot", self.optimistic_slot())?;
         write!(
-            f,
-            ", {}: {}",
-            "next_sync_committee_branch",
-            self.next_sync_committee_branch()
-        )?;
-        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
-        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
+            f,
+            ", {}: {}",
+            "optimistic_header_root",
+            self.optimistic_header_root()
+        )?;
+        write!(f, ", {}: {}", "finalized_slot", self.finalized_slot())?;
         write!(f, " }}")
     }
 }
-impl<'r> SyncCommitteeUpdateReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
+impl<'r> ClientReader<'r> {
+    pub const TOTAL_SIZE: usize = 129;
+    pub const FIELD_SIZES: [usize; 8] = [1, 8, 8, 32, 32, 8, 32, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn minimal_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[1..9])
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn maximal_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[9..17])
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn tip_header_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[17..49])
     }
-    pub fn attested_header(&self) -> HeaderReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn headers_mmr_root(&self) -> HeaderDigestReader<'r> {
+        HeaderDigestReader::new_unchecked(&self.as_slice()[49..81])
     }
-    pub fn next_sync_committee_branch(&self) -> SszProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        SszProofReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn optimistic_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[81..89])
     }
-    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn optimistic_header_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[89..121])
     }
-    pub fn signature_slot(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn finalized_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[121..129])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SyncCommitteeUpdateReader<'r> {
-    type Entity = SyncCommitteeUpdate;
-    const NAME: &'static str = "SyncCommitteeUpdateReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientReader<'r> {
+    type Entity = Client;
+    const NAME: &'static str = "ClientReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SyncCommitteeUpdateReader(slice)
+        ClientReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        SyncAggregateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SyncCommitteeUpdateBuilder {
-    pub(crate) attested_header: Header,
-    pub(crate) next_sync_committee_branch: SszProof,
-    pub(crate) sync_aggregate: SyncAggregate,
-    pub(crate) signature_slot: Uint64,
+pub struct ClientBuilder {
+    pub(crate) id: Byte,
+    pub(crate) minimal_slot: Uint64,
+    pub(crate) maximal_slot: Uint64,
+    pub(crate) tip_header_root: Hash,
+    pub(crate) headers_mmr_root: HeaderDigest,
+    pub(crate) optimistic_slot: Uint64,
+    pub(crate) optimistic_header_root: Hash,
+    pub(crate) finalized_slot: Uint64,
 }
-impl SyncCommitteeUpdateBuilder {
-    pub const FIELD_COUNT: usize = 4;
-    pub fn attested_header(mut self, v: Header) -> Self {
-        self.attested_header = v;
+impl ClientBuilder {
+    pub const TOTAL_SIZE: usize = 129;
+    pub const FIELD_SIZES: [usize; 8] = [1, 8, 8, 32, 32, 8, 32, 8];
+    pub const FIELD_COUNT: usize = 8;
+    pub fn id(mut self, v: Byte) -> Self {
+        self.id = v;
         self
     }
-    pub fn next_sync_committee_branch(mut self, v: SszProof) -> Self {
-        self.next_sync_committee_branch = v;
+    pub fn minimal_slot(mut self, v: Uint64) -> Self {
+        self.minimal_slot = v;
         self
     }
-    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
-        self.sync_aggregate = v;
+    pub fn maximal_slot(mut self, v: Uint64) -> Self {
+        self.maximal_slot = v;
         self
     }
-    pub fn signature_slot(mut self, v: Uint64) -> Self {
-        self.signature_slot = v;
+    pub fn tip_header_root(mut self, v: Hash) -> Self {
+        self.tip_header_root = v;
+        self
+    }
+    pub fn headers_mmr_root(mut self, v: HeaderDigest) -> Self {
+        self.headers_mmr_root = v;
+        self
+    }
+    pub fn optimistic_slot(mut self, v: Uint64) -> Self {
+        self.optimistic_slot = v;
+        self
+    }
+    pub fn optimistic_header_root(mut self, v: Hash) -> Self {
+        self.optimistic_header_root = v;
+        self
+    }
+    pub fn finalized_slot(mut self, v: Uint64) -> Self {
+        self.finalized_slot = v;
         self
     }
 }
-impl molecule::prelude::Builder for SyncCommitteeUpdateBuilder {
-    type Entity = SyncCommitteeUpdate;
-    const NAME: &'static str = "SyncCommitteeUpdateBuilder";
+impl molecule::prelude::Builder for ClientBuilder {
+    type Entity = Client;
+    const NAME: &'static str = "ClientBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.attested_header.as_slice().len()
-            + self.next_sync_committee_branch.as_slice().len()
-            + self.sync_aggregate.as_slice().len()
-            + self.signature_slot.as_slice().len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.attested_header.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.next_sync_committee_branch.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.sync_aggregate.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.signature_slot.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.attested_header.as_slice())?;
-        writer.write_all(self.next_sync_committee_branch.as_slice())?;
-        writer.write_all(self.sync_aggregate.as_slice())?;
-        writer.write_all(self.signature_slot.as_slice())?;
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.minimal_slot.as_slice())?;
+        writer.write_all(self.maximal_slot.as_slice())?;
+        writer.write_all(self.tip_header_root.as_slice())?;
+        writer.write_all(self.headers_mmr_root.as_slice())?;
+        writer.write_all(self.optimistic_slot.as_slice())?;
+        writer.write_all(self.optimistic_header_root.as_slice())?;
+        writer.write_all(self.finalized_slot.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SyncCommitteeUpdate::new_unchecked(inner.into())
+        Client::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TransactionProof(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TransactionProof {
+pub struct ClientSyncCommittee(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7512,31 +16446,16 @@ impl ::core::fmt::LowerHex for TransactionProof {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TransactionProof {
+impl ::core::fmt::Debug for ClientSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TransactionProof {
+impl ::core::fmt::Display for ClientSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(f, ", {}: {}", "transaction_index", self.transaction_index())?;
-        write!(f, ", {}: {}", "receipts_root", self.receipts_root())?;
-        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
-        write!(
-            f,
-            ", {}: {}",
-            "transaction_ssz_proof",
-            self.transaction_ssz_proof()
-        )?;
-        write!(f, ", {}: {}", "receipt_mpt_proof", self.receipt_mpt_proof())?;
-        write!(
-            f,
-            ", {}: {}",
-            "receipts_root_ssz_proof",
-            self.receipts_root_ssz_proof()
-        )?;
+        write!(f, "{}: {}", "period", self.period())?;
+        write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7544,23 +16463,19 @@ impl ::core::fmt::Display for TransactionProof {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TransactionProof {
+impl ::core::default::Default for ClientSyncCommittee {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        TransactionProof::new_unchecked(v)
+        ClientSyncCommittee::new_unchecked(v)
     }
 }
-impl TransactionProof {
-    const DEFAULT_VALUE: [u8; 200] = [
-        200, 0, 0, 0, 32, 0, 0, 0, 144, 0, 0, 0, 152, 0, 0, 0, 184, 0, 0, 0, 188, 0, 0, 0, 192, 0,
-        0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+impl ClientSyncCommittee {
+    const DEFAULT_VALUE: [u8; 84] = [
+        84, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 12, 0, 0, 0,
+        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 7;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7577,61 +16492,31 @@ impl TransactionProof {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> Header {
+    pub fn period(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Header::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn transaction_index(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn receipts_root(&self) -> Hash {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Hash::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn header_mmr_proof(&self) -> MmrProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        MmrProof::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn transaction_ssz_proof(&self) -> SszProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        SszProof::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn receipt_mpt_proof(&self) -> MptProof {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        MptProof::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn receipts_root_ssz_proof(&self) -> SszProof {
+    pub fn data(&self) -> SyncCommittee {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            SszProof::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SyncCommittee::new_unchecked(self.0.slice(start..end))
         } else {
-            SszProof::new_unchecked(self.0.slice(start..))
+            SyncCommittee::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TransactionProofReader<'r> {
-        TransactionProofReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientSyncCommitteeReader<'r> {
+        ClientSyncCommitteeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TransactionProof {
-    type Builder = TransactionProofBuilder;
-    const NAME: &'static str = "TransactionProof";
+impl molecule::prelude::Entity for ClientSyncCommittee {
+    type Builder = ClientSyncCommitteeBuilder;
+    const NAME: &'static str = "ClientSyncCommittee";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TransactionProof(data)
+        ClientSyncCommittee(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -7640,28 +16525,21 @@ impl molecule::prelude::Entity for TransactionProof {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionProofReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientSyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionProofReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientSyncCommitteeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .header(self.header())
-            .transaction_index(self.transaction_index())
-            .receipts_root(self.receipts_root())
-            .header_mmr_proof(self.header_mmr_proof())
-            .transaction_ssz_proof(self.transaction_ssz_proof())
-            .receipt_mpt_proof(self.receipt_mpt_proof())
-            .receipts_root_ssz_proof(self.receipts_root_ssz_proof())
+        Self::new_builder().period(self.period()).data(self.data())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TransactionProofReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
+pub struct ClientSyncCommitteeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7670,31 +16548,16 @@ impl<'r> ::core::fmt::LowerHex for TransactionProofReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TransactionProofReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
+impl<'r> ::core::fmt::Display for ClientSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "header", self.header())?;
-        write!(f, ", {}: {}", "transaction_index", self.transaction_index())?;
-        write!(f, ", {}: {}", "receipts_root", self.receipts_root())?;
-        write!(f, ", {}: {}", "header_mmr_proof", self.header_mmr_proof())?;
-        write!(
-            f,
-            ", {}: {}",
-            "transaction_ssz_proof",
-            self.transaction_ssz_proof()
-        )?;
-        write!(f, ", {}: {}", "receipt_mpt_proof", self.receipt_mpt_proof())?;
-        write!(
-            f,
-            ", {}: {}",
-            "receipts_root_ssz_proof",
-            self.receipts_root_ssz_proof()
-        )?;
+        write!(f, "{}: {}", "period", self.period())?;
+        write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7702,8 +16565,8 @@ impl<'r> ::core::fmt::Display for TransactionProofReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TransactionProofReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
+impl<'r> ClientSyncCommitteeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -7720,61 +16583,31 @@ impl<'r> TransactionProofReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn header(&self) -> HeaderReader<'r> {
+    pub fn period(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        HeaderReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn transaction_index(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
         Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn receipts_root(&self) -> HashReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        HashReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn header_mmr_proof(&self) -> MmrProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn transaction_ssz_proof(&self) -> SszProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        SszProofReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn receipt_mpt_proof(&self) -> MptProofReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        MptProofReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn receipts_root_ssz_proof(&self) -> SszProofReader<'r> {
+    pub fn data(&self) -> SyncCommitteeReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            SszProofReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SyncCommitteeReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            SszProofReader::new_unchecked(&self.as_slice()[start..])
+            SyncCommitteeReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
-    type Entity = TransactionProof;
-    const NAME: &'static str = "TransactionProofReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientSyncCommitteeReader<'r> {
+    type Entity = ClientSyncCommittee;
+    const NAME: &'static str = "ClientSyncCommitteeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TransactionProofReader(slice)
+        ClientSyncCommitteeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -7813,110 +16646,60 @@ impl<'r> molecule::prelude::Reader<'r> for TransactionProofReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        HashReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        MmrProofReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        SszProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        MptProofReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        SszProofReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SyncCommitteeReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TransactionProofBuilder {
-    pub(crate) header: Header,
-    pub(crate) transaction_index: Uint64,
-    pub(crate) receipts_root: Hash,
-    pub(crate) header_mmr_proof: MmrProof,
-    pub(crate) transaction_ssz_proof: SszProof,
-    pub(crate) receipt_mpt_proof: MptProof,
-    pub(crate) receipts_root_ssz_proof: SszProof,
+pub struct ClientSyncCommitteeBuilder {
+    pub(crate) period: Uint64,
+    pub(crate) data: SyncCommittee,
 }
-impl TransactionProofBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn header(mut self, v: Header) -> Self {
-        self.header = v;
-        self
-    }
-    pub fn transaction_index(mut self, v: Uint64) -> Self {
-        self.transaction_index = v;
-        self
-    }
-    pub fn receipts_root(mut self, v: Hash) -> Self {
-        self.receipts_root = v;
-        self
-    }
-    pub fn header_mmr_proof(mut self, v: MmrProof) -> Self {
-        self.header_mmr_proof = v;
-        self
-    }
-    pub fn transaction_ssz_proof(mut self, v: SszProof) -> Self {
-        self.transaction_ssz_proof = v;
-        self
-    }
-    pub fn receipt_mpt_proof(mut self, v: MptProof) -> Self {
-        self.receipt_mpt_proof = v;
+impl ClientSyncCommitteeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn period(mut self, v: Uint64) -> Self {
+        self.period = v;
         self
     }
-    pub fn receipts_root_ssz_proof(mut self, v: SszProof) -> Self {
-        self.receipts_root_ssz_proof = v;
+    pub fn data(mut self, v: SyncCommittee) -> Self {
+        self.data = v;
         self
     }
 }
-impl molecule::prelude::Builder for TransactionProofBuilder {
-    type Entity = TransactionProof;
-    const NAME: &'static str = "TransactionProofBuilder";
+impl molecule::prelude::Builder for ClientSyncCommitteeBuilder {
+    type Entity = ClientSyncCommittee;
+    const NAME: &'static str = "ClientSyncCommitteeBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.header.as_slice().len()
-            + self.transaction_index.as_slice().len()
-            + self.receipts_root.as_slice().len()
-            + self.header_mmr_proof.as_slice().len()
-            + self.transaction_ssz_proof.as_slice().len()
-            + self.receipt_mpt_proof.as_slice().len()
-            + self.receipts_root_ssz_proof.as_slice().len()
+            + self.period.as_slice().len()
+            + self.data.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.header.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transaction_index.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.receipts_root.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.header_mmr_proof.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.transaction_ssz_proof.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.receipt_mpt_proof.as_slice().len();
+        total_size += self.period.as_slice().len();
         offsets.push(total_size);
-        total_size += self.receipts_root_ssz_proof.as_slice().len();
+        total_size += self.data.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.header.as_slice())?;
-        writer.write_all(self.transaction_index.as_slice())?;
-        writer.write_all(self.receipts_root.as_slice())?;
-        writer.write_all(self.header_mmr_proof.as_slice())?;
-        writer.write_all(self.transaction_ssz_proof.as_slice())?;
-        writer.write_all(self.receipt_mpt_proof.as_slice())?;
-        writer.write_all(self.receipts_root_ssz_proof.as_slice())?;
+        writer.write_all(self.period.as_slice())?;
+        writer.write_all(self.data.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TransactionProof::new_unchecked(inner.into())
+        ClientSyncCommittee::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TransactionPayload(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TransactionPayload {
+pub struct ClientUncompressedSyncCommittee(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUncompressedSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -7925,16 +16708,16 @@ impl ::core::fmt::LowerHex for TransactionPayload {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TransactionPayload {
+impl ::core::fmt::Debug for ClientUncompressedSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TransactionPayload {
+impl ::core::fmt::Display for ClientUncompressedSyncCommittee {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "transaction", self.transaction())?;
-        write!(f, ", {}: {}", "receipt", self.receipt())?;
+        write!(f, "{}: {}", "period", self.period())?;
+        write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -7942,15 +16725,19 @@ impl ::core::fmt::Display for TransactionPayload {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TransactionPayload {
+impl ::core::default::Default for ClientUncompressedSyncCommittee {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        TransactionPayload::new_unchecked(v)
+        ClientUncompressedSyncCommittee::new_unchecked(v)
     }
 }
-impl TransactionPayload {
-    const DEFAULT_VALUE: [u8; 20] = [
-        20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ClientUncompressedSyncCommittee {
+    const DEFAULT_VALUE: [u8; 132] = [
+        132, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 12, 0, 0, 0,
+        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
@@ -7969,31 +16756,31 @@ impl TransactionPayload {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn transaction(&self) -> Bytes {
+    pub fn period(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn receipt(&self) -> Bytes {
+    pub fn data(&self) -> UncompressedSyncCommittee {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            UncompressedSyncCommittee::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            UncompressedSyncCommittee::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TransactionPayloadReader<'r> {
-        TransactionPayloadReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientUncompressedSyncCommitteeReader<'r> {
+        ClientUncompressedSyncCommitteeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TransactionPayload {
-    type Builder = TransactionPayloadBuilder;
-    const NAME: &'static str = "TransactionPayload";
+impl molecule::prelude::Entity for ClientUncompressedSyncCommittee {
+    type Builder = ClientUncompressedSyncCommitteeBuilder;
+    const NAME: &'static str = "ClientUncompressedSyncCommittee";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TransactionPayload(data)
+        ClientUncompressedSyncCommittee(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8002,23 +16789,22 @@ impl molecule::prelude::Entity for TransactionPayload {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionPayloadReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientUncompressedSyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TransactionPayloadReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientUncompressedSyncCommitteeReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .transaction(self.transaction())
-            .receipt(self.receipt())
+        Self::new_builder().period(self.period()).data(self.data())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TransactionPayloadReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TransactionPayloadReader<'r> {
+pub struct ClientUncompressedSyncCommitteeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUncompressedSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8027,16 +16813,16 @@ impl<'r> ::core::fmt::LowerHex for TransactionPayloadReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TransactionPayloadReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientUncompressedSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TransactionPayloadReader<'r> {
+impl<'r> ::core::fmt::Display for ClientUncompressedSyncCommitteeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "transaction", self.transaction())?;
-        write!(f, ", {}: {}", "receipt", self.receipt())?;
+        write!(f, "{}: {}", "period", self.period())?;
+        write!(f, ", {}: {}", "data", self.data())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -8044,7 +16830,7 @@ impl<'r> ::core::fmt::Display for TransactionPayloadReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TransactionPayloadReader<'r> {
+impl<'r> ClientUncompressedSyncCommitteeReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -8062,31 +16848,31 @@ impl<'r> TransactionPayloadReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn transaction(&self) -> BytesReader<'r> {
+    pub fn period(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn receipt(&self) -> BytesReader<'r> {
+    pub fn data(&self) -> UncompressedSyncCommitteeReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            UncompressedSyncCommitteeReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            UncompressedSyncCommitteeReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TransactionPayloadReader<'r> {
-    type Entity = TransactionPayload;
-    const NAME: &'static str = "TransactionPayloadReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientUncompressedSyncCommitteeReader<'r> {
+    type Entity = ClientUncompressedSyncCommittee;
+    const NAME: &'static str = "ClientUncompressedSyncCommitteeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TransactionPayloadReader(slice)
+        ClientUncompressedSyncCommitteeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8125,60 +16911,60 @@ impl<'r> molecule::prelude::Reader<'r> for TransactionPayloadReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        UncompressedSyncCommitteeReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TransactionPayloadBuilder {
-    pub(crate) transaction: Bytes,
-    pub(crate) receipt: Bytes,
+pub struct ClientUncompressedSyncCommitteeBuilder {
+    pub(crate) period: Uint64,
+    pub(crate) data: UncompressedSyncCommittee,
 }
-impl TransactionPayloadBuilder {
+impl ClientUncompressedSyncCommitteeBuilder {
     pub const FIELD_COUNT: usize = 2;
-    pub fn transaction(mut self, v: Bytes) -> Self {
-        self.transaction = v;
+    pub fn period(mut self, v: Uint64) -> Self {
+        self.period = v;
         self
     }
-    pub fn receipt(mut self, v: Bytes) -> Self {
-        self.receipt = v;
+    pub fn data(mut self, v: UncompressedSyncCommittee) -> Self {
+        self.data = v;
         self
     }
 }
-impl molecule::prelude::Builder for TransactionPayloadBuilder {
-    type Entity = TransactionPayload;
-    const NAME: &'static str = "TransactionPayloadBuilder";
+impl molecule::prelude::Builder for ClientUncompressedSyncCommitteeBuilder {
+    type Entity = ClientUncompressedSyncCommittee;
+    const NAME: &'static str = "ClientUncompressedSyncCommitteeBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.transaction.as_slice().len()
-            + self.receipt.as_slice().len()
+            + self.period.as_slice().len()
+            + self.data.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.transaction.as_slice().len();
+        total_size += self.period.as_slice().len();
         offsets.push(total_size);
-        total_size += self.receipt.as_slice().len();
+        total_size += self.data.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.transaction.as_slice())?;
-        writer.write_all(self.receipt.as_slice())?;
+        writer.write_all(self.period.as_slice())?;
+        writer.write_all(self.data.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TransactionPayload::new_unchecked(inner.into())
+        ClientUncompressedSyncCommittee::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ClientInfo(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClientInfo {
+pub struct ClientSyncCommitteeCommitment(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientSyncCommitteeCommitment {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8187,62 +16973,53 @@ impl ::core::fmt::LowerHex for ClientInfo {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClientInfo {
+impl ::core::fmt::Debug for ClientSyncCommitteeCommitment {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClientInfo {
+impl ::core::fmt::Display for ClientSyncCommitteeCommitment {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "minimal_headers_count",
-            self.minimal_headers_count()
-        )?;
+        write!(f, "{}: {}", "period", self.period())?;
         write!(
             f,
             ", {}: {}",
-            "genesis_validators_root",
-            self.genesis_validators_root()
+            "sync_committee_root",
+            self.sync_committee_root()
         )?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClientInfo {
+impl ::core::default::Default for ClientSyncCommitteeCommitment {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClientInfo::new_unchecked(v)
+        ClientSyncCommitteeCommitment::new_unchecked(v)
     }
 }
-impl ClientInfo {
-    const DEFAULT_VALUE: [u8; 34] = [
+impl ClientSyncCommitteeCommitment {
+    const DEFAULT_VALUE: [u8; 40] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn last_client_id(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
-    }
-    pub fn minimal_headers_count(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(1..2))
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn period(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0..8))
     }
-    pub fn genesis_validators_root(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(2..34))
+    pub fn sync_committee_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(8..40))
     }
-    pub fn as_reader<'r>(&'r self) -> ClientInfoReader<'r> {
-        ClientInfoReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientSyncCommitteeCommitmentReader<'r> {
+        ClientSyncCommitteeCommitmentReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClientInfo {
-    type Builder = ClientInfoBuilder;
-    const NAME: &'static str = "ClientInfo";
+impl molecule::prelude::Entity for ClientSyncCommitteeCommitment {
+    type Builder = ClientSyncCommitteeCommitmentBuilder;
+    const NAME: &'static str = "ClientSyncCommitteeCommitment";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClientInfo(data)
+        ClientSyncCommitteeCommitment(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8251,24 +17028,24 @@ impl molecule::prelude::Entity for ClientInfo {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientSyncCommitteeCommitmentReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientSyncCommitteeCommitmentReader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .last_client_id(self.last_client_id())
-            .minimal_headers_count(self.minimal_headers_count())
-            .genesis_validators_root(self.genesis_validators_root())
+            .period(self.period())
+            .sync_committee_root(self.sync_committee_root())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientInfoReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientInfoReader<'r> {
+pub struct ClientSyncCommitteeCommitmentReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientSyncCommitteeCommitmentReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8277,52 +17054,210 @@ impl<'r> ::core::fmt::LowerHex for ClientInfoReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientInfoReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientSyncCommitteeCommitmentReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientInfoReader<'r> {
+impl<'r> ::core::fmt::Display for ClientSyncCommitteeCommitmentReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "minimal_headers_count",
-            self.minimal_headers_count()
-        )?;
+        write!(f, "{}: {}", "period", self.period())?;
         write!(
             f,
             ", {}: {}",
-            "genesis_validators_root",
-            self.genesis_validators_root()
+            "sync_committee_root",
+            self.sync_committee_root()
         )?;
         write!(f, " }}")
     }
 }
-impl<'r> ClientInfoReader<'r> {
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn last_client_id(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> ClientSyncCommitteeCommitmentReader<'r> {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn period(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
     }
-    pub fn minimal_headers_count(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    pub fn sync_committee_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[8..40])
     }
-    pub fn genesis_validators_root(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[2..34])
+}
+impl<'r> molecule::prelude::Reader<'r> for ClientSyncCommitteeCommitmentReader<'r> {
+    type Entity = ClientSyncCommitteeCommitment;
+    const NAME: &'static str = "ClientSyncCommitteeCommitmentReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ClientSyncCommitteeCommitmentReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ClientSyncCommitteeCommitmentBuilder {
+    pub(crate) period: Uint64,
+    pub(crate) sync_committee_root: Hash,
+}
+impl ClientSyncCommitteeCommitmentBuilder {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn period(mut self, v: Uint64) -> Self {
+        self.period = v;
+        self
+    }
+    pub fn sync_committee_root(mut self, v: Hash) -> Self {
+        self.sync_committee_root = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ClientSyncCommitteeCommitmentBuilder {
+    type Entity = ClientSyncCommitteeCommitment;
+    const NAME: &'static str = "ClientSyncCommitteeCommitmentBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.period.as_slice())?;
+        writer.write_all(self.sync_committee_root.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ClientSyncCommitteeCommitment::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ClientTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ClientTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ClientTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ClientTypeArgs {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ClientTypeArgs::new_unchecked(v)
+    }
+}
+impl ClientTypeArgs {
+    const DEFAULT_VALUE: [u8; 33] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_id(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn clients_count(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(32..33))
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientTypeArgsReader<'r> {
+        ClientTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ClientTypeArgs {
+    type Builder = ClientTypeArgsBuilder;
+    const NAME: &'static str = "ClientTypeArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ClientTypeArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ClientTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .type_id(self.type_id())
+            .clients_count(self.clients_count())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ClientTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ClientTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ClientTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ClientTypeArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_id(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn clients_count(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[32..33])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClientInfoReader<'r> {
-    type Entity = ClientInfo;
-    const NAME: &'static str = "ClientInfoReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientTypeArgsReader<'r> {
+    type Entity = ClientTypeArgs;
+    const NAME: &'static str = "ClientTypeArgsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientInfoReader(slice)
+        ClientTypeArgsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8337,50 +17272,44 @@ impl<'r> molecule::prelude::Reader<'r> for ClientInfoReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientInfoBuilder {
-    pub(crate) last_client_id: Byte,
-    pub(crate) minimal_headers_count: Byte,
-    pub(crate) genesis_validators_root: Hash,
+pub struct ClientTypeArgsBuilder {
+    pub(crate) type_id: Hash,
+    pub(crate) clients_count: Byte,
 }
-impl ClientInfoBuilder {
-    pub const TOTAL_SIZE: usize = 34;
-    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn last_client_id(mut self, v: Byte) -> Self {
-        self.last_client_id = v;
-        self
-    }
-    pub fn minimal_headers_count(mut self, v: Byte) -> Self {
-        self.minimal_headers_count = v;
+impl ClientTypeArgsBuilder {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [32, 1];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn type_id(mut self, v: Hash) -> Self {
+        self.type_id = v;
         self
     }
-    pub fn genesis_validators_root(mut self, v: Hash) -> Self {
-        self.genesis_validators_root = v;
+    pub fn clients_count(mut self, v: Byte) -> Self {
+        self.clients_count = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientInfoBuilder {
-    type Entity = ClientInfo;
-    const NAME: &'static str = "ClientInfoBuilder";
+impl molecule::prelude::Builder for ClientTypeArgsBuilder {
+    type Entity = ClientTypeArgs;
+    const NAME: &'static str = "ClientTypeArgsBuilder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.last_client_id.as_slice())?;
-        writer.write_all(self.minimal_headers_count.as_slice())?;
-        writer.write_all(self.genesis_validators_root.as_slice())?;
+        writer.write_all(self.type_id.as_slice())?;
+        writer.write_all(self.clients_count.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClientInfo::new_unchecked(inner.into())
+        ClientTypeArgs::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct Client(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Client {
+pub struct ClientUpdateV0(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientUpdateV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8389,61 +17318,118 @@ impl ::core::fmt::LowerHex for Client {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Client {
+impl ::core::fmt::Debug for ClientUpdateV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Client {
+impl ::core::fmt::Display for ClientUpdateV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
-        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
-        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
-        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for Client {
+impl ::core::default::Default for ClientUpdateV0 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Client::new_unchecked(v)
+        ClientUpdateV0::new_unchecked(v)
     }
 }
-impl Client {
-    const DEFAULT_VALUE: [u8; 81] = [
+impl ClientUpdateV0 {
+    const DEFAULT_VALUE: [u8; 320] = [
+        64, 1, 0, 0, 28, 0, 0, 0, 140, 0, 0, 0, 144, 0, 0, 0, 48, 1, 0, 0, 56, 1, 0, 0, 60, 1, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 81;
-    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn id(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn minimal_slot(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(1..9))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn maximal_slot(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(9..17))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn tip_header_root(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(17..49))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers_mmr_root(&self) -> HeaderDigest {
-        HeaderDigest::new_unchecked(self.0.slice(49..81))
+    pub fn attested_header(&self) -> Header {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Header::new_unchecked(self.0.slice(start..end))
     }
-    pub fn as_reader<'r>(&'r self) -> ClientReader<'r> {
-        ClientReader::new_unchecked(self.as_slice())
+    pub fn finality_branch(&self) -> SszProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregate {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregate::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signature_slot(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProof {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProof::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn headers(&self) -> HeaderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            HeaderVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            HeaderVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientUpdateV0Reader<'r> {
+        ClientUpdateV0Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Client {
-    type Builder = ClientBuilder;
-    const NAME: &'static str = "Client";
+impl molecule::prelude::Entity for ClientUpdateV0 {
+    type Builder = ClientUpdateV0Builder;
+    const NAME: &'static str = "ClientUpdateV0";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Client(data)
+        ClientUpdateV0(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8452,26 +17438,27 @@ impl molecule::prelude::Entity for Client {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientUpdateV0Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientUpdateV0Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .id(self.id())
-            .minimal_slot(self.minimal_slot())
-            .maximal_slot(self.maximal_slot())
-            .tip_header_root(self.tip_header_root())
-            .headers_mmr_root(self.headers_mmr_root())
+            .attested_header(self.attested_header())
+            .finality_branch(self.finality_branch())
+            .sync_aggregate(self.sync_aggregate())
+            .signature_slot(self.signature_slot())
+            .new_headers_mmr_proof(self.new_headers_mmr_proof())
+            .headers(self.headers())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientReader<'r> {
+pub struct ClientUpdateV0Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientUpdateV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8480,120 +17467,231 @@ impl<'r> ::core::fmt::LowerHex for ClientReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientUpdateV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientReader<'r> {
+impl<'r> ::core::fmt::Display for ClientUpdateV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "id", self.id())?;
-        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
-        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
-        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
-        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
+        write!(f, "{}: {}", "attested_header", self.attested_header())?;
+        write!(f, ", {}: {}", "finality_branch", self.finality_branch())?;
+        write!(f, ", {}: {}", "sync_aggregate", self.sync_aggregate())?;
+        write!(f, ", {}: {}", "signature_slot", self.signature_slot())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_headers_mmr_proof",
+            self.new_headers_mmr_proof()
+        )?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> ClientReader<'r> {
-    pub const TOTAL_SIZE: usize = 81;
-    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn id(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+impl<'r> ClientUpdateV0Reader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn minimal_slot(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[1..9])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn maximal_slot(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[9..17])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn tip_header_root(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[17..49])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn headers_mmr_root(&self) -> HeaderDigestReader<'r> {
-        HeaderDigestReader::new_unchecked(&self.as_slice()[49..81])
+    pub fn attested_header(&self) -> HeaderReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        HeaderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn finality_branch(&self) -> SszProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        SszProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sync_aggregate(&self) -> SyncAggregateReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        SyncAggregateReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signature_slot(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_headers_mmr_proof(&self) -> MmrProofReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        MmrProofReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn headers(&self) -> HeaderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            HeaderVecReader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClientReader<'r> {
-    type Entity = Client;
-    const NAME: &'static str = "ClientReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientUpdateV0Reader<'r> {
+    type Entity = ClientUpdateV0;
+    const NAME: &'static str = "ClientUpdateV0Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientReader(slice)
+        ClientUpdateV0Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
         }
+        HeaderReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SszProofReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        SyncAggregateReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        MmrProofReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        HeaderVecReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientBuilder {
-    pub(crate) id: Byte,
-    pub(crate) minimal_slot: Uint64,
-    pub(crate) maximal_slot: Uint64,
-    pub(crate) tip_header_root: Hash,
-    pub(crate) headers_mmr_root: HeaderDigest,
+pub struct ClientUpdateV0Builder {
+    pub(crate) attested_header: Header,
+    pub(crate) finality_branch: SszProof,
+    pub(crate) sync_aggregate: SyncAggregate,
+    pub(crate) signature_slot: Uint64,
+    pub(crate) new_headers_mmr_proof: MmrProof,
+    pub(crate) headers: HeaderVec,
 }
-impl ClientBuilder {
-    pub const TOTAL_SIZE: usize = 81;
-    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
-    pub const FIELD_COUNT: usize = 5;
-    pub fn id(mut self, v: Byte) -> Self {
-        self.id = v;
+impl ClientUpdateV0Builder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn attested_header(mut self, v: Header) -> Self {
+        self.attested_header = v;
         self
     }
-    pub fn minimal_slot(mut self, v: Uint64) -> Self {
-        self.minimal_slot = v;
+    pub fn finality_branch(mut self, v: SszProof) -> Self {
+        self.finality_branch = v;
         self
     }
-    pub fn maximal_slot(mut self, v: Uint64) -> Self {
-        self.maximal_slot = v;
+    pub fn sync_aggregate(mut self, v: SyncAggregate) -> Self {
+        self.sync_aggregate = v;
         self
     }
-    pub fn tip_header_root(mut self, v: Hash) -> Self {
-        self.tip_header_root = v;
+    pub fn signature_slot(mut self, v: Uint64) -> Self {
+        self.signature_slot = v;
         self
     }
-    pub fn headers_mmr_root(mut self, v: HeaderDigest) -> Self {
-        self.headers_mmr_root = v;
+    pub fn new_headers_mmr_proof(mut self, v: MmrProof) -> Self {
+        self.new_headers_mmr_proof = v;
+        self
+    }
+    pub fn headers(mut self, v: HeaderVec) -> Self {
+        self.headers = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientBuilder {
-    type Entity = Client;
-    const NAME: &'static str = "ClientBuilder";
+impl molecule::prelude::Builder for ClientUpdateV0Builder {
+    type Entity = ClientUpdateV0;
+    const NAME: &'static str = "ClientUpdateV0Builder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.attested_header.as_slice().len()
+            + self.finality_branch.as_slice().len()
+            + self.sync_aggregate.as_slice().len()
+            + self.signature_slot.as_slice().len()
+            + self.new_headers_mmr_proof.as_slice().len()
+            + self.headers.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.id.as_slice())?;
-        writer.write_all(self.minimal_slot.as_slice())?;
-        writer.write_all(self.maximal_slot.as_slice())?;
-        writer.write_all(self.tip_header_root.as_slice())?;
-        writer.write_all(self.headers_mmr_root.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.attested_header.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.finality_branch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sync_aggregate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signature_slot.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_headers_mmr_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.attested_header.as_slice())?;
+        writer.write_all(self.finality_branch.as_slice())?;
+        writer.write_all(self.sync_aggregate.as_slice())?;
+        writer.write_all(self.signature_slot.as_slice())?;
+        writer.write_all(self.new_headers_mmr_proof.as_slice())?;
+        writer.write_all(self.headers.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Client::new_unchecked(inner.into())
+        ClientUpdateV0::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ClientSyncCommittee(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClientSyncCommittee {
+pub struct ClientInfoV0(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientInfoV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8602,77 +17700,62 @@ impl ::core::fmt::LowerHex for ClientSyncCommittee {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClientSyncCommittee {
+impl ::core::fmt::Debug for ClientInfoV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClientSyncCommittee {
+impl ::core::fmt::Display for ClientInfoV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "period", self.period())?;
-        write!(f, ", {}: {}", "data", self.data())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
+        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "minimal_headers_count",
+            self.minimal_headers_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "genesis_validators_root",
+            self.genesis_validators_root()
+        )?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClientSyncCommittee {
+impl ::core::default::Default for ClientInfoV0 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClientSyncCommittee::new_unchecked(v)
+        ClientInfoV0::new_unchecked(v)
     }
 }
-impl ClientSyncCommittee {
-    const DEFAULT_VALUE: [u8; 84] = [
-        84, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 12, 0, 0, 0,
-        16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+impl ClientInfoV0 {
+    const DEFAULT_VALUE: [u8; 34] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn last_client_id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn period(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
+    pub fn minimal_headers_count(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
     }
-    pub fn data(&self) -> SyncCommittee {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            SyncCommittee::new_unchecked(self.0.slice(start..end))
-        } else {
-            SyncCommittee::new_unchecked(self.0.slice(start..))
-        }
+    pub fn genesis_validators_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(2..34))
     }
-    pub fn as_reader<'r>(&'r self) -> ClientSyncCommitteeReader<'r> {
-        ClientSyncCommitteeReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ClientInfoV0Reader<'r> {
+        ClientInfoV0Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClientSyncCommittee {
-    type Builder = ClientSyncCommitteeBuilder;
-    const NAME: &'static str = "ClientSyncCommittee";
+impl molecule::prelude::Entity for ClientInfoV0 {
+    type Builder = ClientInfoV0Builder;
+    const NAME: &'static str = "ClientInfoV0";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClientSyncCommittee(data)
+        ClientInfoV0(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8681,21 +17764,24 @@ impl molecule::prelude::Entity for ClientSyncCommittee {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientSyncCommitteeReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientInfoV0Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientSyncCommitteeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientInfoV0Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().period(self.period()).data(self.data())
+        Self::new_builder()
+            .last_client_id(self.last_client_id())
+            .minimal_headers_count(self.minimal_headers_count())
+            .genesis_validators_root(self.genesis_validators_root())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientSyncCommitteeReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientSyncCommitteeReader<'r> {
+pub struct ClientInfoV0Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientInfoV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8704,158 +17790,110 @@ impl<'r> ::core::fmt::LowerHex for ClientSyncCommitteeReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientSyncCommitteeReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientInfoV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientSyncCommitteeReader<'r> {
+impl<'r> ::core::fmt::Display for ClientInfoV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "period", self.period())?;
-        write!(f, ", {}: {}", "data", self.data())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
-        write!(f, " }}")
-    }
-}
-impl<'r> ClientSyncCommitteeReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+        write!(f, "{}: {}", "last_client_id", self.last_client_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "minimal_headers_count",
+            self.minimal_headers_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "genesis_validators_root",
+            self.genesis_validators_root()
+        )?;
+        write!(f, " }}")
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+}
+impl<'r> ClientInfoV0Reader<'r> {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn last_client_id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn period(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn minimal_headers_count(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
     }
-    pub fn data(&self) -> SyncCommitteeReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            SyncCommitteeReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            SyncCommitteeReader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn genesis_validators_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[2..34])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClientSyncCommitteeReader<'r> {
-    type Entity = ClientSyncCommittee;
-    const NAME: &'static str = "ClientSyncCommitteeReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientInfoV0Reader<'r> {
+    type Entity = ClientInfoV0;
+    const NAME: &'static str = "ClientInfoV0Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientSyncCommitteeReader(slice)
+        ClientInfoV0Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        SyncCommitteeReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientSyncCommitteeBuilder {
-    pub(crate) period: Uint64,
-    pub(crate) data: SyncCommittee,
+pub struct ClientInfoV0Builder {
+    pub(crate) last_client_id: Byte,
+    pub(crate) minimal_headers_count: Byte,
+    pub(crate) genesis_validators_root: Hash,
 }
-impl ClientSyncCommitteeBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn period(mut self, v: Uint64) -> Self {
-        self.period = v;
+impl ClientInfoV0Builder {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 3] = [1, 1, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn last_client_id(mut self, v: Byte) -> Self {
+        self.last_client_id = v;
         self
     }
-    pub fn data(mut self, v: SyncCommittee) -> Self {
-        self.data = v;
+    pub fn minimal_headers_count(mut self, v: Byte) -> Self {
+        self.minimal_headers_count = v;
+        self
+    }
+    pub fn genesis_validators_root(mut self, v: Hash) -> Self {
+        self.genesis_validators_root = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientSyncCommitteeBuilder {
-    type Entity = ClientSyncCommittee;
-    const NAME: &'static str = "ClientSyncCommitteeBuilder";
+impl molecule::prelude::Builder for ClientInfoV0Builder {
+    type Entity = ClientInfoV0;
+    const NAME: &'static str = "ClientInfoV0Builder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.period.as_slice().len()
-            + self.data.as_slice().len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.period.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.data.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.period.as_slice())?;
-        writer.write_all(self.data.as_slice())?;
+        writer.write_all(self.last_client_id.as_slice())?;
+        writer.write_all(self.minimal_headers_count.as_slice())?;
+        writer.write_all(self.genesis_validators_root.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClientSyncCommittee::new_unchecked(inner.into())
+        ClientInfoV0::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct ClientTypeArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ClientTypeArgs {
+pub struct ClientV0(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ClientV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8864,48 +17902,61 @@ impl ::core::fmt::LowerHex for ClientTypeArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ClientTypeArgs {
+impl ::core::fmt::Debug for ClientV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ClientTypeArgs {
+impl ::core::fmt::Display for ClientV0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_id", self.type_id())?;
-        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
+        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
+        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ClientTypeArgs {
+impl ::core::default::Default for ClientV0 {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        ClientTypeArgs::new_unchecked(v)
+        ClientV0::new_unchecked(v)
     }
 }
-impl ClientTypeArgs {
-    const DEFAULT_VALUE: [u8; 33] = [
+impl ClientV0 {
+    const DEFAULT_VALUE: [u8; 81] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZES: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_id(&self) -> Hash {
-        Hash::new_unchecked(self.0.slice(0..32))
+    pub const TOTAL_SIZE: usize = 81;
+    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn clients_count(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(32..33))
+    pub fn minimal_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(1..9))
     }
-    pub fn as_reader<'r>(&'r self) -> ClientTypeArgsReader<'r> {
-        ClientTypeArgsReader::new_unchecked(self.as_slice())
+    pub fn maximal_slot(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(9..17))
+    }
+    pub fn tip_header_root(&self) -> Hash {
+        Hash::new_unchecked(self.0.slice(17..49))
+    }
+    pub fn headers_mmr_root(&self) -> HeaderDigest {
+        HeaderDigest::new_unchecked(self.0.slice(49..81))
+    }
+    pub fn as_reader<'r>(&'r self) -> ClientV0Reader<'r> {
+        ClientV0Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ClientTypeArgs {
-    type Builder = ClientTypeArgsBuilder;
-    const NAME: &'static str = "ClientTypeArgs";
+impl molecule::prelude::Entity for ClientV0 {
+    type Builder = ClientV0Builder;
+    const NAME: &'static str = "ClientV0";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ClientTypeArgs(data)
+        ClientV0(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -8914,23 +17965,26 @@ impl molecule::prelude::Entity for ClientTypeArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        ClientV0Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ClientTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ClientV0Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .type_id(self.type_id())
-            .clients_count(self.clients_count())
+            .id(self.id())
+            .minimal_slot(self.minimal_slot())
+            .maximal_slot(self.maximal_slot())
+            .tip_header_root(self.tip_header_root())
+            .headers_mmr_root(self.headers_mmr_root())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ClientTypeArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ClientTypeArgsReader<'r> {
+pub struct ClientV0Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ClientV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -8939,38 +17993,50 @@ impl<'r> ::core::fmt::LowerHex for ClientTypeArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ClientTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for ClientV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ClientTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Display for ClientV0Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "type_id", self.type_id())?;
-        write!(f, ", {}: {}", "clients_count", self.clients_count())?;
+        write!(f, "{}: {}", "id", self.id())?;
+        write!(f, ", {}: {}", "minimal_slot", self.minimal_slot())?;
+        write!(f, ", {}: {}", "maximal_slot", self.maximal_slot())?;
+        write!(f, ", {}: {}", "tip_header_root", self.tip_header_root())?;
+        write!(f, ", {}: {}", "headers_mmr_root", self.headers_mmr_root())?;
         write!(f, " }}")
     }
 }
-impl<'r> ClientTypeArgsReader<'r> {
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZES: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_id(&self) -> HashReader<'r> {
-        HashReader::new_unchecked(&self.as_slice()[0..32])
+impl<'r> ClientV0Reader<'r> {
+    pub const TOTAL_SIZE: usize = 81;
+    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn clients_count(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[32..33])
+    pub fn minimal_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[1..9])
+    }
+    pub fn maximal_slot(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[9..17])
+    }
+    pub fn tip_header_root(&self) -> HashReader<'r> {
+        HashReader::new_unchecked(&self.as_slice()[17..49])
+    }
+    pub fn headers_mmr_root(&self) -> HeaderDigestReader<'r> {
+        HeaderDigestReader::new_unchecked(&self.as_slice()[49..81])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ClientTypeArgsReader<'r> {
-    type Entity = ClientTypeArgs;
-    const NAME: &'static str = "ClientTypeArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for ClientV0Reader<'r> {
+    type Entity = ClientV0;
+    const NAME: &'static str = "ClientV0Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ClientTypeArgsReader(slice)
+        ClientV0Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -8985,38 +18051,56 @@ impl<'r> molecule::prelude::Reader<'r> for ClientTypeArgsReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct ClientTypeArgsBuilder {
-    pub(crate) type_id: Hash,
-    pub(crate) clients_count: Byte,
+pub struct ClientV0Builder {
+    pub(crate) id: Byte,
+    pub(crate) minimal_slot: Uint64,
+    pub(crate) maximal_slot: Uint64,
+    pub(crate) tip_header_root: Hash,
+    pub(crate) headers_mmr_root: HeaderDigest,
 }
-impl ClientTypeArgsBuilder {
-    pub const TOTAL_SIZE: usize = 33;
-    pub const FIELD_SIZES: [usize; 2] = [32, 1];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn type_id(mut self, v: Hash) -> Self {
-        self.type_id = v;
+impl ClientV0Builder {
+    pub const TOTAL_SIZE: usize = 81;
+    pub const FIELD_SIZES: [usize; 5] = [1, 8, 8, 32, 32];
+    pub const FIELD_COUNT: usize = 5;
+    pub fn id(mut self, v: Byte) -> Self {
+        self.id = v;
         self
     }
-    pub fn clients_count(mut self, v: Byte) -> Self {
-        self.clients_count = v;
+    pub fn minimal_slot(mut self, v: Uint64) -> Self {
+        self.minimal_slot = v;
+        self
+    }
+    pub fn maximal_slot(mut self, v: Uint64) -> Self {
+        self.maximal_slot = v;
+        self
+    }
+    pub fn tip_header_root(mut self, v: Hash) -> Self {
+        self.tip_header_root = v;
+        self
+    }
+    pub fn headers_mmr_root(mut self, v: HeaderDigest) -> Self {
+        self.headers_mmr_root = v;
         self
     }
 }
-impl molecule::prelude::Builder for ClientTypeArgsBuilder {
-    type Entity = ClientTypeArgs;
-    const NAME: &'static str = "ClientTypeArgsBuilder";
+impl molecule::prelude::Builder for ClientV0Builder {
+    type Entity = ClientV0;
+    const NAME: &'static str = "ClientV0Builder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.type_id.as_slice())?;
-        writer.write_all(self.clients_count.as_slice())?;
+        writer.write_all(self.id.as_slice())?;
+        writer.write_all(self.minimal_slot.as_slice())?;
+        writer.write_all(self.maximal_slot.as_slice())?;
+        writer.write_all(self.tip_header_root.as_slice())?;
+        writer.write_all(self.headers_mmr_root.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ClientTypeArgs::new_unchecked(inner.into())
+        ClientV0::new_unchecked(inner.into())
     }
 }
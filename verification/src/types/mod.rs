@@ -8,4 +8,8 @@ pub mod prelude;
 mod conversion;
 mod extension;
 
+pub use conversion::{
+    decode_client, decode_client_info, decode_legacy_witness, decode_witness, LegacyWitnessType,
+    WitnessType, BARE_WITNESS_VERSION, WITNESS_ENVELOPE_VERSION,
+};
 pub use generated::packed;
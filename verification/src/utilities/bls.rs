@@ -5,12 +5,119 @@
 
 use alloc::vec::Vec;
 
-use bls::{AggregateSignature, Error as BlsError, PublicKey, PublicKeyBytes};
+use bls::{AggregatePublicKey, AggregateSignature, Error as BlsError, PublicKey, PublicKeyBytes};
 use ssz_types::{typenum, FixedVector};
 use tree_hash::{Hash256, TreeHash as _};
 use tree_hash_derive::TreeHash;
 
-use crate::{consensus_specs::helpers, types::core};
+use crate::{
+    consensus_specs::ChainConfig,
+    types::{core, packed, prelude::*},
+};
+
+/// The modulus of the base field of the BLS12-381 curve, in big-endian.
+const BLS12_381_FIELD_MODULUS: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x9a, 0x4b, 0x1b, 0xa7, 0xb6, 0x43, 0x4b, 0xac, 0xd7,
+    0x64, 0x77, 0x4b, 0x84, 0xf3, 0x85, 0x12, 0xbf, 0x67, 0x30, 0xd2, 0xa0, 0xf6, 0xb0, 0xf6, 0x24,
+    0x1e, 0xab, 0xff, 0xfe, 0xb1, 0x53, 0xff, 0xff, 0xb9, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xaa, 0xab,
+];
+
+/// The strategies to aggregate the public keys of the participants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggregationStrategy {
+    /// Adds up the public keys of all participants.
+    Addition,
+    /// Subtracts the public keys of all non-participants from the aggregate public key of the
+    /// whole sync committee.
+    Subtraction,
+}
+
+/// The packed client sync committee, which is stored in the compressed or the uncompressed
+/// encoding.
+pub trait PackedClientSyncCommittee<'r>: Reader<'r> {
+    /// Returns the period of the client sync committee.
+    fn sync_committee_period(&self) -> u64;
+
+    /// Loads the actual public keys of all members.
+    fn load_all_pubkeys(&self) -> Result<Vec<PublicKey>, BlsError>;
+
+    /// Loads the actual aggregate public key of all members.
+    fn load_aggregate_pubkey(&self) -> Result<PublicKey, BlsError>;
+
+    /// Returns the count of the public keys of all members.
+    fn pubkeys_count(&self) -> usize;
+
+    /// Calculates the tree hash root of the sync committee.
+    ///
+    /// The uncompressed public keys are validated during the calculation.
+    fn sync_committee_root(&self) -> Result<core::Hash, BlsError>;
+
+    /// Converts into the client sync committee which public keys are compressed.
+    ///
+    /// The uncompressed public keys are validated during the conversion.
+    fn to_client_sync_committee(&self) -> Result<core::ClientSyncCommittee, BlsError>;
+}
+
+impl<'r> PackedClientSyncCommittee<'r> for packed::ClientSyncCommitteeReader<'r> {
+    fn sync_committee_period(&self) -> u64 {
+        self.period().unpack()
+    }
+
+    fn load_all_pubkeys(&self) -> Result<Vec<PublicKey>, BlsError> {
+        self.data()
+            .pubkeys()
+            .iter()
+            .map(|pubkey| PublicKey::deserialize(pubkey.as_slice()))
+            .collect()
+    }
+
+    fn load_aggregate_pubkey(&self) -> Result<PublicKey, BlsError> {
+        PublicKey::deserialize(self.data().aggregate_pubkey().as_slice())
+    }
+
+    fn pubkeys_count(&self) -> usize {
+        self.data().pubkeys().len()
+    }
+
+    fn sync_committee_root(&self) -> Result<core::Hash, BlsError> {
+        Ok(self.data().tree_hash_root())
+    }
+
+    fn to_client_sync_committee(&self) -> Result<core::ClientSyncCommittee, BlsError> {
+        Ok(self.unpack())
+    }
+}
+
+impl<'r> PackedClientSyncCommittee<'r> for packed::ClientUncompressedSyncCommitteeReader<'r> {
+    fn sync_committee_period(&self) -> u64 {
+        self.period().unpack()
+    }
+
+    fn load_all_pubkeys(&self) -> Result<Vec<PublicKey>, BlsError> {
+        self.data().unpack().load_all_pubkeys()
+    }
+
+    fn load_aggregate_pubkey(&self) -> Result<PublicKey, BlsError> {
+        PublicKey::deserialize_uncompressed(self.data().aggregate_pubkey().as_slice())
+    }
+
+    fn pubkeys_count(&self) -> usize {
+        self.data().pubkeys().len()
+    }
+
+    fn sync_committee_root(&self) -> Result<core::Hash, BlsError> {
+        self.to_client_sync_committee()
+            .map(|client_sync_committee| client_sync_committee.data.tree_hash_root())
+    }
+
+    fn to_client_sync_committee(&self) -> Result<core::ClientSyncCommittee, BlsError> {
+        let client_sync_committee = core::ClientSyncCommittee {
+            period: self.period().unpack(),
+            data: self.data().unpack().compress()?,
+        };
+        Ok(client_sync_committee)
+    }
+}
 
 impl core::SyncCommittee {
     /// Decompress the bytes of public keys into actual public keys.
@@ -20,46 +127,247 @@ impl core::SyncCommittee {
             .map(PublicKeyBytes::decompress)
             .collect()
     }
+
+    /// Decompress all public keys, then store them as uncompressed bytes.
+    pub fn uncompress(&self) -> Result<core::UncompressedSyncCommittee, BlsError> {
+        let pubkeys = self
+            .decompress_all_pubkeys()?
+            .iter()
+            .map(|pubkey| pubkey.serialize_uncompressed().into())
+            .collect();
+        let aggregate_pubkey = self
+            .aggregate_pubkey
+            .decompress()?
+            .serialize_uncompressed()
+            .into();
+        let data = core::UncompressedSyncCommittee {
+            pubkeys,
+            aggregate_pubkey,
+        };
+        Ok(data)
+    }
+}
+
+impl core::UncompressedSyncCommittee {
+    /// Loads the actual public keys from the uncompressed bytes, no decompression is required.
+    ///
+    /// N.B. Neither the subgroup check nor the infinity check is done, so the uncompressed
+    /// public keys should be validated before they are stored.
+    pub fn load_all_pubkeys(&self) -> Result<Vec<PublicKey>, BlsError> {
+        self.pubkeys
+            .iter()
+            .map(|pubkey| PublicKey::deserialize_uncompressed(pubkey.as_ref()))
+            .collect()
+    }
+
+    /// Validates all uncompressed public keys, then compress them.
+    pub fn compress(&self) -> Result<core::SyncCommittee, BlsError> {
+        let pubkeys = self
+            .pubkeys
+            .iter()
+            .map(core::BlsUncompressedPubkey::validate_and_compress)
+            .collect::<Result<_, _>>()?;
+        let aggregate_pubkey = self.aggregate_pubkey.validate_and_compress()?;
+        let data = core::SyncCommittee {
+            pubkeys,
+            aggregate_pubkey,
+        };
+        Ok(data)
+    }
+}
+
+impl core::ClientSyncCommittee {
+    /// Converts into the client sync committee which public keys are uncompressed.
+    pub fn uncompress(&self) -> Result<core::ClientUncompressedSyncCommittee, BlsError> {
+        let client_sync_committee = core::ClientUncompressedSyncCommittee {
+            period: self.period,
+            data: self.data.uncompress()?,
+        };
+        Ok(client_sync_committee)
+    }
+}
+
+impl core::BlsUncompressedPubkey {
+    /// Validates the uncompressed public key, then compress it.
+    ///
+    /// All checks for the uncompressed public key are same as the checks in decompression.
+    pub fn validate_and_compress(&self) -> Result<core::BlsPubkey, BlsError> {
+        let compressed = PublicKey::deserialize_uncompressed(self.as_ref())?.compress();
+        // Decompress the compressed public key to do the subgroup check and the infinity check.
+        let _ = compressed.decompress()?;
+        Ok(compressed)
+    }
 }
 
 impl core::SyncAggregate {
     /// Verifies the signature against the given public keys and one message.
     ///
+    /// The signature is invalid if any bit after the public keys is set.
+    ///
     /// N.B. `pubkeys.len()` should be checked.
     pub fn fast_aggregate_verify(
         &self,
         pubkeys: &[PublicKey],
         message: Hash256,
     ) -> Result<bool, BlsError> {
+        if self.count_participants(pubkeys.len()).is_none() {
+            return Ok(false);
+        }
+        let participants_pubkey = profile!(
+            AggregatePubkeys,
+            self.aggregate_participant_pubkeys(pubkeys)
+        )?;
+        self.verify_signature(&participants_pubkey, message)
+    }
+
+    /// Same as [`Self::fast_aggregate_verify`], but the public keys of the participants are
+    /// aggregated by subtracting the non-participants from the aggregate public key.
+    ///
+    /// N.B. `pubkeys.len()` should be checked, and `aggregate_pubkey` should be the aggregate
+    /// public key of `pubkeys`.
+    pub fn fast_aggregate_verify_by_subtraction(
+        &self,
+        pubkeys: &[PublicKey],
+        aggregate_pubkey: &PublicKey,
+        message: Hash256,
+    ) -> Result<bool, BlsError> {
+        if self.count_participants(pubkeys.len()).is_none() {
+            return Ok(false);
+        }
+        let participants_pubkey = profile!(
+            AggregatePubkeys,
+            self.aggregate_participant_pubkeys_by_subtraction(pubkeys, aggregate_pubkey)
+        )?;
+        self.verify_signature(&participants_pubkey, message)
+    }
+
+    // Verifies the signature with the aggregate public key of the participants.
+    fn verify_signature(
+        &self,
+        participants_pubkey: &PublicKey,
+        message: Hash256,
+    ) -> Result<bool, BlsError> {
+        profile!(
+            Pairing,
+            AggregateSignature::deserialize(self.sync_committee_signature.as_ref())
+                .map(|signature| signature.fast_aggregate_verify(message, &[participants_pubkey]))
+        )
+    }
+
+    /// Selects the aggregation strategy which requires less point additions.
+    pub fn aggregation_strategy(&self) -> AggregationStrategy {
+        self.aggregation_strategy_with_config(&ChainConfig::MAINNET)
+    }
+
+    /// Same as [`Self::aggregation_strategy`], with the sync committee size of the chain.
+    pub fn aggregation_strategy_with_config(&self, config: &ChainConfig) -> AggregationStrategy {
+        let total = config.sync_committee_size;
+        let ones = self.count_participants(total).unwrap_or_default();
+        if ones * 2 > total {
+            AggregationStrategy::Subtraction
+        } else {
+            AggregationStrategy::Addition
+        }
+    }
+
+    /// Aggregates the public keys of all participants by adding them up.
+    ///
+    /// N.B. `pubkeys.len()` should be checked.
+    pub fn aggregate_participant_pubkeys(
+        &self,
+        pubkeys: &[PublicKey],
+    ) -> Result<PublicKey, BlsError> {
         let participant_pubkeys = self.filter_participant_pubkeys(pubkeys);
-        AggregateSignature::deserialize(self.sync_committee_signature.as_ref())
-            .map(|signature| signature.fast_aggregate_verify(message, &participant_pubkeys))
+        AggregatePublicKey::aggregate(&participant_pubkeys)?.to_public_key()
+    }
+
+    /// Aggregates the public keys of all participants by subtracting the public keys of all
+    /// non-participants from the aggregate public key.
+    ///
+    /// N.B. `pubkeys.len()` should be checked, and `aggregate_pubkey` should be the aggregate
+    /// public key of `pubkeys`.
+    pub fn aggregate_participant_pubkeys_by_subtraction(
+        &self,
+        pubkeys: &[PublicKey],
+        aggregate_pubkey: &PublicKey,
+    ) -> Result<PublicKey, BlsError> {
+        let negated_pubkeys = self
+            .filter_non_participant_pubkeys(pubkeys)
+            .into_iter()
+            .map(negate_pubkey)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut summands = Vec::with_capacity(negated_pubkeys.len() + 1);
+        summands.push(aggregate_pubkey);
+        summands.extend(negated_pubkeys.iter());
+        AggregatePublicKey::aggregate(&summands)?.to_public_key()
     }
 
     /// Filters the participant public keys with a bits vector.
     ///
-    /// N.B. `pubkeys.len() == self.sync_committee_bits.bits_size()` should be checked.
+    /// N.B. `pubkeys.len()` should be checked.
     pub fn filter_participant_pubkeys<'a>(&self, pubkeys: &'a [PublicKey]) -> Vec<&'a PublicKey> {
+        self.filter_pubkeys(pubkeys, true)
+    }
+
+    /// Filters the non-participant public keys with a bits vector.
+    ///
+    /// N.B. `pubkeys.len()` should be checked.
+    pub fn filter_non_participant_pubkeys<'a>(
+        &self,
+        pubkeys: &'a [PublicKey],
+    ) -> Vec<&'a PublicKey> {
+        self.filter_pubkeys(pubkeys, false)
+    }
+
+    fn filter_pubkeys<'a>(
+        &self,
+        pubkeys: &'a [PublicKey],
+        participated: bool,
+    ) -> Vec<&'a PublicKey> {
         let bits = self.sync_committee_bits.as_ref();
-        assert_eq!(pubkeys.len(), bits.len() * 8);
+        // The bits after the public keys are the padding of a smaller sync committee, they are
+        // rejected before the signature verification.
+        assert!(pubkeys.len() <= bits.len() * 8);
         pubkeys
             .iter()
             .enumerate()
             .filter_map(|(i, pubkey)| {
                 let x = i / 8;
                 let y = i % 8;
-                if bits[x] & (1 << y) == 0 {
-                    None
-                } else {
+                if (bits[x] & (1 << y) != 0) == participated {
                     Some(pubkey)
+                } else {
+                    None
                 }
             })
             .collect()
     }
 }
 
+/// Negates a public key, the result is the additive inverse of the G1 point.
+///
+/// The negation of `(x, y)` is `(x, p - y)`, which `p` is the modulus of the base field, so it's
+/// done on the uncompressed bytes directly.
+pub fn negate_pubkey(pubkey: &PublicKey) -> Result<PublicKey, BlsError> {
+    let mut bytes = pubkey.serialize_uncompressed();
+    let y = &mut bytes[48..];
+    if y.iter().any(|byte| *byte != 0) {
+        let mut borrow = false;
+        for (y_byte, p_byte) in y.iter_mut().zip(BLS12_381_FIELD_MODULUS.iter()).rev() {
+            let (diff, borrow_1) = p_byte.overflowing_sub(*y_byte);
+            let (diff, borrow_2) = diff.overflowing_sub(u8::from(borrow));
+            *y_byte = diff;
+            borrow = borrow_1 || borrow_2;
+        }
+    }
+    PublicKey::deserialize_uncompressed(&bytes)
+}
+
 /// Computes the signing root for the corresponding data at the given `signature_slot`.
+///
+/// The fork version is chosen by the fork schedule of the chain.
 pub fn compute_signing_root_at_signature_slot(
+    config: &ChainConfig,
     signed_root: Hash256,
     signature_slot: u64,
     domain_type: &[u8; 4],
@@ -70,8 +378,7 @@ pub fn compute_signing_root_at_signature_slot(
     } else {
         0
     };
-    let fork_version =
-        helpers::compute_fork_version(helpers::compute_epoch_at_slot(fork_version_slot));
+    let fork_version = config.compute_fork_version_at_slot(fork_version_slot);
     let domain = compute_domain(domain_type, &fork_version, genesis_validators_root);
     compute_signing_root(signed_root, domain)
 }
@@ -146,3 +453,16 @@ pub fn compute_fork_data_root(
 ) -> Hash256 {
     ForkData::new(current_version, genesis_validators_root).tree_hash_root()
 }
+
+/// Returns the 4-byte fork digest for the ``current_version`` and ``genesis_validators_root``.
+/// This is a digest primarily used for domain separation on the p2p layer.
+///
+/// See [`compute_fork_digest`].
+///
+/// [`compute_fork_digest`]: https://github.com/ethereum/consensus-specs/blob/v1.0.0/specs/phase0/beacon-chain.md#compute_fork_digest
+pub fn compute_fork_digest(current_version: &[u8; 4], genesis_validators_root: Hash256) -> [u8; 4] {
+    let fork_data_root = compute_fork_data_root(current_version, genesis_validators_root);
+    let mut fork_digest = [0u8; 4];
+    fork_digest.copy_from_slice(&fork_data_root[..4]);
+    fork_digest
+}
@@ -6,7 +6,12 @@ use ::core::cmp::PartialEq;
 #[cfg(feature = "std")]
 use alloc::fmt;
 
-use ckb_mmr::{Merge, MerkleProof, Result as MMRResult, MMR};
+use alloc::vec::Vec;
+
+use ckb_mmr::{
+    leaf_index_to_mmr_size, leaf_index_to_pos, Error as MMRError, Merge, MerkleProof,
+    Result as MMRResult, MMR,
+};
 use eth2_hashing::{hash32_concat, hash_fixed, HASH_LEN};
 use tree_hash::{Hash256, TreeHash as _};
 
@@ -60,7 +65,7 @@ impl fmt::Display for HeaderWithCache {
 impl core::Header {
     /// Calculates the root of a header and caches the root.
     pub fn calc_cache(self) -> HeaderWithCache {
-        let root = self.tree_hash_root();
+        let root = profile!(TreeHash, self.tree_hash_root());
         HeaderWithCache { inner: self, root }
     }
 }
@@ -121,3 +126,70 @@ impl Merge for MergeHeaderDigest {
         Self::merge(rhs, lhs)
     }
 }
+
+/// Verifies the previous root with an incremental MMR proof, which is generated for all leaves
+/// after the first `prev_leaves_count` leaves, then calculates the new root.
+///
+/// It's same as `MerkleProof::verify_incremental`, but the new root is returned, so it's only
+/// calculated once.
+///
+/// Returns `None` if the previous root is not matched.
+pub fn calculate_incremental_root(
+    proof: &MMRProof,
+    prev_root: &packed::HeaderDigest,
+    prev_leaves_count: u64,
+    incremental: Vec<packed::HeaderDigest>,
+) -> MMRResult<Option<packed::HeaderDigest>> {
+    if prev_leaves_count == 0 || incremental.is_empty() {
+        return Err(MMRError::CorruptedProof);
+    }
+    // The proof items are the previous peaks: the peaks which are still peaks are in order, then
+    // the peaks which are merged with the incremental leaves are from the lowest to the highest.
+    let prev_peaks_positions = get_peaks(leaf_index_to_mmr_size(prev_leaves_count - 1));
+    if prev_peaks_positions.len() != proof.proof_items().len() {
+        return Err(MMRError::CorruptedProof);
+    }
+    let curr_peaks_positions = get_peaks(proof.mmr_size());
+    let kept_peaks_count = prev_peaks_positions
+        .iter()
+        .zip(curr_peaks_positions.iter())
+        .take_while(|(prev, curr)| prev == curr)
+        .count();
+    let mut prev_peaks = proof.proof_items().to_vec();
+    prev_peaks[kept_peaks_count..].reverse();
+    // Bag the peaks from right to left.
+    while prev_peaks.len() > 1 {
+        let right_peak = prev_peaks.pop().expect("pop");
+        let left_peak = prev_peaks.pop().expect("pop");
+        prev_peaks.push(MergeHeaderDigest::merge_peaks(&right_peak, &left_peak)?);
+    }
+    let calculated_prev_root = prev_peaks.pop().ok_or(MMRError::CorruptedProof)?;
+    if &calculated_prev_root != prev_root {
+        return Ok(None);
+    }
+    let leaves = incremental
+        .into_iter()
+        .enumerate()
+        .map(|(offset, leaf)| (leaf_index_to_pos(prev_leaves_count + offset as u64), leaf))
+        .collect();
+    proof.calculate_root(leaves).map(Some)
+}
+
+// Returns the positions of the peaks in an MMR, from left to right.
+//
+// The MMR is split into perfect binary trees greedily, from the highest to the lowest.
+fn get_peaks(mmr_size: u64) -> Vec<u64> {
+    let mut peaks = Vec::new();
+    let mut start = 0;
+    let mut remaining = mmr_size;
+    while remaining > 0 {
+        let mut tree_size = 1;
+        while tree_size * 2 + 1 <= remaining {
+            tree_size = tree_size * 2 + 1;
+        }
+        start += tree_size;
+        remaining -= tree_size;
+        peaks.push(start - 1);
+    }
+    peaks
+}
@@ -48,11 +48,14 @@ pub fn keccak256(slice: &[u8]) -> [u8; 32] {
 }
 
 /// Verify a Merkle-Patricia Trie (MPT) Proof.
-pub fn verify_proof(proof: &[Vec<u8>], root: &[u8], path: &[u8], value: &[u8]) -> bool {
+///
+/// The nodes in the proof could be owned or borrowed bytes.
+pub fn verify_proof<T: AsRef<[u8]>>(proof: &[T], root: &[u8], path: &[u8], value: &[u8]) -> bool {
     let mut expected_hash = root.to_owned();
     let mut path_offset = 0;
 
     for (i, node) in proof.iter().enumerate() {
+        let node = node.as_ref();
         if expected_hash != keccak256(node).to_vec() {
             return false;
         }
@@ -23,6 +23,106 @@ pub const fn floor_depth(num: usize) -> u32 {
     }
 }
 
+/// The layout of an SSZ container: the names of all fields, in order.
+///
+/// It's used to compute generalized indexes at compile time.
+pub struct ContainerLayout {
+    fields: &'static [&'static str],
+}
+
+impl ContainerLayout {
+    pub const fn new(fields: &'static [&'static str]) -> Self {
+        Self { fields }
+    }
+
+    /// The names of the fields.
+    pub const fn fields(&self) -> &'static [&'static str] {
+        self.fields
+    }
+
+    /// The count of the fields.
+    pub const fn fields_count(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// The depth of the merkle tree of the fields.
+    pub const fn depth(&self) -> u32 {
+        ceil_depth(self.fields.len())
+    }
+
+    /// The index of a field; panics if the field doesn't exist.
+    pub const fn field_index(&self, name: &str) -> usize {
+        let mut index = 0;
+        while index < self.fields.len() {
+            if str_eq(self.fields[index], name) {
+                return index;
+            }
+            index += 1;
+        }
+        panic!("the field doesn't exist in the container");
+    }
+
+    /// The generalized index of a field in the container.
+    ///
+    /// See `get_generalized_index` in [Generalized Merkle tree index] for more details.
+    ///
+    /// [Generalized Merkle tree index]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/ssz/merkle-proofs.md#generalized-merkle-tree-index
+    pub const fn generalized_index(&self, name: &str) -> usize {
+        2usize.pow(self.depth()) + self.field_index(name)
+    }
+}
+
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let lhs = lhs.as_bytes();
+    let rhs = rhs.as_bytes();
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < lhs.len() {
+        if lhs[index] != rhs[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// The generalized index of an item in a vector.
+///
+/// See `get_generalized_index` in [Generalized Merkle tree index] for more details.
+///
+/// [Generalized Merkle tree index]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/ssz/merkle-proofs.md#generalized-merkle-tree-index
+pub const fn get_generalized_index_of_vector_item(length: usize, index: usize) -> usize {
+    2usize.pow(ceil_depth(length)) + index
+}
+
+/// The generalized index of an item in a list, the length of the list is mixed in.
+///
+/// See `get_generalized_index` in [Generalized Merkle tree index] for more details.
+///
+/// [Generalized Merkle tree index]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/ssz/merkle-proofs.md#generalized-merkle-tree-index
+pub const fn get_generalized_index_of_list_item(limit: usize, index: usize) -> usize {
+    2usize.pow(ceil_depth(limit) + 1) + index
+}
+
+/// Concatenates generalized indexes, from the top to the bottom.
+///
+/// See `concat_generalized_indices` in [Merkle multiproofs] for more details.
+///
+/// [Merkle multiproofs]: https://github.com/ethereum/consensus-specs/blob/v1.1.0/ssz/merkle-proofs.md#merkle-multiproofs
+pub const fn concat_generalized_indices(indices: &[usize]) -> usize {
+    let mut output = 1;
+    let mut i = 0;
+    while i < indices.len() {
+        let index = indices[i];
+        let size = 2usize.pow(floor_depth(index));
+        output = output * size + (index - size);
+        i += 1;
+    }
+    output
+}
+
 /// Merkleizes a length.
 ///
 /// See [Merkleization] for more details.
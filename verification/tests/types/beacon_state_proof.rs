@@ -0,0 +1,158 @@
+use eth2_types::{MainnetEthSpec, SyncCommittee};
+use eth_light_client_in_ckb_prover::{CachedBeaconState, SszPathError};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::forks,
+    error::BeaconStateProofError,
+    types::{core, prelude::*},
+};
+use ssz::Encode as _;
+use tree_hash::{Hash256, TreeHash as _};
+
+use crate::{
+    setup,
+    types::{build_beacon_state_in_capella, load_bootstrap},
+};
+
+const CURRENT_SYNC_COMMITTEE_INDEX: u64 =
+    forks::capella::generalized_index::beacon_state::CURRENT_SYNC_COMMITTEE_INDEX as u64;
+
+#[test]
+fn mainnet_testcase_in_capella() {
+    setup();
+
+    let bootstrap = load_bootstrap(6632736);
+    let client = bootstrap.build_client();
+    let current_sync_committee = bootstrap.original().current_sync_committee.as_ref().clone();
+
+    let proof = core::BeaconStateProof {
+        header: bootstrap.original().header.clone().into(),
+        header_mmr_proof: Vec::new(),
+        generalized_index: CURRENT_SYNC_COMMITTEE_INDEX,
+        value: current_sync_committee.as_ssz_bytes(),
+        state_ssz_proof: bootstrap.original().current_sync_committee_branch.to_vec(),
+    };
+
+    let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+        proof.pack().as_reader(),
+        CURRENT_SYNC_COMMITTEE_INDEX,
+    );
+    let (status, value) = result.ok().unwrap();
+    assert_eq!(status, core::HeaderStatus::Finalized);
+    assert_eq!(value, current_sync_committee);
+
+    // The proof is same after packing and unpacking.
+    let unpacked_proof: core::BeaconStateProof = proof.pack().unpack();
+    assert_eq!(unpacked_proof.pack().as_slice(), proof.pack().as_slice());
+
+    // The field should be the expected one.
+    {
+        let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+            proof.pack().as_reader(),
+            CURRENT_SYNC_COMMITTEE_INDEX + 1,
+        );
+        assert!(matches!(
+            result,
+            Err(BeaconStateProofError::MismatchedGeneralizedIndex)
+        ));
+    }
+
+    // The header should be in the client.
+    {
+        let other_client = load_bootstrap(6184960).build_client();
+        let result = other_client
+            .verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+                proof.pack().as_reader(),
+                CURRENT_SYNC_COMMITTEE_INDEX,
+            );
+        assert!(matches!(result, Err(BeaconStateProofError::Unsynchronized)));
+    }
+
+    // The value should be decoded as the expected type.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.value.pop();
+        let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+            wrong_proof.pack().as_reader(),
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        );
+        assert!(matches!(result, Err(BeaconStateProofError::SszError)));
+    }
+
+    // The value should be proven by the state root.
+    {
+        let other_sync_committee = load_bootstrap(6184960)
+            .original()
+            .current_sync_committee
+            .as_ref()
+            .clone();
+        let wrong_proof = core::BeaconStateProof {
+            value: other_sync_committee.as_ssz_bytes(),
+            ..proof.clone()
+        };
+        let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+            wrong_proof.pack().as_reader(),
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        );
+        assert!(matches!(result, Err(BeaconStateProofError::StateSszProof)));
+    }
+
+    // The length of the branch should match the generalized index.
+    {
+        let mut wrong_proof = proof;
+        wrong_proof.state_ssz_proof.pop();
+        let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+            wrong_proof.pack().as_reader(),
+            CURRENT_SYNC_COMMITTEE_INDEX,
+        );
+        assert!(matches!(result, Err(BeaconStateProofError::StateSszProof)));
+    }
+}
+
+#[test]
+fn testcase_build_beacon_state_proof_from_beacon_state() {
+    setup();
+
+    let state = CachedBeaconState::try_from(build_beacon_state_in_capella(6632736, 3)).unwrap();
+    assert_eq!(state.state_root(), state.original().tree_hash_root());
+
+    let header = core::Header {
+        slot: 6632736,
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: state.state_root(),
+        body_root: Hash256::repeat_byte(2),
+    };
+    let client = header.initialize_client();
+    let current_sync_committee = state
+        .original()
+        .current_sync_committee()
+        .unwrap()
+        .as_ref()
+        .clone();
+
+    let proof = state
+        .build_beacon_state_proof(
+            "current_sync_committee",
+            &current_sync_committee,
+            &header,
+            Vec::new(),
+        )
+        .unwrap();
+    assert_eq!(proof.generalized_index, CURRENT_SYNC_COMMITTEE_INDEX);
+    let result = client.verify_packed_beacon_state_proof::<SyncCommittee<MainnetEthSpec>>(
+        proof.pack().as_reader(),
+        CURRENT_SYNC_COMMITTEE_INDEX,
+    );
+    let (status, value) = result.ok().unwrap();
+    assert_eq!(status, core::HeaderStatus::Finalized);
+    assert_eq!(value, current_sync_committee);
+
+    // The path should be a field in the beacon state.
+    let result = state.build_beacon_state_proof(
+        "unknown_sync_committee",
+        &current_sync_committee,
+        &header,
+        Vec::new(),
+    );
+    assert!(matches!(result, Err(SszPathError::UnknownField(_))));
+}
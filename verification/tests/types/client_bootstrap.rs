@@ -1,10 +1,18 @@
-use std::fs;
+use std::{fs, io, sync::Arc};
 
-use eth2_types::{light_client_bootstrap::PatchedLightClientBootstrap, MainnetEthSpec};
-use eth_light_client_in_ckb_prover::LightClientBootstrap;
-use eth_light_client_in_ckb_verification::types::prelude::*;
+use eth2_types::{
+    light_client_bootstrap::PatchedLightClientBootstrap, BeaconBlockHeader, MainnetEthSpec,
+};
+use eth_light_client_in_ckb_prover::{
+    CachedBeaconState, LightClientBootstrap, SyntheticChain, SyntheticChainConfig,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::helpers,
+    types::{core, prelude::*},
+};
+use tree_hash::{Hash256, TreeHash as _};
 
-use crate::{find_json_files, setup};
+use crate::{find_json_files, setup, types::build_beacon_state_in_capella};
 
 #[test]
 fn mainnet_testcase_client_bootstraps() {
@@ -12,6 +20,80 @@ fn mainnet_testcase_client_bootstraps() {
     client_bootstrap(dump_dir_opt);
 }
 
+// The beacon state is consistent with the header and the sync committees of a synthetic chain,
+// the other fields are random.
+#[test]
+fn synthetic_testcase_client_bootstrap_from_beacon_state() {
+    setup();
+
+    let slot = 6632736;
+    let chain = SyntheticChain::new(SyntheticChainConfig::new(slot, slot + 1));
+    let period = helpers::compute_sync_committee_period_at_slot(slot);
+    let latest_block_header = BeaconBlockHeader {
+        slot: slot.into(),
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: Hash256::zero(),
+        body_root: Hash256::repeat_byte(2),
+    };
+    let state = {
+        let mut state = build_beacon_state_in_capella(slot, 42);
+        *state.latest_block_header_mut() = latest_block_header.clone();
+        *state.current_sync_committee_mut().unwrap() =
+            Arc::new(chain.sync_committee(period).clone());
+        *state.next_sync_committee_mut().unwrap() =
+            Arc::new(chain.sync_committee(period + 1).clone());
+        CachedBeaconState::try_from(state).unwrap()
+    };
+    assert_eq!(state.state_root(), state.original().tree_hash_root());
+    // The state root in the latest block header is filled when the next slot is processed.
+    let header = BeaconBlockHeader {
+        state_root: state.state_root(),
+        ..latest_block_header
+    };
+
+    let bootstrap = LightClientBootstrap::from_beacon_state(&state, header.clone()).unwrap();
+    assert_eq!(
+        bootstrap.original().current_sync_committee.as_ref(),
+        chain.sync_committee(period)
+    );
+
+    let client_bootstrap = bootstrap.build_client_bootstrap();
+    let packed_client_sync_committee = bootstrap.build_client_sync_committee().pack();
+    let result = client_bootstrap
+        .verify_packed_client_sync_committee(packed_client_sync_committee.as_reader());
+    assert!(result.is_ok());
+
+    // The branch should be proven by the state root.
+    {
+        let mut wrong_client_bootstrap = client_bootstrap;
+        wrong_client_bootstrap.header.state_root = Hash256::repeat_byte(3);
+        let result = wrong_client_bootstrap
+            .verify_packed_client_sync_committee(packed_client_sync_committee.as_reader());
+        assert!(result.is_err());
+    }
+
+    // The header should be at the slot of the beacon state.
+    {
+        let wrong_header = BeaconBlockHeader {
+            slot: (slot + 1).into(),
+            ..header.clone()
+        };
+        let result = LightClientBootstrap::from_beacon_state(&state, wrong_header);
+        assert!(matches!(result, Err(err) if err.kind() == io::ErrorKind::InvalidInput));
+    }
+
+    // The header should commit the beacon state.
+    {
+        let wrong_header = BeaconBlockHeader {
+            state_root: Hash256::repeat_byte(3),
+            ..header
+        };
+        let result = LightClientBootstrap::from_beacon_state(&state, wrong_header);
+        assert!(matches!(result, Err(err) if err.kind() == io::ErrorKind::InvalidInput));
+    }
+}
+
 fn client_bootstrap(dump_dir_opt: Option<&'static str>) {
     setup();
 
@@ -41,6 +123,45 @@ fn client_bootstrap(dump_dir_opt: Option<&'static str>) {
             "failed to verify client sync committee (slot: {slot})"
         );
 
+        // The tree hash root is calculated from the packed sync committee directly.
+        assert_eq!(
+            reader.data().tree_hash_root(),
+            packed_client_sync_committee
+                .data()
+                .unpack()
+                .tree_hash_root(),
+        );
+
+        // Same sync committee, but all public keys are uncompressed.
+        let mut client_uncompressed_sync_committee =
+            packed_client_sync_committee.unpack().uncompress().unwrap();
+        let packed_client_uncompressed_sync_committee = client_uncompressed_sync_committee.pack();
+        let reader = packed_client_uncompressed_sync_committee.as_reader();
+        let result = client_bootstrap.verify_packed_client_sync_committee(reader);
+        assert!(
+            result.is_ok(),
+            "failed to verify client uncompressed sync committee (slot: {slot})"
+        );
+        let compressed = client_uncompressed_sync_committee.data.compress().unwrap();
+        assert_eq!(
+            compressed.pack().as_slice(),
+            packed_client_sync_committee.data().as_slice()
+        );
+
+        // An uncompressed public key which is not on the curve.
+        let mut uncompressed_pubkey: [u8; 96] =
+            client_uncompressed_sync_committee.data.pubkeys[0].into();
+        uncompressed_pubkey[95] ^= 1;
+        client_uncompressed_sync_committee.data.pubkeys[0] =
+            core::BlsUncompressedPubkey::from(uncompressed_pubkey);
+        let packed_client_uncompressed_sync_committee = client_uncompressed_sync_committee.pack();
+        let reader = packed_client_uncompressed_sync_committee.as_reader();
+        let result = client_bootstrap.verify_packed_client_sync_committee(reader);
+        assert!(
+            result.is_err(),
+            "invalid client uncompressed sync committee should fail (slot: {slot})"
+        );
+
         let expected_packed_client = bootstrap.build_client().pack();
         let packed_client = client_bootstrap.header.initialize_client().pack();
         assert_eq!(
@@ -0,0 +1,136 @@
+use std::fs;
+
+use eth2_types::light_client_optimistic_update::LightClientOptimisticUpdate;
+use eth_light_client_in_ckb_prover::DummyLightClient;
+use eth_light_client_in_ckb_verification::types::{core, prelude::*};
+
+use crate::{
+    setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+        load_genesis_validators_root,
+    },
+};
+
+#[test]
+fn mainnet_testcase_in_capella() {
+    let param = Parameter {
+        bootstrap_slot: 6632736,
+        finalized_slots: vec![6632768, 6632800, 6632832, 6632864, 6632896, 6632928],
+        ..Default::default()
+    };
+    client_optimistic_update(param);
+}
+
+#[derive(Default)]
+struct Parameter {
+    bootstrap_slot: u64,
+    finalized_slots: Vec<u64>,
+    dump_dir_opt: Option<&'static str>,
+}
+
+fn client_optimistic_update(param: Parameter) {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let mut light_client = DummyLightClient::new(load_bootstrap(param.bootstrap_slot));
+    let packed_client_sync_committee = light_client.client_sync_committee().pack();
+
+    let mut client = light_client.client().clone();
+
+    for finalized_slot in param.finalized_slots {
+        let finality_update = load_finality_update(finalized_slot);
+        // The attested part of a finality update is an optimistic update.
+        let optimistic_update = LightClientOptimisticUpdate {
+            attested_header: finality_update.attested_header.clone(),
+            sync_aggregate: finality_update.sync_aggregate.clone(),
+            signature_slot: finality_update.signature_slot,
+        };
+
+        // Finalize headers, the optimistic header should be kept if it's still newer.
+        {
+            let headers = ((client.maximal_slot + 1)..=finalized_slot)
+                .map(load_beacon_block_header_from_json_or_create_default)
+                .collect::<Vec<_>>();
+            let client_update = light_client.apply_finality_update(finality_update, headers);
+            let new_client = light_client.client().clone();
+            let result = client_update.verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(result.is_ok());
+            client = new_client;
+        }
+
+        // Update the optimistic header.
+        {
+            let client_optimistic_update = light_client.apply_optimistic_update(optimistic_update);
+            let new_client = light_client.client().clone();
+            assert_eq!(
+                new_client.optimistic_slot,
+                client_optimistic_update.attested_header.slot
+            );
+            let result = client_optimistic_update.verify_client_optimistic_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(result.is_ok());
+
+            // An optimistic update could not be applied twice.
+            let result = client_optimistic_update.verify_client_optimistic_update(
+                new_client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(result.is_err());
+
+            if let Some(dump_dir) = param.dump_dir_opt {
+                let packed_client_optimistic_update = client_optimistic_update.pack();
+                let update_filepath = format!(
+                    "{dump_dir}/client_optimistic_update-{:09}.data",
+                    client_optimistic_update.attested_header.slot
+                );
+                fs::write(update_filepath, packed_client_optimistic_update.as_slice()).unwrap();
+                let packed_client = new_client.pack();
+                let client_filepath = format!(
+                    "{dump_dir}/client-{:09}_{finalized_slot:09}-{:09}.data",
+                    param.bootstrap_slot, new_client.optimistic_slot
+                );
+                fs::write(client_filepath, packed_client.as_slice()).unwrap();
+            }
+
+            client = new_client;
+        }
+
+        // Check the status of proven headers.
+        {
+            let finalized_header = light_client.beacon_header_at_slot(finalized_slot).unwrap();
+            let proof = core::TransactionProof {
+                header: finalized_header.inner.clone(),
+                transaction_index: 0,
+                receipts_root: Default::default(),
+                header_mmr_proof: light_client.build_header_mmr_proof(finalized_slot),
+                transaction_ssz_proof: Vec::new(),
+                receipt_mpt_proof: Vec::new(),
+                receipts_root_ssz_proof: Vec::new(),
+            };
+            let result = client.verify_packed_transaction_proof(proof.pack().as_reader());
+            assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+
+            let optimistic_header = light_client.optimistic_header().unwrap();
+            let proof = core::TransactionProof {
+                header: optimistic_header.inner.clone(),
+                header_mmr_proof: Vec::new(),
+                ..proof
+            };
+            let result = client.verify_packed_transaction_proof(proof.pack().as_reader());
+            assert!(matches!(result, Ok(core::HeaderStatus::Optimistic)));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use eth_light_client_in_ckb_prover::DummyLightClient;
+use eth_light_client_in_ckb_verification::{
+    error::SyncCommitteeCommitmentError,
+    types::{core, prelude::*},
+};
+
+use crate::{
+    setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+        load_genesis_validators_root,
+    },
+};
+
+#[test]
+fn mainnet_testcase_in_capella() {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let mut light_client = DummyLightClient::new(load_bootstrap(6632736));
+    let client_sync_committee = light_client.client_sync_committee().clone();
+    let packed_client_sync_committee = client_sync_committee.pack();
+    let packed_client_uncompressed_sync_committee =
+        client_sync_committee.uncompress().unwrap().pack();
+
+    let commitment = client_sync_committee.commitment();
+    let packed_commitment = commitment.pack();
+    assert!(packed_commitment.as_slice().len() < 64);
+    assert!(packed_client_sync_committee.as_slice().len() > 24 * 1024);
+
+    // The commitment is same after packing and unpacking.
+    let unpacked_commitment: core::ClientSyncCommitteeCommitment = packed_commitment.unpack();
+    assert_eq!(
+        unpacked_commitment.pack().as_slice(),
+        packed_commitment.as_slice()
+    );
+
+    // The sync committee from the witness is checked, then it's used to verify the update.
+    let result =
+        commitment.verify_packed_client_sync_committee(packed_client_sync_committee.as_reader());
+    assert!(result.is_ok());
+    let result = commitment
+        .verify_packed_client_sync_committee(packed_client_uncompressed_sync_committee.as_reader());
+    assert!(result.is_ok());
+
+    let old_client = light_client.client().clone();
+    let finalized_slot = 6632768;
+    let headers = ((old_client.maximal_slot + 1)..=finalized_slot)
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect::<Vec<_>>();
+    let client_update =
+        light_client.apply_finality_update(load_finality_update(finalized_slot), headers);
+    let new_client = light_client.client().clone();
+    let result = client_update.verify_client_update(
+        old_client,
+        genesis_validators_root,
+        packed_client_sync_committee.as_reader(),
+        new_client,
+    );
+    assert!(result.is_ok());
+
+    // The period should be same.
+    {
+        let wrong_client_sync_committee = core::ClientSyncCommittee {
+            period: client_sync_committee.period + 1,
+            data: client_sync_committee.data.clone(),
+        };
+        let result = commitment
+            .verify_packed_client_sync_committee(wrong_client_sync_committee.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeCommitmentError::MismatchedPeriod)
+        ));
+    }
+
+    // The sync committee should be same.
+    {
+        let other_sync_committee = DummyLightClient::new(load_bootstrap(6184960))
+            .client_sync_committee()
+            .data
+            .clone();
+        let wrong_client_sync_committee = core::ClientSyncCommittee {
+            period: client_sync_committee.period,
+            data: other_sync_committee,
+        };
+        let result = commitment
+            .verify_packed_client_sync_committee(wrong_client_sync_committee.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeCommitmentError::MismatchedSyncCommitteeRoot)
+        ));
+    }
+
+    // The size of the sync committee should be correct.
+    {
+        let mut wrong_client_sync_committee = client_sync_committee.clone();
+        wrong_client_sync_committee.data.pubkeys.pop();
+        let result = commitment
+            .verify_packed_client_sync_committee(wrong_client_sync_committee.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeCommitmentError::UnexpectedSyncCommitteeSize)
+        ));
+    }
+}
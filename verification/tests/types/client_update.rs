@@ -1,15 +1,19 @@
 use std::fs;
 
-use eth2_types::{
-    light_client_bootstrap::PatchedLightClientBootstrap,
-    light_client_finality_update::PatchedLightClientFinalityUpdate, MainnetEthSpec,
-};
+use eth2_types::light_client_optimistic_update::LightClientOptimisticUpdate;
 use eth_light_client_in_ckb_prover::DummyLightClient;
-use eth_light_client_in_ckb_verification::{consensus_specs::helpers, types::prelude::*};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::helpers,
+    error::ClientUpdateError,
+    types::{core, prelude::*},
+};
 
 use crate::{
-    find_json_file, setup,
-    types::{load_beacon_block_header_from_json_or_create_default, load_genesis_validators_root},
+    setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+        load_genesis_validators_root,
+    },
 };
 
 #[test]
@@ -23,6 +27,18 @@ fn mainnet_testcase_in_capella() {
     client_update(param);
 }
 
+#[test]
+fn mainnet_testcase_force_update_in_capella() {
+    let param = ForceUpdateParameter {
+        bootstrap_slot: 6632736,
+        stalled_finalized_slot: 6632768,
+        finalized_slot: 6632864,
+        force_update_timeout: 64,
+        checked_slot: 6632854,
+    };
+    client_force_update(param);
+}
+
 #[derive(Default)]
 struct Parameter {
     bootstrap_slot: u64,
@@ -36,18 +52,14 @@ fn client_update(param: Parameter) {
 
     let genesis_validators_root = load_genesis_validators_root();
 
-    let mut light_client = {
-        let case_dir = "mainnet/light_client/bootstrap";
-        let filename = format!("slot-{:09}.json", param.bootstrap_slot);
-        let json_file = find_json_file(case_dir, &filename);
-        let json_str = fs::read_to_string(json_file).unwrap();
-        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-        let bootstrap: PatchedLightClientBootstrap<MainnetEthSpec> =
-            serde_json::from_value(json_value["data"].clone()).unwrap();
-        DummyLightClient::new(bootstrap.into())
-    };
+    let mut light_client = DummyLightClient::new(load_bootstrap(param.bootstrap_slot));
     let bootstrap_period = helpers::compute_sync_committee_period_at_slot(param.bootstrap_slot);
     let packed_client_sync_committee = light_client.client_sync_committee().pack();
+    let packed_client_uncompressed_sync_committee = light_client
+        .client_sync_committee()
+        .uncompress()
+        .unwrap()
+        .pack();
 
     let mut client = light_client.client().clone();
     client.id += 1;
@@ -66,22 +78,13 @@ fn client_update(param: Parameter) {
             param.bootstrap_slot,
         );
 
-        let finality_update = {
-            let case_dir = "mainnet/light_client/finality_update";
-            let filename = format!("slot-{:09}.json", finalized_slot);
-            let json_file = find_json_file(case_dir, &filename);
-            let json_str = fs::read_to_string(json_file).unwrap();
-            let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-            let finality_update: PatchedLightClientFinalityUpdate<MainnetEthSpec> =
-                serde_json::from_value(json_value["data"].clone()).unwrap();
-            finality_update
-        };
+        let finality_update = load_finality_update(finalized_slot);
 
         let headers = ((client.maximal_slot + 1)..=finalized_slot)
             .map(load_beacon_block_header_from_json_or_create_default)
             .collect::<Vec<_>>();
 
-        let client_update = light_client.apply_finality_update(finality_update.into(), headers);
+        let client_update = light_client.apply_finality_update(finality_update, headers);
         let mut new_client = light_client.client().clone();
         new_client.id = client.id;
 
@@ -93,6 +96,159 @@ fn client_update(param: Parameter) {
         );
         assert!(result.is_ok());
 
+        // Verify with the uncompressed sync committee.
+        let result = client_update.verify_client_update(
+            client.clone(),
+            genesis_validators_root,
+            packed_client_uncompressed_sync_committee.as_reader(),
+            new_client.clone(),
+        );
+        assert!(result.is_ok());
+
+        // Verify the packed client update without unpacking it.
+        let packed_client_update = client_update.pack();
+        for result in [
+            packed_client_update.as_reader().verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            ),
+            packed_client_update.as_reader().verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_uncompressed_sync_committee.as_reader(),
+                new_client.clone(),
+            ),
+        ] {
+            assert!(result.is_ok());
+        }
+
+        // Verify with the full header encoding.
+        {
+            let cached_headers = client_update
+                .headers
+                .calc_cache(
+                    client.tip_header_root,
+                    client.maximal_slot + 1,
+                    client_update.attested_header.slot,
+                )
+                .unwrap_or_else(|_| panic!("failed to rebuild compact headers"));
+            let full_headers = cached_headers
+                .into_iter()
+                .map(|header| header.inner)
+                .collect::<Vec<_>>();
+            let full_client_update = core::ClientUpdate {
+                headers: core::HeaderRange::Full(full_headers),
+                ..client_update.clone()
+            };
+            assert!(
+                client_update.pack().as_slice().len() < full_client_update.pack().as_slice().len()
+            );
+            let result = full_client_update.verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(result.is_ok());
+            let result = full_client_update.pack().as_reader().verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(result.is_ok());
+        }
+
+        // Empty runs without any slots are invalid.
+        if let core::HeaderRange::Compact(ref compact_headers) = client_update.headers {
+            let mut items = compact_headers.items.clone();
+            items.insert(0, core::CompactHeaderItem::EmptySlots(0));
+            let invalid_client_update = core::ClientUpdate {
+                headers: core::HeaderRange::Compact(core::CompactHeaders {
+                    first_slot: compact_headers.first_slot,
+                    items,
+                }),
+                ..client_update.clone()
+            };
+            let result = invalid_client_update.verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(matches!(
+                result,
+                Err(ClientUpdateError::InvalidCompactHeaders)
+            ));
+            let result = invalid_client_update
+                .pack()
+                .as_reader()
+                .verify_client_update(
+                    client.clone(),
+                    genesis_validators_root,
+                    packed_client_sync_committee.as_reader(),
+                    new_client.clone(),
+                );
+            assert!(matches!(
+                result,
+                Err(ClientUpdateError::InvalidCompactHeaders)
+            ));
+        } else {
+            panic!("client update should use compact headers");
+        }
+
+        // Compact headers should be in the range from the next slot of the client to the
+        // attested slot, even if they are only a few bytes of empty slots.
+        if let core::HeaderRange::Compact(ref compact_headers) = client_update.headers {
+            let mut oversized_items = compact_headers.items.clone();
+            oversized_items.push(core::CompactHeaderItem::EmptySlots(u64::MAX));
+            let shifted_first_slot = compact_headers.first_slot + 1;
+            for (first_slot, items, is_first_slot_invalid) in [
+                (compact_headers.first_slot, oversized_items, false),
+                (shifted_first_slot, compact_headers.items.clone(), true),
+            ] {
+                let invalid_client_update = core::ClientUpdate {
+                    headers: core::HeaderRange::Compact(core::CompactHeaders { first_slot, items }),
+                    ..client_update.clone()
+                };
+                let result = invalid_client_update.verify_client_update(
+                    client.clone(),
+                    genesis_validators_root,
+                    packed_client_sync_committee.as_reader(),
+                    new_client.clone(),
+                );
+                if is_first_slot_invalid {
+                    assert!(matches!(result, Err(ClientUpdateError::FirstHeaderSlot)));
+                } else {
+                    assert!(matches!(
+                        result,
+                        Err(ClientUpdateError::InvalidCompactHeaders)
+                    ));
+                }
+                let result = invalid_client_update
+                    .pack()
+                    .as_reader()
+                    .verify_client_update(
+                        client.clone(),
+                        genesis_validators_root,
+                        packed_client_sync_committee.as_reader(),
+                        new_client.clone(),
+                    );
+                if is_first_slot_invalid {
+                    assert!(matches!(result, Err(ClientUpdateError::FirstHeaderSlot)));
+                } else {
+                    assert!(matches!(
+                        result,
+                        Err(ClientUpdateError::InvalidCompactHeaders)
+                    ));
+                }
+            }
+        } else {
+            panic!("client update should use compact headers");
+        }
+
         if let Some(dump_dir) = param.dump_dir_opt {
             let packed_client_update = client_update.pack();
             let client_update_filepath =
@@ -114,3 +270,192 @@ fn client_update(param: Parameter) {
         }
     }
 }
+
+struct ForceUpdateParameter {
+    bootstrap_slot: u64,
+    // The attested header of this finality update is applied by a force update.
+    stalled_finalized_slot: u64,
+    // Finalize the force updated headers by this finality update.
+    finalized_slot: u64,
+    force_update_timeout: u64,
+    // Check the status of the header at this slot.
+    checked_slot: u64,
+}
+
+fn client_force_update(param: ForceUpdateParameter) {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let new_light_client = || DummyLightClient::new(load_bootstrap(param.bootstrap_slot));
+    let load_optimistic_update = |finalized_slot: u64| {
+        let finality_update = load_finality_update(finalized_slot);
+        LightClientOptimisticUpdate {
+            attested_header: finality_update.attested_header,
+            sync_aggregate: finality_update.sync_aggregate,
+            signature_slot: finality_update.signature_slot,
+        }
+    };
+
+    let mut light_client = new_light_client();
+    let packed_client_sync_committee = light_client.client_sync_committee().pack();
+    let client = light_client.client().clone();
+
+    let check_header_status = |light_client: &DummyLightClient, expected: core::HeaderStatus| {
+        let header = light_client
+            .beacon_header_at_slot(param.checked_slot)
+            .unwrap();
+        let proof = core::TransactionProof {
+            header: header.inner.clone(),
+            transaction_index: 0,
+            receipts_root: Default::default(),
+            header_mmr_proof: light_client.build_header_mmr_proof(param.checked_slot),
+            transaction_ssz_proof: Vec::new(),
+            receipt_mpt_proof: Vec::new(),
+            receipts_root_ssz_proof: Vec::new(),
+        };
+        let result = light_client
+            .client()
+            .verify_packed_transaction_proof(proof.pack().as_reader());
+        assert!(matches!(result, Ok(status) if status == expected));
+    };
+
+    // Apply the attested header by a force update.
+    let new_client = {
+        let optimistic_update = load_optimistic_update(param.stalled_finalized_slot);
+        let attested_slot: u64 = optimistic_update.attested_header.slot.into();
+        let headers = ((client.maximal_slot + 1)..=attested_slot)
+            .map(load_beacon_block_header_from_json_or_create_default)
+            .collect::<Vec<_>>();
+        let client_update = light_client.apply_force_update(optimistic_update, headers);
+        let new_client = light_client.client().clone();
+        assert_eq!(new_client.maximal_slot, attested_slot);
+        assert_eq!(new_client.finalized_slot, client.finalized_slot);
+
+        let mut client_info = core::ClientInfo {
+            last_client_id: 0,
+            minimal_headers_count: 0,
+            genesis_validators_root,
+            force_update_timeout: 0,
+        };
+        for (force_update_timeout, expected) in [
+            (0, false),
+            (attested_slot - client.finalized_slot + 1, false),
+            (attested_slot - client.finalized_slot, true),
+            (param.force_update_timeout, true),
+        ] {
+            client_info.force_update_timeout = force_update_timeout;
+            let result = client_update.verify_client_force_update(
+                client.clone(),
+                client_info.clone(),
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert_eq!(
+                result.is_ok(),
+                expected,
+                "verify force update expect {expected} but got opposite \
+                (timeout: {force_update_timeout})"
+            );
+            let packed_result = client_update.pack().as_reader().verify_client_force_update(
+                client.clone(),
+                client_info.clone(),
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert_eq!(
+                result.map_err(|err| err as i8),
+                packed_result.map_err(|err| err as i8),
+                "verify packed force update got a different result \
+                (timeout: {force_update_timeout})"
+            );
+        }
+
+        // A force update is not a finality update.
+        let result = client_update.verify_client_update(
+            client.clone(),
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client.clone(),
+        );
+        assert!(result.is_err());
+
+        check_header_status(&light_client, core::HeaderStatus::ForceUpdated);
+
+        new_client
+    };
+
+    // The timeout of the next force update starts from the last force update.
+    {
+        let mut chained_light_client = new_light_client();
+        let optimistic_update = load_optimistic_update(param.stalled_finalized_slot);
+        let headers = ((client.maximal_slot + 1)..=new_client.maximal_slot)
+            .map(load_beacon_block_header_from_json_or_create_default)
+            .collect::<Vec<_>>();
+        let _client_update = chained_light_client.apply_force_update(optimistic_update, headers);
+        assert_eq!(
+            new_client.last_force_update_slot(),
+            Some(new_client.maximal_slot)
+        );
+
+        let optimistic_update = load_optimistic_update(param.finalized_slot);
+        let attested_slot: u64 = optimistic_update.attested_header.slot.into();
+        let headers = ((new_client.maximal_slot + 1)..=attested_slot)
+            .map(load_beacon_block_header_from_json_or_create_default)
+            .collect::<Vec<_>>();
+        let client_update = chained_light_client.apply_force_update(optimistic_update, headers);
+        let chained_client = chained_light_client.client().clone();
+
+        let mut client_info = core::ClientInfo {
+            last_client_id: 0,
+            minimal_headers_count: 0,
+            genesis_validators_root,
+            force_update_timeout: 0,
+        };
+        for (force_update_timeout, expected) in [
+            // The timeout has passed since the last finalized header, but not since the last
+            // force update.
+            (attested_slot - new_client.finalized_slot, false),
+            (attested_slot - new_client.maximal_slot + 1, false),
+            (attested_slot - new_client.maximal_slot, true),
+            (u64::MAX, false),
+        ] {
+            client_info.force_update_timeout = force_update_timeout;
+            let result = client_update.verify_client_force_update(
+                new_client.clone(),
+                client_info.clone(),
+                packed_client_sync_committee.as_reader(),
+                chained_client.clone(),
+            );
+            if expected {
+                assert!(result.is_ok());
+            } else {
+                assert!(matches!(
+                    result,
+                    Err(ClientUpdateError::ForceUpdateBeforeTimeout)
+                ));
+            }
+        }
+    }
+
+    // Finalize the force updated headers.
+    {
+        let finality_update = load_finality_update(param.finalized_slot);
+        let headers = ((new_client.maximal_slot + 1)..=param.finalized_slot)
+            .map(load_beacon_block_header_from_json_or_create_default)
+            .collect::<Vec<_>>();
+        let client_update = light_client.apply_finality_update(finality_update, headers);
+        let finalized_client = light_client.client().clone();
+        assert_eq!(finalized_client.finalized_slot, param.finalized_slot);
+
+        let result = client_update.verify_client_update(
+            new_client,
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            finalized_client,
+        );
+        assert!(result.is_ok());
+
+        check_header_status(&light_client, core::HeaderStatus::Finalized);
+    }
+}
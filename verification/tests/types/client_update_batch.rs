@@ -0,0 +1,319 @@
+use eth_light_client_in_ckb_prover::{
+    DummyLightClient, LightClientBootstrap, LightClientUpdate, SyntheticChain, SyntheticChainConfig,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, helpers},
+    error::{ClientUpdateBatchError, ClientUpdateError, SyncCommitteeUpdateError},
+    types::{core, prelude::*},
+};
+
+use crate::{
+    setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+        load_genesis_validators_root, load_light_client_update,
+    },
+};
+
+#[test]
+fn mainnet_testcase_in_capella() {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let mut light_client = DummyLightClient::new(load_bootstrap(6632736));
+    let packed_client_sync_committee = light_client.client_sync_committee().pack();
+    let packed_client_uncompressed_sync_committee = light_client
+        .client_sync_committee()
+        .uncompress()
+        .unwrap()
+        .pack();
+    let old_client = light_client.client().clone();
+
+    let mut items = Vec::new();
+    for finalized_slot in [6632768, 6632800, 6632832, 6632864] {
+        let client_update = apply_finality_update(&mut light_client, finalized_slot);
+        items.push(core::ClientUpdateBatchItem::ClientUpdate(client_update));
+    }
+    let new_client = light_client.client().clone();
+    let batch = core::ClientUpdateBatch { items };
+
+    let result = batch.verify_client_update_batch(
+        old_client.clone(),
+        genesis_validators_root,
+        packed_client_sync_committee.as_reader(),
+        new_client.clone(),
+    );
+    assert!(matches!(result, Ok(None)));
+
+    // Verify with the uncompressed sync committee.
+    let result = batch.verify_client_update_batch(
+        old_client.clone(),
+        genesis_validators_root,
+        packed_client_uncompressed_sync_committee.as_reader(),
+        new_client.clone(),
+    );
+    assert!(matches!(result, Ok(None)));
+
+    // The batch is same after packing and unpacking.
+    let unpacked_batch: core::ClientUpdateBatch = batch.pack().unpack();
+    assert_eq!(unpacked_batch.pack().as_slice(), batch.pack().as_slice());
+
+    // The new client should be the client after the last update.
+    {
+        let mut wrong_new_client = new_client.clone();
+        wrong_new_client.maximal_slot -= 1;
+        let result = batch.verify_client_update_batch(
+            old_client.clone(),
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            wrong_new_client,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateBatchError::InvalidClientUpdate(
+                ClientUpdateError::ClientMaximalSlot
+            ))
+        ));
+    }
+
+    // Updates should be applied in order.
+    {
+        let mut items = batch.items.clone();
+        items.swap(0, 1);
+        let result = core::ClientUpdateBatch { items }.verify_client_update_batch(
+            old_client.clone(),
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client.clone(),
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateBatchError::InvalidClientUpdate(
+                ClientUpdateError::FirstHeaderSlot
+            ))
+        ));
+    }
+
+    // An empty batch is invalid.
+    {
+        let result = core::ClientUpdateBatch { items: Vec::new() }.verify_client_update_batch(
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client,
+        );
+        assert!(matches!(result, Err(ClientUpdateBatchError::EmptyBatch)));
+    }
+}
+
+// The client update after the rotation is from another period, so only the rotation itself is
+// checked; a batch which chains updates across periods is in `synthetic_testcase_across_periods`.
+#[test]
+fn mainnet_testcase_sync_committee_rotation() {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let bootstrap_slot = 4612096;
+    let bootstrap = load_bootstrap(bootstrap_slot);
+    let current_sync_committee = bootstrap.build_client_sync_committee();
+    let packed_current_sync_committee = current_sync_committee.pack();
+    let old_client = bootstrap
+        .build_client_bootstrap()
+        .header
+        .initialize_client();
+
+    let update = load_light_client_update(563);
+    let rotation = core::SyncCommitteeRotation {
+        sync_committee_update: update.build_sync_committee_update(),
+        next_sync_committee: update.build_next_client_sync_committee(),
+    };
+
+    let unrelated_client_update = {
+        let mut light_client = DummyLightClient::new(load_bootstrap(6632736));
+        apply_finality_update(&mut light_client, 6632768)
+    };
+
+    // The rotation passes, so the batch fails at the unrelated client update.
+    {
+        let batch = core::ClientUpdateBatch {
+            items: vec![
+                core::ClientUpdateBatchItem::SyncCommitteeRotation(rotation.clone()),
+                core::ClientUpdateBatchItem::ClientUpdate(unrelated_client_update.clone()),
+            ],
+        };
+        let result = batch.verify_client_update_batch(
+            old_client.clone(),
+            genesis_validators_root,
+            packed_current_sync_committee.as_reader(),
+            old_client.clone(),
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateBatchError::InvalidClientUpdate(
+                ClientUpdateError::FirstHeaderSlot
+            ))
+        ));
+    }
+
+    // The next sync committee should be proven by the sync committee update.
+    {
+        let wrong_rotation = core::SyncCommitteeRotation {
+            next_sync_committee: core::ClientSyncCommittee {
+                period: rotation.next_sync_committee.period,
+                data: current_sync_committee.data.clone(),
+            },
+            ..rotation.clone()
+        };
+        let batch = core::ClientUpdateBatch {
+            items: vec![
+                core::ClientUpdateBatchItem::SyncCommitteeRotation(wrong_rotation),
+                core::ClientUpdateBatchItem::ClientUpdate(unrelated_client_update.clone()),
+            ],
+        };
+        let result = batch.verify_client_update_batch(
+            old_client.clone(),
+            genesis_validators_root,
+            packed_current_sync_committee.as_reader(),
+            old_client.clone(),
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateBatchError::InvalidSyncCommitteeRotation(
+                SyncCommitteeUpdateError::InvalidNextSyncCommitteeBranch
+            ))
+        ));
+    }
+
+    // The last item should be a client update.
+    {
+        let batch = core::ClientUpdateBatch {
+            items: vec![core::ClientUpdateBatchItem::SyncCommitteeRotation(rotation)],
+        };
+        let result = batch.verify_client_update_batch(
+            old_client.clone(),
+            genesis_validators_root,
+            packed_current_sync_committee.as_reader(),
+            old_client,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateBatchError::LastItemIsNotClientUpdate)
+        ));
+    }
+}
+
+// The batch chains a client update in the current period, a sync committee rotation and a
+// client update signed by the rotated sync committee.
+#[test]
+fn synthetic_testcase_across_periods() {
+    setup();
+
+    let boundary_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH);
+    let start_slot = boundary_slot - forks::phase0::SLOTS_PER_EPOCH * 5;
+    let end_slot = boundary_slot + forks::phase0::SLOTS_PER_EPOCH * 5;
+    let mut config = SyntheticChainConfig::new(start_slot, end_slot);
+    config.empty_slots = (start_slot + 70..start_slot + 100).collect();
+    let chain = SyntheticChain::new(config);
+    let genesis_validators_root = chain.genesis_validators_root();
+
+    let bootstrap = LightClientBootstrap::from(chain.bootstrap());
+    let packed_client_sync_committee = bootstrap.build_client_sync_committee().pack();
+    let mut light_client = DummyLightClient::new(bootstrap);
+    let old_client = light_client.client().clone();
+
+    let attested_slot = boundary_slot - 2;
+    let signature_slot = boundary_slot - 1;
+    let first_client_update = {
+        let finality_update = chain.finality_update(attested_slot, signature_slot);
+        let finalized_slot = finality_update.finalized_header.slot.as_u64();
+        let headers = chain.beacon_block_headers(old_client.maximal_slot + 1, finalized_slot);
+        light_client.apply_finality_update(finality_update, headers)
+    };
+    let rotation = {
+        let update =
+            LightClientUpdate::from(chain.light_client_update(attested_slot, signature_slot));
+        core::SyncCommitteeRotation {
+            sync_committee_update: update.build_sync_committee_update(),
+            next_sync_committee: update.build_next_client_sync_committee(),
+        }
+    };
+    let last_client_update = {
+        let attested_slot = boundary_slot + 128;
+        let finality_update = chain.finality_update(attested_slot, attested_slot + 1);
+        let finalized_slot = finality_update.finalized_header.slot.as_u64();
+        let headers =
+            chain.beacon_block_headers(light_client.client().maximal_slot + 1, finalized_slot);
+        light_client.apply_finality_update(finality_update, headers)
+    };
+    let new_client = light_client.client().clone();
+
+    let batch = core::ClientUpdateBatch {
+        items: vec![
+            core::ClientUpdateBatchItem::ClientUpdate(first_client_update.clone()),
+            core::ClientUpdateBatchItem::SyncCommitteeRotation(rotation.clone()),
+            core::ClientUpdateBatchItem::ClientUpdate(last_client_update.clone()),
+        ],
+    };
+    let result = batch.verify_client_update_batch(
+        old_client.clone(),
+        genesis_validators_root,
+        packed_client_sync_committee.as_reader(),
+        new_client.clone(),
+    );
+    let next_period = helpers::compute_sync_committee_period_at_slot(boundary_slot);
+    match result {
+        Ok(Some(sync_committee)) => {
+            assert_eq!(sync_committee.period, next_period);
+            assert_eq!(
+                sync_committee.pack().as_slice(),
+                rotation.next_sync_committee.pack().as_slice()
+            );
+        }
+        _ => panic!("failed to verify the batch across periods"),
+    }
+
+    // The packed batch is verified same as the unpacked one.
+    let unpacked_batch: core::ClientUpdateBatch = batch.pack().unpack();
+    let result = unpacked_batch.verify_client_update_batch(
+        old_client.clone(),
+        genesis_validators_root,
+        packed_client_sync_committee.as_reader(),
+        new_client.clone(),
+    );
+    assert!(matches!(result, Ok(Some(_))));
+
+    // Without the rotation, the last client update is signed by an unknown sync committee.
+    {
+        let batch = core::ClientUpdateBatch {
+            items: vec![
+                core::ClientUpdateBatchItem::ClientUpdate(first_client_update),
+                core::ClientUpdateBatchItem::ClientUpdate(last_client_update),
+            ],
+        };
+        let result = batch.verify_client_update_batch(
+            old_client,
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client,
+        );
+        match result {
+            Err(err) => {
+                let code = 32 + ClientUpdateError::MismatchedSyncCommittee as i8;
+                assert_eq!(err.error_code(), code);
+            }
+            Ok(_) => panic!("the batch without the rotation should be rejected"),
+        }
+    }
+}
+
+fn apply_finality_update(
+    light_client: &mut DummyLightClient,
+    finalized_slot: u64,
+) -> core::ClientUpdate {
+    let headers = ((light_client.client().maximal_slot + 1)..=finalized_slot)
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect::<Vec<_>>();
+    light_client.apply_finality_update(load_finality_update(finalized_slot), headers)
+}
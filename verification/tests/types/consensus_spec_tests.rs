@@ -0,0 +1,738 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use eth2_types::{
+    light_client_bootstrap::LightClientBootstrap, light_client_update::LightClientUpdate,
+    typenum::Unsigned as _, BeaconBlock, BeaconBlockBodyCapella, BeaconBlockCapella, BeaconState,
+    BeaconStateAltair, BeaconStateCapella, BeaconStateMerge, EthSpec, MainnetEthSpec,
+    MinimalEthSpec, SyncCommittee,
+};
+use eth_light_client_in_ckb_prover::{
+    CachedBeaconBlock, CachedBeaconState, LightClientBootstrapCapella, LightClientUpdateCapella,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, helpers, ChainConfig, Fork},
+    types::{core, prelude::*},
+    utilities::{mmr, ssz::is_valid_merkle_branch},
+};
+use serde_yaml::Value;
+use ssz::Decode as _;
+use tree_hash::{Hash256, TreeHash as _};
+use walkdir::WalkDir;
+
+use crate::{setup, test_data};
+
+// N.B. The test vectors should be vendored by `make vendor-consensus-spec-tests`, the tests fail
+// without them.
+const CONSENSUS_SPEC_TESTS_DIR: &str = "consensus-spec-tests/tests";
+const FORKS: &[&str] = &["altair", "bellatrix", "capella"];
+
+// The proofs are same in all presets, and the beacon states are only supported in the mainnet
+// preset.
+#[test]
+fn light_client_single_merkle_proof() {
+    setup();
+
+    for fork in FORKS {
+        for (suite, case_dir) in find_case_dirs("mainnet", fork, "single_merkle_proof") {
+            let object = read_ssz_snappy(&case_dir.join("object.ssz_snappy"));
+            let proof = read_yaml(&case_dir.join("proof.yaml"));
+            let leaf = yaml_hash(&proof["leaf"]);
+            let leaf_index = proof["leaf_index"].as_u64().unwrap() as usize;
+            let branch = proof["branch"]
+                .as_sequence()
+                .unwrap()
+                .iter()
+                .map(yaml_hash)
+                .collect::<Vec<_>>();
+
+            let case_name = case_dir.file_name().unwrap().to_str().unwrap();
+            let (root, ssz_proof, expected_index) = match suite.as_str() {
+                "BeaconState" => {
+                    let state = decode_beacon_state(fork, &object);
+                    let (path, index) = beacon_state_generalized_index(fork, case_name);
+                    let ssz_proof = state.generate_proof_for_beacon_state(path).unwrap();
+                    (state.state_root(), ssz_proof, index)
+                }
+                "BeaconBlockBody" => {
+                    assert_eq!(case_name, "execution_merkle_proof");
+                    let block = decode_beacon_block_body(fork, &object);
+                    let ssz_proof = block
+                        .generate_proof_for_block_body("execution_payload")
+                        .unwrap();
+                    let index = forks::capella::containers::BEACON_BLOCK_BODY
+                        .generalized_index("execution_payload");
+                    (block.body_root(), ssz_proof, index)
+                }
+                _ => panic!("unsupported suite {suite}"),
+            };
+
+            // The generalized indexes in the verification should be same as the consensus specs.
+            assert_eq!(leaf_index, expected_index);
+            assert_eq!(ssz_proof.generalized_index, leaf_index);
+            assert_eq!(ssz_proof.leaf, leaf);
+            assert_eq!(ssz_proof.branch, branch);
+
+            let depth = helpers::floorlog2(leaf_index) as usize;
+            let index = helpers::get_subtree_index(leaf_index);
+            assert!(is_valid_merkle_branch(&leaf, &branch, depth, index, &root));
+        }
+    }
+}
+
+// The update ranking and the sync tests are only generated in the minimal preset.
+
+#[test]
+fn light_client_update_ranking() {
+    setup();
+
+    for fork in FORKS {
+        for (_, case_dir) in find_case_dirs("minimal", fork, "update_ranking") {
+            run_update_ranking_case::<MinimalEthSpec>(fork, &case_dir);
+        }
+    }
+}
+
+#[test]
+fn light_client_sync() {
+    setup();
+
+    for fork in FORKS {
+        for (_, case_dir) in find_case_dirs("minimal", fork, "sync") {
+            run_sync_case::<MinimalEthSpec>(fork, &case_dir);
+        }
+    }
+}
+
+fn run_update_ranking_case<E: EthSpec>(fork: &str, case_dir: &Path) {
+    let config = load_chain_config::<E>(fork, case_dir);
+    let meta = read_yaml(&case_dir.join("meta.yaml"));
+    let updates_count = meta["updates_count"].as_u64().unwrap();
+    let summaries = (0..updates_count)
+        .map(|index| {
+            let file = case_dir.join(format!("updates_{index}.ssz_snappy"));
+            let update = decode_light_client_update::<E>(fork, &read_ssz_snappy(&file));
+            core::UpdateSummary::from(&update)
+        })
+        .collect::<Vec<_>>();
+    // The updates are sorted from the best to the worst.
+    for (index, pair) in summaries.windows(2).enumerate() {
+        assert!(
+            !pair[1].is_better_than(&pair[0], &config),
+            "{}: update {} should not be better than update {index}",
+            case_dir.display(),
+            index + 1
+        );
+    }
+}
+
+fn run_sync_case<E: EthSpec>(fork: &str, case_dir: &Path) {
+    let config = load_chain_config::<E>(fork, case_dir);
+    let meta = read_yaml(&case_dir.join("meta.yaml"));
+    let genesis_validators_root = yaml_hash(&meta["genesis_validators_root"]);
+    let trusted_block_root = yaml_hash(&meta["trusted_block_root"]);
+
+    let bootstrap = {
+        let file = case_dir.join("bootstrap.ssz_snappy");
+        decode_light_client_bootstrap::<E>(fork, &read_ssz_snappy(&file))
+    };
+    assert_eq!(bootstrap.header.tree_hash_root(), trusted_block_root);
+    let header: core::Header = bootstrap.header.clone().into();
+    let period = config.compute_sync_committee_period_at_slot(header.slot);
+    let client_sync_committee =
+        build_client_sync_committee(period, &bootstrap.current_sync_committee);
+    let client_bootstrap = core::ClientBootstrap {
+        header: header.clone(),
+        current_sync_committee_branch: bootstrap.current_sync_committee_branch.to_vec(),
+    };
+    let result = client_bootstrap.verify_packed_client_sync_committee_with_config(
+        &config,
+        client_sync_committee.pack().as_reader(),
+    );
+    assert!(result.is_ok(), "{}: bootstrap", case_dir.display());
+
+    let mut runner = SyncCaseRunner {
+        config,
+        genesis_validators_root,
+        finalized_header: header.clone(),
+        optimistic_header: header,
+        sync_committees: BTreeMap::from([(period, client_sync_committee)]),
+        updates: Vec::new(),
+    };
+    let steps = read_yaml(&case_dir.join("steps.yaml"));
+    for (index, step) in steps.as_sequence().unwrap().iter().enumerate() {
+        let context = format!("{} (step {index})", case_dir.display());
+        if let Some(step) = step.get("process_update") {
+            let update_name = step["update"].as_str().unwrap();
+            let update = {
+                let file = case_dir.join(format!("{update_name}.ssz_snappy"));
+                decode_light_client_update::<E>(fork, &read_ssz_snappy(&file))
+            };
+            runner.process_update(Update::from(update), &step["checks"], &context);
+        } else if let Some(step) = step.get("force_update") {
+            runner.force_update(&step["checks"], &context);
+        } else {
+            panic!("{context}: unsupported step {step:?}");
+        }
+    }
+}
+
+// Runs the steps of a sync test case.
+//
+// The finalized header and the optimistic header are taken from the expected checks of each
+// step, and each transition between them should be accepted by the verification, as the
+// client cells are updated. The sync committees are stored in the same way as the sync
+// committee cells, they are accepted by the verification from the updates.
+struct SyncCaseRunner {
+    config: ChainConfig,
+    genesis_validators_root: Hash256,
+    finalized_header: core::Header,
+    optimistic_header: core::Header,
+    sync_committees: BTreeMap<u64, core::ClientSyncCommittee>,
+    updates: Vec<Update>,
+}
+
+// A light client update in the test vectors, with the core types.
+struct Update {
+    attested_header: core::Header,
+    next_sync_committee: Option<core::SyncCommittee>,
+    next_sync_committee_branch: core::SszProof,
+    finalized_header: Option<core::Header>,
+    finality_branch: core::SszProof,
+    sync_aggregate: core::SyncAggregate,
+    signature_slot: u64,
+}
+
+impl<E: EthSpec> From<LightClientUpdate<E>> for Update {
+    fn from(update: LightClientUpdate<E>) -> Self {
+        let summary = core::UpdateSummary::from(&update);
+        let next_sync_committee = if summary.has_next_sync_committee {
+            Some(build_client_sync_committee(0, &update.next_sync_committee).data)
+        } else {
+            None
+        };
+        let finalized_header = if summary.has_finality() {
+            Some(update.finalized_header.into())
+        } else {
+            None
+        };
+        Self {
+            attested_header: update.attested_header.into(),
+            next_sync_committee,
+            next_sync_committee_branch: update.next_sync_committee_branch.to_vec(),
+            finalized_header,
+            finality_branch: update.finality_branch.to_vec(),
+            sync_aggregate: update.sync_aggregate.into(),
+            signature_slot: update.signature_slot.into(),
+        }
+    }
+}
+
+impl SyncCaseRunner {
+    // References:
+    // - [`process_light_client_update`](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#process_light_client_update)
+    fn process_update(&mut self, update: Update, checks: &Value, context: &str) {
+        self.verify_next_sync_committee(&update, context);
+
+        let (finalized_slot, finalized_root) = expected_header(checks, "finalized_header");
+        let (optimistic_slot, optimistic_root) = expected_header(checks, "optimistic_header");
+
+        let attested_root = update.attested_header.tree_hash_root();
+        if optimistic_root != self.optimistic_header.tree_hash_root()
+            && optimistic_root == attested_root
+        {
+            self.verify_optimistic_update(&update, context);
+            self.optimistic_header = update.attested_header.clone();
+        }
+
+        if finalized_root != self.finalized_header.tree_hash_root() {
+            let finalized_header = update
+                .finalized_header
+                .clone()
+                .filter(|header| header.tree_hash_root() == finalized_root)
+                .unwrap_or_else(|| panic!("{context}: the finalized header is not in the update"));
+            self.verify_client_update(&update, &finalized_header, false, context);
+            self.apply_finalized_header(finalized_header);
+        }
+
+        self.updates.push(update);
+        self.check(finalized_slot, optimistic_slot, optimistic_root, context);
+    }
+
+    // References:
+    // - [`process_light_client_store_force_update`](https://github.com/ethereum/consensus-specs/blob/v1.3.0/specs/altair/light-client/sync-protocol.md#process_light_client_store_force_update)
+    fn force_update(&mut self, checks: &Value, context: &str) {
+        let (finalized_slot, finalized_root) = expected_header(checks, "finalized_header");
+        let (optimistic_slot, optimistic_root) = expected_header(checks, "optimistic_header");
+
+        if finalized_root != self.finalized_header.tree_hash_root() {
+            // The attested header is treated as the finalized header, when the finalized header
+            // in the best update is not newer.
+            let found = self.updates.iter().rev().find_map(|update| {
+                if update.attested_header.tree_hash_root() == finalized_root {
+                    Some((update, update.attested_header.clone(), true))
+                } else {
+                    update
+                        .finalized_header
+                        .clone()
+                        .filter(|header| header.tree_hash_root() == finalized_root)
+                        .map(|header| (update, header, false))
+                }
+            });
+            let (update, finalized_header, is_force_update) = found
+                .unwrap_or_else(|| panic!("{context}: no update has the forced finalized header"));
+            self.verify_client_update(update, &finalized_header, is_force_update, context);
+            self.apply_finalized_header(finalized_header);
+        }
+
+        self.check(finalized_slot, optimistic_slot, optimistic_root, context);
+    }
+
+    // Verifies the next sync committee in the update as a sync committee update, then stores it.
+    //
+    // The verification requires supermajority participation, so the next sync committees in the
+    // other updates are ignored.
+    fn verify_next_sync_committee(&mut self, update: &Update, context: &str) {
+        let next_sync_committee = match update.next_sync_committee.as_ref() {
+            Some(next_sync_committee) => next_sync_committee,
+            None => return,
+        };
+        if !update
+            .sync_aggregate
+            .has_supermajority_with_config(&self.config)
+        {
+            return;
+        }
+        let attested_period = self
+            .config
+            .compute_sync_committee_period_at_slot(update.attested_header.slot);
+        let signature_period = self
+            .config
+            .compute_sync_committee_period_at_slot(update.signature_slot);
+        let next_client_sync_committee = core::ClientSyncCommittee {
+            period: attested_period + 1,
+            data: next_sync_committee.clone(),
+        }
+        .pack();
+        let sync_committee_update = core::SyncCommitteeUpdate {
+            attested_header: update.attested_header.clone(),
+            next_sync_committee_branch: update.next_sync_committee_branch.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        };
+        let current_client_sync_committee = match self.sync_committees.get(&attested_period) {
+            Some(sync_committee) => sync_committee.pack(),
+            None => return,
+        };
+        let result = if signature_period == attested_period {
+            sync_committee_update.verify_packed_client_sync_committee_with_config(
+                &self.config,
+                update.attested_header.slot,
+                self.genesis_validators_root,
+                current_client_sync_committee.as_reader(),
+                next_client_sync_committee.as_reader(),
+            )
+        } else {
+            let stored_next_client_sync_committee =
+                self.sync_committee(signature_period, context).pack();
+            sync_committee_update
+                .verify_packed_client_sync_committee_signed_in_next_period_with_config(
+                    &self.config,
+                    update.attested_header.slot,
+                    self.genesis_validators_root,
+                    current_client_sync_committee.as_reader(),
+                    stored_next_client_sync_committee.as_reader(),
+                    next_client_sync_committee.as_reader(),
+                )
+        };
+        assert!(
+            result.is_ok(),
+            "{context}: sync committee update, error: {:?}",
+            result.map_err(|err| err as i8)
+        );
+        self.sync_committees
+            .entry(attested_period + 1)
+            .or_insert_with(|| next_client_sync_committee.unpack());
+    }
+
+    // Verifies the attested header as the new optimistic header of the client.
+    fn verify_optimistic_update(&self, update: &Update, context: &str) {
+        let old_client = {
+            let mut client = self.finalized_header.initialize_client();
+            client.optimistic_slot = self.optimistic_header.slot;
+            client.optimistic_header_root = self.optimistic_header.tree_hash_root();
+            client
+        };
+        let new_client = core::Client {
+            optimistic_slot: update.attested_header.slot,
+            optimistic_header_root: update.attested_header.tree_hash_root(),
+            ..old_client.clone()
+        };
+        let optimistic_update = core::ClientOptimisticUpdate {
+            attested_header: update.attested_header.clone(),
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+        };
+        let client_sync_committee = self.sync_committee_at_slot(update.signature_slot, context);
+        let result = optimistic_update.verify_client_optimistic_update_with_config(
+            &self.config,
+            old_client,
+            self.genesis_validators_root,
+            client_sync_committee.pack().as_reader(),
+            new_client,
+        );
+        assert!(
+            result.is_ok(),
+            "{context}: optimistic update, error: {:?}",
+            result.map_err(|err| err as i8)
+        );
+    }
+
+    // Verifies the finalized header, or the attested header for a force update, as the new tip
+    // header of the client.
+    //
+    // The headers between the finalized headers are not in the test vectors, so the header is
+    // applied onto a client whose tip is its parent; the leaves in the MMR are the header roots,
+    // so the client could be built from the parent root only.
+    fn verify_client_update(
+        &self,
+        update: &Update,
+        header: &core::Header,
+        is_force_update: bool,
+        context: &str,
+    ) {
+        let parent_slot = header.slot - 1;
+        let cached_header = header.clone().calc_cache();
+
+        let store = mmr::lib::util::MemStore::default();
+        let mut mmr = mmr::ClientRootMMR::new(0, &store);
+        let parent_digest = core::HeaderDigest {
+            children_hash: header.parent_root,
+        };
+        mmr.push(parent_digest.pack()).unwrap();
+        let old_headers_mmr_root = mmr.get_root().unwrap().unpack();
+        mmr.push(cached_header.packed_digest()).unwrap();
+        let new_headers_mmr_root = mmr.get_root().unwrap().unpack();
+        let new_headers_mmr_proof = mmr
+            .gen_proof(vec![mmr::lib::leaf_index_to_pos(1)])
+            .unwrap()
+            .proof_items()
+            .iter()
+            .map(|item| item.unpack())
+            .collect::<Vec<_>>();
+
+        let old_client = core::Client {
+            id: 0,
+            minimal_slot: parent_slot,
+            maximal_slot: parent_slot,
+            tip_header_root: header.parent_root,
+            headers_mmr_root: old_headers_mmr_root,
+            optimistic_slot: parent_slot,
+            optimistic_header_root: header.parent_root,
+            finalized_slot: parent_slot,
+        };
+        let new_client = core::Client {
+            maximal_slot: header.slot,
+            tip_header_root: cached_header.root,
+            headers_mmr_root: new_headers_mmr_root,
+            optimistic_slot: header.slot,
+            optimistic_header_root: cached_header.root,
+            finalized_slot: if is_force_update {
+                parent_slot
+            } else {
+                header.slot
+            },
+            ..old_client.clone()
+        };
+        let client_update = core::ClientUpdate {
+            attested_header: update.attested_header.clone(),
+            finality_branch: if is_force_update {
+                Vec::new()
+            } else {
+                update.finality_branch.clone()
+            },
+            sync_aggregate: update.sync_aggregate.clone(),
+            signature_slot: update.signature_slot,
+            new_headers_mmr_proof,
+            headers: core::HeaderRange::compact(header.parent_root, &[cached_header]),
+        };
+
+        let packed_client_sync_committee = self
+            .sync_committee_at_slot(update.signature_slot, context)
+            .pack();
+        let result = if is_force_update {
+            let client_info = core::ClientInfo {
+                last_client_id: 0,
+                minimal_headers_count: 1,
+                genesis_validators_root: self.genesis_validators_root,
+                force_update_timeout: 1,
+            };
+            client_update.verify_client_force_update_with_config(
+                &self.config,
+                old_client,
+                client_info,
+                packed_client_sync_committee.as_reader(),
+                new_client,
+            )
+        } else {
+            client_update.verify_client_update_with_config(
+                &self.config,
+                old_client,
+                self.genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client,
+            )
+        };
+        assert!(
+            result.is_ok(),
+            "{context}: client update (force: {is_force_update}), error: {:?}",
+            result.map_err(|err| err as i8)
+        );
+    }
+
+    fn apply_finalized_header(&mut self, finalized_header: core::Header) {
+        if finalized_header.slot > self.optimistic_header.slot {
+            self.optimistic_header = finalized_header.clone();
+        }
+        self.finalized_header = finalized_header;
+    }
+
+    fn sync_committee(&self, period: u64, context: &str) -> &core::ClientSyncCommittee {
+        self.sync_committees
+            .get(&period)
+            .unwrap_or_else(|| panic!("{context}: no sync committee for period {period}"))
+    }
+
+    fn sync_committee_at_slot(&self, slot: u64, context: &str) -> &core::ClientSyncCommittee {
+        let period = self.config.compute_sync_committee_period_at_slot(slot);
+        self.sync_committee(period, context)
+    }
+
+    fn check(
+        &self,
+        finalized_slot: u64,
+        optimistic_slot: u64,
+        optimistic_root: Hash256,
+        context: &str,
+    ) {
+        assert_eq!(
+            self.finalized_header.slot, finalized_slot,
+            "{context}: finalized header"
+        );
+        assert_eq!(
+            self.optimistic_header.slot, optimistic_slot,
+            "{context}: optimistic header"
+        );
+        assert_eq!(
+            self.optimistic_header.tree_hash_root(),
+            optimistic_root,
+            "{context}: optimistic header"
+        );
+    }
+}
+
+fn expected_header(checks: &Value, name: &str) -> (u64, Hash256) {
+    let expected = &checks[name];
+    let slot = expected["slot"].as_u64().unwrap();
+    let root = yaml_hash(&expected["beacon_root"]);
+    (slot, root)
+}
+
+fn build_client_sync_committee<E: EthSpec>(
+    period: u64,
+    sync_committee: &SyncCommittee<E>,
+) -> core::ClientSyncCommittee {
+    core::ClientSyncCommittee {
+        period,
+        data: core::SyncCommittee {
+            pubkeys: sync_committee.pubkeys.to_vec(),
+            aggregate_pubkey: sync_committee.aggregate_pubkey,
+        },
+    }
+}
+
+// Loads the preset of `E` and the fork schedule of a test case.
+//
+// The forks until the fork of the test vectors are activated at genesis, and the later forks
+// are not scheduled, unless they are overridden by the `config.yaml` of the test case.
+fn load_chain_config<E: EthSpec>(fork: &str, case_dir: &Path) -> ChainConfig {
+    let spec = E::default_spec();
+    let fork_index = FORKS.iter().position(|name| *name == fork).unwrap();
+    let fork_epoch = |index: usize| if index <= fork_index { 0 } else { u64::MAX };
+    let mut config = ChainConfig {
+        slots_per_epoch: E::slots_per_epoch(),
+        epochs_per_sync_committee_period: E::EpochsPerSyncCommitteePeriod::to_u64(),
+        sync_committee_size: E::SyncCommitteeSize::to_usize(),
+        genesis_fork_version: spec.genesis_fork_version,
+        altair_fork: Fork {
+            epoch: fork_epoch(0),
+            version: spec.altair_fork_version,
+        },
+        bellatrix_fork: Fork {
+            epoch: fork_epoch(1),
+            version: spec.bellatrix_fork_version,
+        },
+        capella_fork: Fork {
+            epoch: fork_epoch(2),
+            version: spec.capella_fork_version,
+        },
+    };
+    let file = case_dir.join("config.yaml");
+    if file.is_file() {
+        let yaml = read_yaml(&file);
+        if let Some(version) = yaml.get("GENESIS_FORK_VERSION") {
+            config.genesis_fork_version = yaml_fork_version(version);
+        }
+        for (name, fork) in [
+            ("ALTAIR", &mut config.altair_fork),
+            ("BELLATRIX", &mut config.bellatrix_fork),
+            ("CAPELLA", &mut config.capella_fork),
+        ] {
+            if let Some(epoch) = yaml.get(format!("{name}_FORK_EPOCH")) {
+                fork.epoch = epoch.as_u64().unwrap();
+            }
+            if let Some(version) = yaml.get(format!("{name}_FORK_VERSION")) {
+                fork.version = yaml_fork_version(version);
+            }
+        }
+    }
+    config
+}
+
+fn beacon_state_generalized_index(fork: &str, case_name: &str) -> (&'static str, usize) {
+    let indexes = match fork {
+        "altair" => {
+            use forks::altair::generalized_index::beacon_state::*;
+            [
+                CURRENT_SYNC_COMMITTEE_INDEX,
+                NEXT_SYNC_COMMITTEE_INDEX,
+                FINALIZED_ROOT_INDEX,
+            ]
+        }
+        "bellatrix" => {
+            use forks::bellatrix::generalized_index::beacon_state::*;
+            [
+                CURRENT_SYNC_COMMITTEE_INDEX,
+                NEXT_SYNC_COMMITTEE_INDEX,
+                FINALIZED_ROOT_INDEX,
+            ]
+        }
+        "capella" => {
+            use forks::capella::generalized_index::beacon_state::*;
+            [
+                CURRENT_SYNC_COMMITTEE_INDEX,
+                NEXT_SYNC_COMMITTEE_INDEX,
+                FINALIZED_ROOT_INDEX,
+            ]
+        }
+        _ => panic!("unsupported fork {fork}"),
+    };
+    match case_name {
+        "current_sync_committee_merkle_proof" => ("current_sync_committee", indexes[0]),
+        "next_sync_committee_merkle_proof" => ("next_sync_committee", indexes[1]),
+        "finality_root_merkle_proof" => ("finalized_checkpoint.root", indexes[2]),
+        _ => panic!("unsupported case {case_name}"),
+    }
+}
+
+// The layouts are chosen by the forks of the test vectors, instead of the slots, since the
+// forks are activated at genesis in the test vectors.
+
+fn decode_beacon_state(fork: &str, bytes: &[u8]) -> CachedBeaconState {
+    let state = match fork {
+        "altair" => BeaconState::Altair(BeaconStateAltair::from_ssz_bytes(bytes).unwrap()),
+        "bellatrix" => BeaconState::Merge(BeaconStateMerge::from_ssz_bytes(bytes).unwrap()),
+        "capella" => BeaconState::Capella(BeaconStateCapella::from_ssz_bytes(bytes).unwrap()),
+        _ => panic!("unsupported fork {fork}"),
+    };
+    CachedBeaconState::try_from(state).unwrap()
+}
+
+fn decode_beacon_block_body(fork: &str, bytes: &[u8]) -> CachedBeaconBlock {
+    assert_eq!(fork, "capella");
+    let body = BeaconBlockBodyCapella::<MainnetEthSpec>::from_ssz_bytes(bytes).unwrap();
+    let block = BeaconBlock::Capella(BeaconBlockCapella {
+        slot: Default::default(),
+        proposer_index: 0,
+        parent_root: Default::default(),
+        state_root: Default::default(),
+        body,
+    });
+    CachedBeaconBlock::from(block)
+}
+
+fn decode_light_client_bootstrap<E: EthSpec>(fork: &str, bytes: &[u8]) -> LightClientBootstrap<E> {
+    if fork == "capella" {
+        LightClientBootstrapCapella::<E>::from_ssz_bytes(bytes)
+            .unwrap()
+            .into()
+    } else {
+        LightClientBootstrap::<E>::from_ssz_bytes(bytes).unwrap()
+    }
+}
+
+fn decode_light_client_update<E: EthSpec>(fork: &str, bytes: &[u8]) -> LightClientUpdate<E> {
+    if fork == "capella" {
+        LightClientUpdateCapella::<E>::from_ssz_bytes(bytes)
+            .unwrap()
+            .into()
+    } else {
+        LightClientUpdate::<E>::from_ssz_bytes(bytes).unwrap()
+    }
+}
+
+// Returns the suite names and the directories of all cases for a handler.
+fn find_case_dirs(preset: &str, fork: &str, handler: &str) -> Vec<(String, PathBuf)> {
+    let handler_dir = format!(
+        "{}/{CONSENSUS_SPEC_TESTS_DIR}/{preset}/{fork}/light_client/{handler}",
+        test_data::ROOT
+    );
+    assert!(
+        Path::new(&handler_dir).is_dir(),
+        "no test vectors in {handler_dir}, run `make vendor-consensus-spec-tests` to vendor them"
+    );
+    let case_dirs = WalkDir::new(&handler_dir)
+        .min_depth(2)
+        .max_depth(2)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir())
+        .map(|entry| {
+            let suite = entry
+                .path()
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(|name| name.to_str())
+                .unwrap()
+                .to_owned();
+            (suite, entry.into_path())
+        })
+        .collect::<Vec<_>>();
+    assert!(!case_dirs.is_empty(), "no test cases in {handler_dir}");
+    case_dirs
+}
+
+// The SSZ files in the test vectors are compressed in the snappy block format.
+fn read_ssz_snappy(file: &Path) -> Vec<u8> {
+    let bytes = fs::read(file).unwrap();
+    snap::raw::Decoder::new().decompress_vec(&bytes).unwrap()
+}
+
+fn read_yaml(file: &Path) -> Value {
+    let yaml_str = fs::read_to_string(file).unwrap();
+    serde_yaml::from_str(&yaml_str).unwrap()
+}
+
+fn yaml_hash(value: &Value) -> Hash256 {
+    serde_yaml::from_value(value.clone()).unwrap()
+}
+
+// The fork versions are in hex strings, such as `0x01000001`.
+fn yaml_fork_version(value: &Value) -> [u8; 4] {
+    let hex = value.as_str().unwrap().trim_start_matches("0x");
+    u32::from_str_radix(hex, 16).unwrap().to_be_bytes()
+}
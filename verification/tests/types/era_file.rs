@@ -0,0 +1,223 @@
+use std::{
+    fs,
+    io::{self, Write as _},
+};
+
+use eth2_types::{BeaconBlock, MainnetEthSpec, Signature, SignedBeaconBlock};
+use eth_light_client_in_ckb_prover::{
+    DummyLightClient, EraFile, LightClientUpdate, SyntheticChain, SyntheticChainConfig,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::forks::phase0::SLOTS_PER_HISTORICAL_ROOT,
+    types::{core, prelude::*},
+};
+use ssz::Encode as _;
+use tree_hash::TreeHash as _;
+
+use crate::{
+    find_json_file, setup,
+    types::{load_beacon_block_header_from_json_or_create_default, load_bootstrap},
+};
+
+const VERSION: [u8; 2] = [0x65, 0x32];
+const COMPRESSED_SIGNED_BEACON_BLOCK: [u8; 2] = [0x01, 0x00];
+const COMPRESSED_BEACON_STATE: [u8; 2] = [0x02, 0x00];
+const SLOT_INDEX: [u8; 2] = [0x69, 0x32];
+
+// The era file is built with the blocks in the test data, and all other slots are empty.
+#[test]
+fn mainnet_testcase_in_capella() {
+    setup();
+
+    let bootstrap_slot = 6632736;
+    let block_slot = 6632854;
+    let era = block_slot / SLOTS_PER_HISTORICAL_ROOT + 1;
+    let era_start_slot = (era - 1) * SLOTS_PER_HISTORICAL_ROOT;
+    let era_end_slot = era * SLOTS_PER_HISTORICAL_ROOT;
+
+    let block = load_signed_block(block_slot);
+    let era_file = EraFile::from_bytes(build_era_file(era, &[block])).unwrap();
+    assert_eq!(era_file.era(), era);
+    assert_eq!(era_file.slots(), era_start_slot..era_end_slot);
+
+    // Read headers and blocks by slots.
+    let expected_beacon_header = load_beacon_block_header_from_json_or_create_default(block_slot);
+    let expected_header: core::Header = expected_beacon_header.clone().into();
+    let header = era_file.header_at_slot(block_slot).unwrap().unwrap();
+    assert_eq!(header.pack().as_slice(), expected_header.pack().as_slice());
+    let block = era_file.block_at_slot(block_slot).unwrap().unwrap();
+    assert_eq!(block.body_root(), header.body_root);
+    assert!(era_file.header_at_slot(block_slot - 1).unwrap().is_none());
+    assert!(era_file.header_at_slot(era_end_slot).unwrap().is_none());
+    let headers = era_file.beacon_block_headers().unwrap();
+    assert_eq!(headers.len() as u64, SLOTS_PER_HISTORICAL_ROOT);
+    assert_eq!(
+        headers[(block_slot - era_start_slot) as usize],
+        expected_beacon_header
+    );
+
+    // The state in the built era file is invalid.
+    assert!(era_file.state().is_err());
+
+    // The headers in the era file should continue from the tip of the light client, but the
+    // blocks between the bootstrap and the block in the era file are absent.
+    {
+        let mut light_client = DummyLightClient::new(load_bootstrap(bootstrap_slot));
+        let client = light_client.client().clone();
+        let result = light_client.apply_era_file(&era_file);
+        assert!(matches!(result, Err(err) if err.kind() == io::ErrorKind::InvalidData));
+        let new_client = light_client.client();
+        assert_eq!(new_client.maximal_slot, client.maximal_slot);
+        assert_eq!(new_client.headers_mmr_root, client.headers_mmr_root);
+    }
+
+    // The era file should be complete.
+    {
+        let mut bytes = build_era_file(era, &[]);
+        bytes.pop();
+        assert!(EraFile::from_bytes(bytes).is_err());
+    }
+
+    // The start slot in the slot index should not be negative.
+    {
+        let mut bytes = build_era_file(era, &[]);
+        let position = bytes.len() - 8 * 3;
+        bytes[position..position + 8].copy_from_slice(&(-1i64).to_le_bytes());
+        assert!(EraFile::from_bytes(bytes).is_err());
+    }
+}
+
+// All slots after the bootstrap in the era are empty, so a synthetic chain could continue from
+// the headers in the era file.
+#[test]
+fn synthetic_testcase_update_after_era() {
+    setup();
+
+    let era = 800;
+    let era_start_slot = (era - 1) * SLOTS_PER_HISTORICAL_ROOT;
+    let era_end_slot = era * SLOTS_PER_HISTORICAL_ROOT;
+    let bootstrap_slot = era_start_slot + 32;
+    let mut config = SyntheticChainConfig::new(bootstrap_slot, era_end_slot + 128);
+    config.empty_slots = (bootstrap_slot + 1..era_end_slot).collect();
+    let chain = SyntheticChain::new(config);
+    let genesis_validators_root = chain.genesis_validators_root();
+    let bootstrap_root = chain
+        .header_at_slot(bootstrap_slot)
+        .unwrap()
+        .tree_hash_root();
+
+    let mut light_client = DummyLightClient::new(chain.bootstrap().into());
+    let era_file = EraFile::from_bytes(build_era_file(era, &[])).unwrap();
+    light_client.apply_era_file(&era_file).unwrap();
+    let client = light_client.client().clone();
+    assert_eq!(client.maximal_slot, era_end_slot - 1);
+    assert_eq!(client.finalized_slot, era_end_slot - 1);
+    // The last headers in the era are empty, so the tip is still the bootstrap header.
+    assert_eq!(client.tip_header_root, bootstrap_root);
+    assert_eq!(client.optimistic_header_root, bootstrap_root);
+
+    // The era file should be continuous with the light client.
+    {
+        let next_era_file = EraFile::from_bytes(build_era_file(era + 2, &[])).unwrap();
+        let mut light_client = DummyLightClient::new(chain.bootstrap().into());
+        light_client.apply_era_file(&era_file).unwrap();
+        assert!(light_client.apply_era_file(&next_era_file).is_err());
+    }
+
+    // The era is the whole sync committee period, so the headers after it are signed by the
+    // next sync committee.
+    let packed_client_sync_committee = light_client.client_sync_committee().pack();
+    let packed_next_client_sync_committee = {
+        let update =
+            LightClientUpdate::from(chain.light_client_update(bootstrap_slot, bootstrap_slot + 1));
+        let packed_next_client_sync_committee = update.build_next_client_sync_committee().pack();
+        let result = update
+            .build_sync_committee_update()
+            .verify_packed_client_sync_committee(
+                client.maximal_slot,
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                packed_next_client_sync_committee.as_reader(),
+            );
+        assert!(result.is_ok());
+        packed_next_client_sync_committee
+    };
+
+    let attested_slot = era_end_slot + 96;
+    let finality_update = chain.finality_update(attested_slot, attested_slot + 1);
+    let finalized_slot = finality_update.finalized_header.slot.as_u64();
+    assert_eq!(finalized_slot, era_end_slot + 32);
+    let headers = chain.beacon_block_headers(client.maximal_slot + 1, finalized_slot);
+    let client_update = light_client.apply_finality_update(finality_update, headers);
+    let new_client = light_client.client().clone();
+    let result = client_update.verify_client_update(
+        client,
+        genesis_validators_root,
+        packed_next_client_sync_committee.as_reader(),
+        new_client,
+    );
+    assert!(result.is_ok());
+}
+
+fn load_signed_block(slot: u64) -> SignedBeaconBlock<MainnetEthSpec> {
+    let case_dir = "mainnet/beacon/block";
+    let filename = format!("slot-{slot:09}.json");
+    let json_file = find_json_file(case_dir, &filename);
+    let json_str = fs::read_to_string(json_file).unwrap();
+    let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let block: BeaconBlock<MainnetEthSpec> =
+        serde_json::from_value(json_value["data"]["message"].clone()).unwrap();
+    let signature: Signature =
+        serde_json::from_value(json_value["data"]["signature"].clone()).unwrap();
+    SignedBeaconBlock::from_block(block, signature)
+}
+
+// Builds an era file with the blocks, the state is not available, so an empty state is used.
+fn build_era_file(era: u64, blocks: &[SignedBeaconBlock<MainnetEthSpec>]) -> Vec<u8> {
+    let start_slot = (era - 1) * SLOTS_PER_HISTORICAL_ROOT;
+    let mut bytes = Vec::new();
+    write_entry(&mut bytes, VERSION, &[]);
+    let mut block_positions = vec![None; SLOTS_PER_HISTORICAL_ROOT as usize];
+    for block in blocks {
+        let index = (block.slot().as_u64() - start_slot) as usize;
+        block_positions[index] = Some(bytes.len());
+        let data = compress(&block.as_ssz_bytes());
+        write_entry(&mut bytes, COMPRESSED_SIGNED_BEACON_BLOCK, &data);
+    }
+    let state_position = bytes.len();
+    write_entry(&mut bytes, COMPRESSED_BEACON_STATE, &compress(&[]));
+    write_slot_index(&mut bytes, start_slot, &block_positions);
+    write_slot_index(
+        &mut bytes,
+        era * SLOTS_PER_HISTORICAL_ROOT,
+        &[Some(state_position)],
+    );
+    bytes
+}
+
+fn write_entry(bytes: &mut Vec<u8>, entry_type: [u8; 2], data: &[u8]) {
+    bytes.extend_from_slice(&entry_type);
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&[0, 0]);
+    bytes.extend_from_slice(data);
+}
+
+fn write_slot_index(bytes: &mut Vec<u8>, start_slot: u64, positions: &[Option<usize>]) {
+    let index_position = bytes.len() as i64;
+    let mut data = Vec::new();
+    data.extend_from_slice(&(start_slot as i64).to_le_bytes());
+    for position in positions {
+        let offset = position
+            .map(|position| position as i64 - index_position)
+            .unwrap_or_default();
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data.extend_from_slice(&(positions.len() as i64).to_le_bytes());
+    write_entry(bytes, SLOT_INDEX, &data);
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = snap::write::FrameEncoder::new(Vec::new());
+    encoder.write_all(data).unwrap();
+    encoder.into_inner().unwrap()
+}
@@ -0,0 +1,311 @@
+use eth2_types::{HistoricalSummary, VariableList};
+use eth_light_client_in_ckb_prover::{CachedBeaconState, SszNode};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{
+        forks::{
+            self,
+            phase0::{HISTORICAL_ROOTS_LIMIT, SLOTS_PER_HISTORICAL_ROOT},
+        },
+        get_generalized_index_of_historical_block_root, helpers, ChainConfig,
+    },
+    error::HistoricalHeaderProofError,
+    types::{core, prelude::*},
+};
+use ssz::Decode as _;
+use tree_hash::{Hash256, TreeHash as _};
+
+use crate::{setup, types::build_beacon_state_in_capella};
+
+// Only the block roots and the historical summaries are filled in the beacon states.
+#[test]
+fn mock_testcase_in_capella() {
+    setup();
+
+    let capella_start_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH);
+    let summary_index = 2;
+    let block_root_index = 100;
+    let header_slot =
+        capella_start_slot + SLOTS_PER_HISTORICAL_ROOT * summary_index + block_root_index;
+    let header = core::Header {
+        slot: header_slot,
+        proposer_index: 7,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: Hash256::repeat_byte(2),
+        body_root: Hash256::repeat_byte(3),
+    };
+
+    // The block roots in the state at the end of the era of the header.
+    let block_roots = {
+        let roots = (0..SLOTS_PER_HISTORICAL_ROOT)
+            .map(|index| {
+                if index == block_root_index {
+                    SszNode::leaf(&header)
+                } else {
+                    SszNode::leaf_with_root(Hash256::from_low_u64_be(index))
+                }
+            })
+            .collect();
+        SszNode::vector(roots)
+    };
+    let anchor_state = {
+        let summaries = (0..4)
+            .map(|index| {
+                let block_summary_root = if index == summary_index {
+                    block_roots.root()
+                } else {
+                    Hash256::from_low_u64_be(index)
+                };
+                SszNode::container(vec![
+                    (
+                        "block_summary_root",
+                        SszNode::leaf_with_root(block_summary_root),
+                    ),
+                    (
+                        "state_summary_root",
+                        SszNode::leaf_with_root(Hash256::repeat_byte(index as u8)),
+                    ),
+                ])
+            })
+            .collect();
+        let fields = forks::capella::containers::BEACON_STATE
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let node = if *name == "historical_summaries" {
+                    SszNode::list(summaries, HISTORICAL_ROOTS_LIMIT)
+                } else {
+                    SszNode::leaf_with_root(Hash256::repeat_byte(index as u8))
+                };
+                (*name, node)
+            })
+            .collect::<Vec<_>>();
+        SszNode::container(fields)
+    };
+
+    let anchor_header = core::Header {
+        slot: capella_start_slot + SLOTS_PER_HISTORICAL_ROOT * 4 + 5,
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(4),
+        state_root: anchor_state.root(),
+        body_root: Hash256::repeat_byte(5),
+    };
+    let client = anchor_header.initialize_client();
+
+    let header_ssz_proof = {
+        let block_roots_proof = block_roots
+            .generate_proof(&format!("[{block_root_index}]"))
+            .unwrap();
+        let block_summary_root_proof = anchor_state
+            .generate_proof(&format!(
+                "historical_summaries[{summary_index}].block_summary_root"
+            ))
+            .unwrap();
+        assert_eq!(block_roots_proof.leaf, header.tree_hash_root());
+        assert_eq!(block_summary_root_proof.leaf, block_roots.root());
+        let generalized_index = get_generalized_index_of_historical_block_root(
+            &ChainConfig::MAINNET,
+            anchor_header.slot,
+            header_slot,
+        )
+        .unwrap();
+        assert_eq!(
+            generalized_index >> block_roots_proof.branch.len(),
+            block_summary_root_proof.generalized_index
+        );
+        // The states before Capella have no historical summaries.
+        assert!(get_generalized_index_of_historical_block_root(
+            &ChainConfig::MAINNET,
+            capella_start_slot - 1,
+            header_slot
+        )
+        .is_none());
+        let mut branch = block_roots_proof.branch;
+        branch.extend(block_summary_root_proof.branch);
+        branch
+    };
+    let historical_proof = core::HistoricalHeaderProof {
+        anchor_header: anchor_header.clone(),
+        anchor_header_mmr_proof: Vec::new(),
+        header_ssz_proof,
+    };
+
+    let result = client.verify_packed_historical_header(
+        header.pack().as_reader(),
+        historical_proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+
+    // The historical header could be used in a transaction proof.
+    {
+        let historical_tx_proof = core::HistoricalTransactionProof {
+            historical_header_proof: historical_proof.clone(),
+            transaction_proof: core::TransactionProof {
+                header: header.clone(),
+                transaction_index: 0,
+                receipts_root: Default::default(),
+                header_mmr_proof: Vec::new(),
+                transaction_ssz_proof: Vec::new(),
+                receipt_mpt_proof: Vec::new(),
+                receipts_root_ssz_proof: Vec::new(),
+            },
+        };
+        let packed_proof = historical_tx_proof.pack();
+        let result = client.verify_packed_historical_transaction_header(packed_proof.as_reader());
+        assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+
+        // The proof is same after packing and unpacking.
+        let unpacked_proof: core::HistoricalTransactionProof = packed_proof.unpack();
+        assert_eq!(unpacked_proof.pack().as_slice(), packed_proof.as_slice());
+    }
+
+    // The historical header should be proven by the state root of the anchor header.
+    {
+        let wrong_header = core::Header {
+            proposer_index: 8,
+            ..header.clone()
+        };
+        let result = client.verify_packed_historical_header(
+            wrong_header.pack().as_reader(),
+            historical_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HistoricalHeaderProofError::HistoricalHeaderSszProof)
+        ));
+    }
+
+    // The era of the historical header should be completed before the anchor header.
+    {
+        let wrong_header = core::Header {
+            slot: anchor_header.slot - 1,
+            ..header.clone()
+        };
+        let result = client.verify_packed_historical_header(
+            wrong_header.pack().as_reader(),
+            historical_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HistoricalHeaderProofError::UnsupportedHistoricalSlot)
+        ));
+    }
+
+    // The historical header should be since Capella.
+    {
+        let wrong_header = core::Header {
+            slot: capella_start_slot - 1,
+            ..header.clone()
+        };
+        let result = client.verify_packed_historical_header(
+            wrong_header.pack().as_reader(),
+            historical_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HistoricalHeaderProofError::UnsupportedHistoricalSlot)
+        ));
+    }
+
+    // The anchor header should be in the client.
+    {
+        let other_header = core::Header {
+            slot: anchor_header.slot + 1,
+            ..anchor_header
+        };
+        let other_client = other_header.initialize_client();
+        let result = other_client.verify_packed_historical_header(
+            header.pack().as_reader(),
+            historical_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HistoricalHeaderProofError::Unsynchronized)
+        ));
+    }
+}
+
+#[test]
+fn testcase_build_historical_header_proof_from_beacon_states() {
+    setup();
+
+    let capella_start_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH);
+    let summary_index = 2;
+    let block_root_index = 100;
+    let header_slot =
+        capella_start_slot + SLOTS_PER_HISTORICAL_ROOT * summary_index + block_root_index;
+    let header = core::Header {
+        slot: header_slot,
+        proposer_index: 7,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: Hash256::repeat_byte(2),
+        body_root: Hash256::repeat_byte(3),
+    };
+
+    // The beacon state at the end of the era of the header.
+    let era_state = {
+        let era_end_slot = capella_start_slot + SLOTS_PER_HISTORICAL_ROOT * (summary_index + 1);
+        let mut state = build_beacon_state_in_capella(era_end_slot, 1);
+        state.block_roots_mut()[block_root_index as usize] = header.tree_hash_root();
+        CachedBeaconState::try_from(state).unwrap()
+    };
+    let anchor_slot = capella_start_slot + SLOTS_PER_HISTORICAL_ROOT * 4 + 5;
+    let anchor_state = {
+        let summaries = (0..4)
+            .map(|index| {
+                let block_summary_root = if index == summary_index {
+                    era_state.original().block_roots().tree_hash_root()
+                } else {
+                    Hash256::from_low_u64_be(index)
+                };
+                let state_summary_root = Hash256::repeat_byte(index as u8);
+                let bytes = [block_summary_root.as_bytes(), state_summary_root.as_bytes()].concat();
+                HistoricalSummary::from_ssz_bytes(&bytes).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut state = build_beacon_state_in_capella(anchor_slot, 2);
+        *state.historical_summaries_mut().unwrap() = VariableList::new(summaries).unwrap();
+        CachedBeaconState::try_from(state).unwrap()
+    };
+    assert_eq!(
+        anchor_state.state_root(),
+        anchor_state.original().tree_hash_root()
+    );
+
+    let anchor_header = core::Header {
+        slot: anchor_slot,
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(4),
+        state_root: anchor_state.state_root(),
+        body_root: Hash256::repeat_byte(5),
+    };
+    let client = anchor_header.initialize_client();
+
+    let historical_proof = anchor_state.build_historical_header_proof(
+        &anchor_header,
+        Vec::new(),
+        &era_state,
+        header_slot,
+    );
+    let result = client.verify_packed_historical_header(
+        header.pack().as_reader(),
+        historical_proof.pack().as_reader(),
+    );
+    assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+
+    // Other headers in the era are not proven by the proof.
+    {
+        let wrong_header = core::Header {
+            slot: header_slot + 1,
+            ..header
+        };
+        let result = client.verify_packed_historical_header(
+            wrong_header.pack().as_reader(),
+            historical_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(HistoricalHeaderProofError::HistoricalHeaderSszProof)
+        ));
+    }
+}
@@ -1,14 +1,33 @@
 use std::fs;
 
-use eth2_types::BeaconBlockHeader;
+use eth2_types::{
+    light_client_bootstrap::PatchedLightClientBootstrap,
+    light_client_finality_update::{LightClientFinalityUpdate, PatchedLightClientFinalityUpdate},
+    light_client_update::PatchedLightClientUpdate,
+    test_utils::{SeedableRng as _, TestRandom as _, XorShiftRng},
+    BeaconBlockHeader, BeaconState, BeaconStateCapella, MainnetEthSpec,
+};
+use eth_light_client_in_ckb_prover::{LightClientBootstrap, LightClientUpdate};
 use tree_hash::Hash256;
 
 use crate::find_json_file;
 
+mod beacon_state_proof;
 mod client_bootstrap;
+mod client_optimistic_update;
+mod client_sync_committee_commitment;
 mod client_update;
+mod client_update_batch;
+mod consensus_spec_tests;
+mod era_file;
+mod historical_header_proof;
+mod multi_header_proof;
+mod ssz_decoding;
 mod sync_committee_update;
+mod synthetic_chain;
 mod transaction_verification;
+mod validator_proof;
+mod witness_envelope;
 
 pub(crate) fn load_beacon_block_header_from_json_or_create_default(slot: u64) -> BeaconBlockHeader {
     let case_dir = "mainnet/beacon/header";
@@ -36,6 +55,51 @@ pub(crate) fn load_genesis_validators_root() -> Hash256 {
     serde_json::from_value(json_value["genesis_validators_root"].clone()).unwrap()
 }
 
+pub(crate) fn load_bootstrap(slot: u64) -> LightClientBootstrap {
+    let case_dir = "mainnet/light_client/bootstrap";
+    let filename = format!("slot-{slot:09}.json");
+    let json_file = find_json_file(case_dir, &filename);
+    let json_str = fs::read_to_string(json_file).unwrap();
+    let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let bootstrap: PatchedLightClientBootstrap<MainnetEthSpec> =
+        serde_json::from_value(json_value["data"].clone()).unwrap();
+    bootstrap.into()
+}
+
+pub(crate) fn load_finality_update(
+    finalized_slot: u64,
+) -> LightClientFinalityUpdate<MainnetEthSpec> {
+    let case_dir = "mainnet/light_client/finality_update";
+    let filename = format!("slot-{finalized_slot:09}.json");
+    let json_file = find_json_file(case_dir, &filename);
+    let json_str = fs::read_to_string(json_file).unwrap();
+    let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let finality_update: PatchedLightClientFinalityUpdate<MainnetEthSpec> =
+        serde_json::from_value(json_value["data"].clone()).unwrap();
+    finality_update.into()
+}
+
+pub(crate) fn load_light_client_update(period: u64) -> LightClientUpdate {
+    let case_dir = "mainnet/light_client/update";
+    let filename = format!("period-{period:06}.json");
+    let json_file = find_json_file(case_dir, &filename);
+    let json_str = fs::read_to_string(json_file).unwrap();
+    let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let update: PatchedLightClientUpdate<MainnetEthSpec> =
+        serde_json::from_value(json_value[0]["data"].clone()).unwrap();
+    update.into()
+}
+
+// Builds a beacon state with the Capella layout, all fields are random except the slot.
+pub(crate) fn build_beacon_state_in_capella(slot: u64, seed: u8) -> BeaconState<MainnetEthSpec> {
+    let mut rng = XorShiftRng::from_seed([seed; 16]);
+    let mut state = BeaconState::Capella(BeaconStateCapella::<MainnetEthSpec>::random_for_test(
+        &mut rng,
+    ));
+    *state.slot_mut() = slot.into();
+    state
+}
+
 #[test]
 fn mainnet_genesis_validators_root() {
     let dump_dir_opt = None;
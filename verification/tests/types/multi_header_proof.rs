@@ -0,0 +1,254 @@
+use eth_light_client_in_ckb_prover::{DummyLightClient, SyntheticChain, SyntheticChainConfig};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, helpers},
+    error::{MultiHeaderProofError, TxVerificationError},
+    types::{core, decode_witness, packed, prelude::*, WITNESS_ENVELOPE_VERSION},
+};
+use tree_hash::Hash256;
+
+use crate::setup;
+
+#[test]
+fn synthetic_testcase_multi_header_proof() {
+    setup();
+
+    let start_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH)
+        + forks::phase0::SLOTS_PER_EPOCH * forks::altair::EPOCHS_PER_SYNC_COMMITTEE_PERIOD * 2;
+    let end_slot = start_slot + forks::phase0::SLOTS_PER_EPOCH * 5;
+    let mut config = SyntheticChainConfig::new(start_slot, end_slot);
+    config.empty_slots = (start_slot + 20..start_slot + 30).collect();
+    let chain = SyntheticChain::new(config);
+
+    let mut light_client = DummyLightClient::new(chain.bootstrap().into());
+    {
+        let attested_slot = end_slot - 2;
+        let finality_update = chain.finality_update(attested_slot, attested_slot + 1);
+        let finalized_slot = finality_update.finalized_header.slot.as_u64();
+        let headers = chain.beacon_block_headers(start_slot + 1, finalized_slot);
+        let _ = light_client.apply_finality_update(finality_update, headers);
+    }
+    let optimistic_slot = end_slot - 2;
+    {
+        let optimistic_update = chain.optimistic_update(optimistic_slot, optimistic_slot + 1);
+        let _ = light_client.apply_optimistic_update(optimistic_update);
+    }
+    let client = light_client.client().clone();
+    assert_eq!(client.minimal_slot, start_slot);
+    assert_eq!(client.maximal_slot, start_slot + 64);
+    assert_eq!(client.optimistic_slot, optimistic_slot);
+
+    // Several headers, include an empty slot, are proven by one MMR proof.
+    let slots = [
+        start_slot + 3,
+        start_slot + 10,
+        start_slot + 11,
+        start_slot + 25,
+        start_slot + 64,
+    ];
+    let proof = light_client.build_multi_header_proof(&slots).unwrap();
+    assert_eq!(proof.headers.len(), slots.len());
+    {
+        let result = client.verify_headers(
+            proof.headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        let header_statuses = result.ok().unwrap();
+        assert_eq!(header_statuses.len(), slots.len());
+        assert!(header_statuses
+            .iter()
+            .all(|status| *status == core::HeaderStatus::Finalized));
+
+        // The MMR proof should be smaller than all single proofs.
+        let single_proofs_size: usize = slots
+            .iter()
+            .map(|slot| light_client.build_header_mmr_proof(*slot).len())
+            .sum();
+        assert!(proof.headers_mmr_proof.len() < single_proofs_size);
+    }
+
+    // For one header, the MMR proof is same as the single header proof.
+    {
+        let slot = start_slot + 10;
+        let proof = light_client.build_multi_header_proof(&[slot]).unwrap();
+        let single_proof = light_client.build_header_mmr_proof(slot);
+        assert_eq!(
+            proof.headers_mmr_proof.pack().as_slice(),
+            single_proof.pack().as_slice()
+        );
+        let result = client.verify_single_header(
+            proof.headers[0].pack().as_reader(),
+            single_proof.pack().as_reader(),
+        );
+        assert!(matches!(result, Ok(true)));
+    }
+
+    // The witness envelope for the proof.
+    {
+        let envelope = core::WitnessEnvelope {
+            version: WITNESS_ENVELOPE_VERSION,
+            witness: core::Witness::MultiHeaderProof(proof.clone()),
+        };
+        let packed_envelope = envelope.pack();
+        let result = decode_witness::<packed::MultiHeaderProofReader>(packed_envelope.as_slice());
+        let (_, reader) = result.ok().unwrap();
+        let result = client.verify_headers(reader.headers(), reader.headers_mmr_proof());
+        assert!(result.is_ok());
+    }
+
+    // The optimistic header is proven with the headers in the MMR.
+    {
+        let mut slots_with_optimistic = slots.to_vec();
+        slots_with_optimistic.push(optimistic_slot);
+        let proof_with_optimistic = light_client
+            .build_multi_header_proof(&slots_with_optimistic)
+            .unwrap();
+        assert_eq!(
+            proof_with_optimistic.headers_mmr_proof.pack().as_slice(),
+            proof.headers_mmr_proof.pack().as_slice()
+        );
+        let result = client.verify_headers(
+            proof_with_optimistic.headers.pack().as_reader(),
+            proof_with_optimistic.headers_mmr_proof.pack().as_reader(),
+        );
+        let header_statuses = result.ok().unwrap();
+        assert_eq!(
+            header_statuses.last(),
+            Some(&core::HeaderStatus::Optimistic)
+        );
+
+        // Only the optimistic header, no MMR proof is required.
+        let proof_for_optimistic = light_client
+            .build_multi_header_proof(&[optimistic_slot])
+            .unwrap();
+        assert!(proof_for_optimistic.headers_mmr_proof.is_empty());
+        let result = client.verify_headers(
+            proof_for_optimistic.headers.pack().as_reader(),
+            proof_for_optimistic.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result.as_deref(),
+            Ok([core::HeaderStatus::Optimistic])
+        ));
+
+        let mut headers = proof_with_optimistic.headers.clone();
+        headers.last_mut().unwrap().body_root = Hash256::repeat_byte(1);
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof_with_optimistic.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(MultiHeaderProofError::OptimisticHeaderRoot)
+        ));
+    }
+
+    // The headers of the transaction proofs are proven by the multi-header proof.
+    {
+        let tx_proof = |slot: u64| core::TransactionProof {
+            header: chain.header_at_slot(slot).unwrap().clone().into(),
+            transaction_index: 0,
+            receipts_root: Default::default(),
+            header_mmr_proof: Vec::new(),
+            transaction_ssz_proof: Vec::new(),
+            receipt_mpt_proof: Vec::new(),
+            receipts_root_ssz_proof: Vec::new(),
+        };
+        let mut slots_with_optimistic = slots.to_vec();
+        slots_with_optimistic.push(optimistic_slot);
+        let proof_with_optimistic = light_client
+            .build_multi_header_proof(&slots_with_optimistic)
+            .unwrap()
+            .pack();
+
+        let tx_proofs = [tx_proof(start_slot + 10), tx_proof(optimistic_slot)]
+            .iter()
+            .map(|tx_proof| tx_proof.pack())
+            .collect::<Vec<_>>();
+        let tx_proof_readers = tx_proofs
+            .iter()
+            .map(|tx_proof| tx_proof.as_reader())
+            .collect::<Vec<_>>();
+        let result = client
+            .verify_packed_transaction_proofs(proof_with_optimistic.as_reader(), &tx_proof_readers);
+        assert_eq!(
+            result.ok().unwrap(),
+            vec![
+                core::HeaderStatus::Finalized,
+                core::HeaderStatus::Optimistic
+            ]
+        );
+
+        let unproven_tx_proof = tx_proof(start_slot + 12).pack();
+        let result = client.verify_packed_transaction_proofs(
+            proof_with_optimistic.as_reader(),
+            &[unproven_tx_proof.as_reader()],
+        );
+        assert!(matches!(result, Err(TxVerificationError::UnprovenHeader)));
+    }
+
+    // The slots should be in the client.
+    {
+        let result = light_client.build_multi_header_proof(&[start_slot + 3, start_slot + 65]);
+        assert!(result.is_err());
+    }
+
+    // The headers should not be changed.
+    {
+        let mut headers = proof.headers.clone();
+        headers[2].body_root = Hash256::repeat_byte(1);
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(result, Err(MultiHeaderProofError::HeaderMmrProof)));
+    }
+
+    // The headers should be sorted without duplicates.
+    {
+        let mut headers = proof.headers.clone();
+        headers.swap(0, 1);
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(MultiHeaderProofError::UnsortedHeaders)
+        ));
+
+        let mut headers = proof.headers.clone();
+        headers.insert(1, headers[0].clone());
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(MultiHeaderProofError::UnsortedHeaders)
+        ));
+    }
+
+    // The headers should be in the client.
+    {
+        let mut headers = proof.headers.clone();
+        headers.push(
+            chain
+                .header_at_slot(start_slot + 65)
+                .unwrap()
+                .clone()
+                .into(),
+        );
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(result, Err(MultiHeaderProofError::Unsynchronized)));
+
+        let headers: core::HeaderVec = Vec::new();
+        let result = client.verify_headers(
+            headers.pack().as_reader(),
+            proof.headers_mmr_proof.pack().as_reader(),
+        );
+        assert!(matches!(result, Err(MultiHeaderProofError::EmptyHeaders)));
+    }
+}
@@ -0,0 +1,246 @@
+use std::{fs, io::Write as _};
+
+use eth2_types::{
+    light_client_bootstrap::PatchedLightClientBootstrap,
+    light_client_update::PatchedLightClientUpdate, BeaconBlock, MainnetEthSpec, Signature,
+    SignedBeaconBlock,
+};
+use eth_light_client_in_ckb_prover::{
+    CachedBeaconBlock, LightClientBootstrap, LightClientBootstrapCapella, LightClientFork,
+    LightClientHeaderCapella, LightClientUpdate, LightClientUpdateCapella,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::helpers, types::prelude::*, utilities::bls::compute_fork_digest,
+};
+use ssz::Encode as _;
+use tree_hash::Hash256;
+
+use crate::{find_json_files, setup, types::load_genesis_validators_root};
+
+#[test]
+fn light_client_bootstrap_round_trip() {
+    setup();
+
+    let json_files = find_json_files("mainnet/light_client/bootstrap", "slot-");
+    assert!(!json_files.is_empty());
+    for json_file in json_files {
+        let json_str = fs::read_to_string(&json_file).unwrap();
+        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        let data = &json_value["data"];
+        let patched: PatchedLightClientBootstrap<MainnetEthSpec> =
+            serde_json::from_value(data.clone()).unwrap();
+        let expected: LightClientBootstrap = patched.into();
+        let fork = LightClientFork::from_name(json_value["version"].as_str().unwrap()).unwrap();
+
+        let bytes = if fork == LightClientFork::Capella {
+            let bootstrap = LightClientBootstrapCapella {
+                header: load_light_client_header_capella(&data["header"]),
+                current_sync_committee: serde_json::from_value(
+                    data["current_sync_committee"].clone(),
+                )
+                .unwrap(),
+                current_sync_committee_branch: serde_json::from_value(
+                    data["current_sync_committee_branch"].clone(),
+                )
+                .unwrap(),
+            };
+            // The layout before Capella should not be accepted since Capella.
+            let wrong_bytes = expected.original().as_ssz_bytes();
+            let result = LightClientBootstrap::from_ssz_bytes(&wrong_bytes, fork);
+            assert!(result.is_err());
+            let result =
+                LightClientBootstrap::from_ssz_bytes(&wrong_bytes, LightClientFork::Altair);
+            assert!(result.is_err());
+            bootstrap.as_ssz_bytes()
+        } else {
+            expected.original().as_ssz_bytes()
+        };
+
+        let bootstrap = LightClientBootstrap::from_ssz_bytes(&bytes, fork).unwrap();
+        assert_eq!(bootstrap.original(), expected.original());
+        assert_eq!(
+            bootstrap.build_client_bootstrap().pack().as_slice(),
+            expected.build_client_bootstrap().pack().as_slice()
+        );
+
+        let genesis_validators_root = load_genesis_validators_root();
+        let fork_version = helpers::compute_fork_version_at_slot(expected.slot().into());
+        let chunk = encode_response_chunk(&fork_version, &bytes);
+        let bootstrap =
+            LightClientBootstrap::from_p2p_response(&chunk, genesis_validators_root).unwrap();
+        assert_eq!(bootstrap.original(), expected.original());
+
+        // The context bytes are the fork digest of another chain.
+        let result = LightClientBootstrap::from_p2p_response(&chunk, Hash256::repeat_byte(1));
+        assert!(result.is_err());
+        // The response code is not success.
+        let mut wrong_chunk = chunk.clone();
+        wrong_chunk[0] = 1;
+        let result = LightClientBootstrap::from_p2p_response(&wrong_chunk, genesis_validators_root);
+        assert!(result.is_err());
+        // The payload is truncated.
+        let wrong_chunk = &chunk[..chunk.len() - 1];
+        let result = LightClientBootstrap::from_p2p_response(wrong_chunk, genesis_validators_root);
+        assert!(result.is_err());
+        // Only one bootstrap is allowed in a response.
+        let wrong_chunks = [chunk.clone(), chunk].concat();
+        let result =
+            LightClientBootstrap::from_p2p_response(&wrong_chunks, genesis_validators_root);
+        assert!(result.is_err());
+    }
+}
+
+#[test]
+fn light_client_update_round_trip() {
+    setup();
+
+    let json_files = find_json_files("mainnet/light_client/update", "period-");
+    assert!(!json_files.is_empty());
+    for json_file in json_files {
+        let json_str = fs::read_to_string(&json_file).unwrap();
+        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        let mut chunks = Vec::new();
+        let mut expected_updates = Vec::new();
+        for item in json_value.as_array().unwrap() {
+            let data = &item["data"];
+            let patched: PatchedLightClientUpdate<MainnetEthSpec> =
+                serde_json::from_value(data.clone()).unwrap();
+            let expected: LightClientUpdate = patched.into();
+            let fork = LightClientFork::from_name(item["version"].as_str().unwrap()).unwrap();
+
+            let bytes = if fork == LightClientFork::Capella {
+                let update = LightClientUpdateCapella {
+                    attested_header: load_light_client_header_capella(&data["attested_header"]),
+                    next_sync_committee: serde_json::from_value(
+                        data["next_sync_committee"].clone(),
+                    )
+                    .unwrap(),
+                    next_sync_committee_branch: serde_json::from_value(
+                        data["next_sync_committee_branch"].clone(),
+                    )
+                    .unwrap(),
+                    finalized_header: load_light_client_header_capella(&data["finalized_header"]),
+                    finality_branch: serde_json::from_value(data["finality_branch"].clone())
+                        .unwrap(),
+                    sync_aggregate: serde_json::from_value(data["sync_aggregate"].clone()).unwrap(),
+                    signature_slot: serde_json::from_value(data["signature_slot"].clone()).unwrap(),
+                };
+                // The layout before Capella should not be accepted since Capella.
+                let wrong_bytes = expected.original().as_ssz_bytes();
+                let result = LightClientUpdate::from_ssz_bytes(&wrong_bytes, fork);
+                assert!(result.is_err());
+                let result =
+                    LightClientUpdate::from_ssz_bytes(&wrong_bytes, LightClientFork::Altair);
+                assert!(result.is_err());
+                update.as_ssz_bytes()
+            } else {
+                expected.original().as_ssz_bytes()
+            };
+
+            let update = LightClientUpdate::from_ssz_bytes(&bytes, fork).unwrap();
+            assert_eq!(update.original(), expected.original());
+            assert_eq!(
+                update.build_sync_committee_update().pack().as_slice(),
+                expected.build_sync_committee_update().pack().as_slice()
+            );
+
+            let attested_slot = expected.original().attested_header.slot.into();
+            let fork_version = helpers::compute_fork_version_at_slot(attested_slot);
+            chunks.push(encode_response_chunk(&fork_version, &bytes));
+            expected_updates.push(expected);
+        }
+
+        // The updates in a response could be in different forks.
+        let genesis_validators_root = load_genesis_validators_root();
+        let updates =
+            LightClientUpdate::from_p2p_response(&chunks.concat(), genesis_validators_root)
+                .unwrap();
+        assert_eq!(updates.len(), expected_updates.len());
+        for (update, expected) in updates.iter().zip(expected_updates.iter()) {
+            assert_eq!(update.original(), expected.original());
+        }
+    }
+}
+
+#[test]
+fn beacon_block_round_trip() {
+    setup();
+
+    let json_files = find_json_files("mainnet/beacon/block", "slot-");
+    assert!(!json_files.is_empty());
+    for json_file in json_files {
+        let json_str = fs::read_to_string(&json_file).unwrap();
+        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        let block: BeaconBlock<MainnetEthSpec> =
+            serde_json::from_value(json_value["data"]["message"].clone()).unwrap();
+        let signature: Signature =
+            serde_json::from_value(json_value["data"]["signature"].clone()).unwrap();
+        let bytes = SignedBeaconBlock::from_block(block.clone(), signature).as_ssz_bytes();
+        let expected: CachedBeaconBlock = block.into();
+
+        let block = CachedBeaconBlock::from_ssz_bytes(&bytes).unwrap();
+        assert_eq!(block.original(), expected.original());
+        assert_eq!(block.body_root(), expected.body_root());
+
+        let block = CachedBeaconBlock::from_ssz_snappy_bytes(&compress(&bytes)).unwrap();
+        assert_eq!(block.original(), expected.original());
+
+        let genesis_validators_root = load_genesis_validators_root();
+        let fork_version = helpers::compute_fork_version_at_slot(expected.slot().into());
+        let fork_digest = compute_fork_digest(&fork_version, genesis_validators_root);
+        let message = snap::raw::Encoder::new().compress_vec(&bytes).unwrap();
+        let block =
+            CachedBeaconBlock::from_gossip_message(&message, &fork_digest, genesis_validators_root)
+                .unwrap();
+        assert_eq!(block.original(), expected.original());
+
+        // The gossip messages are in the snappy block format, not the frame format.
+        let result = CachedBeaconBlock::from_gossip_message(
+            &compress(&bytes),
+            &fork_digest,
+            genesis_validators_root,
+        );
+        assert!(result.is_err());
+        // The topic is for another fork.
+        let wrong_fork_digest = compute_fork_digest(&[0xff; 4], genesis_validators_root);
+        let result = CachedBeaconBlock::from_gossip_message(
+            &message,
+            &wrong_fork_digest,
+            genesis_validators_root,
+        );
+        assert!(result.is_err());
+
+        // The bytes should be a signed block.
+        let wrong_bytes = expected.original().as_ssz_bytes();
+        assert!(CachedBeaconBlock::from_ssz_bytes(&wrong_bytes).is_err());
+    }
+}
+
+fn load_light_client_header_capella(json_value: &serde_json::Value) -> LightClientHeaderCapella {
+    LightClientHeaderCapella {
+        beacon: serde_json::from_value(json_value["beacon"].clone()).unwrap(),
+        execution: serde_json::from_value(json_value["execution"].clone()).unwrap(),
+        execution_branch: serde_json::from_value(json_value["execution_branch"].clone()).unwrap(),
+    }
+}
+
+// Encodes a successful response chunk in the req/resp domain with the `ssz_snappy` encoding.
+fn encode_response_chunk(fork_version: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+    let fork_digest = compute_fork_digest(fork_version, load_genesis_validators_root());
+    let mut chunk = vec![0];
+    chunk.extend_from_slice(&fork_digest);
+    let mut length = payload.len();
+    while length >= 0x80 {
+        chunk.push((length & 0x7f) as u8 | 0x80);
+        length >>= 7;
+    }
+    chunk.push(length as u8);
+    chunk.extend_from_slice(&compress(payload));
+    chunk
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = snap::write::FrameEncoder::new(Vec::new());
+    encoder.write_all(data).unwrap();
+    encoder.into_inner().unwrap()
+}
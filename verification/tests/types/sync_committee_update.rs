@@ -1,16 +1,18 @@
 use std::fs;
 
-use eth2_types::{
-    light_client_bootstrap::PatchedLightClientBootstrap,
-    light_client_update::PatchedLightClientUpdate, MainnetEthSpec,
+use eth_light_client_in_ckb_prover::{
+    BestUpdateStore, LightClientBootstrap, LightClientUpdate, SyntheticChain, SyntheticChainConfig,
 };
-use eth_light_client_in_ckb_prover::{LightClientBootstrap, LightClientUpdate};
 use eth_light_client_in_ckb_verification::{
-    consensus_specs::{forks, helpers},
-    types::prelude::*,
+    consensus_specs::{forks, helpers, ChainConfig},
+    error::SyncCommitteeUpdateError,
+    types::{core, prelude::*},
 };
 
-use crate::{find_json_file, setup, types::load_genesis_validators_root};
+use crate::{
+    setup,
+    types::{load_bootstrap, load_genesis_validators_root, load_light_client_update},
+};
 
 #[test]
 fn mainnet_testcase_altair_to_bellatrix() {
@@ -32,6 +34,213 @@ fn mainnet_testcase_bellatrix_to_capella() {
     sync_committee_update(param);
 }
 
+#[test]
+fn mainnet_testcase_altair_to_bellatrix_signed_in_next_period() {
+    let param = Parameter {
+        bootstrap_slot: 4612096,
+        count: 5,
+        ..Default::default()
+    };
+    sync_committee_update_signed_in_next_period(param);
+}
+
+#[test]
+fn mainnet_testcase_bellatrix_to_capella_signed_in_next_period() {
+    let param = Parameter {
+        bootstrap_slot: 6184960,
+        count: 5,
+        ..Default::default()
+    };
+    sync_committee_update_signed_in_next_period(param);
+}
+
+// The attested header is in the last slots of a period, but the signature is in the next period.
+#[test]
+fn synthetic_testcase_signed_in_next_period_at_boundary() {
+    setup();
+
+    let boundary_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH);
+    let start_slot = boundary_slot - forks::phase0::SLOTS_PER_EPOCH * 5;
+    let end_slot = boundary_slot + forks::phase0::SLOTS_PER_EPOCH * 5;
+    let chain = SyntheticChain::new(SyntheticChainConfig::new(start_slot, end_slot));
+    let genesis_validators_root = chain.genesis_validators_root();
+
+    let current_period = helpers::compute_sync_committee_period_at_slot(start_slot);
+    let signature_period = helpers::compute_sync_committee_period_at_slot(boundary_slot);
+    assert_eq!(current_period + 1, signature_period);
+
+    let current_sync_committee = LightClientBootstrap::from(chain.bootstrap())
+        .build_client_sync_committee()
+        .pack();
+    let stored_next_sync_committee =
+        LightClientUpdate::from(chain.light_client_update(start_slot + 1, start_slot + 2))
+            .build_next_client_sync_committee()
+            .pack();
+
+    // Attested in the current period, so the stored next sync committee is proven again.
+    {
+        let update = LightClientUpdate::from(
+            chain.light_client_update(boundary_slot - 2, boundary_slot + 1),
+        );
+        let sync_committee_update = update.build_sync_committee_update();
+        let next_sync_committee = update.build_next_client_sync_committee().pack();
+        assert_eq!(
+            next_sync_committee.as_slice(),
+            stored_next_sync_committee.as_slice()
+        );
+
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                start_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                stored_next_sync_committee.as_reader(),
+                next_sync_committee.as_reader(),
+            );
+        assert!(result.is_ok());
+
+        // The signature could not be verified with the current sync committee.
+        let result = sync_committee_update.verify_packed_client_sync_committee(
+            start_slot,
+            genesis_validators_root,
+            current_sync_committee.as_reader(),
+            next_sync_committee.as_reader(),
+        );
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeUpdateError::SignatureInNextPeriod)
+        ));
+
+        // Another sync committee in the period of the stored one.
+        let conflicted_next_sync_committee = {
+            let mut sync_committee = chain
+                .light_client_update(boundary_slot + 1, boundary_slot + 2)
+                .build_next_client_sync_committee();
+            sync_committee.period = signature_period;
+            sync_committee.pack()
+        };
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                start_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                stored_next_sync_committee.as_reader(),
+                conflicted_next_sync_committee.as_reader(),
+            );
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeUpdateError::ConflictedNextSyncCommittee)
+        ));
+
+        // The stored next sync committee is uncompressed, but the others are compressed.
+        let stored_next_uncompressed_sync_committee = stored_next_sync_committee
+            .unpack()
+            .uncompress()
+            .unwrap()
+            .pack();
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                start_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                stored_next_uncompressed_sync_committee.as_reader(),
+                next_sync_committee.as_reader(),
+            );
+        assert!(result.is_ok());
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                start_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                stored_next_uncompressed_sync_committee.as_reader(),
+                conflicted_next_sync_committee.as_reader(),
+            );
+        assert!(matches!(
+            result,
+            Err(SyncCommitteeUpdateError::ConflictedNextSyncCommittee)
+        ));
+    }
+
+    // Attested in the period of the stored next sync committee, so the sync committee after it
+    // is proven.
+    {
+        let update = LightClientUpdate::from(
+            chain.light_client_update(boundary_slot + 1, boundary_slot + 2),
+        );
+        let sync_committee_update = update.build_sync_committee_update();
+        let next_sync_committee = update.build_next_client_sync_committee().pack();
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                boundary_slot - 1,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                stored_next_sync_committee.as_reader(),
+                next_sync_committee.as_reader(),
+            );
+        assert!(result.is_ok());
+    }
+}
+
+#[test]
+fn mainnet_best_update_selection() {
+    setup();
+
+    let mut store = BestUpdateStore::default();
+    for period in (563..=568).chain(755..=760) {
+        let update = load_light_client_update(period);
+        assert_eq!(update.sync_committee_period(), period);
+
+        let config = &ChainConfig::MAINNET;
+        let summary = update.summary();
+        assert!(summary.has_supermajority(config));
+        assert!(summary.has_relevant_sync_committee(config));
+        assert!(!summary.is_better_than(&summary, config));
+
+        // Fewer participants.
+        let worse = core::UpdateSummary {
+            active_participants: summary.active_participants - 1,
+            ..summary
+        };
+        assert!(summary.is_better_than(&worse, config));
+        assert!(!worse.is_better_than(&summary, config));
+        // No supermajority participation.
+        let worse = core::UpdateSummary {
+            active_participants: forks::altair::SYNC_COMMITTEE_SIZE * 2 / 3 - 1,
+            attested_slot: summary.attested_slot - 1,
+            ..summary
+        };
+        assert!(summary.is_better_than(&worse, config));
+        // No next sync committee, as a finality update.
+        let worse = core::UpdateSummary {
+            has_next_sync_committee: false,
+            active_participants: forks::altair::SYNC_COMMITTEE_SIZE,
+            ..summary
+        };
+        assert!(summary.is_better_than(&worse, config));
+        // Newer data.
+        let worse = core::UpdateSummary {
+            attested_slot: summary.attested_slot + 1,
+            signature_slot: summary.signature_slot + 1,
+            ..summary
+        };
+        assert!(summary.is_better_than(&worse, config));
+
+        assert!(store.insert(update.clone()));
+        // A same update is not better than the stored one.
+        assert!(!store.insert(update.clone()));
+        assert!(!update.is_better_than(store.get(period).unwrap(), config));
+    }
+    assert_eq!(store.periods().count(), 12);
+
+    store.prune(755);
+    assert_eq!(
+        store.periods().collect::<Vec<_>>(),
+        (755..=760).collect::<Vec<_>>()
+    );
+    assert!(store.remove(755).is_some());
+    assert!(store.get(755).is_none());
+}
+
 #[derive(Default)]
 struct Parameter {
     bootstrap_slot: u64,
@@ -44,16 +253,7 @@ fn sync_committee_update(param: Parameter) {
 
     let genesis_validators_root = load_genesis_validators_root();
 
-    let bootstrap: LightClientBootstrap = {
-        let case_dir = "mainnet/light_client/bootstrap";
-        let filename = format!("slot-{:09}.json", param.bootstrap_slot);
-        let json_file = find_json_file(case_dir, &filename);
-        let json_str = fs::read_to_string(json_file).unwrap();
-        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-        let bootstrap: PatchedLightClientBootstrap<MainnetEthSpec> =
-            serde_json::from_value(json_value["data"].clone()).unwrap();
-        bootstrap.into()
-    };
+    let bootstrap = load_bootstrap(param.bootstrap_slot);
     let bootstrap_period = helpers::compute_sync_committee_period_at_slot(param.bootstrap_slot);
 
     let mut current_period = bootstrap_period;
@@ -68,16 +268,7 @@ fn sync_committee_update(param: Parameter) {
     }
 
     for i in 0..param.count {
-        let update: LightClientUpdate = {
-            let case_dir = "mainnet/light_client/update";
-            let filename = format!("period-{:06}.json", current_period);
-            let json_file = find_json_file(case_dir, &filename);
-            let json_str = fs::read_to_string(json_file).unwrap();
-            let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-            let update: PatchedLightClientUpdate<MainnetEthSpec> =
-                serde_json::from_value(json_value[0]["data"].clone()).unwrap();
-            update.into()
-        };
+        let update = load_light_client_update(current_period);
 
         let sync_committee_update = update.build_sync_committee_update();
         let next_sync_committee = update.build_next_client_sync_committee().pack();
@@ -106,6 +297,26 @@ fn sync_committee_update(param: Parameter) {
             );
         }
 
+        // Verify with the uncompressed sync committees.
+        {
+            let current_uncompressed_sync_committee =
+                current_sync_committee.unpack().uncompress().unwrap().pack();
+            let next_uncompressed_sync_committee =
+                next_sync_committee.unpack().uncompress().unwrap().pack();
+            let result = sync_committee_update.verify_packed_client_sync_committee(
+                first_slot,
+                genesis_validators_root,
+                current_uncompressed_sync_committee.as_reader(),
+                next_uncompressed_sync_committee.as_reader(),
+            );
+            assert!(
+                result.is_ok(),
+                "verify next client uncompressed sync committee failed \
+                (loop: {i}, bootstrap-slot: {}, current-period: {current_period})",
+                param.bootstrap_slot
+            );
+        }
+
         if let Some(dump_dir) = param.dump_dir_opt {
             let periods_passed = i + 1;
             let packed_sync_committee_update = sync_committee_update.pack();
@@ -129,3 +340,84 @@ fn sync_committee_update(param: Parameter) {
         current_sync_committee = next_sync_committee;
     }
 }
+
+// The last client is still in the previous period, but the next client sync committee, which is
+// in the period of the signature, has been stored already.
+//
+// N.B. All updates in the test data are signed in the same period as their attested headers,
+// the case, which the attested header is in the previous period, is covered by the synthetic
+// test case.
+fn sync_committee_update_signed_in_next_period(param: Parameter) {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    let bootstrap = load_bootstrap(param.bootstrap_slot);
+    let bootstrap_period = helpers::compute_sync_committee_period_at_slot(param.bootstrap_slot);
+
+    let mut current_period = bootstrap_period;
+    let mut current_sync_committee = bootstrap.build_client_sync_committee().pack();
+    let mut stored_next_sync_committee = load_light_client_update(current_period)
+        .build_next_client_sync_committee()
+        .pack();
+    let slots_in_one_period =
+        forks::phase0::SLOTS_PER_EPOCH * forks::altair::EPOCHS_PER_SYNC_COMMITTEE_PERIOD;
+
+    for i in 0..param.count {
+        let signature_period = current_period + 1;
+        let update = load_light_client_update(signature_period);
+
+        let sync_committee_update = update.build_sync_committee_update();
+        let next_sync_committee = update.build_next_client_sync_committee().pack();
+
+        // first slot in current period
+        let first_slot = slots_in_one_period * current_period;
+        for (client_max_slot, expected) in [
+            (first_slot - 1, false),
+            (first_slot, true),
+            (first_slot + slots_in_one_period - 1, true),
+            (first_slot + slots_in_one_period, false),
+        ] {
+            let result = sync_committee_update
+                .verify_packed_client_sync_committee_signed_in_next_period(
+                    client_max_slot,
+                    genesis_validators_root,
+                    current_sync_committee.as_reader(),
+                    stored_next_sync_committee.as_reader(),
+                    next_sync_committee.as_reader(),
+                );
+            assert_eq!(
+                result.is_ok(),
+                expected,
+                "verify next client sync committee expect {expected} but got opposite \
+                (loop: {i}, bootstrap-slot: {}, bootstrap-period: {bootstrap_period}, \
+                current-period: {current_period}, client-max-slot: {client_max_slot})",
+                param.bootstrap_slot
+            );
+
+            // The signature could not be verified with the current sync committee.
+            let result = sync_committee_update.verify_packed_client_sync_committee(
+                client_max_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                next_sync_committee.as_reader(),
+            );
+            assert!(result.is_err());
+        }
+
+        // The stored next sync committee should be in the period of the signature.
+        let result = sync_committee_update
+            .verify_packed_client_sync_committee_signed_in_next_period(
+                first_slot,
+                genesis_validators_root,
+                current_sync_committee.as_reader(),
+                current_sync_committee.as_reader(),
+                next_sync_committee.as_reader(),
+            );
+        assert!(result.is_err());
+
+        current_period += 1;
+        current_sync_committee = stored_next_sync_committee;
+        stored_next_sync_committee = next_sync_committee;
+    }
+}
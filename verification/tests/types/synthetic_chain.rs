@@ -0,0 +1,262 @@
+use eth2_types::MinimalEthSpec;
+use eth_light_client_in_ckb_prover::{
+    DummyLightClient, LightClientBootstrap, LightClientUpdate, SyntheticChain, SyntheticChainConfig,
+};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, helpers, ChainConfig, Fork},
+    error::{ClientBootstrapError, ClientUpdateError},
+    types::{core, prelude::*},
+    utilities::{bls, ssz::is_valid_merkle_branch},
+};
+use tree_hash::{Hash256, TreeHash as _};
+
+use crate::setup;
+
+// The fork from Bellatrix to Capella is at the start of a sync committee period.
+#[test]
+fn synthetic_testcase_bellatrix_to_capella() {
+    setup();
+
+    let capella_start_slot = helpers::compute_start_slot_at_epoch(forks::capella::FORK_EPOCH);
+    let start_slot = capella_start_slot - forks::phase0::SLOTS_PER_EPOCH * 5;
+    let end_slot = capella_start_slot + forks::phase0::SLOTS_PER_EPOCH * 5;
+    let mut config = SyntheticChainConfig::new(start_slot, end_slot);
+    // A long run of empty slots, and an empty slot at the epoch boundary.
+    config.empty_slots = (start_slot + 70..start_slot + 100).collect();
+    config.empty_slots.insert(capella_start_slot + 64);
+    let chain = SyntheticChain::new(config);
+    let genesis_validators_root = chain.genesis_validators_root();
+
+    let bootstrap = LightClientBootstrap::from(chain.bootstrap());
+    let client_bootstrap = bootstrap.build_client_bootstrap();
+    let packed_client_sync_committee = bootstrap.build_client_sync_committee().pack();
+    let result = client_bootstrap
+        .verify_packed_client_sync_committee(packed_client_sync_committee.as_reader());
+    assert!(result.is_ok());
+
+    let mut light_client = DummyLightClient::new(bootstrap);
+
+    // Finality update in Bellatrix.
+    let attested_slot = capella_start_slot - 2;
+    let signature_slot = capella_start_slot - 1;
+    {
+        let client = light_client.client().clone();
+        let finality_update = chain.finality_update(attested_slot, signature_slot);
+        let finalized_slot = finality_update.finalized_header.slot.as_u64();
+        assert_eq!(finalized_slot, start_slot + 64);
+        let headers = chain.beacon_block_headers(client.maximal_slot + 1, finalized_slot);
+        let client_update = light_client.apply_finality_update(finality_update, headers);
+        let new_client = light_client.client().clone();
+
+        let result = client_update.verify_client_update(
+            client.clone(),
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client.clone(),
+        );
+        assert!(result.is_ok());
+
+        // Exactly two-thirds of the sync committee is a supermajority.
+        let participants_count = (forks::altair::SYNC_COMMITTEE_SIZE * 2 + 2) / 3;
+        for (count, expected) in [(participants_count, true), (participants_count - 1, false)] {
+            let mut client_update = client_update.clone();
+            client_update.sync_aggregate = chain
+                .sign_attested_header(attested_slot, signature_slot, count)
+                .into();
+            let result = client_update.verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            if expected {
+                assert!(result.is_ok());
+            } else {
+                assert!(matches!(
+                    result,
+                    Err(ClientUpdateError::NotSupermajorityParticipation)
+                ));
+            }
+        }
+
+        // The finalized header should be proven by the finality branch.
+        {
+            let mut client_update = client_update.clone();
+            client_update.finality_branch[0] = Hash256::repeat_byte(1);
+            let result = client_update.verify_client_update(
+                client.clone(),
+                genesis_validators_root,
+                packed_client_sync_committee.as_reader(),
+                new_client.clone(),
+            );
+            assert!(matches!(
+                result,
+                Err(ClientUpdateError::InvalidFinalityBranch)
+            ));
+        }
+    }
+
+    // Sync committee update at the period boundary.
+    let update = LightClientUpdate::from(chain.light_client_update(attested_slot, signature_slot));
+    let packed_next_client_sync_committee = update.build_next_client_sync_committee().pack();
+    {
+        let sync_committee_update = update.build_sync_committee_update();
+        let result = sync_committee_update.verify_packed_client_sync_committee(
+            light_client.client().maximal_slot,
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            packed_next_client_sync_committee.as_reader(),
+        );
+        assert!(result.is_ok());
+        let next_period = helpers::compute_sync_committee_period_at_slot(capella_start_slot);
+        assert_eq!(
+            update.original().next_sync_committee.as_ref(),
+            chain.sync_committee(next_period)
+        );
+    }
+
+    // Finality update in Capella, signed by the next sync committee.
+    {
+        let client = light_client.client().clone();
+        let attested_slot = capella_start_slot + 128;
+        let finality_update = chain.finality_update(attested_slot, attested_slot + 1);
+        // The header at the epoch boundary is empty, so the previous header is finalized.
+        let finalized_slot = finality_update.finalized_header.slot.as_u64();
+        assert_eq!(finalized_slot, capella_start_slot + 63);
+        let headers = chain.beacon_block_headers(client.maximal_slot + 1, finalized_slot);
+        let client_update = light_client.apply_finality_update(finality_update, headers);
+        let new_client = light_client.client().clone();
+
+        let result = client_update.verify_client_update(
+            client.clone(),
+            genesis_validators_root,
+            packed_next_client_sync_committee.as_reader(),
+            new_client.clone(),
+        );
+        assert!(result.is_ok());
+
+        // The sync committee of the previous period should not be accepted.
+        let result = client_update.verify_client_update(
+            client,
+            genesis_validators_root,
+            packed_client_sync_committee.as_reader(),
+            new_client,
+        );
+        assert!(matches!(
+            result,
+            Err(ClientUpdateError::MismatchedSyncCommittee)
+        ));
+    }
+}
+
+// The chain with the minimal preset and a fork schedule which activates Capella at the second
+// sync committee period.
+#[test]
+fn synthetic_testcase_minimal_preset() {
+    setup();
+
+    let chain_config = ChainConfig {
+        slots_per_epoch: 8,
+        epochs_per_sync_committee_period: 8,
+        sync_committee_size: 32,
+        genesis_fork_version: [0x00, 0x00, 0x00, 0x01],
+        altair_fork: Fork {
+            epoch: 0,
+            version: [0x01, 0x00, 0x00, 0x01],
+        },
+        bellatrix_fork: Fork {
+            epoch: 0,
+            version: [0x02, 0x00, 0x00, 0x01],
+        },
+        capella_fork: Fork {
+            epoch: 8,
+            version: [0x03, 0x00, 0x00, 0x01],
+        },
+    };
+    let config = SyntheticChainConfig::with_chain_config(1, 160, chain_config.clone());
+    let chain = SyntheticChain::<MinimalEthSpec>::with_preset(config);
+    assert_eq!(chain.chain_config(), &chain_config);
+
+    let bootstrap = chain.bootstrap();
+    assert_eq!(bootstrap.current_sync_committee.pubkeys.len(), 32);
+    assert_eq!(
+        bootstrap.current_sync_committee.as_ref(),
+        chain.sync_committee(0)
+    );
+    {
+        let leaf_index =
+            forks::bellatrix::generalized_index::beacon_state::CURRENT_SYNC_COMMITTEE_INDEX;
+        assert!(is_valid_merkle_branch(
+            &bootstrap.current_sync_committee.tree_hash_root(),
+            &bootstrap.current_sync_committee_branch,
+            helpers::floorlog2(leaf_index) as usize,
+            helpers::get_subtree_index(leaf_index),
+            &bootstrap.header.state_root,
+        ));
+    }
+    {
+        let client_bootstrap = core::ClientBootstrap {
+            header: bootstrap.header.clone().into(),
+            current_sync_committee_branch: bootstrap.current_sync_committee_branch.to_vec(),
+        };
+        let packed_client_sync_committee = core::ClientSyncCommittee {
+            period: 0,
+            data: core::SyncCommittee {
+                pubkeys: bootstrap.current_sync_committee.pubkeys.to_vec(),
+                aggregate_pubkey: bootstrap.current_sync_committee.aggregate_pubkey,
+            },
+        }
+        .pack();
+        let result = client_bootstrap.verify_packed_client_sync_committee_with_config(
+            &chain_config,
+            packed_client_sync_committee.as_reader(),
+        );
+        assert!(result.is_ok());
+        // The size of the sync committee is checked by the preset.
+        let result = client_bootstrap
+            .verify_packed_client_sync_committee(packed_client_sync_committee.as_reader());
+        assert!(matches!(
+            result,
+            Err(ClientBootstrapError::UnexpectedSyncCommitteeSize)
+        ));
+    }
+
+    // The attested header is in Capella, the signature is signed in the second period.
+    let attested_slot = 96;
+    let signature_slot = 97;
+    let finality_update = chain.finality_update(attested_slot, signature_slot);
+    assert_eq!(finality_update.finalized_header.slot.as_u64(), 80);
+    {
+        let leaf_index = forks::capella::generalized_index::beacon_state::FINALIZED_ROOT_INDEX;
+        assert!(is_valid_merkle_branch(
+            &finality_update.finalized_header.tree_hash_root(),
+            &finality_update.finality_branch,
+            helpers::floorlog2(leaf_index) as usize,
+            helpers::get_subtree_index(leaf_index),
+            &finality_update.attested_header.state_root,
+        ));
+    }
+
+    let sync_committee = chain.sync_committee(1);
+    let pubkeys = core::SyncCommittee {
+        pubkeys: sync_committee.pubkeys.to_vec(),
+        aggregate_pubkey: sync_committee.aggregate_pubkey,
+    }
+    .decompress_all_pubkeys()
+    .unwrap();
+    let sync_aggregate: core::SyncAggregate = finality_update.sync_aggregate.into();
+    assert!(sync_aggregate.has_supermajority_with_config(&chain_config));
+    let attested_root = finality_update.attested_header.tree_hash_root();
+    // The fork version of the signature is chosen by the fork schedule of the chain.
+    for (config, expected) in [(&chain_config, true), (&ChainConfig::MAINNET, false)] {
+        let message = bls::compute_signing_root_at_signature_slot(
+            config,
+            attested_root,
+            signature_slot,
+            &forks::altair::DOMAIN_SYNC_COMMITTEE,
+            chain.genesis_validators_root(),
+        );
+        let result = sync_aggregate.fast_aggregate_verify(&pubkeys, message);
+        assert!(matches!(result, Ok(verified) if verified == expected));
+    }
+}
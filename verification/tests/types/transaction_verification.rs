@@ -1,14 +1,24 @@
 use std::fs;
 
-use eth2_types::{
-    light_client_bootstrap::PatchedLightClientBootstrap,
-    light_client_finality_update::PatchedLightClientFinalityUpdate, BeaconBlock, MainnetEthSpec,
+use eth2_types::{BeaconBlock, MainnetEthSpec};
+use eth_light_client_in_ckb_prover::{
+    CachedBeaconBlock, DummyLightClient, Receipts, TransactionIndex,
+};
+use eth_light_client_in_ckb_verification::{
+    error::TxVerificationError,
+    types::{core, prelude::*},
+    utilities::mpt,
 };
-use eth_light_client_in_ckb_prover::{CachedBeaconBlock, DummyLightClient, Receipts};
-use eth_light_client_in_ckb_verification::types::{core, prelude::*};
 use ethers_core::types::TransactionReceipt;
+use rlp::RlpStream;
+use tree_hash::Hash256;
 
-use crate::{find_json_file, setup, types::load_beacon_block_header_from_json_or_create_default};
+use crate::{
+    find_json_file, setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+    },
+};
 
 #[test]
 fn mainnet_testcase_in_capella() {
@@ -21,6 +31,111 @@ fn mainnet_testcase_in_capella() {
     transaction_verification(param);
 }
 
+// The receipts are built by hand and proven by a trie with only one leaf, and the block is
+// rebuilt with the root of that trie, so the rebuilt block could only be an optimistic header.
+#[test]
+fn mainnet_testcase_optimistic_header_and_invalid_receipt() {
+    setup();
+
+    let bootstrap_slot = 6632736;
+    let block_slot = 6632854;
+    let index = 0;
+
+    let client = DummyLightClient::new(load_bootstrap(bootstrap_slot))
+        .client()
+        .clone();
+    let block_json_value = {
+        let case_dir = "mainnet/beacon/block";
+        let filename = format!("slot-{block_slot:09}.json");
+        let json_file = find_json_file(case_dir, &filename);
+        let json_str = fs::read_to_string(json_file).unwrap();
+        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        json_value["data"]["message"].clone()
+    };
+
+    // The first field is the status code since Byzantium, or the intermediate state root before.
+    let encode_receipt = |status_or_root: &[u8]| {
+        let mut stream = RlpStream::new_list(4);
+        stream.append(&status_or_root);
+        stream.append(&21000u64);
+        stream.append(&[0u8; 256].as_ref());
+        stream.begin_list(0);
+        stream.out().to_vec()
+    };
+    let build_transaction = |receipt: Vec<u8>| {
+        let leaf = {
+            let mut stream = RlpStream::new_list(2);
+            // The hex-prefix encoding of the path of the leaf, the key is `rlp(0)`.
+            stream.append(&[0x20u8, 0x80].as_ref());
+            stream.append(&receipt);
+            stream.out().to_vec()
+        };
+        let receipts_root = Hash256::from(mpt::keccak256(&leaf));
+        let mut json_value = block_json_value.clone();
+        json_value["body"]["execution_payload"]["receipts_root"] =
+            serde_json::Value::String(format!("{receipts_root:#x}"));
+        let block: BeaconBlock<MainnetEthSpec> = serde_json::from_value(json_value).unwrap();
+        let header = core::Header::from(block.block_header()).calc_cache();
+        let block: CachedBeaconBlock = block.into();
+        let proof = core::TransactionProof {
+            header: header.inner,
+            transaction_index: index as u64,
+            receipts_root,
+            header_mmr_proof: Vec::new(),
+            transaction_ssz_proof: block.generate_transaction_proof_for_block_body(index),
+            receipt_mpt_proof: vec![leaf],
+            receipts_root_ssz_proof: block.generate_receipts_root_proof_for_block_body(),
+        };
+        let payload = core::TransactionPayload {
+            transaction: block.transaction(index).unwrap().to_vec(),
+            receipt,
+        };
+        (header.root, proof.pack(), payload.pack())
+    };
+
+    // The transaction in the optimistic header.
+    {
+        let (header_root, proof, payload) = build_transaction(encode_receipt(&[1]));
+        let mut client = client.clone();
+        client.optimistic_slot = block_slot;
+        client.optimistic_header_root = header_root;
+
+        let result = client.verify_packed_transaction(proof.as_reader(), payload.as_reader());
+        let verified = result.ok().unwrap();
+        assert_eq!(verified.header_status, core::HeaderStatus::Optimistic);
+        assert_eq!(verified.slot, block_slot);
+        assert_eq!(verified.header_root, header_root);
+        assert!(verified.receipt_status);
+        assert!(verified.logs.is_empty());
+
+        // The optimistic header should be matched.
+        client.optimistic_header_root = Hash256::repeat_byte(1);
+        let result = client.verify_packed_transaction(proof.as_reader(), payload.as_reader());
+        assert!(matches!(
+            result,
+            Err(TxVerificationError::OptimisticHeaderRoot)
+        ));
+
+        // The header should be the optimistic header, or in the MMR of the client.
+        client.optimistic_slot = block_slot + 1;
+        let result = client.verify_packed_transaction(proof.as_reader(), payload.as_reader());
+        assert!(matches!(result, Err(TxVerificationError::Unsynchronized)));
+    }
+
+    // The receipts, which are proven but could not be decoded.
+    for status_or_root in [&[2u8][..], &[0x01; 32][..]] {
+        let (header_root, proof, payload) = build_transaction(encode_receipt(status_or_root));
+        let mut client = client.clone();
+        client.optimistic_slot = block_slot;
+        client.optimistic_header_root = header_root;
+
+        let result = proof.as_reader().verify_packed_payload(payload.as_reader());
+        assert!(result.is_ok());
+        let result = client.verify_packed_transaction(proof.as_reader(), payload.as_reader());
+        assert!(matches!(result, Err(TxVerificationError::InvalidReceipt)));
+    }
+}
+
 #[derive(Default)]
 struct Parameter {
     bootstrap_slot: u64,
@@ -35,32 +150,14 @@ fn transaction_verification(param: Parameter) {
 
     let mut if_tx_dumped = false;
 
-    let mut light_client = {
-        let case_dir = "mainnet/light_client/bootstrap";
-        let filename = format!("slot-{:09}.json", param.bootstrap_slot);
-        let json_file = find_json_file(case_dir, &filename);
-        let json_str = fs::read_to_string(json_file).unwrap();
-        let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-        let bootstrap: PatchedLightClientBootstrap<MainnetEthSpec> =
-            serde_json::from_value(json_value["data"].clone()).unwrap();
-        DummyLightClient::new(bootstrap.into())
-    };
+    let mut light_client = DummyLightClient::new(load_bootstrap(param.bootstrap_slot));
 
     for finalized_slot in param.finalized_slots {
-        let finality_update = {
-            let case_dir = "mainnet/light_client/finality_update";
-            let filename = format!("slot-{finalized_slot:09}.json");
-            let json_file = find_json_file(case_dir, &filename);
-            let json_str = fs::read_to_string(json_file).unwrap();
-            let json_value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
-            let finality_update: PatchedLightClientFinalityUpdate<MainnetEthSpec> =
-                serde_json::from_value(json_value["data"].clone()).unwrap();
-            finality_update
-        };
+        let finality_update = load_finality_update(finalized_slot);
         let headers = ((light_client.client().maximal_slot + 1)..=finalized_slot)
             .map(load_beacon_block_header_from_json_or_create_default)
             .collect::<Vec<_>>();
-        let _client_update = light_client.apply_finality_update(finality_update.into(), headers);
+        let _client_update = light_client.apply_finality_update(finality_update, headers);
 
         let client = light_client.client().clone();
 
@@ -105,6 +202,10 @@ fn transaction_verification(param: Parameter) {
             let receipts_root = receipts.root();
             let receipts_root_ssz_proof = block.generate_receipts_root_proof_for_block_body();
 
+            let mut transaction_index = TransactionIndex::default();
+            transaction_index.insert_block(&block);
+            assert_eq!(transaction_index.len(), transactions_count);
+
             let header = light_client.beacon_header_at_slot(slot).unwrap();
             let header_mmr_proof = light_client.build_header_mmr_proof(slot);
 
@@ -146,6 +247,81 @@ fn transaction_verification(param: Parameter) {
                     index
                 );
 
+                let result = packed_proof
+                    .as_reader()
+                    .verify_packed_payload(packed_payload.as_reader());
+                assert!(
+                    result.is_ok(),
+                    "failed to verify packed payload with packed proof for block#{}.transaction#{}",
+                    number,
+                    index
+                );
+
+                let verified = client
+                    .verify_packed_transaction(packed_proof.as_reader(), packed_payload.as_reader())
+                    .ok()
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "failed to verify transaction for block#{number}.transaction#{index}"
+                        )
+                    });
+                {
+                    let receipt = &receipts.original()[index];
+                    assert_eq!(verified.header_status, core::HeaderStatus::Finalized);
+                    assert_eq!(verified.slot, slot);
+                    assert_eq!(verified.header_root, header.root);
+                    assert_eq!(verified.transaction_index, index as u64);
+                    assert_eq!(
+                        verified.transaction_hash.as_bytes(),
+                        receipt.transaction_hash.as_bytes()
+                    );
+                    assert_eq!(verified.receipts_root, receipts_root);
+                    assert_eq!(
+                        verified.receipt_status,
+                        receipt.status.unwrap().as_u64() == 1
+                    );
+                    assert_eq!(verified.logs.len(), receipt.logs.len());
+                    for (log, expected) in verified.logs.iter().zip(receipt.logs.iter()) {
+                        assert_eq!(log.address.as_bytes(), expected.address.as_bytes());
+                        assert_eq!(log.topics.len(), expected.topics.len());
+                        for (topic, expected) in log.topics.iter().zip(expected.topics.iter()) {
+                            assert_eq!(topic.as_bytes(), expected.as_bytes());
+                        }
+                        assert_eq!(log.data, expected.data.to_vec());
+                    }
+                }
+
+                // Look up the transaction by its hash, then prove the hash.
+                {
+                    let transaction_hash =
+                        Hash256::from_slice(receipts.original()[index].transaction_hash.as_bytes());
+                    assert_eq!(
+                        transaction_index.get(&transaction_hash),
+                        Some((slot, index))
+                    );
+                    let transaction = payload.transaction.as_slice();
+                    let result = proof.verify_transaction_with_hash(transaction, &transaction_hash);
+                    assert!(result.is_ok());
+                    let result = packed_proof
+                        .as_reader()
+                        .verify_transaction_with_hash(transaction, &transaction_hash);
+                    assert!(result.is_ok());
+
+                    let mismatched_hash = Hash256::repeat_byte(1);
+                    let result = proof.verify_transaction_with_hash(transaction, &mismatched_hash);
+                    assert!(matches!(
+                        result,
+                        Err(TxVerificationError::MismatchedTransactionHash)
+                    ));
+                    let result = packed_proof
+                        .as_reader()
+                        .verify_transaction_with_hash(transaction, &mismatched_hash);
+                    assert!(matches!(
+                        result,
+                        Err(TxVerificationError::MismatchedTransactionHash)
+                    ));
+                }
+
                 if let Some(dump_tx_index) = param.dump_tx_index_opt {
                     if index == dump_tx_index {
                         if let Some(dump_dir) = param.dump_dir_opt {
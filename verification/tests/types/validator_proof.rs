@@ -0,0 +1,224 @@
+use eth2_types::{
+    test_utils::{SeedableRng as _, TestRandom as _, XorShiftRng},
+    Validator, VariableList,
+};
+use eth_light_client_in_ckb_prover::{CachedBeaconState, SszNode, SszPathError};
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{
+        forks::{self, phase0::VALIDATOR_REGISTRY_LIMIT},
+        get_generalized_index_of_balances_chunk_in_beacon_state_offset,
+        get_generalized_index_of_validator_in_beacon_state_offset, ChainConfig,
+    },
+    error::ValidatorProofError,
+    types::{core, prelude::*},
+};
+use tree_hash::{Hash256, TreeHash as _};
+
+use crate::{setup, types::build_beacon_state_in_capella};
+
+// Only the validators and the balances are filled in the beacon state.
+#[test]
+fn mock_testcase_in_capella() {
+    setup();
+
+    let validators = (0u8..10)
+        .map(|i| core::Validator {
+            pubkey: core::BlsPubkey::deserialize(&[i + 1; 48]).unwrap(),
+            withdrawal_credentials: Hash256::repeat_byte(i),
+            effective_balance: 32_000_000_000,
+            slashed: i == 3,
+            activation_eligibility_epoch: u64::from(i),
+            activation_epoch: u64::from(i) + 1,
+            exit_epoch: u64::MAX,
+            withdrawable_epoch: u64::MAX,
+        })
+        .collect::<Vec<_>>();
+    let balances = (0u64..10)
+        .map(|i| 32_000_000_000 + i * 1_000_003)
+        .collect::<Vec<_>>();
+    let state = {
+        let fields = forks::capella::containers::BEACON_STATE
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let node = match *name {
+                    "validators" => SszNode::list(
+                        validators.iter().map(SszNode::leaf).collect(),
+                        VALIDATOR_REGISTRY_LIMIT,
+                    ),
+                    "balances" => SszNode::basic_list(&balances, VALIDATOR_REGISTRY_LIMIT),
+                    _ => SszNode::leaf_with_root(Hash256::repeat_byte(index as u8)),
+                };
+                (*name, node)
+            })
+            .collect();
+        SszNode::container(fields)
+    };
+
+    let header = core::Header {
+        slot: 6632736,
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: state.root(),
+        body_root: Hash256::repeat_byte(2),
+    };
+    let client = header.initialize_client();
+
+    let build_proof = |validator_index: usize| {
+        let validator_proof = state
+            .generate_proof(&format!("validators[{validator_index}]"))
+            .unwrap();
+        let balances_chunk_proof = state
+            .generate_proof(&format!("balances[{}]", validator_index / 4))
+            .unwrap();
+        assert_eq!(
+            validator_proof.generalized_index,
+            get_generalized_index_of_validator_in_beacon_state_offset(
+                &ChainConfig::MAINNET,
+                header.slot
+            ) + validator_index
+        );
+        assert_eq!(
+            balances_chunk_proof.generalized_index,
+            get_generalized_index_of_balances_chunk_in_beacon_state_offset(
+                &ChainConfig::MAINNET,
+                header.slot
+            ) + validator_index / 4
+        );
+        core::ValidatorProof {
+            header: header.clone(),
+            header_mmr_proof: Vec::new(),
+            validator_index: validator_index as u64,
+            validator: validators[validator_index].clone(),
+            validator_ssz_proof: validator_proof.branch,
+            balances_chunk: balances_chunk_proof.leaf,
+            balances_chunk_ssz_proof: balances_chunk_proof.branch,
+        }
+    };
+
+    for validator_index in 0..validators.len() {
+        let proof = build_proof(validator_index);
+        let packed_proof = proof.pack();
+        let result = client.verify_packed_validator_proof(packed_proof.as_reader());
+        assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+        assert_eq!(proof.balance(), balances[validator_index]);
+        assert_eq!(
+            packed_proof.as_reader().balance(),
+            balances[validator_index]
+        );
+
+        // The proof is same after packing and unpacking.
+        let unpacked_proof: core::ValidatorProof = packed_proof.unpack();
+        assert_eq!(unpacked_proof.pack().as_slice(), packed_proof.as_slice());
+    }
+
+    let proof = build_proof(5);
+
+    // The validator should be proven by the state root.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.validator.exit_epoch = 100;
+        let result = client.verify_packed_validator_proof(wrong_proof.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(ValidatorProofError::ValidatorSszProof)
+        ));
+    }
+
+    // The validator index should be proven by the state root.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.validator_index = 6;
+        let result = client.verify_packed_validator_proof(wrong_proof.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(ValidatorProofError::ValidatorSszProof)
+        ));
+    }
+
+    // The balance should be proven by the state root.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.balances_chunk = Hash256::repeat_byte(1);
+        let result = client.verify_packed_validator_proof(wrong_proof.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(ValidatorProofError::BalancesChunkSszProof)
+        ));
+    }
+
+    // The validator index should be less than the limit.
+    {
+        let mut wrong_proof = proof.clone();
+        wrong_proof.validator_index = VALIDATOR_REGISTRY_LIMIT as u64;
+        let result = client.verify_packed_validator_proof(wrong_proof.pack().as_reader());
+        assert!(matches!(
+            result,
+            Err(ValidatorProofError::InvalidValidatorIndex)
+        ));
+    }
+
+    // The header should be in the client.
+    {
+        let other_header = core::Header {
+            slot: header.slot + 1,
+            ..header.clone()
+        };
+        let other_client = other_header.initialize_client();
+        let result = other_client.verify_packed_validator_proof(proof.pack().as_reader());
+        assert!(matches!(result, Err(ValidatorProofError::Unsynchronized)));
+    }
+}
+
+#[test]
+fn testcase_build_validator_proof_from_beacon_state() {
+    setup();
+
+    let mut rng = XorShiftRng::from_seed([8; 16]);
+    let validators = (0..10)
+        .map(|_| Validator::random_for_test(&mut rng))
+        .collect::<Vec<_>>();
+    let balances = (0u64..10)
+        .map(|i| 32_000_000_000 + i * 1_000_003)
+        .collect::<Vec<_>>();
+    let state = {
+        let mut state = build_beacon_state_in_capella(6632736, 7);
+        *state.validators_mut() = VariableList::new(validators.clone()).unwrap();
+        *state.balances_mut() = VariableList::new(balances.clone()).unwrap();
+        CachedBeaconState::try_from(state).unwrap()
+    };
+    assert_eq!(state.state_root(), state.original().tree_hash_root());
+
+    let header = core::Header {
+        slot: 6632736,
+        proposer_index: 1,
+        parent_root: Hash256::repeat_byte(1),
+        state_root: state.state_root(),
+        body_root: Hash256::repeat_byte(2),
+    };
+    let client = header.initialize_client();
+
+    for (validator_index, validator) in validators.iter().enumerate() {
+        let proof = state
+            .build_validator_proof(validator_index, &header, Vec::new())
+            .unwrap();
+        assert_eq!(
+            proof.validator.pack().as_slice(),
+            core::Validator::from(validator.clone()).pack().as_slice()
+        );
+        let result = client.verify_packed_validator_proof(proof.pack().as_reader());
+        assert!(matches!(result, Ok(core::HeaderStatus::Finalized)));
+        assert_eq!(proof.balance(), balances[validator_index]);
+    }
+
+    // The validator should be in the beacon state.
+    let result = state.build_validator_proof(validators.len(), &header, Vec::new());
+    assert!(matches!(
+        result,
+        Err(SszPathError::IndexOutOfBounds {
+            index: 10,
+            length: 10
+        })
+    ));
+}
@@ -0,0 +1,240 @@
+use eth_light_client_in_ckb_prover::DummyLightClient;
+use eth_light_client_in_ckb_verification::{
+    error::WitnessError,
+    types::{
+        core, decode_client, decode_client_info, decode_legacy_witness, decode_witness, packed,
+        prelude::*, BARE_WITNESS_VERSION, WITNESS_ENVELOPE_VERSION,
+    },
+};
+
+use crate::{
+    setup,
+    types::{
+        load_beacon_block_header_from_json_or_create_default, load_bootstrap, load_finality_update,
+        load_genesis_validators_root,
+    },
+};
+
+#[test]
+fn witness_envelope_and_bare_witness() {
+    setup();
+
+    let client_bootstrap = load_bootstrap(6632736).build_client_bootstrap();
+    let packed_client_bootstrap = client_bootstrap.pack();
+
+    // The bare witness in the old version.
+    {
+        let result =
+            decode_witness::<packed::ClientBootstrapReader>(packed_client_bootstrap.as_slice());
+        let (version, reader) = result.ok().unwrap();
+        assert_eq!(version, BARE_WITNESS_VERSION);
+        assert_eq!(reader.as_slice(), packed_client_bootstrap.as_slice());
+
+        let result =
+            decode_witness::<packed::SyncCommitteeUpdateReader>(packed_client_bootstrap.as_slice());
+        assert!(matches!(result, Err(WitnessError::InvalidWitness)));
+
+        let result =
+            decode_witness::<packed::ClientUpdateReader>(packed_client_bootstrap.as_slice());
+        assert!(matches!(result, Err(WitnessError::LegacyWitnessLayout)));
+    }
+
+    // The witness envelope in the current version.
+    let envelope = core::WitnessEnvelope {
+        version: WITNESS_ENVELOPE_VERSION,
+        witness: core::Witness::ClientBootstrap(client_bootstrap),
+    };
+    let packed_envelope = envelope.pack();
+    {
+        let result = decode_witness::<packed::ClientBootstrapReader>(packed_envelope.as_slice());
+        let (version, reader) = result.ok().unwrap();
+        assert_eq!(version, WITNESS_ENVELOPE_VERSION);
+        assert_eq!(reader.as_slice(), packed_client_bootstrap.as_slice());
+
+        let unpacked: core::WitnessEnvelope = packed_envelope.unpack();
+        assert_eq!(unpacked.pack().as_slice(), packed_envelope.as_slice());
+    }
+
+    // The type of the witness should be matched.
+    {
+        let result = decode_witness::<packed::ClientUpdateReader>(packed_envelope.as_slice());
+        assert!(matches!(result, Err(WitnessError::MismatchedWitnessType)));
+    }
+
+    // The version should be supported.
+    for version in [BARE_WITNESS_VERSION, WITNESS_ENVELOPE_VERSION + 1] {
+        let packed_envelope = packed_envelope
+            .clone()
+            .as_builder()
+            .version(version.into())
+            .build();
+        let result = decode_witness::<packed::ClientBootstrapReader>(packed_envelope.as_slice());
+        assert!(matches!(
+            result,
+            Err(WitnessError::UnsupportedWitnessVersion)
+        ));
+    }
+
+    // A bare witness with 2 fields should not be decoded as a witness envelope.
+    {
+        let payload = core::TransactionPayload {
+            transaction: vec![0x02; 3],
+            receipt: vec![0x01],
+        };
+        let packed_payload = payload.pack();
+        let result = decode_witness::<packed::TransactionPayloadReader>(packed_payload.as_slice());
+        let (version, _) = result.ok().unwrap();
+        assert_eq!(version, BARE_WITNESS_VERSION);
+    }
+}
+
+#[test]
+fn legacy_client_update_and_cells() {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+    let mut light_client = DummyLightClient::new(load_bootstrap(6632736));
+    let sync_committee = light_client.client_sync_committee().clone();
+    let old_client = light_client.client().clone();
+    let finalized_slot = 6632768;
+    let headers = ((old_client.maximal_slot + 1)..=finalized_slot)
+        .map(load_beacon_block_header_from_json_or_create_default)
+        .collect::<Vec<_>>();
+    let client_update =
+        light_client.apply_finality_update(load_finality_update(finalized_slot), headers);
+    let mut new_client = light_client.client().clone();
+    new_client.id = old_client.id;
+
+    // The headers are in the full encoding in the legacy layout.
+    let full_headers = ((old_client.maximal_slot + 1)..=finalized_slot)
+        .map(|slot| {
+            light_client
+                .beacon_header_at_slot(slot)
+                .unwrap()
+                .inner
+                .clone()
+        })
+        .collect::<Vec<_>>();
+    let packed_client_update = client_update.pack();
+    let legacy_client_update = packed::ClientUpdateV0::new_builder()
+        .attested_header(packed_client_update.attested_header())
+        .finality_branch(packed_client_update.finality_branch())
+        .sync_aggregate(packed_client_update.sync_aggregate())
+        .signature_slot(packed_client_update.signature_slot())
+        .new_headers_mmr_proof(packed_client_update.new_headers_mmr_proof())
+        .headers(full_headers.pack())
+        .build();
+
+    // The bare client update is decoded in the legacy layout.
+    let decoded_client_update = {
+        let result =
+            decode_legacy_witness::<packed::ClientUpdateReader>(legacy_client_update.as_slice());
+        let (version, decoded) = result.ok().unwrap();
+        assert_eq!(version, BARE_WITNESS_VERSION);
+        if let core::HeaderRange::Full(ref headers) = decoded.headers {
+            assert_eq!(headers.len(), full_headers.len());
+        } else {
+            panic!("the headers in the legacy layout should be in the full encoding");
+        }
+
+        let result =
+            decode_legacy_witness::<packed::ClientUpdateReader>(packed_client_update.as_slice());
+        assert!(matches!(result, Err(WitnessError::InvalidWitness)));
+
+        let result = decode_witness::<packed::ClientUpdateReader>(legacy_client_update.as_slice());
+        assert!(matches!(result, Err(WitnessError::LegacyWitnessLayout)));
+
+        decoded
+    };
+
+    // The client update in a witness envelope is decoded in the current layout.
+    {
+        let envelope = core::WitnessEnvelope {
+            version: WITNESS_ENVELOPE_VERSION,
+            witness: core::Witness::ClientUpdate(client_update),
+        };
+        let packed_envelope = envelope.pack();
+        let result =
+            decode_legacy_witness::<packed::ClientUpdateReader>(packed_envelope.as_slice());
+        let (version, decoded) = result.ok().unwrap();
+        assert_eq!(version, WITNESS_ENVELOPE_VERSION);
+        assert_eq!(decoded.pack().as_slice(), packed_client_update.as_slice());
+    }
+
+    // The clients in the legacy layout have no optimistic header and no finalized slot, so the
+    // tip header is used for both.
+    let legacy_client = |client: &core::Client| {
+        let packed_client = client.pack();
+        packed::ClientV0::new_builder()
+            .id(packed_client.id())
+            .minimal_slot(packed_client.minimal_slot())
+            .maximal_slot(packed_client.maximal_slot())
+            .tip_header_root(packed_client.tip_header_root())
+            .headers_mmr_root(packed_client.headers_mmr_root())
+            .build()
+    };
+    let decoded_old_client = {
+        let legacy_old_client = legacy_client(&old_client);
+        let decoded = decode_client(legacy_old_client.as_slice()).unwrap();
+        assert_eq!(decoded.pack().as_slice(), old_client.pack().as_slice());
+        decoded
+    };
+    let decoded_new_client = {
+        let legacy_new_client = legacy_client(&new_client);
+        let decoded = decode_client(legacy_new_client.as_slice()).unwrap();
+        assert_eq!(decoded.optimistic_slot, new_client.maximal_slot);
+        assert_eq!(decoded.optimistic_header_root, new_client.tip_header_root);
+        assert_eq!(decoded.finalized_slot, new_client.maximal_slot);
+
+        let decoded_current = decode_client(new_client.pack().as_slice()).unwrap();
+        assert_eq!(
+            decoded_current.pack().as_slice(),
+            new_client.pack().as_slice()
+        );
+
+        assert!(decode_client(&legacy_new_client.as_slice()[1..]).is_err());
+        decoded
+    };
+
+    // The legacy client update is verified with the legacy clients.
+    {
+        let result = decoded_client_update.verify_client_update(
+            decoded_old_client,
+            genesis_validators_root,
+            sync_committee.pack().as_reader(),
+            decoded_new_client,
+        );
+        assert!(result.is_ok());
+    }
+
+    // The forced update is disabled for the client info in the legacy layout.
+    {
+        let client_info = core::ClientInfo {
+            last_client_id: 2,
+            minimal_headers_count: 3,
+            genesis_validators_root,
+            force_update_timeout: 8192,
+        };
+        let packed_client_info = client_info.pack();
+        let legacy_client_info = packed::ClientInfoV0::new_builder()
+            .last_client_id(packed_client_info.last_client_id())
+            .minimal_headers_count(packed_client_info.minimal_headers_count())
+            .genesis_validators_root(packed_client_info.genesis_validators_root())
+            .build();
+
+        let decoded = decode_client_info(legacy_client_info.as_slice()).unwrap();
+        assert_eq!(decoded.last_client_id, client_info.last_client_id);
+        assert_eq!(
+            decoded.minimal_headers_count,
+            client_info.minimal_headers_count
+        );
+        assert_eq!(decoded.genesis_validators_root, genesis_validators_root);
+        assert_eq!(decoded.force_update_timeout, 0);
+
+        let decoded = decode_client_info(packed_client_info.as_slice()).unwrap();
+        assert_eq!(
+            decoded.force_update_timeout,
+            client_info.force_update_timeout
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use eth_light_client_in_ckb_verification::{
+    consensus_specs::{forks, ChainConfig},
+    types::core,
+    utilities::bls::{self, AggregationStrategy},
+};
+use proptest::prelude::*;
+use tree_hash::TreeHash as _;
+
+use crate::{
+    setup,
+    types::{load_bootstrap, load_genesis_validators_root, load_light_client_update},
+};
+
+#[test]
+fn aggregation_strategies_are_equivalent() {
+    setup();
+
+    let sync_committee = load_bootstrap(4612096).build_client_sync_committee().data;
+    let pubkeys = sync_committee.decompress_all_pubkeys().unwrap();
+    let aggregate_pubkey = sync_committee.aggregate_pubkey.decompress().unwrap();
+
+    proptest!(ProptestConfig::with_cases(64), |(bits in prop::collection::vec(any::<u8>(), 64))| {
+        let sync_aggregate = core::SyncAggregate {
+            sync_committee_bits: core::SyncCommitteeBits::from_slice(&bits),
+            sync_committee_signature: [0u8; 96].into(),
+        };
+        let by_addition = sync_aggregate.aggregate_participant_pubkeys(&pubkeys);
+        let by_subtraction = sync_aggregate
+            .aggregate_participant_pubkeys_by_subtraction(&pubkeys, &aggregate_pubkey);
+        match (by_addition, by_subtraction) {
+            (Ok(by_addition), Ok(by_subtraction)) => {
+                prop_assert_eq!(
+                    by_addition.serialize_uncompressed(),
+                    by_subtraction.serialize_uncompressed()
+                );
+            }
+            // No participants, the aggregate public key is the point at infinity.
+            (Err(_), Err(_)) => prop_assert!(bits.iter().all(|byte| *byte == 0)),
+            _ => prop_assert!(false, "only one aggregation strategy failed"),
+        }
+    });
+}
+
+#[test]
+fn negate_pubkeys() {
+    setup();
+
+    let sync_committee = load_bootstrap(4612096).build_client_sync_committee().data;
+    let pubkeys = sync_committee.decompress_all_pubkeys().unwrap();
+
+    proptest!(|(index in 0..forks::altair::SYNC_COMMITTEE_SIZE)| {
+        let pubkey = &pubkeys[index];
+        let negated = bls::negate_pubkey(pubkey).unwrap();
+        prop_assert_ne!(negated.serialize_uncompressed(), pubkey.serialize_uncompressed());
+        let negated_twice = bls::negate_pubkey(&negated).unwrap();
+        prop_assert_eq!(negated_twice.serialize_uncompressed(), pubkey.serialize_uncompressed());
+    });
+}
+
+#[test]
+fn mainnet_signatures_with_both_aggregation_strategies() {
+    setup();
+
+    let genesis_validators_root = load_genesis_validators_root();
+
+    for (bootstrap_slot, period) in [(4612096, 563), (6184960, 755)] {
+        let sync_committee = load_bootstrap(bootstrap_slot)
+            .build_client_sync_committee()
+            .data;
+        let pubkeys = sync_committee.decompress_all_pubkeys().unwrap();
+        let aggregate_pubkey = sync_committee.aggregate_pubkey.decompress().unwrap();
+
+        let update = load_light_client_update(period).build_sync_committee_update();
+        let message = bls::compute_signing_root_at_signature_slot(
+            &ChainConfig::MAINNET,
+            update.attested_header.tree_hash_root(),
+            update.signature_slot,
+            &forks::altair::DOMAIN_SYNC_COMMITTEE,
+            genesis_validators_root,
+        );
+        let sync_aggregate = update.sync_aggregate;
+        assert_eq!(
+            sync_aggregate.aggregation_strategy(),
+            AggregationStrategy::Subtraction
+        );
+
+        let result = sync_aggregate.fast_aggregate_verify(&pubkeys, message);
+        assert!(matches!(result, Ok(true)));
+        let result = sync_aggregate.fast_aggregate_verify_by_subtraction(
+            &pubkeys,
+            &aggregate_pubkey,
+            message,
+        );
+        assert!(matches!(result, Ok(true)));
+
+        // Drop one participant, the signature should be invalid for both strategies.
+        let mut bits = sync_aggregate.sync_committee_bits;
+        let index = bits.as_bytes().iter().position(|byte| *byte != 0).unwrap();
+        let byte = bits.as_bytes()[index];
+        bits.as_bytes_mut()[index] = byte & (byte - 1);
+        let sync_aggregate = core::SyncAggregate {
+            sync_committee_bits: bits,
+            ..sync_aggregate
+        };
+        let result = sync_aggregate.fast_aggregate_verify(&pubkeys, message);
+        assert!(matches!(result, Ok(false)));
+        let result = sync_aggregate.fast_aggregate_verify_by_subtraction(
+            &pubkeys,
+            &aggregate_pubkey,
+            message,
+        );
+        assert!(matches!(result, Ok(false)));
+    }
+}
+
+#[test]
+fn padding_bits_of_smaller_sync_committee() {
+    setup();
+
+    let config = ChainConfig {
+        sync_committee_size: 32,
+        ..ChainConfig::MAINNET
+    };
+    let sync_committee = load_bootstrap(4612096).build_client_sync_committee().data;
+    let pubkeys = sync_committee.decompress_all_pubkeys().unwrap();
+    let pubkeys = &pubkeys[..config.sync_committee_size];
+
+    // All members participated, without any padding bits.
+    let mut bits = [0u8; 64];
+    bits[..4].copy_from_slice(&[0xff; 4]);
+    let sync_aggregate = core::SyncAggregate {
+        sync_committee_bits: core::SyncCommitteeBits::from_slice(&bits),
+        sync_committee_signature: [0u8; 96].into(),
+    };
+    assert_eq!(sync_aggregate.count_participants(32), Some(32));
+    assert!(sync_aggregate.has_supermajority_with_config(&config));
+    assert_eq!(
+        sync_aggregate.aggregation_strategy_with_config(&config),
+        AggregationStrategy::Subtraction
+    );
+
+    // Only two members participated, and the padding bits are set to forge a supermajority.
+    let mut bits = [0xffu8; 64];
+    bits[..4].copy_from_slice(&[0b11, 0, 0, 0]);
+    let sync_aggregate = core::SyncAggregate {
+        sync_committee_bits: core::SyncCommitteeBits::from_slice(&bits),
+        sync_committee_signature: [0u8; 96].into(),
+    };
+    assert_eq!(sync_aggregate.count_participants(32), None);
+    assert!(!sync_aggregate.has_supermajority_with_config(&config));
+    assert_eq!(
+        sync_aggregate.aggregation_strategy_with_config(&config),
+        AggregationStrategy::Addition
+    );
+    let result = sync_aggregate.fast_aggregate_verify(pubkeys, Default::default());
+    assert!(matches!(result, Ok(false)));
+}
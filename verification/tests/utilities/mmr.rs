@@ -1,7 +1,10 @@
 use std::fs::read_to_string;
 
 use eth2_types::BeaconBlockHeader;
-use eth_light_client_in_ckb_verification::types::core;
+use eth_light_client_in_ckb_verification::{
+    types::{core, packed, prelude::*},
+    utilities::mmr,
+};
 use tree_hash::Hash256;
 
 use crate::find_json_files;
@@ -31,6 +34,86 @@ fn test_header_root_case_5() {
     test_header_root(5);
 }
 
+#[test]
+fn test_empty_header_roots_in_compact_headers() {
+    let first_slot = 6632737;
+    let headers = core::HeaderRange::Compact(core::CompactHeaders {
+        first_slot,
+        items: vec![core::CompactHeaderItem::EmptySlots(40)],
+    });
+    let cached_headers = headers
+        .calc_cache(Hash256::zero(), first_slot, first_slot + 39)
+        .unwrap_or_else(|_| panic!("failed to rebuild empty headers"));
+    assert_eq!(cached_headers.len(), 40);
+    for (offset, cached_header) in cached_headers.into_iter().enumerate() {
+        let header = core::Header {
+            slot: first_slot + offset as u64,
+            proposer_index: 0,
+            parent_root: Hash256::zero(),
+            state_root: Hash256::zero(),
+            body_root: Hash256::zero(),
+        };
+        assert_eq!(cached_header.root, header.calc_cache().root);
+    }
+}
+
+#[test]
+fn test_calculate_incremental_root() {
+    let digests = (0..64u64)
+        .map(|index| {
+            core::HeaderDigest {
+                children_hash: Hash256::from_low_u64_be(index + 1),
+            }
+            .pack()
+        })
+        .collect::<Vec<_>>();
+    for prev_leaves_count in 1..40 {
+        for incremental_count in 1..(digests.len() as u64 - prev_leaves_count) {
+            let store = mmr::lib::util::MemStore::default();
+            let mut mmr = mmr::ClientRootMMR::new(0, &store);
+            for digest in &digests[..prev_leaves_count as usize] {
+                mmr.push(digest.clone()).unwrap();
+            }
+            let prev_root = mmr.get_root().unwrap();
+            let leaves_count = prev_leaves_count + incremental_count;
+            let incremental = digests[prev_leaves_count as usize..leaves_count as usize].to_vec();
+            let positions = incremental
+                .iter()
+                .map(|digest| mmr.push(digest.clone()).unwrap())
+                .collect::<Vec<_>>();
+            let root = mmr.get_root().unwrap();
+            let proof = mmr.gen_proof(positions).unwrap();
+
+            let result =
+                proof.verify_incremental(root.clone(), prev_root.clone(), incremental.clone());
+            assert!(result.unwrap());
+            let result = mmr::calculate_incremental_root(
+                &proof,
+                &prev_root,
+                prev_leaves_count,
+                incremental.clone(),
+            );
+            assert_eq!(
+                result.unwrap().map(|root| root.as_slice().to_vec()),
+                Some(root.as_slice().to_vec()),
+                "failed to calculate the incremental root for {incremental_count} leaves \
+                after {prev_leaves_count} leaves"
+            );
+
+            let wrong_prev_root = packed::HeaderDigest::new_builder()
+                .children_hash(Hash256::repeat_byte(1).pack())
+                .build();
+            let result = mmr::calculate_incremental_root(
+                &proof,
+                &wrong_prev_root,
+                prev_leaves_count,
+                incremental,
+            );
+            assert!(matches!(result, Ok(None)));
+        }
+    }
+}
+
 fn test_header_root(case_id: usize) {
     let case_dir = format!("mainnet/case-{}/beacon", case_id);
 
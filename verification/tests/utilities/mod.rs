@@ -1,3 +1,4 @@
+mod bls;
 mod mmr;
 mod mpt;
 mod ssz;
@@ -1,12 +1,14 @@
+use eth_light_client_in_ckb_prover::{SszNode, SszPathError};
 use eth_light_client_in_ckb_verification::{
-    consensus_specs::helpers,
+    consensus_specs::{forks, helpers},
     utilities::ssz::{
-        ceil_depth, floor_depth, is_valid_merkle_branch, length_hash, verify_merkle_proof,
+        ceil_depth, concat_generalized_indices, floor_depth, get_generalized_index_of_list_item,
+        is_valid_merkle_branch, length_hash, verify_merkle_proof, ContainerLayout,
     },
 };
 use merkle_proof::MerkleTree;
 use ssz_derive::{Decode, Encode};
-use ssz_types::{typenum, VariableList};
+use ssz_types::{typenum, FixedVector, VariableList};
 use tree_hash::{Hash256, TreeHash};
 use tree_hash_derive::TreeHash;
 
@@ -87,6 +89,17 @@ impl Demo {
     }
 }
 
+impl Demo {
+    fn ssz_node(&self) -> SszNode {
+        let d_nodes = self.c.iter().map(SszNode::leaf).collect::<Vec<_>>();
+        SszNode::container(vec![
+            ("a", SszNode::leaf(&self.a)),
+            ("b", SszNode::leaf(&self.b)),
+            ("c", SszNode::list(d_nodes, C::max_len())),
+        ])
+    }
+}
+
 impl DemoCache {
     fn c_data_root(&self) -> Hash256 {
         self.c_data_root
@@ -211,3 +224,198 @@ macro_rules! is_valid_merkle_branch_wrapper {
 
 create_test_for_verify!(test_verify_merkle_proof, verify_merkle_proof_wrapper);
 create_test_for_verify!(test_is_valid_merkle_branch, is_valid_merkle_branch_wrapper);
+
+#[test]
+fn test_generate_proof_by_path() {
+    let d_vec = (0u8..=10)
+        .map(|i| VariableList::from(vec![i, i + 1, i * 2]))
+        .collect::<Vec<_>>();
+    let d_vec_len = d_vec.len();
+    let demo = Demo {
+        a: 1,
+        b: 2,
+        c: VariableList::from(d_vec),
+    };
+    let node = demo.ssz_node();
+    let cache = demo.generate_cache();
+    assert_eq!(node.root(), cache.root());
+    assert_eq!(node.node("c").unwrap().root(), cache.c_root());
+    assert_eq!(
+        node.node("c").unwrap().data_root(),
+        Some(cache.c_data_root())
+    );
+
+    for index in 0..d_vec_len {
+        let proof = node.generate_proof(&format!("c[{index}]")).unwrap();
+        assert_eq!(proof.leaf, cache.d_roots[index]);
+        assert_eq!(proof.branch, cache.generate_d_proof(index));
+        assert!(verify_merkle_proof(
+            &cache.root(),
+            &proof.leaf,
+            &proof.branch,
+            proof.generalized_index
+        ));
+
+        let proof = node
+            .node("c")
+            .and_then(|c| c.generate_proof(&format!("[{index}]")))
+            .unwrap();
+        assert_eq!(proof.branch, cache.generate_d_proof_for_c(index));
+        assert_eq!(
+            proof.generalized_index,
+            2usize.pow(cache.c_depth + 1) + index
+        );
+    }
+
+    let proof = node.generate_proof("b").unwrap();
+    assert_eq!(proof.leaf, cache.b_root);
+    assert_eq!(proof.generalized_index, 5);
+    assert!(verify_merkle_proof(
+        &cache.root(),
+        &proof.leaf,
+        &proof.branch,
+        proof.generalized_index
+    ));
+
+    assert_eq!(
+        node.generate_proof("d").unwrap_err(),
+        SszPathError::UnknownField("d".to_owned())
+    );
+    assert_eq!(
+        node.generate_proof("a[0]").unwrap_err(),
+        SszPathError::NotList(0)
+    );
+    assert_eq!(
+        node.generate_proof("c[11]").unwrap_err(),
+        SszPathError::IndexOutOfBounds {
+            index: 11,
+            length: d_vec_len
+        }
+    );
+    assert!(matches!(
+        node.generate_proof("c[x]"),
+        Err(SszPathError::InvalidPath(_))
+    ));
+}
+
+#[test]
+fn test_generalized_indexes_from_container_layouts() {
+    const LAYOUT: ContainerLayout = ContainerLayout::new(&["a", "b", "c"]);
+    assert_eq!(LAYOUT.fields_count(), 3);
+    assert_eq!(LAYOUT.depth(), 2);
+    assert_eq!(LAYOUT.field_index("c"), 2);
+    assert_eq!(LAYOUT.generalized_index("a"), 4);
+    assert_eq!(LAYOUT.generalized_index("c"), 6);
+
+    assert_eq!(get_generalized_index_of_list_item(4, 1), 9);
+    assert_eq!(concat_generalized_indices(&[]), 1);
+    assert_eq!(concat_generalized_indices(&[6, 1]), 6);
+    assert_eq!(concat_generalized_indices(&[6, 9]), 6 * 8 + 1);
+    assert_eq!(concat_generalized_indices(&[2, 3, 5]), 0b10_1_01);
+
+    // Light client indexes are not changed since Altair.
+    {
+        use forks::altair::generalized_index::beacon_state as altair;
+        use forks::bellatrix::generalized_index::beacon_state as bellatrix;
+        use forks::capella::generalized_index::beacon_state as capella;
+        for (finalized_root, current_sync_committee, next_sync_committee) in [
+            (
+                altair::FINALIZED_ROOT_INDEX,
+                altair::CURRENT_SYNC_COMMITTEE_INDEX,
+                altair::NEXT_SYNC_COMMITTEE_INDEX,
+            ),
+            (
+                bellatrix::FINALIZED_ROOT_INDEX,
+                bellatrix::CURRENT_SYNC_COMMITTEE_INDEX,
+                bellatrix::NEXT_SYNC_COMMITTEE_INDEX,
+            ),
+            (
+                capella::FINALIZED_ROOT_INDEX,
+                capella::CURRENT_SYNC_COMMITTEE_INDEX,
+                capella::NEXT_SYNC_COMMITTEE_INDEX,
+            ),
+        ] {
+            assert_eq!(finalized_root, 105);
+            assert_eq!(current_sync_committee, 54);
+            assert_eq!(next_sync_committee, 55);
+        }
+    }
+
+    // Execution payload indexes are same for Bellatrix and Capella.
+    {
+        use forks::bellatrix::generalized_index as bellatrix;
+        use forks::capella::generalized_index as capella;
+        for (
+            receipts_root_in_execution_payload,
+            receipts_root_in_block_body,
+            transaction_in_execution_payload_offset,
+            transaction_in_block_body_offset,
+        ) in [
+            (
+                bellatrix::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD,
+                bellatrix::RECEIPTS_ROOT_IN_BLOCK_BODY,
+                bellatrix::TRANSACTION_IN_EXECUTION_PAYLOAD_OFFSET,
+                bellatrix::TRANSACTION_IN_BLOCK_BODY_OFFSET,
+            ),
+            (
+                capella::RECEIPTS_ROOT_IN_EXECUTION_PAYLOAD,
+                capella::RECEIPTS_ROOT_IN_BLOCK_BODY,
+                capella::TRANSACTION_IN_EXECUTION_PAYLOAD_OFFSET,
+                capella::TRANSACTION_IN_BLOCK_BODY_OFFSET,
+            ),
+        ] {
+            assert_eq!(receipts_root_in_execution_payload, 16 + 3);
+            assert_eq!(receipts_root_in_block_body, 256 + 16 * 9 + 3);
+            assert_eq!(
+                transaction_in_execution_payload_offset,
+                (1 << 25) + (1 << 21) * 13
+            );
+            assert_eq!(
+                transaction_in_block_body_offset,
+                (1 << 29) + (1 << 25) * 9 + (1 << 21) * 13
+            );
+        }
+    }
+}
+
+#[test]
+fn test_generate_proof_for_vectors_and_basic_lists() {
+    let roots = (0u8..8)
+        .map(|i| Hash256::repeat_byte(i))
+        .collect::<Vec<_>>();
+    let vector: FixedVector<Hash256, typenum::U8> = FixedVector::from(roots.clone());
+    let node = SszNode::vector(roots.iter().map(SszNode::leaf).collect());
+    assert_eq!(node.root(), vector.tree_hash_root());
+    for (index, root) in roots.iter().enumerate() {
+        let proof = node.generate_proof(&format!("[{index}]")).unwrap();
+        assert_eq!(proof.leaf, *root);
+        assert_eq!(proof.generalized_index, 8 + index);
+        assert!(verify_merkle_proof(
+            &node.root(),
+            &proof.leaf,
+            &proof.branch,
+            proof.generalized_index
+        ));
+    }
+
+    let balances = (0u64..11).map(|i| i * 1_000_000_007).collect::<Vec<_>>();
+    let list: VariableList<u64, typenum::U64> = VariableList::from(balances.clone());
+    let node = SszNode::basic_list(&balances, 64);
+    assert_eq!(node.root(), list.tree_hash_root());
+    assert_eq!(node.items_count(), Some(balances.len()));
+    // 4 balances are packed into a chunk.
+    for chunk_index in 0..3 {
+        let proof = node.generate_proof(&format!("[{chunk_index}]")).unwrap();
+        assert_eq!(proof.generalized_index, 32 + chunk_index);
+        assert_eq!(
+            &proof.leaf.as_bytes()[..8],
+            &balances[chunk_index * 4].to_le_bytes()
+        );
+        assert!(verify_merkle_proof(
+            &node.root(),
+            &proof.leaf,
+            &proof.branch,
+            proof.generalized_index
+        ));
+    }
+}